@@ -0,0 +1,169 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::error::BackendError;
+use crate::types::NodeVersion;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const XZ_MAGIC: &[u8] = &[0xfd, b'7', b'z', b'X', b'Z', 0x00];
+const ZIP_MAGIC: &[u8] = &[b'P', b'K', 0x03, 0x04];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    TarGz,
+    TarXz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    fn from_file_name(name: &str) -> Option<(Self, &str)> {
+        [
+            (".tar.gz", Self::TarGz),
+            (".tgz", Self::TarGz),
+            (".tar.xz", Self::TarXz),
+            (".zip", Self::Zip),
+        ]
+        .into_iter()
+        .find_map(|(extension, format)| name.strip_suffix(extension).map(|stem| (format, stem)))
+    }
+
+    fn magic(self) -> &'static [u8] {
+        match self {
+            Self::TarGz => GZIP_MAGIC,
+            Self::TarXz => XZ_MAGIC,
+            Self::Zip => ZIP_MAGIC,
+        }
+    }
+}
+
+/// A local Node.js distribution archive such as `node-v22.11.0-linux-x64.tar.xz`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeArchive {
+    pub path: PathBuf,
+    pub version: NodeVersion,
+    pub format: ArchiveFormat,
+    /// Top-level directory inside the archive, e.g. `node-v22.11.0-linux-x64`.
+    pub root_dir: String,
+}
+
+impl NodeArchive {
+    /// Check that `path` looks like an official Node.js distribution archive.
+    ///
+    /// The file name must follow the `node-v<version>-<platform>-<arch>` naming
+    /// used on nodejs.org and the file contents must start with the matching
+    /// archive signature.
+    ///
+    /// # Errors
+    /// Returns [`BackendError::InvalidArchive`] when the name or contents do not
+    /// match, or an IO error if the file cannot be read.
+    pub fn inspect(path: &Path) -> Result<Self, BackendError> {
+        let (format, root_dir, version) = parse_archive_file_name(path)?;
+
+        let mut header = [0u8; 6];
+        let mut file = std::fs::File::open(path)?;
+        let read = file.read(&mut header)?;
+        if !header[..read].starts_with(format.magic()) {
+            return Err(BackendError::invalid_archive(
+                "file contents do not match its extension",
+            ));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            version,
+            format,
+            root_dir: root_dir.to_string(),
+        })
+    }
+}
+
+fn parse_archive_file_name(
+    path: &Path,
+) -> Result<(ArchiveFormat, &str, NodeVersion), BackendError> {
+    let name = path
+        .file_name()
+        .and_then(std::ffi::OsStr::to_str)
+        .ok_or_else(|| BackendError::invalid_archive("missing file name"))?;
+
+    let (format, stem) = ArchiveFormat::from_file_name(name)
+        .ok_or_else(|| BackendError::invalid_archive("expected a .tar.gz, .tar.xz or .zip file"))?;
+
+    let rest = stem
+        .strip_prefix("node-v")
+        .ok_or_else(|| BackendError::invalid_archive("file name must start with \"node-v\""))?;
+    let (version, platform) = rest.split_once('-').ok_or_else(|| {
+        BackendError::invalid_archive("file name does not contain a platform and architecture")
+    })?;
+    let version = version
+        .parse::<NodeVersion>()
+        .map_err(|_| BackendError::invalid_archive("file name does not contain a version"))?;
+    if !platform.contains('-') {
+        return Err(BackendError::invalid_archive(
+            "file name does not contain a platform and architecture",
+        ));
+    }
+
+    Ok((format, stem, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ArchiveFormat, NodeArchive, parse_archive_file_name};
+    use crate::error::BackendError;
+    use crate::types::NodeVersion;
+
+    #[test]
+    fn parses_official_archive_names() {
+        let (format, root, version) =
+            parse_archive_file_name(Path::new("/tmp/node-v22.11.0-linux-x64.tar.xz"))
+                .expect("valid archive name");
+        assert_eq!(format, ArchiveFormat::TarXz);
+        assert_eq!(root, "node-v22.11.0-linux-x64");
+        assert_eq!(version, NodeVersion::new(22, 11, 0));
+
+        let (format, _, _) =
+            parse_archive_file_name(Path::new("node-v20.1.0-win-x64.zip")).expect("valid zip name");
+        assert_eq!(format, ArchiveFormat::Zip);
+    }
+
+    #[test]
+    fn rejects_unrelated_file_names() {
+        for name in [
+            "node-v22.11.0.tar.gz",
+            "npm-v10.0.0-linux-x64.tar.gz",
+            "node-vlatest-linux-x64.tar.gz",
+            "node-v22.11.0-linux-x64.rar",
+        ] {
+            assert!(
+                matches!(
+                    parse_archive_file_name(Path::new(name)),
+                    Err(BackendError::InvalidArchive { .. })
+                ),
+                "{name} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn inspect_checks_archive_signature() {
+        let dir = std::env::temp_dir().join(format!("versi-archive-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+
+        let valid = dir.join("node-v22.11.0-darwin-arm64.tar.gz");
+        std::fs::write(&valid, [0x1f, 0x8b, 0x08, 0x00]).expect("write gzip header");
+        let archive = NodeArchive::inspect(&valid).expect("gzip archive should be accepted");
+        assert_eq!(archive.version, NodeVersion::new(22, 11, 0));
+        assert_eq!(archive.root_dir, "node-v22.11.0-darwin-arm64");
+
+        let mislabeled = dir.join("node-v22.11.0-linux-x64.tar.xz");
+        std::fs::write(&mislabeled, b"not an archive").expect("write text file");
+        assert!(matches!(
+            NodeArchive::inspect(&mislabeled),
+            Err(BackendError::InvalidArchive { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
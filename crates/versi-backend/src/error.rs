@@ -17,6 +17,9 @@ pub enum BackendError {
         details: String,
     },
 
+    #[error("Installation failed during extract: {0}")]
+    ExtractFailed(#[from] versi_core::ArchiveExtractError),
+
     #[error("Network error during {operation} ({stage}): {details}")]
     NetworkError {
         operation: &'static str,
@@ -42,6 +45,9 @@ pub enum BackendError {
         details: String,
     },
 
+    #[error("Not a Node.js distribution archive: {reason}")]
+    InvalidArchive { reason: String },
+
     #[error("Timeout waiting for command")]
     Timeout,
 }
@@ -62,6 +68,12 @@ impl BackendError {
         }
     }
 
+    pub fn invalid_archive(reason: impl Into<String>) -> Self {
+        Self::InvalidArchive {
+            reason: reason.into(),
+        }
+    }
+
     pub fn network_request(operation: &'static str, details: impl Into<String>) -> Self {
         Self::NetworkError {
             operation,
@@ -114,6 +126,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extract_failure_keeps_archive_error_as_source() {
+        let cause = versi_core::ArchiveExtractError::MissingRoot("node-v20.11.0-linux-x64".into());
+        let error = BackendError::from(cause.clone());
+
+        assert_eq!(error, BackendError::ExtractFailed(cause));
+        let source = std::error::Error::source(&error).expect("extract error should have a source");
+        assert!(source.is::<versi_core::ArchiveExtractError>());
+    }
+
     #[test]
     fn command_failed_display_includes_stderr() {
         let error = BackendError::CommandFailed {
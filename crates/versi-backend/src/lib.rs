@@ -7,19 +7,28 @@
 //! - `BackendProvider`: detection, install, update checks, and manager creation.
 //! - `VersionManager`: operational backend API (list/install/uninstall/default).
 //! - Shared types: version models and grouping helpers used by the GUI layer.
+//! - `NodeArchive`: validation of local Node.js distribution archives.
+//! - `verify_node_install`: checksum verification shared by the backends.
 
+mod archive;
 mod error;
 mod traits;
 mod types;
+mod verify;
 
+/// Local Node.js distribution archive validation.
+pub use archive::{ArchiveFormat, NodeArchive};
 /// Error type shared by backend providers and managers.
 pub use error::{BackendError, NetworkStage};
 /// Backend traits and capability metadata used by the application.
 pub use traits::{
     BackendDetection, BackendInfo, BackendProvider, BackendUpdate, ManagerCapabilities,
-    ShellInitOptions, VersionManager,
+    ShellInitOptions, ShellOptionSupport, VersionManager,
 };
 /// Version and grouping models shared across backend implementations.
 pub use types::{
-    InstalledVersion, NodeVersion, RemoteVersion, VersionComponent, VersionGroup, VersionParseError,
+    InstalledVersion, NodeVersion, RemoteVersion, VersionAlias, VersionComponent, VersionGroup,
+    VersionParseError,
 };
+/// Checksum verification of installed versions.
+pub use verify::verify_node_install;
@@ -1,10 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
 use crate::error::BackendError;
-use crate::types::{InstalledVersion, NodeVersion, RemoteVersion};
+use crate::types::{InstalledVersion, NodeVersion, RemoteVersion, VersionAlias};
 
 #[derive(Debug, Clone)]
 pub struct BackendDetection {
@@ -28,8 +28,12 @@ pub trait BackendProvider: Send + Sync {
     fn display_name(&self) -> &'static str;
     fn shell_config_marker(&self) -> &str;
     fn shell_config_label(&self) -> &str;
+    /// Shell command that installs `version` with this backend.
+    fn install_command_hint(&self, version: &str) -> String;
+    /// Shell command that makes `version` the default with this backend.
+    fn default_command_hint(&self, version: &str) -> String;
     async fn detect(&self) -> BackendDetection;
-    async fn install_backend(&self) -> Result<(), BackendError>;
+    async fn install_backend(&self, retry_delays_secs: &[u64]) -> Result<(), BackendError>;
     async fn check_for_update(
         &self,
         client: &reqwest::Client,
@@ -54,9 +58,37 @@ pub struct ManagerCapabilities {
     pub supports_lts_filter: bool,
     pub supports_use_version: bool,
     pub supports_shell_integration: bool,
-    pub supports_auto_switch: bool,
-    pub supports_corepack: bool,
-    pub supports_resolve_engines: bool,
+    pub shell_options: ShellOptionSupport,
+    pub supports_archive_install: bool,
+    /// Whether [`VersionManager::set_alias`] and
+    /// [`VersionManager::remove_alias`] are implemented.
+    pub supports_aliases: bool,
+}
+
+/// Which [`ShellInitOptions`] a backend understands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShellOptionSupport {
+    pub auto_switch: bool,
+    pub resolve_engines: bool,
+    pub corepack: bool,
+}
+
+impl ShellOptionSupport {
+    pub const ALL: Self = Self {
+        auto_switch: true,
+        resolve_engines: true,
+        corepack: true,
+    };
+    pub const NONE: Self = Self {
+        auto_switch: false,
+        resolve_engines: false,
+        corepack: false,
+    };
+
+    #[must_use]
+    pub fn any(self) -> bool {
+        self.auto_switch || self.resolve_engines || self.corepack
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +135,78 @@ pub trait VersionManager: Send + Sync {
         })
     }
 
+    /// Install a version from a local Node.js distribution archive.
+    ///
+    /// Callers should validate the file with [`crate::NodeArchive::inspect`]
+    /// first so obviously wrong files are rejected before touching the backend.
+    async fn install_from_archive(&self, _archive: &Path) -> Result<(), BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "install_from_archive",
+        })
+    }
+
+    /// Check an installed version against the checksums published by the
+    /// Node.js distribution. Returns `Ok(false)` when the files do not match.
+    async fn verify_installed(
+        &self,
+        _client: &reqwest::Client,
+        _version: &NodeVersion,
+    ) -> Result<bool, BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "verify_installed",
+        })
+    }
+
+    async fn list_aliases(&self) -> Result<Vec<VersionAlias>, BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "list_aliases",
+        })
+    }
+
+    async fn set_alias(&self, _name: &str, _version: &str) -> Result<(), BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "set_alias",
+        })
+    }
+
+    async fn remove_alias(&self, _name: &str) -> Result<(), BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "remove_alias",
+        })
+    }
+
+    /// Run `corepack enable` (or `disable`) with an installed version, which
+    /// adds or removes the `yarn` and `pnpm` shims next to its `node`.
+    async fn set_corepack(&self, _version: &str, _enabled: bool) -> Result<(), BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "set_corepack",
+        })
+    }
+
+    /// Check that `node --version` runs for an installed version.
+    async fn node_runs(&self, _version: &NodeVersion) -> Result<bool, BackendError> {
+        Err(BackendError::Unsupported {
+            operation: "node_runs",
+        })
+    }
+
+    /// Return the installed versions whose `node` binary no longer runs.
+    ///
+    /// This spawns a process per version, so it is kept out of
+    /// [`VersionManager::list_installed`] and run separately afterwards.
+    async fn validate_installed(
+        &self,
+        versions: &[NodeVersion],
+    ) -> Result<Vec<NodeVersion>, BackendError> {
+        let mut broken = Vec::new();
+        for version in versions {
+            if !self.node_runs(version).await? {
+                broken.push(version.clone());
+            }
+        }
+        Ok(broken)
+    }
+
     async fn list_remote_lts(&self) -> Result<Vec<RemoteVersion>, BackendError> {
         let all = self.list_remote().await?;
         Ok(all
@@ -112,6 +216,12 @@ pub trait VersionManager: Send + Sync {
     }
 
     fn shell_init_command(&self, shell: &str, options: &ShellInitOptions) -> Option<String>;
+
+    /// Program and arguments that start `shell` with `version` active, for
+    /// opening a terminal on that version. `None` when the backend can't.
+    fn version_shell_command(&self, _version: &str, _shell: &str) -> Option<Vec<String>> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -125,6 +235,7 @@ mod tests {
     struct MockManager {
         info: BackendInfo,
         remote: Vec<RemoteVersion>,
+        broken: Vec<NodeVersion>,
     }
 
     impl MockManager {
@@ -138,6 +249,7 @@ mod tests {
                     in_path: true,
                 },
                 remote,
+                broken: Vec::new(),
             }
         }
     }
@@ -184,6 +296,10 @@ mod tests {
             Ok(())
         }
 
+        async fn node_runs(&self, version: &NodeVersion) -> Result<bool, BackendError> {
+            Ok(!self.broken.contains(version))
+        }
+
         fn shell_init_command(&self, _shell: &str, _options: &ShellInitOptions) -> Option<String> {
             None
         }
@@ -214,6 +330,21 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn default_install_from_archive_returns_unsupported() {
+        let manager = MockManager::new(Vec::new());
+        let result = manager
+            .install_from_archive(std::path::Path::new("/tmp/node-v20.0.0-linux-x64.tar.xz"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(BackendError::Unsupported {
+                operation: "install_from_archive"
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn list_remote_lts_filters_non_lts_versions() {
         let manager = MockManager::new(vec![
@@ -251,4 +382,17 @@ mod tests {
         assert_eq!(remote_versions.len(), 1);
         assert_eq!(remote_versions[0].version.to_string(), "v20.1.0");
     }
+
+    #[tokio::test]
+    async fn validate_installed_reports_versions_that_do_not_run() {
+        let mut manager = MockManager::new(Vec::new());
+        manager.broken = vec![NodeVersion::new(18, 19, 0)];
+
+        let broken = manager
+            .validate_installed(&[NodeVersion::new(20, 11, 0), NodeVersion::new(18, 19, 0)])
+            .await
+            .expect("mock validation should succeed");
+
+        assert_eq!(broken, vec![NodeVersion::new(18, 19, 0)]);
+    }
 }
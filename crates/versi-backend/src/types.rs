@@ -149,6 +149,13 @@ pub struct RemoteVersion {
     pub is_latest: bool,
 }
 
+/// A named alias such as `default` or `project` pointing at an installed version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionAlias {
+    pub name: String,
+    pub version: NodeVersion,
+}
+
 #[derive(Debug, Clone)]
 pub struct VersionGroup {
     pub major: u32,
@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use log::warn;
+use versi_core::{ChecksumError, NodeVerification};
+
+use crate::{BackendError, NodeVersion};
+
+/// Compare the files in `install_dir` with the checksums published for
+/// `version` under `dist_url`.
+///
+/// Returns `Ok(false)` when a file doesn't match.
+///
+/// # Errors
+/// Returns [`BackendError::Unsupported`] when there are no published
+/// checksums for this platform, and a network or backend error when they
+/// can't be downloaded or the install can't be read.
+pub async fn verify_node_install(
+    client: &reqwest::Client,
+    dist_url: &str,
+    version: &NodeVersion,
+    install_dir: &Path,
+) -> Result<bool, BackendError> {
+    versi_core::verify_node_install(client, dist_url, &version.to_string(), install_dir)
+        .await
+        .map(|verification| verification_passed(version, verification))
+        .map_err(verification_error)
+}
+
+fn verification_error(error: ChecksumError) -> BackendError {
+    match error {
+        ChecksumError::UnsupportedPlatform => BackendError::Unsupported {
+            operation: "verify_installed",
+        },
+        error @ (ChecksumError::Http { .. } | ChecksumError::Status { .. }) => {
+            BackendError::network_request("verify_installed", error.to_string())
+        }
+        error => BackendError::BackendSpecific {
+            context: "verify_installed",
+            details: error.to_string(),
+        },
+    }
+}
+
+fn verification_passed(version: &NodeVersion, verification: NodeVerification) -> bool {
+    match verification {
+        NodeVerification::Verified => true,
+        NodeVerification::Mismatch { file } => {
+            warn!("Checksum mismatch for {version}: {file}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use versi_core::{ChecksumError, NodeVerification};
+
+    use super::{verification_error, verification_passed};
+    use crate::{BackendError, NodeVersion};
+
+    #[test]
+    fn unsupported_platform_maps_to_unsupported_operation() {
+        assert!(matches!(
+            verification_error(ChecksumError::UnsupportedPlatform),
+            BackendError::Unsupported {
+                operation: "verify_installed"
+            }
+        ));
+        assert!(matches!(
+            verification_error(ChecksumError::MissingEntry(
+                "node-v20.11.0.tar.gz".to_string()
+            )),
+            BackendError::BackendSpecific {
+                context: "verify_installed",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn mismatch_fails_verification() {
+        let version = NodeVersion::new(20, 11, 0);
+
+        assert!(verification_passed(&version, NodeVerification::Verified));
+        assert!(!verification_passed(
+            &version,
+            NodeVerification::Mismatch {
+                file: "bin/node".to_string()
+            }
+        ));
+    }
+}
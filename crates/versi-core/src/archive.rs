@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use log::{debug, info};
+use thiserror::Error;
+use tokio::process::Command;
+
+use crate::commands::HideWindow;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ArchiveExtractError {
+    #[error("{} already exists", .0.display())]
+    DestinationExists(PathBuf),
+    #[error("failed to prepare extraction directory: {message}")]
+    Io {
+        kind: std::io::ErrorKind,
+        message: String,
+    },
+    #[error("tar exited with {status}: {stderr}")]
+    Tar { status: String, stderr: String },
+    #[error("archive does not contain the expected {0} directory")]
+    MissingRoot(String),
+}
+
+impl From<std::io::Error> for ArchiveExtractError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io {
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Extract `archive` and move its top-level `root_dir` to `dest`.
+///
+/// Extraction happens in a temporary directory next to `dest` so a failed or
+/// partial extraction never leaves a half-populated version directory behind.
+/// The system `tar` is used, which handles `.tar.gz`, `.tar.xz` and (on
+/// Windows 10+ and macOS) `.zip` archives.
+///
+/// # Errors
+/// Returns an error if `dest` already exists, `tar` fails, or the archive does
+/// not contain `root_dir`.
+pub async fn extract_archive_dir(
+    archive: &Path,
+    root_dir: &str,
+    dest: &Path,
+) -> Result<(), ArchiveExtractError> {
+    if tokio::fs::try_exists(dest).await? {
+        return Err(ArchiveExtractError::DestinationExists(dest.to_path_buf()));
+    }
+
+    let parent = dest.parent().unwrap_or(dest);
+    tokio::fs::create_dir_all(parent).await?;
+    let staging = tempfile::Builder::new()
+        .prefix(".versi-extract-")
+        .tempdir_in(parent)?;
+
+    info!(
+        "Extracting {} into {}",
+        archive.display(),
+        staging.path().display()
+    );
+    let mut cmd = Command::new("tar");
+    cmd.arg("-xf").arg(archive).arg("-C").arg(staging.path());
+    cmd.hide_window();
+    let output = cmd.output().await?;
+    if !output.status.success() {
+        return Err(ArchiveExtractError::Tar {
+            status: output.status.to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    let extracted = staging.path().join(root_dir);
+    if !tokio::fs::try_exists(&extracted).await? {
+        return Err(ArchiveExtractError::MissingRoot(root_dir.to_string()));
+    }
+
+    debug!("Moving {} to {}", extracted.display(), dest.display());
+    tokio::fs::rename(&extracted, dest).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArchiveExtractError, extract_archive_dir};
+
+    #[tokio::test]
+    async fn refuses_to_overwrite_existing_destination() {
+        let temp = tempfile::tempdir().expect("tempdir should be created");
+        let dest = temp.path().join("v22.11.0");
+        std::fs::create_dir_all(&dest).expect("create existing version dir");
+
+        let result = extract_archive_dir(
+            &temp.path().join("node-v22.11.0-linux-x64.tar.xz"),
+            "node-v22.11.0-linux-x64",
+            &dest,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ArchiveExtractError::DestinationExists(path)) if path == dest
+        ));
+    }
+}
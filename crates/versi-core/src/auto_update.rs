@@ -1,11 +1,10 @@
-use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use log::{debug, info, warn};
-use sha2::{Digest, Sha256};
 use thiserror::Error;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
+use versi_platform::BYTES_PER_MB;
 
 #[derive(Debug, Clone)]
 pub enum UpdateProgress {
@@ -47,6 +46,17 @@ pub enum AutoUpdateError {
         context: &'static str,
         details: String,
     },
+    #[error(
+        "not enough free space in {}: {} MB needed, {} MB available",
+        dir.display(),
+        needed.div_ceil(BYTES_PER_MB),
+        available / BYTES_PER_MB
+    )]
+    InsufficientSpace {
+        dir: PathBuf,
+        needed: u64,
+        available: u64,
+    },
     #[error("{0}")]
     Invalid(String),
 }
@@ -78,24 +88,53 @@ impl AutoUpdateError {
 
 /// Download and apply a packaged Versi update.
 ///
+/// The download goes to `download_dir` when set, otherwise the app cache.
+/// When `download_size` is known, the directory is checked for enough free
+/// space before anything is fetched.
+///
+/// Where the platform allows it, the installed binary must report
+/// `expected_version` before the update is kept.
+///
+/// Dropping the future before it completes removes anything downloaded so
+/// far, so aborting the task is a clean way to cancel.
+///
 /// # Errors
-/// Returns an error when downloading, extracting, or applying the update fails.
+/// Returns an error when the download directory is unusable or too full, or
+/// when downloading, extracting, or applying the update fails.
 pub async fn download_and_apply(
     client: &reqwest::Client,
     download_url: &str,
     expected_sha256: Option<&str>,
+    expected_version: &str,
+    download_size: Option<u64>,
+    download_dir: Option<&Path>,
     progress: mpsc::Sender<UpdateProgress>,
 ) -> Result<ApplyResult, AutoUpdateError> {
-    let cache_dir = versi_platform::AppPaths::new()
-        .map_err(|error| {
-            AutoUpdateError::platform("failed to resolve app paths", error.to_string())
-        })?
-        .cache_dir;
-    std::fs::create_dir_all(&cache_dir)
-        .map_err(|error| AutoUpdateError::io("failed to create cache directory", error))?;
+    let download_dir = match download_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => {
+            versi_platform::AppPaths::new()
+                .map_err(|error| {
+                    AutoUpdateError::platform("failed to resolve app paths", error.to_string())
+                })?
+                .cache_dir
+        }
+    };
+    std::fs::create_dir_all(&download_dir).map_err(|error| {
+        AutoUpdateError::io_with_path("failed to create download directory", &download_dir, &error)
+    })?;
 
-    let temp_dir = tempfile::tempdir_in(&cache_dir)
-        .map_err(|error| AutoUpdateError::io("failed to create temp directory", error))?;
+    if let Some(size) = download_size {
+        ensure_free_space(
+            &download_dir,
+            required_space(size, is_msi_package(download_url)),
+            versi_platform::available_space,
+        )?;
+    }
+
+    let temp_dir = tempfile::tempdir_in(&download_dir).map_err(|error| {
+        AutoUpdateError::io_with_path("download directory is not writable", &download_dir, &error)
+    })?;
 
     let raw_name = download_url.rsplit('/').next().unwrap_or("update-download");
     let file_name = Path::new(raw_name)
@@ -109,11 +148,7 @@ pub async fn download_and_apply(
     download_file(client, download_url, &download_path, &progress).await?;
     verify_download_checksum(expected_sha256, file_name, &download_path)?;
 
-    let is_msi = Path::new(file_name)
-        .extension()
-        .is_some_and(|ext| ext.eq_ignore_ascii_case("msi"));
-
-    if is_msi {
+    if is_msi_package(file_name) {
         let _ = progress.send(UpdateProgress::Applying).await;
         let _ = temp_dir.keep();
         return apply_msi(&download_path);
@@ -126,7 +161,45 @@ pub async fn download_and_apply(
     extract_zip(&download_path, &extract_dir)?;
 
     let _ = progress.send(UpdateProgress::Applying).await;
-    apply_update(&extract_dir)
+    apply_update(&extract_dir, expected_version).await
+}
+
+fn is_msi_package(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("msi"))
+}
+
+/// Bytes an update needs on disk: the download, plus room to unpack it
+/// unless it's an installer that's run as-is.
+fn required_space(download_size: u64, is_msi: bool) -> u64 {
+    if is_msi {
+        download_size
+    } else {
+        download_size.saturating_mul(3)
+    }
+}
+
+fn ensure_free_space(
+    dir: &Path,
+    needed: u64,
+    available_space: impl Fn(&Path) -> std::io::Result<u64>,
+) -> Result<(), AutoUpdateError> {
+    let available = match available_space(dir) {
+        Ok(available) => available,
+        Err(error) => {
+            warn!("Could not read free space for {}: {error}", dir.display());
+            return Ok(());
+        }
+    };
+    if available < needed {
+        return Err(AutoUpdateError::InsufficientSpace {
+            dir: dir.to_path_buf(),
+            needed,
+            available,
+        });
+    }
+    Ok(())
 }
 
 fn verify_download_checksum(
@@ -153,23 +226,9 @@ fn verify_download_checksum(
 }
 
 fn sha256_file(path: &Path) -> Result<String, AutoUpdateError> {
-    let mut file = std::fs::File::open(path).map_err(|error| {
-        AutoUpdateError::io_with_path("failed to open file for checksum", path, &error)
-    })?;
-    let mut hasher = Sha256::new();
-    let mut buffer = [0_u8; 8192];
-
-    loop {
-        let read = file.read(&mut buffer).map_err(|error| {
-            AutoUpdateError::io_with_path("failed to read file for checksum", path, &error)
-        })?;
-        if read == 0 {
-            break;
-        }
-        hasher.update(&buffer[..read]);
-    }
-
-    Ok(format!("{:x}", hasher.finalize()))
+    crate::checksum::sha256_file(path).map_err(|error| {
+        AutoUpdateError::io_with_path("failed to read file for checksum", path, &error)
+    })
 }
 
 async fn download_file(
@@ -278,8 +337,13 @@ fn extract_zip(zip_path: &Path, dest: &Path) -> Result<(), AutoUpdateError> {
 }
 
 #[cfg(target_os = "macos")]
-fn apply_update(extract_dir: &Path) -> Result<ApplyResult, AutoUpdateError> {
+async fn apply_update(
+    extract_dir: &Path,
+    expected_version: &str,
+) -> Result<ApplyResult, AutoUpdateError> {
     let new_app = find_app_bundle(extract_dir)?;
+    let exe = std::env::current_exe()
+        .map_err(|error| AutoUpdateError::io("failed to get current executable", error))?;
     let current_bundle = current_app_bundle()?;
     let old_bundle = current_bundle.with_extension("app.old");
 
@@ -312,9 +376,28 @@ fn apply_update(extract_dir: &Path) -> Result<ApplyResult, AutoUpdateError> {
         }
     }
 
-    let _ = std::process::Command::new("xattr")
-        .args(["-cr", &current_bundle.to_string_lossy()])
-        .output();
+    let mut clear_quarantine = tokio::process::Command::new("xattr");
+    clear_quarantine.arg("-cr").arg(&current_bundle);
+    if let Err(error) =
+        versi_platform::output_with_timeout(&mut clear_quarantine, VERSION_PROBE_TIMEOUT).await
+    {
+        warn!("Could not clear extended attributes on the new bundle: {error}");
+    }
+
+    if smoke_test_outcome(run_version_probe(&exe).await.as_ref(), expected_version)
+        == SmokeTestOutcome::Restore
+    {
+        warn!("Updated app failed its smoke test, restoring the previous bundle");
+        let _ = std::fs::remove_dir_all(&current_bundle);
+        std::fs::rename(&old_bundle, &current_bundle).map_err(|error| {
+            AutoUpdateError::io_with_path(
+                "failed to restore previous app bundle",
+                &current_bundle,
+                &error,
+            )
+        })?;
+        return Err(smoke_test_failed(expected_version, true));
+    }
 
     info!("macOS update applied successfully");
     Ok(ApplyResult::RestartRequired)
@@ -398,7 +481,10 @@ fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<(), AutoUpdateError> {
 }
 
 #[cfg(target_os = "linux")]
-fn apply_update(extract_dir: &Path) -> Result<ApplyResult, AutoUpdateError> {
+async fn apply_update(
+    extract_dir: &Path,
+    expected_version: &str,
+) -> Result<ApplyResult, AutoUpdateError> {
     let new_binary = extract_dir.join("versi");
     if !new_binary.exists() {
         return Err(AutoUpdateError::Invalid(
@@ -408,20 +494,89 @@ fn apply_update(extract_dir: &Path) -> Result<ApplyResult, AutoUpdateError> {
 
     let exe = std::env::current_exe()
         .map_err(|error| AutoUpdateError::io("failed to get current executable", error))?;
+    let backup = back_up_binary(&exe, extract_dir);
 
     info!("Replacing binary via self-replace");
     match self_replace::self_replace(&new_binary) {
         Ok(()) => {
             use std::os::unix::fs::PermissionsExt;
             let _ = std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755));
-            info!("Linux update applied successfully");
-            Ok(ApplyResult::RestartRequired)
         }
         Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
             info!("Permission denied, trying pkexec for elevated replacement");
-            apply_update_with_pkexec(&new_binary, &exe)
+            apply_update_with_pkexec(&new_binary, &exe)?;
         }
-        Err(error) => Err(AutoUpdateError::io("failed to replace binary", error)),
+        Err(error) => return Err(AutoUpdateError::io("failed to replace binary", error)),
+    }
+
+    match (
+        smoke_test_outcome(run_version_probe(&exe).await.as_ref(), expected_version),
+        backup,
+    ) {
+        (SmokeTestOutcome::Proceed, _) => {
+            info!("Linux update applied successfully");
+            Ok(ApplyResult::RestartRequired)
+        }
+        (SmokeTestOutcome::Restore, Some(backup)) => {
+            warn!("Updated binary failed its smoke test, restoring the previous one");
+            restore_binary(&backup, &exe)?;
+            Err(smoke_test_failed(expected_version, true))
+        }
+        (SmokeTestOutcome::Restore, None) => Err(smoke_test_failed(expected_version, false)),
+    }
+}
+
+/// Copy the running binary aside before it is replaced. The copy goes next
+/// to it when that directory is writable, otherwise into `scratch_dir`, so
+/// binaries in system locations (replaced through pkexec) can still be
+/// rolled back.
+#[cfg(target_os = "linux")]
+fn back_up_binary(exe: &Path, scratch_dir: &Path) -> Option<PathBuf> {
+    let beside = old_binary_path(exe);
+    match std::fs::copy(exe, &beside) {
+        Ok(_) => return Some(beside),
+        Err(error) => debug!("Could not back up {} beside it: {error}", exe.display()),
+    }
+
+    let scratch = scratch_dir.join(beside.file_name()?);
+    match std::fs::copy(exe, &scratch) {
+        Ok(_) => Some(scratch),
+        Err(error) => {
+            warn!("Could not back up {}: {error}", exe.display());
+            None
+        }
+    }
+}
+
+/// Where the running binary is backed up while an update is applied.
+#[cfg(target_os = "linux")]
+fn old_binary_path(exe: &Path) -> std::path::PathBuf {
+    let mut name = exe.file_name().unwrap_or_default().to_os_string();
+    name.push(".old");
+    exe.with_file_name(name)
+}
+
+#[cfg(target_os = "linux")]
+fn restore_binary(backup: &Path, target: &Path) -> Result<(), AutoUpdateError> {
+    // A backup in the scratch directory may be on another filesystem, where
+    // rename can't reach the target, so fall back to copying it over.
+    let result = std::fs::rename(backup, target).or_else(|error| {
+        if error.kind() == std::io::ErrorKind::PermissionDenied {
+            Err(error)
+        } else {
+            std::fs::copy(backup, target).map(|_| ())
+        }
+    });
+    match result {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::PermissionDenied => {
+            apply_update_with_pkexec(backup, target).map(|_| ())
+        }
+        Err(error) => Err(AutoUpdateError::io_with_path(
+            "failed to restore previous binary",
+            target,
+            &error,
+        )),
     }
 }
 
@@ -457,8 +612,85 @@ fn apply_update_with_pkexec(
     Ok(ApplyResult::RestartRequired)
 }
 
+/// Whether a freshly installed binary is fit to restart into.
+#[cfg(not(target_os = "windows"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SmokeTestOutcome {
+    Proceed,
+    Restore,
+}
+
+/// How long a freshly installed binary gets to answer `--version`.
+#[cfg(not(target_os = "windows"))]
+const VERSION_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Run `<binary> --version`, giving up on binaries that can't be spawned or
+/// don't answer within [`VERSION_PROBE_TIMEOUT`].
+#[cfg(not(target_os = "windows"))]
+async fn run_version_probe(binary: &Path) -> Option<std::process::Output> {
+    let mut command = tokio::process::Command::new(binary);
+    command.arg("--version").stdin(std::process::Stdio::null());
+    versi_platform::output_with_timeout(&mut command, VERSION_PROBE_TIMEOUT)
+        .await
+        .inspect_err(|error| warn!("Could not run {}: {error}", binary.display()))
+        .ok()
+}
+
+/// Keep the new binary only if it runs and reports `expected_version`.
+#[cfg(not(target_os = "windows"))]
+fn smoke_test_outcome(
+    output: Option<&std::process::Output>,
+    expected_version: &str,
+) -> SmokeTestOutcome {
+    match output {
+        Some(output)
+            if output.status.success()
+                && reported_version_matches(
+                    &String::from_utf8_lossy(&output.stdout),
+                    expected_version,
+                ) =>
+        {
+            SmokeTestOutcome::Proceed
+        }
+        _ => SmokeTestOutcome::Restore,
+    }
+}
+
+/// Whether `versi --version` output (e.g. `versi 1.4.0`) names
+/// `expected_version`.
+#[cfg(not(target_os = "windows"))]
+fn reported_version_matches(stdout: &str, expected_version: &str) -> bool {
+    stdout
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().last())
+        .is_some_and(|reported| {
+            !crate::is_newer_version(reported, expected_version)
+                && !crate::is_newer_version(expected_version, reported)
+        })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn smoke_test_failed(expected_version: &str, restored: bool) -> AutoUpdateError {
+    AutoUpdateError::Invalid(if restored {
+        format!(
+            "The updated app did not report version {expected_version}; the previous version was restored"
+        )
+    } else {
+        format!(
+            "The updated app did not report version {expected_version} and no backup was available to restore"
+        )
+    })
+}
+
+// The MSI installer only runs once Versi exits, so there's no binary to
+// smoke-test before restarting on Windows.
 #[cfg(target_os = "windows")]
-fn apply_update(_extract_dir: &Path) -> Result<ApplyResult, AutoUpdateError> {
+#[allow(clippy::unused_async)]
+async fn apply_update(
+    _extract_dir: &Path,
+    _expected_version: &str,
+) -> Result<ApplyResult, AutoUpdateError> {
     unreachable!("Windows uses MSI path, not extract+apply")
 }
 
@@ -492,6 +724,15 @@ pub fn cleanup_old_app_bundle() {
         }
     }
 
+    #[cfg(target_os = "linux")]
+    if let Ok(exe) = std::env::current_exe() {
+        let old = old_binary_path(&exe);
+        if old.exists() {
+            info!("Cleaning up old binary: {}", old.display());
+            let _ = std::fs::remove_file(&old);
+        }
+    }
+
     let Ok(paths) = versi_platform::AppPaths::new() else {
         return;
     };
@@ -615,6 +856,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn free_space_check_rejects_a_full_directory() {
+        use super::{AutoUpdateError, ensure_free_space, required_space};
+
+        let dir = std::path::Path::new("/small/partition");
+        let needed = required_space(40 * 1024 * 1024, false);
+
+        let result = ensure_free_space(dir, needed, |_| Ok(50 * 1024 * 1024));
+        assert!(matches!(
+            result,
+            Err(AutoUpdateError::InsufficientSpace { available, .. })
+                if available == 50 * 1024 * 1024
+        ));
+        assert_eq!(
+            result.expect_err("space check should fail").to_string(),
+            "not enough free space in /small/partition: 120 MB needed, 50 MB available"
+        );
+
+        assert!(ensure_free_space(dir, needed, |_| Ok(needed)).is_ok());
+        assert!(
+            ensure_free_space(dir, needed, |_| Err(std::io::Error::other("unsupported"))).is_ok()
+        );
+    }
+
+    #[test]
+    fn installers_need_no_room_to_unpack() {
+        use super::required_space;
+
+        assert_eq!(required_space(10, true), 10);
+        assert_eq!(required_space(10, false), 30);
+    }
+
     #[test]
     fn sha256_file_returns_known_digest() {
         let temp = tempfile::tempdir().expect("tempdir should be created");
@@ -628,6 +901,70 @@ mod tests {
         );
     }
 
+    #[cfg(not(target_os = "windows"))]
+    fn probe_output(code: i32, stdout: &str) -> std::process::Output {
+        use std::os::unix::process::ExitStatusExt;
+
+        std::process::Output {
+            status: std::process::ExitStatus::from_raw(code << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn smoke_test_proceeds_when_new_binary_reports_expected_version() {
+        use super::{SmokeTestOutcome, smoke_test_outcome};
+
+        let output = probe_output(0, "versi 1.4.0\n");
+        assert_eq!(
+            smoke_test_outcome(Some(&output), "1.4.0"),
+            SmokeTestOutcome::Proceed
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn smoke_test_restores_when_new_binary_fails() {
+        use super::{SmokeTestOutcome, smoke_test_outcome};
+
+        assert_eq!(smoke_test_outcome(None, "1.4.0"), SmokeTestOutcome::Restore);
+        assert_eq!(
+            smoke_test_outcome(Some(&probe_output(1, "versi 1.4.0\n")), "1.4.0"),
+            SmokeTestOutcome::Restore
+        );
+        assert_eq!(
+            smoke_test_outcome(Some(&probe_output(0, "versi 1.3.2\n")), "1.4.0"),
+            SmokeTestOutcome::Restore
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn reported_version_comparison_ignores_prefixes() {
+        use super::reported_version_matches;
+
+        assert!(reported_version_matches("versi 1.4.0\n", "1.4.0"));
+        assert!(reported_version_matches("versi v1.4.0", "1.4.0"));
+        assert!(reported_version_matches(
+            "versi 1.4.0-beta.1",
+            "v1.4.0-beta.1"
+        ));
+        assert!(!reported_version_matches("versi 1.4.0-beta.1", "1.4.0"));
+        assert!(!reported_version_matches("versi 1.3.9", "1.4.0"));
+        assert!(!reported_version_matches("  \n", "1.4.0"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn old_binary_sits_next_to_the_executable() {
+        assert_eq!(
+            super::old_binary_path(std::path::Path::new("/opt/versi/versi")),
+            std::path::PathBuf::from("/opt/versi/versi.old")
+        );
+    }
+
     #[cfg(not(target_os = "windows"))]
     #[test]
     fn apply_msi_reports_unsupported_on_non_windows() {
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::commands::HideWindow;
+
+/// Official Node.js distribution root, used when no mirror is configured.
+pub const DEFAULT_NODE_DIST_URL: &str = "https://nodejs.org/dist";
+
+#[derive(Debug, Error)]
+pub enum ChecksumError {
+    #[error("failed to download {what}: {source}")]
+    Http {
+        what: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("downloading {what} returned {status}")]
+    Status {
+        what: String,
+        status: reqwest::StatusCode,
+    },
+    #[error("{}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("SHASUMS256.txt has no entry for {0}")]
+    MissingEntry(String),
+    #[error("tar exited with {status}: {stderr}")]
+    Tar { status: String, stderr: String },
+    #[error("checksum verification is not available on this platform")]
+    UnsupportedPlatform,
+}
+
+impl ChecksumError {
+    fn io(path: &Path, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+/// Result of comparing an installed version with the published checksums.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeVerification {
+    Verified,
+    /// `file` is the distribution entry whose digest did not match.
+    Mismatch {
+        file: String,
+    },
+}
+
+/// Compute the lowercase hex SHA-256 digest of a file.
+///
+/// # Errors
+/// Returns an error if the file cannot be opened or read.
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0_u8; 8192];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Parse a `SHASUMS256.txt` file into a map of file name to digest.
+pub fn parse_shasums(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (digest, name) = line.trim().split_once(char::is_whitespace)?;
+            let name = name.trim_start().trim_start_matches('*');
+            (!name.is_empty()).then(|| (name.to_string(), digest.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// Verify the Node.js installation in `install_dir` against the checksums
+/// published for `version` under `dist_url`.
+///
+/// On Windows the standalone `node.exe` listed in `SHASUMS256.txt` is compared
+/// directly. Elsewhere the official `.tar.gz` is downloaded, checked against
+/// `SHASUMS256.txt`, and its `bin/node` is compared with the installed one.
+///
+/// # Errors
+/// Returns an error if the checksums or archive cannot be downloaded, a file
+/// cannot be read, or the platform has no published build.
+pub async fn verify_node_install(
+    client: &reqwest::Client,
+    dist_url: &str,
+    version: &str,
+    install_dir: &Path,
+) -> Result<NodeVerification, ChecksumError> {
+    let (os, arch) = platform_target().ok_or(ChecksumError::UnsupportedPlatform)?;
+    let release_url = format!("{}/{version}", dist_url.trim_end_matches('/'));
+
+    let shasums_url = format!("{release_url}/SHASUMS256.txt");
+    let shasums = parse_shasums(&fetch_text(client, &shasums_url).await?);
+    debug!("Fetched {} checksums for {version}", shasums.len());
+
+    if os == "win" {
+        let entry = format!("win-{arch}/node.exe");
+        let expected = shasums
+            .get(&entry)
+            .ok_or_else(|| ChecksumError::MissingEntry(entry.clone()))?;
+        let installed = install_dir.join("node.exe");
+        return compare_digest(&entry, expected, &installed).await;
+    }
+
+    let root = format!("node-{version}-{os}-{arch}");
+    let archive_name = format!("{root}.tar.gz");
+    let expected_archive = shasums
+        .get(&archive_name)
+        .ok_or_else(|| ChecksumError::MissingEntry(archive_name.clone()))?;
+
+    let staging = tempfile::Builder::new()
+        .prefix(".versi-verify-")
+        .tempdir()
+        .map_err(|error| ChecksumError::io(&std::env::temp_dir(), error))?;
+    let archive_path = staging.path().join(&archive_name);
+    download_to(
+        client,
+        &format!("{release_url}/{archive_name}"),
+        &archive_path,
+    )
+    .await?;
+
+    let archive_check = compare_digest(&archive_name, expected_archive, &archive_path).await?;
+    if archive_check != NodeVerification::Verified {
+        warn!("Downloaded {archive_name} does not match SHASUMS256.txt");
+        return Ok(archive_check);
+    }
+
+    let member = format!("{root}/bin/node");
+    extract_member(&archive_path, &member, staging.path()).await?;
+    let reference = sha256_file_blocking(staging.path().join(&member)).await?;
+    let verification =
+        compare_digest("bin/node", &reference, &install_dir.join("bin/node")).await?;
+    if verification == NodeVerification::Verified {
+        info!("Verified {version} installation against {archive_name}");
+    }
+    Ok(verification)
+}
+
+fn platform_target() -> Option<(&'static str, &'static str)> {
+    let os = match std::env::consts::OS {
+        "linux" => "linux",
+        "macos" => "darwin",
+        "windows" => "win",
+        _ => return None,
+    };
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "x64",
+        "aarch64" => "arm64",
+        "x86" if os == "win" => "x86",
+        _ => return None,
+    };
+    Some((os, arch))
+}
+
+async fn compare_digest(
+    entry: &str,
+    expected: &str,
+    path: &Path,
+) -> Result<NodeVerification, ChecksumError> {
+    let actual = sha256_file_blocking(path.to_path_buf()).await?;
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(NodeVerification::Verified)
+    } else {
+        Ok(NodeVerification::Mismatch {
+            file: entry.to_string(),
+        })
+    }
+}
+
+async fn sha256_file_blocking(path: PathBuf) -> Result<String, ChecksumError> {
+    let hashed = path.clone();
+    tokio::task::spawn_blocking(move || {
+        sha256_file(&hashed).map_err(|error| ChecksumError::io(&hashed, error))
+    })
+    .await
+    .map_err(|error| ChecksumError::io(&path, std::io::Error::other(error)))?
+}
+
+async fn fetch_text(client: &reqwest::Client, url: &str) -> Result<String, ChecksumError> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|source| ChecksumError::Http {
+            what: url.to_string(),
+            source,
+        })?;
+    if !response.status().is_success() {
+        return Err(ChecksumError::Status {
+            what: url.to_string(),
+            status: response.status(),
+        });
+    }
+    response.text().await.map_err(|source| ChecksumError::Http {
+        what: url.to_string(),
+        source,
+    })
+}
+
+async fn download_to(
+    client: &reqwest::Client,
+    url: &str,
+    dest: &Path,
+) -> Result<(), ChecksumError> {
+    use futures_util::StreamExt;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|source| ChecksumError::Http {
+            what: url.to_string(),
+            source,
+        })?;
+    if !response.status().is_success() {
+        return Err(ChecksumError::Status {
+            what: url.to_string(),
+            status: response.status(),
+        });
+    }
+
+    let mut file = tokio::fs::File::create(dest)
+        .await
+        .map_err(|error| ChecksumError::io(dest, error))?;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|source| ChecksumError::Http {
+            what: url.to_string(),
+            source,
+        })?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|error| ChecksumError::io(dest, error))?;
+    }
+    file.flush()
+        .await
+        .map_err(|error| ChecksumError::io(dest, error))
+}
+
+async fn extract_member(archive: &Path, member: &str, dest: &Path) -> Result<(), ChecksumError> {
+    let mut cmd = Command::new("tar");
+    cmd.arg("-xzf").arg(archive).arg("-C").arg(dest).arg(member);
+    cmd.hide_window();
+    let output = cmd
+        .output()
+        .await
+        .map_err(|error| ChecksumError::io(archive, error))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(ChecksumError::Tar {
+            status: output.status.to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NodeVerification, compare_digest, parse_shasums, sha256_file};
+
+    #[test]
+    fn parse_shasums_maps_file_names_to_digests() {
+        let shasums = parse_shasums(
+            "ABC123  node-v22.11.0-linux-x64.tar.gz\n\
+             def456  win-x64/node.exe\n\
+             \n\
+             0f0f0f *node-v22.11.0.pkg\n",
+        );
+
+        assert_eq!(
+            shasums
+                .get("node-v22.11.0-linux-x64.tar.gz")
+                .map(String::as_str),
+            Some("abc123")
+        );
+        assert_eq!(
+            shasums.get("win-x64/node.exe").map(String::as_str),
+            Some("def456")
+        );
+        assert_eq!(
+            shasums.get("node-v22.11.0.pkg").map(String::as_str),
+            Some("0f0f0f")
+        );
+        assert_eq!(shasums.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn compare_digest_reports_mismatched_files() {
+        let temp = tempfile::tempdir().expect("tempdir should be created");
+        let path = temp.path().join("node");
+        std::fs::write(&path, b"versi").expect("write file");
+        let digest = sha256_file(&path).expect("checksum should be computed");
+
+        assert_eq!(
+            compare_digest("bin/node", &digest.to_ascii_uppercase(), &path)
+                .await
+                .expect("digest comparison should run"),
+            NodeVerification::Verified
+        );
+        assert_eq!(
+            compare_digest("bin/node", "0000", &path)
+                .await
+                .expect("digest comparison should run"),
+            NodeVerification::Mismatch {
+                file: "bin/node".to_string()
+            }
+        );
+    }
+}
@@ -5,7 +5,11 @@ use sha2::{Digest, Sha256};
 
 const INSTALL_SCRIPT_TIMEOUT: Duration = Duration::from_secs(30);
 const INSTALL_SCRIPT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
-const INSTALL_SCRIPT_RETRY_DELAYS_SECS: [u64; 3] = [0, 2, 5];
+const INSTALL_SCRIPT_MAX_REDIRECTS: usize = 10;
+
+/// Hosts installer scripts may be downloaded from, including redirects.
+pub const TRUSTED_INSTALL_SCRIPT_HOSTS: &[&str] =
+    &["github.com", "raw.githubusercontent.com", "fnm.vercel.app"];
 
 #[derive(Debug, thiserror::Error)]
 pub enum InstallScriptError {
@@ -27,6 +31,14 @@ pub enum InstallScriptError {
         expected_sha256: String,
         actual_sha256: String,
     },
+    #[error("refusing to download installer script from untrusted URL {url}")]
+    UntrustedHost { url: String },
+    #[error("installer script download failed after {attempts} attempt(s): {last_error}")]
+    AttemptsExhausted {
+        attempts: usize,
+        #[source]
+        last_error: Box<InstallScriptError>,
+    },
     #[error("failed to write installer script to {path}: {source}")]
     Write {
         path: String,
@@ -38,18 +50,37 @@ pub enum InstallScriptError {
 /// Download an installer script with timeout/retry policy and SHA-256
 /// verification.
 ///
+/// The URL must use HTTPS and point at one of [`TRUSTED_INSTALL_SCRIPT_HOSTS`];
+/// redirects to any other host are refused.
+///
+/// `retry_delays_secs` lists the delay before each attempt, so `[0, 2, 5]`
+/// makes up to three attempts. An empty list still makes a single attempt.
+///
 /// # Errors
-/// Returns an error if the HTTP request fails, the server responds with a
+/// Returns an error if the URL is not trusted, the HTTP request fails, the server responds with a
 /// non-success status, checksum verification fails, or writing the script to
 /// disk fails.
 pub async fn download_install_script_verified(
     url: &str,
     expected_sha256: &str,
     path: &Path,
+    retry_delays_secs: &[u64],
 ) -> Result<(), InstallScriptError> {
+    verify_trusted_url(url)?;
+
     let client = reqwest::Client::builder()
         .timeout(INSTALL_SCRIPT_TIMEOUT)
         .connect_timeout(INSTALL_SCRIPT_CONNECT_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= INSTALL_SCRIPT_MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if is_trusted_url(attempt.url()) {
+                attempt.follow()
+            } else {
+                let url = attempt.url().to_string();
+                attempt.error(InstallScriptError::UntrustedHost { url })
+            }
+        }))
         .user_agent(format!(
             "versi/{}/installer-script",
             env!("CARGO_PKG_VERSION")
@@ -57,7 +88,7 @@ pub async fn download_install_script_verified(
         .build()
         .map_err(InstallScriptError::ClientBuild)?;
 
-    let script = download_with_retries(&client, url).await?;
+    let script = download_with_retries(retry_delays_secs, || download_once(&client, url)).await?;
     verify_checksum(&script, expected_sha256)?;
 
     tokio::fs::write(path, &script)
@@ -70,29 +101,43 @@ pub async fn download_install_script_verified(
     Ok(())
 }
 
-async fn download_with_retries(
-    client: &reqwest::Client,
-    url: &str,
-) -> Result<Vec<u8>, InstallScriptError> {
+async fn download_with_retries<F, Fut>(
+    retry_delays_secs: &[u64],
+    mut download: F,
+) -> Result<Vec<u8>, InstallScriptError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, InstallScriptError>>,
+{
+    let delays = if retry_delays_secs.is_empty() {
+        &[0][..]
+    } else {
+        retry_delays_secs
+    };
     let mut last_error = None;
 
-    for delay_secs in INSTALL_SCRIPT_RETRY_DELAYS_SECS {
+    for (attempt, &delay_secs) in delays.iter().enumerate() {
         if delay_secs > 0 {
             tokio::time::sleep(Duration::from_secs(delay_secs)).await;
         }
 
-        match download_once(client, url).await {
+        match download().await {
             Ok(bytes) => return Ok(bytes),
             Err(error) => {
+                log::debug!(
+                    "Installer script download attempt {} failed: {error}",
+                    attempt + 1
+                );
                 last_error = Some(error);
             }
         }
     }
 
-    Err(last_error.unwrap_or_else(|| InstallScriptError::Status {
-        url: url.to_string(),
-        status: reqwest::StatusCode::REQUEST_TIMEOUT,
-    }))
+    let last_error = last_error.expect("at least one download attempt is made");
+    Err(InstallScriptError::AttemptsExhausted {
+        attempts: delays.len(),
+        last_error: Box::new(last_error),
+    })
 }
 
 async fn download_once(client: &reqwest::Client, url: &str) -> Result<Vec<u8>, InstallScriptError> {
@@ -122,6 +167,22 @@ async fn download_once(client: &reqwest::Client, url: &str) -> Result<Vec<u8>, I
         })
 }
 
+fn verify_trusted_url(url: &str) -> Result<(), InstallScriptError> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if is_trusted_url(&parsed) => Ok(()),
+        _ => Err(InstallScriptError::UntrustedHost {
+            url: url.to_string(),
+        }),
+    }
+}
+
+fn is_trusted_url(url: &reqwest::Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| TRUSTED_INSTALL_SCRIPT_HOSTS.contains(&host))
+}
+
 fn verify_checksum(script: &[u8], expected_sha256: &str) -> Result<(), InstallScriptError> {
     let actual_sha256 = format!("{:x}", Sha256::digest(script));
     if actual_sha256.eq_ignore_ascii_case(expected_sha256) {
@@ -136,7 +197,99 @@ fn verify_checksum(script: &[u8], expected_sha256: &str) -> Result<(), InstallSc
 
 #[cfg(test)]
 mod tests {
-    use super::verify_checksum;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{InstallScriptError, download_with_retries, verify_checksum, verify_trusted_url};
+
+    fn unavailable() -> InstallScriptError {
+        InstallScriptError::Status {
+            url: "https://example.invalid/install.sh".to_string(),
+            status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    #[tokio::test]
+    async fn download_with_retries_makes_one_attempt_per_delay() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = download_with_retries(&[0, 0, 0], || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(unavailable()) }
+        })
+        .await;
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(matches!(
+            result,
+            Err(InstallScriptError::AttemptsExhausted { attempts: 3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_with_retries_stops_after_success() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = download_with_retries(&[0, 0, 0], || {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if attempt == 0 {
+                    Err(unavailable())
+                } else {
+                    Ok(b"echo ok".to_vec())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(result.expect("second attempt succeeds"), b"echo ok");
+    }
+
+    #[tokio::test]
+    async fn download_with_retries_attempts_once_without_delays() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = download_with_retries(&[], || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(unavailable()) }
+        })
+        .await;
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(matches!(
+            result,
+            Err(InstallScriptError::AttemptsExhausted { attempts: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn verify_trusted_url_accepts_allowlisted_hosts() {
+        for url in [
+            "https://raw.githubusercontent.com/Schniz/fnm/v1.38.1/.ci/install.sh",
+            "https://github.com/nvm-sh/nvm/raw/v0.40.4/install.sh",
+            "https://fnm.vercel.app/install",
+        ] {
+            assert!(verify_trusted_url(url).is_ok(), "{url} should be trusted");
+        }
+    }
+
+    #[test]
+    fn verify_trusted_url_rejects_other_hosts_and_schemes() {
+        for url in [
+            "https://example.com/install.sh",
+            "https://raw.githubusercontent.com.evil.test/install.sh",
+            "http://raw.githubusercontent.com/Schniz/fnm/install.sh",
+            "not a url",
+        ] {
+            assert!(
+                matches!(
+                    verify_trusted_url(url),
+                    Err(InstallScriptError::UntrustedHost { .. })
+                ),
+                "{url} should be rejected"
+            );
+        }
+    }
 
     #[test]
     fn verify_checksum_accepts_matching_digest() {
@@ -5,23 +5,49 @@
 //! - Release schedule loading and querying.
 //! - Version metadata fetching.
 //! - App update discovery and update payload types.
+//! - Checksum verification of installed Node.js versions.
+//! - npm registry connectivity checks.
 //! - Small platform command helpers (for example window-hiding adapters).
 
+mod archive;
 pub mod auto_update;
+mod checksum;
 pub mod commands;
 mod install_script;
 mod metadata;
+mod registry;
 mod schedule;
+#[cfg(test)]
+mod test_support;
 mod update;
 
+/// Staged extraction of local Node.js distribution archives.
+pub use archive::{ArchiveExtractError, extract_archive_dir};
+/// SHA-256 verification of installed Node.js versions.
+pub use checksum::{
+    ChecksumError, DEFAULT_NODE_DIST_URL, NodeVerification, sha256_file, verify_node_install,
+};
 /// Extension trait that normalizes "hide window" behavior on supported command
 /// types.
 pub use commands::HideWindow;
 /// Installer script download helper with retry/verification policy.
-pub use install_script::{InstallScriptError, download_install_script_verified};
+pub use install_script::{
+    InstallScriptError, TRUSTED_INSTALL_SCRIPT_HOSTS, download_install_script_verified,
+};
 /// Release metadata model and fetch helper.
 pub use metadata::{MetadataError, VersionMeta, fetch_version_metadata};
+/// npm registry reachability check.
+pub use registry::{
+    DEFAULT_NPM_REGISTRY_URL, RegistryStatus, UnreachableReason, check_npm_registry,
+    configured_npm_registry,
+};
 /// Node release schedule model and fetch helper.
-pub use schedule::{ReleaseSchedule, ScheduleError, fetch_release_schedule};
+pub use schedule::{
+    BUNDLED_SCHEDULE_DATE, ReleasePhase, ReleaseSchedule, SCHEDULE_SOURCES, ScheduleError,
+    SourceFailure, fetch_release_schedule, fetch_release_schedule_from,
+};
 /// App update model, GitHub release mapping, and version comparison helpers.
-pub use update::{AppUpdate, GitHubRelease, UpdateError, check_for_update, is_newer_version};
+pub use update::{
+    AppUpdate, DEFAULT_UPDATE_REPO, GitHubRelease, UpdateChannel, UpdateError, check_for_update,
+    is_newer_version, is_valid_update_repo,
+};
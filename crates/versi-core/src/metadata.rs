@@ -60,11 +60,18 @@ fn map_entries(entries: Vec<RawEntry>) -> HashMap<String, VersionMeta> {
 
 /// Fetch Node.js version metadata from `nodejs.org`.
 ///
+/// `nodejs.org` only publishes the full release index, so there is no
+/// cheaper per-version request; callers should cache the result.
+///
 /// # Errors
 /// Returns an error when the remote metadata cannot be fetched or parsed.
 pub async fn fetch_version_metadata(
     client: &reqwest::Client,
 ) -> Result<HashMap<String, VersionMeta>, MetadataError> {
+    fetch_index_entries(client).await.map(map_entries)
+}
+
+async fn fetch_index_entries(client: &reqwest::Client) -> Result<Vec<RawEntry>, MetadataError> {
     let response = client
         .get(INDEX_URL)
         .send()
@@ -85,9 +92,7 @@ pub async fn fetch_version_metadata(
         });
     }
 
-    let entries: Vec<RawEntry> = response.json().await.map_err(MetadataError::Parse)?;
-
-    Ok(map_entries(entries))
+    response.json().await.map_err(MetadataError::Parse)
 }
 
 fn response_snippet(body: &str, max_chars: usize) -> String {
@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+use thiserror::Error;
+
+/// Public npm registry, used when no registry is configured.
+pub const DEFAULT_NPM_REGISTRY_URL: &str = "https://registry.npmjs.org";
+
+/// Outcome of a connectivity check against an npm registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryStatus {
+    Reachable { latency: Duration },
+    Unreachable { reason: UnreachableReason },
+}
+
+/// Why a registry check failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum UnreachableReason {
+    #[error("request timed out")]
+    TimedOut,
+    #[error("could not connect")]
+    Connect,
+    #[error("registry returned HTTP {0}")]
+    HttpStatus(u16),
+    #[error("request failed")]
+    Request,
+}
+
+impl RegistryStatus {
+    #[must_use]
+    pub fn is_reachable(&self) -> bool {
+        matches!(self, Self::Reachable { .. })
+    }
+}
+
+/// Check whether the npm registry at `registry_url` answers its ping endpoint.
+///
+/// Uses `client` as-is, so its timeout and proxy configuration apply.
+pub async fn check_npm_registry(client: &reqwest::Client, registry_url: &str) -> RegistryStatus {
+    let url = format!("{}/-/ping", registry_url.trim_end_matches('/'));
+    debug!("Checking npm registry at {url}");
+
+    let started = Instant::now();
+    let status = match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => RegistryStatus::Reachable {
+            latency: started.elapsed(),
+        },
+        Ok(response) => RegistryStatus::Unreachable {
+            reason: UnreachableReason::HttpStatus(response.status().as_u16()),
+        },
+        Err(error) => {
+            debug!("npm registry request to {url} failed: {error}");
+            let reason = if error.is_timeout() {
+                UnreachableReason::TimedOut
+            } else if error.is_connect() {
+                UnreachableReason::Connect
+            } else {
+                UnreachableReason::Request
+            };
+            RegistryStatus::Unreachable { reason }
+        }
+    };
+
+    if let RegistryStatus::Unreachable { reason } = &status {
+        warn!("npm registry {registry_url} is unreachable: {reason}");
+    }
+    status
+}
+
+/// Registry npm would use, given the `npm_config_registry` environment
+/// value and the contents of the user's `.npmrc`, in npm's precedence order.
+/// Falls back to [`DEFAULT_NPM_REGISTRY_URL`].
+#[must_use]
+pub fn configured_npm_registry(env_registry: Option<&str>, npmrc: Option<&str>) -> String {
+    env_registry
+        .map(str::trim)
+        .filter(|registry| !registry.is_empty())
+        .map(str::to_string)
+        .or_else(|| npmrc.and_then(npmrc_registry))
+        .unwrap_or_else(|| DEFAULT_NPM_REGISTRY_URL.to_string())
+}
+
+fn npmrc_registry(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && !line.starts_with(';'))
+        .filter_map(|line| line.split_once('='))
+        .filter(|(key, _)| key.trim() == "registry")
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .rfind(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        DEFAULT_NPM_REGISTRY_URL, RegistryStatus, UnreachableReason, check_npm_registry,
+        configured_npm_registry,
+    };
+    use crate::test_support::{http_response, serve_once};
+
+    fn client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_millis(300))
+            .build()
+            .expect("test client should build")
+    }
+
+    #[tokio::test]
+    async fn registry_answering_ping_is_reachable() {
+        let url = serve_once(Some(http_response("200 OK", "{}")));
+
+        let status = check_npm_registry(&client(), &url).await;
+
+        assert!(status.is_reachable(), "unexpected status: {status:?}");
+    }
+
+    #[tokio::test]
+    async fn registry_that_never_answers_is_unreachable() {
+        let url = serve_once(None);
+
+        let status = check_npm_registry(&client(), &url).await;
+
+        assert_eq!(
+            status,
+            RegistryStatus::Unreachable {
+                reason: UnreachableReason::TimedOut
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn registry_error_status_is_reported() {
+        let url = serve_once(Some(http_response("503 Service Unavailable", "")));
+
+        let status = check_npm_registry(&client(), &url).await;
+
+        assert_eq!(
+            status,
+            RegistryStatus::Unreachable {
+                reason: UnreachableReason::HttpStatus(503)
+            }
+        );
+    }
+
+    #[test]
+    fn configured_registry_prefers_environment_then_npmrc() {
+        let npmrc = "; company mirror\nregistry = \"https://npm.example.com/\"\n";
+
+        assert_eq!(
+            configured_npm_registry(Some("https://env.example.com"), Some(npmrc)),
+            "https://env.example.com"
+        );
+        assert_eq!(
+            configured_npm_registry(None, Some(npmrc)),
+            "https://npm.example.com/"
+        );
+        assert_eq!(
+            configured_npm_registry(Some(""), Some("# registry=https://old.example.com\n")),
+            DEFAULT_NPM_REGISTRY_URL
+        );
+        assert_eq!(
+            configured_npm_registry(None, None),
+            DEFAULT_NPM_REGISTRY_URL
+        );
+    }
+}
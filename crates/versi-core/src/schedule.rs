@@ -1,9 +1,18 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
-const SCHEDULE_URL: &str = "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json";
+/// Places the release schedule is published, tried in order.
+pub const SCHEDULE_SOURCES: &[&str] = &[
+    "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json",
+    "https://cdn.jsdelivr.net/gh/nodejs/Release@main/schedule.json",
+];
+
+/// When `data/release-schedule.json` was last copied from `nodejs/Release`.
+pub const BUNDLED_SCHEDULE_DATE: &str = "2026-10-01";
+
+const BUNDLED_SCHEDULE: &str = include_str!("../data/release-schedule.json");
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct VersionSchedule {
@@ -17,6 +26,27 @@ pub struct VersionSchedule {
     pub codename: Option<String>,
 }
 
+/// Where a release line is in its support lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleasePhase {
+    Current,
+    ActiveLts,
+    Maintenance,
+    EndOfLife,
+}
+
+impl ReleasePhase {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Current => "Current",
+            Self::ActiveLts => "Active LTS",
+            Self::Maintenance => "Maintenance",
+            Self::EndOfLife => "End-of-Life",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleaseSchedule {
     pub versions: HashMap<u32, VersionSchedule>,
@@ -33,21 +63,124 @@ pub enum ScheduleError {
     },
     #[error("failed to parse release schedule: {0}")]
     Parse(#[source] reqwest::Error),
+    #[error("no release schedule source responded: {}", describe_failures(.0))]
+    AllSourcesFailed(Vec<SourceFailure>),
+}
+
+/// A schedule source that could not be used, and why.
+#[derive(Debug)]
+pub struct SourceFailure {
+    pub url: String,
+    pub error: ScheduleError,
+}
+
+fn describe_failures(failures: &[SourceFailure]) -> String {
+    failures
+        .iter()
+        .map(|failure| format!("{} ({})", failure.url, failure.error))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl ReleaseSchedule {
+    /// The schedule snapshot shipped with the app, used when it can't be
+    /// fetched and nothing is cached. See [`BUNDLED_SCHEDULE_DATE`].
+    #[must_use]
+    pub fn bundled() -> Self {
+        let raw = serde_json::from_str(BUNDLED_SCHEDULE).unwrap_or_else(|error| {
+            log::error!("Bundled release schedule is invalid: {error}");
+            HashMap::new()
+        });
+        Self::from_raw(raw)
+    }
+
+    fn from_raw(raw: HashMap<String, VersionSchedule>) -> Self {
+        let versions = raw
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let major = key.trim_start_matches('v').parse().ok()?;
+                Some((major, value))
+            })
+            .collect();
+        Self { versions }
+    }
+
     #[must_use]
     pub fn is_active(&self, major: u32) -> bool {
+        self.is_active_at(major, Utc::now())
+    }
+
+    /// Whether `major` is newer than every line in the schedule, i.e. Node
+    /// has shipped it but the schedule hasn't caught up yet.
+    #[must_use]
+    pub fn is_pending(&self, major: u32) -> bool {
+        !self.versions.contains_key(&major)
+            && self.versions.keys().all(|&scheduled| scheduled < major)
+    }
+
+    /// Whether `major` is still supported as of `as_of`. Pending majors (see
+    /// [`Self::is_pending`]) are active; other majors missing from the
+    /// schedule are assumed active from 18 onwards.
+    #[must_use]
+    pub fn is_active_at(&self, major: u32, as_of: DateTime<Utc>) -> bool {
         let Some(schedule) = self.versions.get(&major) else {
-            return major >= 18;
+            return self.is_pending(major) || major >= 18;
         };
 
         let Ok(end_date) = NaiveDate::parse_from_str(&schedule.end, "%Y-%m-%d") else {
             return true;
         };
 
-        let today = chrono::Utc::now().date_naive();
-        end_date > today
+        end_date > as_of.date_naive()
+    }
+
+    /// Days from today until `major` reaches end-of-life, negative once it
+    /// has. `None` when the major isn't in the schedule.
+    #[must_use]
+    pub fn days_until_eol(&self, major: u32) -> Option<i64> {
+        self.days_until_eol_at(major, Utc::now())
+    }
+
+    #[must_use]
+    pub fn days_until_eol_at(&self, major: u32, as_of: DateTime<Utc>) -> Option<i64> {
+        let schedule = self.versions.get(&major)?;
+        let end_date = NaiveDate::parse_from_str(&schedule.end, "%Y-%m-%d").ok()?;
+        Some(
+            end_date
+                .signed_duration_since(as_of.date_naive())
+                .num_days(),
+        )
+    }
+
+    #[must_use]
+    pub fn release_phase(&self, major: u32) -> Option<ReleasePhase> {
+        self.release_phase_at(major, Utc::now())
+    }
+
+    /// Phase of `major` as of `as_of`. Missing or unparsable `lts` and
+    /// `maintenance` dates are treated as not reached yet, and pending majors
+    /// are [`ReleasePhase::Current`].
+    #[must_use]
+    pub fn release_phase_at(&self, major: u32, as_of: DateTime<Utc>) -> Option<ReleasePhase> {
+        if self.is_pending(major) {
+            return Some(ReleasePhase::Current);
+        }
+        let schedule = self.versions.get(&major)?;
+        let today = as_of.date_naive();
+        let reached = |date: Option<&str>| {
+            date.and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                .is_some_and(|date| date <= today)
+        };
+
+        Some(if reached(Some(&schedule.end)) {
+            ReleasePhase::EndOfLife
+        } else if reached(schedule.maintenance.as_deref()) {
+            ReleasePhase::Maintenance
+        } else if reached(schedule.lts.as_deref()) {
+            ReleasePhase::ActiveLts
+        } else {
+            ReleasePhase::Current
+        })
     }
 
     #[must_use]
@@ -66,32 +199,75 @@ impl ReleaseSchedule {
 
     #[must_use]
     pub fn active_versions(&self) -> Vec<u32> {
+        self.active_versions_at(Utc::now())
+    }
+
+    #[must_use]
+    pub fn active_versions_at(&self, as_of: DateTime<Utc>) -> Vec<u32> {
         self.versions
             .keys()
-            .filter(|&&major| self.is_active(major))
+            .filter(|&&major| self.is_active_at(major, as_of))
             .copied()
             .collect()
     }
 
     #[must_use]
     pub fn active_lts_versions(&self) -> Vec<u32> {
+        self.active_lts_versions_at(Utc::now())
+    }
+
+    #[must_use]
+    pub fn active_lts_versions_at(&self, as_of: DateTime<Utc>) -> Vec<u32> {
         self.versions
             .keys()
-            .filter(|&&major| self.is_active(major) && self.is_lts(major))
+            .filter(|&&major| self.is_active_at(major, as_of) && self.is_lts(major))
             .copied()
             .collect()
     }
 }
 
-/// Fetch and parse the Node.js release schedule.
+/// Fetch and parse the Node.js release schedule from [`SCHEDULE_SOURCES`].
 ///
 /// # Errors
-/// Returns an error when the schedule cannot be downloaded or deserialized.
+/// Returns [`ScheduleError::AllSourcesFailed`] when no source could be
+/// downloaded and deserialized.
 pub async fn fetch_release_schedule(
     client: &reqwest::Client,
+) -> Result<ReleaseSchedule, ScheduleError> {
+    fetch_release_schedule_from(client, SCHEDULE_SOURCES).await
+}
+
+/// Try each of `sources` in order and return the first schedule that loads.
+///
+/// # Errors
+/// Returns [`ScheduleError::AllSourcesFailed`] listing every source's error
+/// when none of them succeed.
+pub async fn fetch_release_schedule_from(
+    client: &reqwest::Client,
+    sources: &[&str],
+) -> Result<ReleaseSchedule, ScheduleError> {
+    let mut failures = Vec::with_capacity(sources.len());
+    for url in sources {
+        match fetch_schedule_source(client, url).await {
+            Ok(schedule) => return Ok(schedule),
+            Err(error) => {
+                log::warn!("Release schedule source {url} failed: {error}");
+                failures.push(SourceFailure {
+                    url: (*url).to_string(),
+                    error,
+                });
+            }
+        }
+    }
+    Err(ScheduleError::AllSourcesFailed(failures))
+}
+
+async fn fetch_schedule_source(
+    client: &reqwest::Client,
+    url: &str,
 ) -> Result<ReleaseSchedule, ScheduleError> {
     let response = client
-        .get(SCHEDULE_URL)
+        .get(url)
         .send()
         .await
         .map_err(ScheduleError::Request)?;
@@ -113,15 +289,7 @@ pub async fn fetch_release_schedule(
     let raw: HashMap<String, VersionSchedule> =
         response.json().await.map_err(ScheduleError::Parse)?;
 
-    let versions: HashMap<u32, VersionSchedule> = raw
-        .into_iter()
-        .filter_map(|(key, value)| {
-            let major = key.trim_start_matches('v').parse().ok()?;
-            Some((major, value))
-        })
-        .collect();
-
-    Ok(ReleaseSchedule { versions })
+    Ok(ReleaseSchedule::from_raw(raw))
 }
 
 fn response_snippet(body: &str, max_chars: usize) -> String {
@@ -135,7 +303,18 @@ fn response_snippet(body: &str, max_chars: usize) -> String {
 
 #[cfg(test)]
 mod tests {
+    use chrono::TimeZone;
+
     use super::*;
+    use crate::test_support::{http_response, serve_once};
+
+    fn serve_schedule_once(response: String) -> String {
+        format!("{}/schedule.json", serve_once(Some(response)))
+    }
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+    }
 
     fn create_test_schedule() -> ReleaseSchedule {
         let mut versions = HashMap::new();
@@ -232,18 +411,179 @@ mod tests {
         assert!(!schedule.is_active(10));
     }
 
+    #[test]
+    fn test_newer_unknown_major_is_pending_and_current() {
+        let schedule = create_test_schedule();
+
+        assert!(schedule.is_pending(24));
+        assert!(schedule.is_active_at(24, at(2099, 1, 1)));
+        assert_eq!(
+            schedule.release_phase_at(24, at(2099, 1, 1)),
+            Some(ReleasePhase::Current)
+        );
+        assert_eq!(schedule.days_until_eol_at(24, at(2099, 1, 1)), None);
+
+        assert!(!schedule.is_pending(23));
+        assert!(!schedule.is_pending(10));
+        assert!(!schedule.is_pending(19));
+    }
+
     #[test]
     fn test_is_active_eol_version() {
         let schedule = create_test_schedule();
         assert!(!schedule.is_active(16));
     }
 
+    #[test]
+    fn test_days_until_eol_future() {
+        let schedule = create_test_schedule();
+        assert_eq!(schedule.days_until_eol_at(20, at(2026, 3, 16)), Some(45));
+    }
+
+    #[test]
+    fn test_days_until_eol_past() {
+        let schedule = create_test_schedule();
+        assert_eq!(schedule.days_until_eol_at(16, at(2023, 9, 21)), Some(-10));
+    }
+
+    #[test]
+    fn test_days_until_eol_unknown_version() {
+        let schedule = create_test_schedule();
+        assert_eq!(schedule.days_until_eol(99), None);
+    }
+
+    #[test]
+    fn test_release_phase_for_each_phase() {
+        let schedule = create_test_schedule();
+
+        assert_eq!(
+            schedule.release_phase_at(20, at(2023, 6, 1)),
+            Some(ReleasePhase::Current)
+        );
+        assert_eq!(
+            schedule.release_phase_at(20, at(2024, 1, 1)),
+            Some(ReleasePhase::ActiveLts)
+        );
+        assert_eq!(
+            schedule.release_phase_at(20, at(2025, 1, 1)),
+            Some(ReleasePhase::Maintenance)
+        );
+        assert_eq!(
+            schedule.release_phase_at(20, at(2026, 5, 1)),
+            Some(ReleasePhase::EndOfLife)
+        );
+    }
+
+    #[test]
+    fn test_release_phase_without_lts_dates() {
+        let schedule = create_test_schedule();
+
+        assert_eq!(
+            schedule.release_phase_at(23, at(2025, 1, 1)),
+            Some(ReleasePhase::Current)
+        );
+        assert_eq!(
+            schedule.release_phase_at(23, at(2025, 6, 1)),
+            Some(ReleasePhase::EndOfLife)
+        );
+        // Unscheduled majors newer than the schedule are pending, so Current.
+        assert_eq!(
+            schedule.release_phase_at(99, at(2025, 1, 1)),
+            Some(ReleasePhase::Current)
+        );
+    }
+
+    #[test]
+    fn test_release_phase_unknown_for_unscheduled_older_major() {
+        let schedule = create_test_schedule();
+
+        assert!(!schedule.is_pending(19));
+        assert_eq!(schedule.release_phase_at(19, at(2025, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_eol_boundary_is_the_end_date_itself() {
+        let schedule = create_test_schedule();
+
+        assert!(schedule.is_active_at(18, at(2025, 4, 29)));
+        assert_eq!(schedule.days_until_eol_at(18, at(2025, 4, 29)), Some(1));
+
+        assert!(!schedule.is_active_at(18, at(2025, 4, 30)));
+        assert_eq!(schedule.days_until_eol_at(18, at(2025, 4, 30)), Some(0));
+        assert_eq!(
+            schedule.release_phase_at(18, at(2025, 4, 30)),
+            Some(ReleasePhase::EndOfLife)
+        );
+        assert!(
+            !schedule
+                .active_lts_versions_at(at(2025, 4, 30))
+                .contains(&18)
+        );
+        assert!(
+            schedule
+                .active_lts_versions_at(at(2025, 4, 29))
+                .contains(&18)
+        );
+    }
+
     #[test]
     fn test_active_lts_versions() {
         let schedule = create_test_schedule();
-        let active_lts = schedule.active_lts_versions();
+        let active_lts = schedule.active_lts_versions_at(at(2025, 1, 1));
         assert!(active_lts.contains(&20));
         assert!(!active_lts.contains(&23));
         assert!(!active_lts.contains(&16));
     }
+
+    #[tokio::test]
+    async fn failing_primary_falls_through_to_secondary() {
+        let primary = serve_schedule_once(http_response("503 Service Unavailable", "down"));
+        let secondary = serve_schedule_once(http_response(
+            "200 OK",
+            r#"{"v22":{"start":"2024-04-24","lts":"2024-10-29","end":"2027-04-30","codename":"Jod"}}"#,
+        ));
+
+        let schedule = fetch_release_schedule_from(
+            &reqwest::Client::new(),
+            &[primary.as_str(), secondary.as_str()],
+        )
+        .await
+        .expect("secondary source should be used");
+
+        assert_eq!(schedule.codename(22), Some("Jod"));
+    }
+
+    #[tokio::test]
+    async fn every_failed_source_is_reported() {
+        let primary = serve_schedule_once(http_response("503 Service Unavailable", ""));
+        let secondary = serve_schedule_once(http_response("404 Not Found", ""));
+
+        let error = fetch_release_schedule_from(
+            &reqwest::Client::new(),
+            &[primary.as_str(), secondary.as_str()],
+        )
+        .await
+        .expect_err("both sources fail");
+
+        let ScheduleError::AllSourcesFailed(failures) = &error else {
+            panic!("unexpected error: {error}");
+        };
+        let urls: Vec<&str> = failures.iter().map(|f| f.url.as_str()).collect();
+        assert_eq!(urls, [primary.as_str(), secondary.as_str()]);
+        assert!(error.to_string().contains("HTTP 404"));
+    }
+
+    #[test]
+    fn test_bundled_schedule_deserializes() {
+        let schedule = ReleaseSchedule::bundled();
+
+        assert_eq!(schedule.codename(22), Some("Jod"));
+        assert_eq!(schedule.codename(20), Some("Iron"));
+        assert!(!schedule.is_lts(23));
+        assert!(!schedule.is_active_at(18, at(2026, 1, 1)));
+        assert!(
+            NaiveDate::parse_from_str(BUNDLED_SCHEDULE_DATE, "%Y-%m-%d").is_ok(),
+            "snapshot date should be a plain date"
+        );
+    }
 }
@@ -0,0 +1,35 @@
+//! One-shot HTTP servers for tests that exercise real requests.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+/// Accept one connection on a local port and answer it with `response`, or
+/// hold it open without answering when `response` is `None`. Returns the
+/// server's base URL.
+pub(crate) fn serve_once(response: Option<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+    let addr = listener.local_addr().expect("mock server address");
+    std::thread::spawn(move || {
+        let Ok((mut stream, _)) = listener.accept() else {
+            return;
+        };
+        let mut buffer = [0_u8; 1024];
+        let _ = stream.read(&mut buffer);
+        match response {
+            Some(response) => {
+                let _ = stream.write_all(response.as_bytes());
+            }
+            None => std::thread::sleep(Duration::from_secs(2)),
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// A complete HTTP/1.1 response that closes the connection after `body`.
+pub(crate) fn http_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
@@ -1,10 +1,11 @@
-use semver::Version;
-use serde::Deserialize;
+use semver::{BuildMetadata, Version};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-const GITHUB_REPO: &str = "almeidx/versi";
+/// Repository app updates are published to unless configured otherwise.
+pub const DEFAULT_UPDATE_REPO: &str = "almeidx/versi";
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AppUpdate {
     pub current_version: String,
     pub latest_version: String,
@@ -31,6 +32,25 @@ pub struct GitHubRelease {
     pub body: Option<String>,
     #[serde(default)]
     pub assets: Vec<GitHubAsset>,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
+}
+
+/// Which app releases are offered as updates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    /// Also offers GitHub pre-releases.
+    Beta,
+}
+
+impl UpdateChannel {
+    fn accepts(self, release: &GitHubRelease) -> bool {
+        !release.draft && (self == Self::Beta || !release.prerelease)
+    }
 }
 
 #[derive(Debug, Error)]
@@ -63,7 +83,45 @@ pub fn asset_name(version: &str) -> Option<String> {
     Some(name)
 }
 
-/// Check GitHub releases for a newer Versi version.
+/// Whether `repo` is a GitHub `owner/name` pair.
+#[must_use]
+pub fn is_valid_update_repo(repo: &str) -> bool {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return false;
+    };
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part != "."
+            && part != ".."
+            && part
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+    };
+    valid_part(owner) && valid_part(name)
+}
+
+fn releases_url(repo: &str) -> String {
+    format!("https://api.github.com/repos/{repo}/releases?per_page=30")
+}
+
+/// The newest release `channel` accepts, by semver of its tag.
+fn select_release(releases: Vec<GitHubRelease>, channel: UpdateChannel) -> Option<GitHubRelease> {
+    releases
+        .into_iter()
+        .filter(|release| channel.accepts(release))
+        .filter_map(|release| {
+            let version = parse_semver(strip_v(&release.tag_name))?;
+            Some((version, release))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+fn strip_v(version: &str) -> &str {
+    version.strip_prefix('v').unwrap_or(version)
+}
+
+/// Check `repo`'s GitHub releases on `channel` for a newer Versi version.
 ///
 /// # Errors
 /// Returns an error when the update API request fails or the release response
@@ -71,8 +129,10 @@ pub fn asset_name(version: &str) -> Option<String> {
 pub async fn check_for_update(
     client: &reqwest::Client,
     current_version: &str,
+    repo: &str,
+    channel: UpdateChannel,
 ) -> Result<Option<AppUpdate>, UpdateError> {
-    let url = format!("https://api.github.com/repos/{GITHUB_REPO}/releases/latest");
+    let url = releases_url(repo);
 
     let response = client
         .get(&url)
@@ -95,45 +155,47 @@ pub async fn check_for_update(
         });
     }
 
-    let release: GitHubRelease = response.json().await.map_err(UpdateError::Parse)?;
-
-    let latest = release
-        .tag_name
-        .strip_prefix('v')
-        .unwrap_or(&release.tag_name);
-    let current = current_version.strip_prefix('v').unwrap_or(current_version);
-
-    if is_newer_version(latest, current) {
-        let (download_url, download_size, download_sha256) = asset_name(latest)
-            .and_then(|expected| {
-                release
-                    .assets
-                    .iter()
-                    .find(|a| a.name == expected)
-                    .and_then(|a| {
-                        parse_sha256_digest(a.digest.as_deref()?).map(|digest| {
-                            (
-                                Some(a.browser_download_url.clone()),
-                                Some(a.size),
-                                Some(digest),
-                            )
-                        })
-                    })
-            })
-            .unwrap_or((None, None, None));
-
-        Ok(Some(AppUpdate {
-            current_version: current.to_string(),
-            latest_version: latest.to_string(),
-            release_url: release.html_url,
-            release_notes: release.body,
-            download_url,
-            download_size,
-            download_sha256,
-        }))
-    } else {
-        Ok(None)
+    let releases: Vec<GitHubRelease> = response.json().await.map_err(UpdateError::Parse)?;
+
+    Ok(select_release(releases, channel)
+        .and_then(|release| update_from_release(release, current_version)))
+}
+
+fn update_from_release(release: GitHubRelease, current_version: &str) -> Option<AppUpdate> {
+    let latest = strip_v(&release.tag_name);
+    let current = strip_v(current_version);
+
+    if !is_newer_version(latest, current) {
+        return None;
     }
+
+    let (download_url, download_size, download_sha256) = asset_name(latest)
+        .and_then(|expected| {
+            release
+                .assets
+                .iter()
+                .find(|a| a.name == expected)
+                .and_then(|a| {
+                    parse_sha256_digest(a.digest.as_deref()?).map(|digest| {
+                        (
+                            Some(a.browser_download_url.clone()),
+                            Some(a.size),
+                            Some(digest),
+                        )
+                    })
+                })
+        })
+        .unwrap_or((None, None, None));
+
+    Some(AppUpdate {
+        current_version: current.to_string(),
+        latest_version: latest.to_string(),
+        release_url: release.html_url,
+        release_notes: release.body,
+        download_url,
+        download_size,
+        download_sha256,
+    })
 }
 
 fn response_snippet(body: &str, max_chars: usize) -> String {
@@ -153,7 +215,15 @@ pub fn is_newer_version(latest: &str, current: &str) -> bool {
     }
 }
 
+/// Parse `version` leniently; build metadata is dropped because it has no
+/// bearing on which release is newer.
 fn parse_semver(version: &str) -> Option<Version> {
+    let mut parsed = parse_semver_with_build(strip_v(version.trim()))?;
+    parsed.build = BuildMetadata::EMPTY;
+    Some(parsed)
+}
+
+fn parse_semver_with_build(version: &str) -> Option<Version> {
     if let Ok(parsed) = Version::parse(version) {
         return Some(parsed);
     }
@@ -213,6 +283,109 @@ mod tests {
         assert!(!is_newer_version("0.9.0", "1.0.0"));
     }
 
+    #[test]
+    fn releases_url_uses_configured_repo() {
+        assert_eq!(
+            releases_url("someone/versi-fork"),
+            "https://api.github.com/repos/someone/versi-fork/releases?per_page=30"
+        );
+        assert_eq!(
+            releases_url(DEFAULT_UPDATE_REPO),
+            "https://api.github.com/repos/almeidx/versi/releases?per_page=30"
+        );
+    }
+
+    fn release(tag_name: &str, prerelease: bool) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: tag_name.to_string(),
+            html_url: format!("https://github.com/almeidx/versi/releases/tag/{tag_name}"),
+            body: None,
+            assets: Vec::new(),
+            prerelease,
+            draft: false,
+        }
+    }
+
+    fn sample_releases() -> Vec<GitHubRelease> {
+        vec![
+            release("v1.3.0-beta.2", true),
+            release("v1.2.1", false),
+            release("v1.3.0-beta.10", true),
+            release("v1.2.0", false),
+        ]
+    }
+
+    #[test]
+    fn stable_channel_skips_prereleases() {
+        let selected = select_release(sample_releases(), UpdateChannel::Stable)
+            .expect("a stable release should be selected");
+        assert_eq!(selected.tag_name, "v1.2.1");
+    }
+
+    #[test]
+    fn beta_channel_picks_newest_prerelease_by_semver() {
+        let selected = select_release(sample_releases(), UpdateChannel::Beta)
+            .expect("a release should be selected");
+        assert_eq!(selected.tag_name, "v1.3.0-beta.10");
+    }
+
+    #[test]
+    fn drafts_are_never_selected() {
+        let mut draft = release("v2.0.0", false);
+        draft.draft = true;
+
+        let selected = select_release(vec![draft, release("v1.2.1", false)], UpdateChannel::Beta)
+            .expect("the published release should be selected");
+        assert_eq!(selected.tag_name, "v1.2.1");
+    }
+
+    #[test]
+    fn beta_build_is_offered_the_final_release() {
+        let update = update_from_release(release("v1.3.0", false), "1.3.0-beta.10")
+            .expect("final release should supersede its beta");
+        assert_eq!(update.latest_version, "1.3.0");
+        assert!(update_from_release(release("v1.3.0-beta.2", true), "1.3.0-beta.10").is_none());
+    }
+
+    #[test]
+    fn update_repo_must_be_owner_and_name() {
+        assert!(is_valid_update_repo("almeidx/versi"));
+        assert!(is_valid_update_repo("my-org/versi.fork_2"));
+        assert!(!is_valid_update_repo("versi"));
+        assert!(!is_valid_update_repo("almeidx/"));
+        assert!(!is_valid_update_repo("/versi"));
+        assert!(!is_valid_update_repo("a/b/c"));
+        assert!(!is_valid_update_repo("owner/.."));
+        assert!(!is_valid_update_repo("owner/name?x=1"));
+        assert!(!is_valid_update_repo(" owner/name"));
+    }
+
+    #[test]
+    fn prerelease_versions_order_below_their_release() {
+        assert!(is_newer_version("1.2.0", "1.2.0-rc.1"));
+        assert!(is_newer_version("1.2.0-rc.2", "1.2.0-rc.1"));
+        assert!(is_newer_version("1.2.0-rc.1", "1.2.0-beta.5"));
+        assert!(is_newer_version("1.2.0-alpha", "1.1.9"));
+        assert!(!is_newer_version("1.2.0-rc.1", "1.2.0"));
+        assert!(!is_newer_version("1.2.0-rc.1", "1.2.0-rc.1"));
+    }
+
+    #[test]
+    fn build_metadata_is_ignored() {
+        assert!(!is_newer_version("1.2.0+build.2", "1.2.0+build.1"));
+        assert!(!is_newer_version("1.2.0+sha.abc", "1.2.0"));
+        assert!(!is_newer_version("1.2.0-rc.1+1", "1.2.0-rc.1"));
+        assert!(is_newer_version("1.2.1+build.1", "1.2.0+build.9"));
+    }
+
+    #[test]
+    fn leading_v_prefix_is_ignored() {
+        assert!(!is_newer_version("v1.2.0", "1.2.0"));
+        assert!(!is_newer_version("1.2.0", "v1.2.0"));
+        assert!(is_newer_version("v1.2.0", "v1.2.0-rc.1"));
+        assert!(is_newer_version("v1.3", "1.2.9"));
+    }
+
     #[test]
     fn parse_sha256_digest_accepts_valid_sha256() {
         let parsed = parse_sha256_digest(
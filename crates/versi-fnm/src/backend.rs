@@ -1,16 +1,19 @@
 use async_trait::async_trait;
-use log::{debug, error, info, trace};
-use std::path::PathBuf;
+use log::{debug, error, info, trace, warn};
+use std::path::{Path, PathBuf};
 use tokio::process::Command;
 
 use versi_core::HideWindow;
 
 use versi_backend::{
-    BackendError, BackendInfo, InstalledVersion, ManagerCapabilities, NodeVersion, RemoteVersion,
-    ShellInitOptions, VersionManager,
+    BackendError, BackendInfo, InstalledVersion, ManagerCapabilities, NodeArchive, NodeVersion,
+    RemoteVersion, ShellInitOptions, ShellOptionSupport, VersionAlias, VersionManager,
 };
 
-use crate::version::{parse_installed_versions, parse_remote_versions};
+use crate::version::{parse_aliases, parse_installed_versions, parse_remote_versions};
+
+/// fnm understands every shell option Versi offers.
+pub const SHELL_OPTION_SUPPORT: ShellOptionSupport = ShellOptionSupport::ALL;
 
 #[derive(Debug, Clone)]
 pub enum Environment {
@@ -114,6 +117,14 @@ impl FnmBackend {
         }
     }
 
+    /// Directory fnm keeps installed versions in, when it can be written to directly.
+    fn archive_install_dir(&self) -> Option<PathBuf> {
+        match (&self.environment, &self.fnm_dir) {
+            (Environment::Native, Some(dir)) => Some(dir.join("node-versions")),
+            _ => None,
+        }
+    }
+
     async fn execute(&self, args: &[&str]) -> Result<String, BackendError> {
         info!("Executing fnm command: {}", args.join(" "));
 
@@ -149,9 +160,9 @@ impl VersionManager for FnmBackend {
             supports_lts_filter: true,
             supports_use_version: true,
             supports_shell_integration: true,
-            supports_auto_switch: true,
-            supports_corepack: true,
-            supports_resolve_engines: true,
+            shell_options: SHELL_OPTION_SUPPORT,
+            supports_archive_install: self.archive_install_dir().is_some(),
+            supports_aliases: true,
         }
     }
 
@@ -198,6 +209,77 @@ impl VersionManager for FnmBackend {
         Ok(())
     }
 
+    async fn install_from_archive(&self, archive: &Path) -> Result<(), BackendError> {
+        let Some(versions_dir) = self.archive_install_dir() else {
+            return Err(BackendError::Unsupported {
+                operation: "install_from_archive",
+            });
+        };
+
+        let archive = NodeArchive::inspect(archive)?;
+        let dest = versions_dir
+            .join(archive.version.to_string())
+            .join("installation");
+        info!(
+            "Installing {} from {} into {}",
+            archive.version,
+            archive.path.display(),
+            dest.display()
+        );
+        versi_core::extract_archive_dir(&archive.path, &archive.root_dir, &dest)
+            .await
+            .map_err(BackendError::from)
+    }
+
+    async fn verify_installed(
+        &self,
+        client: &reqwest::Client,
+        version: &NodeVersion,
+    ) -> Result<bool, BackendError> {
+        let Some(versions_dir) = self.archive_install_dir() else {
+            return Err(BackendError::Unsupported {
+                operation: "verify_installed",
+            });
+        };
+
+        let install_dir = versions_dir.join(version.to_string()).join("installation");
+        let dist_url = self
+            .node_dist_mirror
+            .as_deref()
+            .unwrap_or(versi_core::DEFAULT_NODE_DIST_URL);
+        versi_backend::verify_node_install(client, dist_url, version, &install_dir).await
+    }
+
+    async fn list_aliases(&self) -> Result<Vec<VersionAlias>, BackendError> {
+        let output = self.execute(&["list"]).await?;
+        Ok(parse_aliases(&output))
+    }
+
+    async fn set_alias(&self, name: &str, version: &str) -> Result<(), BackendError> {
+        self.execute(&["alias", version, name]).await?;
+        Ok(())
+    }
+
+    async fn remove_alias(&self, name: &str) -> Result<(), BackendError> {
+        self.execute(&["unalias", name]).await?;
+        Ok(())
+    }
+
+    async fn node_runs(&self, version: &NodeVersion) -> Result<bool, BackendError> {
+        let version = version.to_string();
+        match self
+            .execute(&["exec", "--using", &version, "node", "--version"])
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(BackendError::CommandFailed { stderr }) => {
+                warn!("node {version} failed to run: {}", stderr.trim());
+                Ok(false)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     async fn uninstall(&self, version: &str) -> Result<(), BackendError> {
         self.execute(&["uninstall", version]).await?;
         Ok(())
@@ -213,6 +295,11 @@ impl VersionManager for FnmBackend {
         Ok(())
     }
 
+    async fn set_corepack(&self, version: &str, enabled: bool) -> Result<(), BackendError> {
+        self.execute(&corepack_args(version, enabled)).await?;
+        Ok(())
+    }
+
     fn shell_init_command(&self, shell: &str, options: &ShellInitOptions) -> Option<String> {
         let mut flags = Vec::new();
 
@@ -241,15 +328,34 @@ impl VersionManager for FnmBackend {
             _ => None,
         }
     }
+
+    fn version_shell_command(&self, version: &str, shell: &str) -> Option<Vec<String>> {
+        if !matches!(self.environment, Environment::Native) {
+            return None;
+        }
+        let mut command = Vec::new();
+        if let Some(dir) = self.fnm_dir.as_ref().filter(|_| cfg!(unix)) {
+            command.push("env".to_string());
+            command.push(format!("FNM_DIR={}", dir.display()));
+        }
+        command.push(self.info.path.to_string_lossy().into_owned());
+        command.extend(["exec", "--using", version, shell].map(str::to_string));
+        Some(command)
+    }
+}
+
+fn corepack_args(version: &str, enabled: bool) -> [&str; 5] {
+    let action = if enabled { "enable" } else { "disable" };
+    ["exec", "--using", version, "corepack", action]
 }
 
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use versi_backend::{ShellInitOptions, VersionManager};
+    use versi_backend::{ShellInitOptions, ShellOptionSupport, VersionManager};
 
-    use super::FnmBackend;
+    use super::{FnmBackend, corepack_args};
 
     fn backend() -> FnmBackend {
         FnmBackend::new(PathBuf::from("fnm"), Some("1.38.0".to_string()), None)
@@ -262,9 +368,18 @@ mod tests {
         assert!(capabilities.supports_lts_filter);
         assert!(capabilities.supports_use_version);
         assert!(capabilities.supports_shell_integration);
-        assert!(capabilities.supports_auto_switch);
-        assert!(capabilities.supports_corepack);
-        assert!(capabilities.supports_resolve_engines);
+        assert_eq!(capabilities.shell_options, ShellOptionSupport::ALL);
+        assert!(!capabilities.supports_archive_install);
+        assert!(capabilities.supports_aliases);
+    }
+
+    #[test]
+    fn archive_install_requires_native_fnm_dir() {
+        let native = backend().with_fnm_dir(PathBuf::from("/home/user/.local/share/fnm"));
+        assert!(native.capabilities().supports_archive_install);
+
+        let wsl = FnmBackend::with_wsl("Ubuntu".to_string(), "/usr/bin/fnm".to_string());
+        assert!(!wsl.capabilities().supports_archive_install);
     }
 
     #[test]
@@ -297,10 +412,55 @@ mod tests {
         assert_eq!(command, "fnm env --corepack-enabled | source");
     }
 
+    #[test]
+    fn version_shell_runs_the_shell_through_fnm_exec() {
+        let command = backend()
+            .version_shell_command("v20.11.0", "/bin/zsh")
+            .expect("native fnm should open a version shell");
+
+        assert_eq!(command, ["fnm", "exec", "--using", "v20.11.0", "/bin/zsh"]);
+    }
+
     #[test]
     fn shell_init_command_returns_none_for_unknown_shell() {
         let options = ShellInitOptions::default();
 
         assert!(backend().shell_init_command("nu", &options).is_none());
     }
+
+    #[test]
+    fn corepack_runs_through_fnm_exec_for_the_version() {
+        let command = backend().build_command(&corepack_args("v22.11.0", true));
+        let args: Vec<_> = command.as_std().get_args().collect();
+
+        assert_eq!(command.as_std().get_program(), "fnm");
+        assert_eq!(args, ["exec", "--using", "v22.11.0", "corepack", "enable"]);
+        assert_eq!(
+            corepack_args("v22.11.0", false),
+            ["exec", "--using", "v22.11.0", "corepack", "disable"]
+        );
+    }
+
+    #[test]
+    fn wsl_corepack_command_goes_through_wsl_exe() {
+        let wsl = FnmBackend::with_wsl("Ubuntu".to_string(), "/usr/bin/fnm".to_string());
+        let command = wsl.build_command(&corepack_args("v20.18.0", true));
+        let args: Vec<_> = command.as_std().get_args().collect();
+
+        assert_eq!(command.as_std().get_program(), "wsl.exe");
+        assert_eq!(
+            args,
+            [
+                "-d",
+                "Ubuntu",
+                "--",
+                "/usr/bin/fnm",
+                "exec",
+                "--using",
+                "v20.18.0",
+                "corepack",
+                "enable"
+            ]
+        );
+    }
 }
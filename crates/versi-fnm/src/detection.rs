@@ -145,7 +145,9 @@ async fn get_fnm_version(path: &PathBuf) -> Option<String> {
     Some(version)
 }
 
-pub(crate) async fn install_fnm() -> Result<(), versi_backend::BackendError> {
+pub(crate) async fn install_fnm(
+    retry_delays_secs: &[u64],
+) -> Result<(), versi_backend::BackendError> {
     #[cfg(unix)]
     let status = {
         let script_path = temp_script_path("fnm-install", "sh");
@@ -154,6 +156,7 @@ pub(crate) async fn install_fnm() -> Result<(), versi_backend::BackendError> {
                 FNM_INSTALL_SCRIPT_URL,
                 FNM_INSTALL_SCRIPT_SHA256,
                 &script_path,
+                retry_delays_secs,
             )
             .await?;
             Command::new("bash")
@@ -176,6 +179,7 @@ pub(crate) async fn install_fnm() -> Result<(), versi_backend::BackendError> {
                 FNM_INSTALL_SCRIPT_URL,
                 FNM_INSTALL_SCRIPT_SHA256,
                 &script_path,
+                retry_delays_secs,
             )
             .await?;
             Command::new("powershell")
@@ -217,8 +221,9 @@ async fn download_install_script(
     url: &str,
     expected_sha256: &str,
     path: &std::path::Path,
+    retry_delays_secs: &[u64],
 ) -> Result<(), versi_backend::BackendError> {
-    download_install_script_verified(url, expected_sha256, path)
+    download_install_script_verified(url, expected_sha256, path, retry_delays_secs)
         .await
         .map_err(|error| {
             versi_backend::BackendError::install_failed(
@@ -4,6 +4,6 @@ mod provider;
 mod update;
 mod version;
 
-pub use backend::{Environment, FnmBackend};
+pub use backend::{Environment, FnmBackend, SHELL_OPTION_SUPPORT};
 pub use provider::FnmProvider;
 pub use version::{parse_installed_versions, parse_remote_versions};
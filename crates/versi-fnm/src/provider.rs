@@ -38,6 +38,14 @@ impl BackendProvider for FnmProvider {
         "fnm (Fast Node Manager)"
     }
 
+    fn install_command_hint(&self, version: &str) -> String {
+        format!("fnm install {version}")
+    }
+
+    fn default_command_hint(&self, version: &str) -> String {
+        format!("fnm default {version}")
+    }
+
     async fn detect(&self) -> BackendDetection {
         let detection = detect_fnm().await;
         BackendDetection {
@@ -49,8 +57,8 @@ impl BackendProvider for FnmProvider {
         }
     }
 
-    async fn install_backend(&self) -> Result<(), BackendError> {
-        install_fnm().await
+    async fn install_backend(&self, retry_delays_secs: &[u64]) -> Result<(), BackendError> {
+        install_fnm(retry_delays_secs).await
     }
 
     async fn check_for_update(
@@ -59,6 +59,8 @@ mod tests {
             html_url: "https://github.com/Schniz/fnm/releases/tag/v1.0.0".to_string(),
             body: None,
             assets: Vec::new(),
+            prerelease: false,
+            draft: false,
         }
     }
 
@@ -1,4 +1,4 @@
-use versi_backend::{InstalledVersion, RemoteVersion};
+use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionAlias};
 
 #[must_use]
 pub fn parse_installed_versions(output: &str) -> Vec<InstalledVersion> {
@@ -31,6 +31,32 @@ pub fn parse_installed_versions(output: &str) -> Vec<InstalledVersion> {
         .collect()
 }
 
+/// Parse the aliases `fnm list` prints after each version, e.g.
+/// `* v20.11.0 default, project`.
+#[must_use]
+pub fn parse_aliases(output: &str) -> Vec<VersionAlias> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().trim_start_matches('*').trim();
+            let (version_str, names) = line.split_once(' ')?;
+            let version: NodeVersion = version_str.parse().ok()?;
+            Some(
+                names
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| VersionAlias {
+                        name: name.to_string(),
+                        version: version.clone(),
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
 #[must_use]
 pub fn parse_remote_versions(output: &str) -> Vec<RemoteVersion> {
     output
@@ -73,6 +99,29 @@ mod tests {
         assert!(!versions[2].is_default);
     }
 
+    #[test]
+    fn test_parse_aliases() {
+        let output = "* v20.11.0 default, project\nv18.19.1 legacy\nv16.20.2\n* system";
+        let aliases = parse_aliases(output);
+        assert_eq!(
+            aliases,
+            vec![
+                VersionAlias {
+                    name: "default".to_string(),
+                    version: NodeVersion::new(20, 11, 0),
+                },
+                VersionAlias {
+                    name: "project".to_string(),
+                    version: NodeVersion::new(20, 11, 0),
+                },
+                VersionAlias {
+                    name: "legacy".to_string(),
+                    version: NodeVersion::new(18, 19, 1),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_installed_versions_empty() {
         let output = "";
@@ -1,14 +1,17 @@
 use async_trait::async_trait;
 use log::{debug, info};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use versi_backend::{
-    BackendError, BackendInfo, InstalledVersion, ManagerCapabilities, NodeVersion, RemoteVersion,
-    ShellInitOptions, VersionManager,
+    BackendError, BackendInfo, InstalledVersion, ManagerCapabilities, NodeArchive, NodeVersion,
+    RemoteVersion, ShellInitOptions, ShellOptionSupport, VersionAlias, VersionManager,
 };
 
 use crate::client::{NvmClient, NvmEnvironment};
 
+/// nvm has no auto-switch, engines resolution or corepack integration.
+pub const SHELL_OPTION_SUPPORT: ShellOptionSupport = ShellOptionSupport::NONE;
+
 #[derive(Debug, Clone)]
 pub struct NvmBackend {
     info: BackendInfo,
@@ -55,9 +58,12 @@ impl VersionManager for NvmBackend {
             supports_lts_filter: true,
             supports_use_version: true,
             supports_shell_integration: supports_shell,
-            supports_auto_switch: false,
-            supports_corepack: false,
-            supports_resolve_engines: false,
+            shell_options: SHELL_OPTION_SUPPORT,
+            supports_archive_install: matches!(
+                self.client.environment,
+                NvmEnvironment::Unix { .. }
+            ),
+            supports_aliases: !self.client.is_windows(),
         }
     }
 
@@ -95,6 +101,51 @@ impl VersionManager for NvmBackend {
         self.client.install(version).await
     }
 
+    async fn install_from_archive(&self, archive: &Path) -> Result<(), BackendError> {
+        let NvmEnvironment::Unix { nvm_dir } = &self.client.environment else {
+            return Err(BackendError::Unsupported {
+                operation: "install_from_archive",
+            });
+        };
+
+        let archive = NodeArchive::inspect(archive)?;
+        let dest = nvm_dir
+            .join("versions")
+            .join("node")
+            .join(archive.version.to_string());
+        info!(
+            "nvm: installing {} from {}",
+            archive.version,
+            archive.path.display()
+        );
+        versi_core::extract_archive_dir(&archive.path, &archive.root_dir, &dest)
+            .await
+            .map_err(BackendError::from)
+    }
+
+    async fn verify_installed(
+        &self,
+        client: &reqwest::Client,
+        version: &NodeVersion,
+    ) -> Result<bool, BackendError> {
+        let NvmEnvironment::Unix { nvm_dir } = &self.client.environment else {
+            return Err(BackendError::Unsupported {
+                operation: "verify_installed",
+            });
+        };
+
+        let install_dir = nvm_dir
+            .join("versions")
+            .join("node")
+            .join(version.to_string());
+        versi_backend::verify_node_install(client, &node_dist_url(), version, &install_dir).await
+    }
+
+    async fn node_runs(&self, version: &NodeVersion) -> Result<bool, BackendError> {
+        debug!("nvm: checking that {version} runs");
+        self.client.node_runs(version).await
+    }
+
     async fn uninstall(&self, version: &str) -> Result<(), BackendError> {
         info!("nvm: uninstalling version {version}");
         self.client.uninstall(version).await
@@ -110,6 +161,21 @@ impl VersionManager for NvmBackend {
         self.client.use_version(version).await
     }
 
+    async fn list_aliases(&self) -> Result<Vec<VersionAlias>, BackendError> {
+        debug!("nvm: listing aliases");
+        self.client.aliases().await
+    }
+
+    async fn set_alias(&self, name: &str, version: &str) -> Result<(), BackendError> {
+        info!("nvm: pointing alias {name} at {version}");
+        self.client.set_alias(name, version).await
+    }
+
+    async fn remove_alias(&self, name: &str) -> Result<(), BackendError> {
+        info!("nvm: removing alias {name}");
+        self.client.remove_alias(name).await
+    }
+
     fn shell_init_command(&self, _shell: &str, _options: &ShellInitOptions) -> Option<String> {
         match &self.client.environment {
             NvmEnvironment::Unix { nvm_dir } => Some(format!(
@@ -122,6 +188,36 @@ impl VersionManager for NvmBackend {
             NvmEnvironment::Windows { .. } => None,
         }
     }
+
+    fn version_shell_command(&self, version: &str, shell: &str) -> Option<Vec<String>> {
+        if !matches!(self.client.environment, NvmEnvironment::Unix { .. }) {
+            return None;
+        }
+        let init = self.shell_init_command("bash", &ShellInitOptions::default())?;
+        // The version and shell go in as positional parameters so neither
+        // needs quoting inside the script.
+        Some(vec![
+            "bash".to_string(),
+            "-c".to_string(),
+            format!("{init} && nvm exec \"$1\" \"$2\""),
+            "bash".to_string(),
+            version.to_string(),
+            shell.to_string(),
+        ])
+    }
+}
+
+/// Where nvm downloads Node.js from: `NVM_NODEJS_ORG_MIRROR` when set, so
+/// checksums come from the same place as the install.
+fn node_dist_url() -> String {
+    dist_url_from_mirror(std::env::var("NVM_NODEJS_ORG_MIRROR").ok())
+}
+
+fn dist_url_from_mirror(mirror: Option<String>) -> String {
+    mirror
+        .map(|mirror| mirror.trim().trim_end_matches('/').to_string())
+        .filter(|mirror| !mirror.is_empty())
+        .unwrap_or_else(|| versi_core::DEFAULT_NODE_DIST_URL.to_string())
 }
 
 #[cfg(test)]
@@ -144,16 +240,50 @@ mod tests {
         assert!(caps.supports_shell_integration);
         assert!(caps.supports_lts_filter);
         assert!(caps.supports_use_version);
-        assert!(!caps.supports_auto_switch);
-        assert!(!caps.supports_corepack);
-        assert!(!caps.supports_resolve_engines);
+        assert!(!caps.shell_options.any());
+        assert!(caps.supports_archive_install);
+    }
+
+    #[test]
+    fn version_shell_loads_nvm_then_execs_the_shell() {
+        let command = unix_backend()
+            .version_shell_command("v20.11.0", "/bin/zsh")
+            .expect("unix nvm should open a version shell");
+
+        assert_eq!(command[..2], ["bash", "-c"]);
+        assert!(command[2].starts_with("export NVM_DIR=\"/home/user/.nvm\""));
+        assert!(command[2].ends_with("&& nvm exec \"$1\" \"$2\""));
+        assert_eq!(command[3..], ["bash", "v20.11.0", "/bin/zsh"]);
+        assert!(
+            windows_backend()
+                .version_shell_command("v20.11.0", "powershell")
+                .is_none()
+        );
     }
 
     #[test]
     fn windows_capabilities_no_shell_integration() {
         let caps = windows_backend().capabilities();
         assert!(!caps.supports_shell_integration);
+        assert!(!caps.supports_aliases);
         assert!(caps.supports_lts_filter);
         assert!(caps.supports_use_version);
+        assert!(!caps.supports_archive_install);
+    }
+
+    #[test]
+    fn checksums_come_from_the_nvm_mirror_when_set() {
+        assert_eq!(
+            dist_url_from_mirror(Some("https://npmmirror.com/mirrors/node/".to_string())),
+            "https://npmmirror.com/mirrors/node"
+        );
+        assert_eq!(
+            dist_url_from_mirror(Some(String::new())),
+            versi_core::DEFAULT_NODE_DIST_URL
+        );
+        assert_eq!(
+            dist_url_from_mirror(None),
+            versi_core::DEFAULT_NODE_DIST_URL
+        );
     }
 }
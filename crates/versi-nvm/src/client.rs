@@ -2,9 +2,10 @@ use std::path::PathBuf;
 use tokio::process::Command;
 
 use crate::version::{
-    parse_unix_installed, parse_unix_remote, parse_windows_installed, parse_windows_remote,
+    parse_unix_aliases, parse_unix_installed, parse_unix_remote, parse_windows_installed,
+    parse_windows_remote,
 };
-use versi_backend::{BackendError, InstalledVersion, NodeVersion, RemoteVersion};
+use versi_backend::{BackendError, InstalledVersion, NodeVersion, RemoteVersion, VersionAlias};
 use versi_platform::HideWindow;
 
 #[derive(Debug, Clone)]
@@ -218,6 +219,48 @@ impl NvmClient {
         Ok(())
     }
 
+    /// List user-defined aliases.
+    ///
+    /// # Errors
+    /// Returns an error on nvm-windows, which has no aliases, or if `nvm alias` fails.
+    pub async fn aliases(&self) -> Result<Vec<VersionAlias>, BackendError> {
+        if self.is_windows() {
+            return Err(BackendError::Unsupported {
+                operation: "list_aliases",
+            });
+        }
+        let output = self.execute(&["alias"]).await?;
+        Ok(parse_unix_aliases(&output))
+    }
+
+    /// Point an alias at a version, creating it if needed.
+    ///
+    /// # Errors
+    /// Returns an error on nvm-windows or if `nvm alias` fails.
+    pub async fn set_alias(&self, name: &str, version: &str) -> Result<(), BackendError> {
+        if self.is_windows() {
+            return Err(BackendError::Unsupported {
+                operation: "set_alias",
+            });
+        }
+        self.execute(&["alias", name, version]).await?;
+        Ok(())
+    }
+
+    /// Remove an alias.
+    ///
+    /// # Errors
+    /// Returns an error on nvm-windows or if `nvm unalias` fails.
+    pub async fn remove_alias(&self, name: &str) -> Result<(), BackendError> {
+        if self.is_windows() {
+            return Err(BackendError::Unsupported {
+                operation: "remove_alias",
+            });
+        }
+        self.execute(&["unalias", name]).await?;
+        Ok(())
+    }
+
     /// Activate a Node.js version for the current shell context.
     ///
     /// # Errors
@@ -227,6 +270,35 @@ impl NvmClient {
         Ok(())
     }
 
+    /// Check that the `node` binary of an installed version runs.
+    ///
+    /// # Errors
+    /// Returns an error if `nvm` itself cannot be started.
+    pub async fn node_runs(&self, version: &NodeVersion) -> Result<bool, BackendError> {
+        let version = version.to_string();
+        if let NvmEnvironment::Windows { nvm_exe } = &self.environment {
+            let Some(nvm_home) = nvm_exe.parent() else {
+                return Ok(false);
+            };
+            let mut cmd = Command::new(nvm_home.join(&version).join("node.exe"));
+            cmd.arg("--version");
+            cmd.hide_window();
+            return Ok(cmd
+                .output()
+                .await
+                .is_ok_and(|output| output.status.success()));
+        }
+
+        match self
+            .execute(&["exec", "--silent", &version, "node", "--version"])
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(BackendError::CommandFailed { .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
     /// Return the installed `nvm` tool version string.
     ///
     /// # Errors
@@ -169,7 +169,7 @@ pub fn detect_nvm_environment(detection: &NvmDetection) -> Option<NvmEnvironment
     }
 }
 
-pub async fn install_nvm() -> Result<(), versi_backend::BackendError> {
+pub async fn install_nvm(retry_delays_secs: &[u64]) -> Result<(), versi_backend::BackendError> {
     #[cfg(unix)]
     {
         let script_path = temp_script_path("nvm-install", "sh");
@@ -178,6 +178,7 @@ pub async fn install_nvm() -> Result<(), versi_backend::BackendError> {
                 NVM_INSTALL_SCRIPT_URL,
                 NVM_INSTALL_SCRIPT_SHA256,
                 &script_path,
+                retry_delays_secs,
             )
             .await?;
             Command::new("bash")
@@ -203,6 +204,7 @@ pub async fn install_nvm() -> Result<(), versi_backend::BackendError> {
 
     #[cfg(windows)]
     {
+        let _ = retry_delays_secs;
         Err(versi_backend::BackendError::install_failed(
             "unsupported platform flow",
             "Automatic nvm-windows installation is not supported. Please install manually from https://github.com/coreybutler/nvm-windows/releases",
@@ -223,8 +225,9 @@ async fn download_install_script(
     url: &str,
     expected_sha256: &str,
     path: &std::path::Path,
+    retry_delays_secs: &[u64],
 ) -> Result<(), versi_backend::BackendError> {
-    download_install_script_verified(url, expected_sha256, path)
+    download_install_script_verified(url, expected_sha256, path, retry_delays_secs)
         .await
         .map_err(|error| {
             versi_backend::BackendError::install_failed(
@@ -5,7 +5,7 @@ mod provider;
 mod update;
 mod version;
 
-pub use backend::NvmBackend;
+pub use backend::{NvmBackend, SHELL_OPTION_SUPPORT};
 pub use client::{NvmClient, NvmEnvironment};
 pub use detection::{NvmDetection, NvmVariant};
 pub use provider::NvmProvider;
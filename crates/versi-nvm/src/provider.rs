@@ -50,6 +50,14 @@ impl BackendProvider for NvmProvider {
         "nvm (Node Version Manager)"
     }
 
+    fn install_command_hint(&self, version: &str) -> String {
+        format!("nvm install {version}")
+    }
+
+    fn default_command_hint(&self, version: &str) -> String {
+        format!("nvm alias default {version}")
+    }
+
     async fn detect(&self) -> BackendDetection {
         let detection = detect_nvm().await;
         let path = detection.nvm_dir.clone().or(detection.nvm_exe);
@@ -63,8 +71,8 @@ impl BackendProvider for NvmProvider {
         }
     }
 
-    async fn install_backend(&self) -> Result<(), BackendError> {
-        install_nvm().await
+    async fn install_backend(&self, retry_delays_secs: &[u64]) -> Result<(), BackendError> {
+        install_nvm(retry_delays_secs).await
     }
 
     async fn check_for_update(
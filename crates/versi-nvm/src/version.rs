@@ -1,5 +1,5 @@
 use log::debug;
-use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion};
+use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionAlias};
 
 pub fn parse_unix_installed(output: &str) -> Vec<InstalledVersion> {
     let mut default_version: Option<NodeVersion> = None;
@@ -70,6 +70,37 @@ pub fn parse_unix_installed(output: &str) -> Vec<InstalledVersion> {
     versions
 }
 
+/// Parse user-defined aliases from `nvm alias` output, skipping nvm's
+/// built-in aliases and aliases that do not resolve to an installed version.
+pub fn parse_unix_aliases(output: &str) -> Vec<VersionAlias> {
+    const BUILTIN: [&str; 5] = ["node", "stable", "unstable", "iojs", "system"];
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, target) = line.trim().split_once(" -> ")?;
+            let name = name.trim();
+            if name.is_empty() || BUILTIN.contains(&name) || name.starts_with("lts/") {
+                return None;
+            }
+            let resolved = target
+                .rsplit_once("(-> ")
+                .map_or(target, |(_, inner)| inner.trim_end_matches(')'));
+            let version_str = resolved
+                .trim()
+                .trim_start_matches('v')
+                .split(|c: char| !c.is_ascii_digit() && c != '.')
+                .next()
+                .unwrap_or("");
+            let version = version_str.parse().ok()?;
+            Some(VersionAlias {
+                name: name.to_string(),
+                version,
+            })
+        })
+        .collect()
+}
+
 pub fn parse_windows_installed(output: &str) -> Vec<InstalledVersion> {
     let mut versions = Vec::new();
 
@@ -190,6 +221,26 @@ pub fn parse_windows_remote(output: &str) -> Vec<RemoteVersion> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_unix_aliases() {
+        let output = "default -> 20 (-> v20.11.0)\nproject -> v18.19.1\nbroken -> 14 (-> N/A)\nnode -> stable (-> v20.11.0) (default)\nstable -> 20.11 (-> v20.11.0) (default)\nlts/* -> lts/iron (-> v20.11.0)\n";
+        let aliases = parse_unix_aliases(output);
+
+        assert_eq!(
+            aliases,
+            vec![
+                VersionAlias {
+                    name: "default".to_string(),
+                    version: NodeVersion::new(20, 11, 0),
+                },
+                VersionAlias {
+                    name: "project".to_string(),
+                    version: NodeVersion::new(18, 19, 1),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_unix_installed_basic() {
         let output = "->     v20.11.0\n       v18.19.1\ndefault -> 20 (-> v20.11.0)\n";
@@ -1,3 +1,7 @@
+use std::io;
+use std::process::Output;
+use std::time::Duration;
+
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
 
@@ -32,9 +36,58 @@ impl HideWindow for std::process::Command {
     }
 }
 
+/// Run `command` to completion, killing it if it is still running after
+/// `timeout`.
+///
+/// # Errors
+/// Returns an error of kind [`io::ErrorKind::TimedOut`] when the deadline
+/// passes, or the spawn/wait error otherwise.
+pub async fn output_with_timeout(
+    command: &mut tokio::process::Command,
+    timeout: Duration,
+) -> io::Result<Output> {
+    command.kill_on_drop(true);
+    match tokio::time::timeout(timeout, command.output()).await {
+        Ok(output) => output,
+        Err(_) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("command timed out after {}s", timeout.as_secs()),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::HideWindow;
+    use std::time::Duration;
+
+    use super::{HideWindow, output_with_timeout};
+
+    fn slow_command() -> tokio::process::Command {
+        #[cfg(windows)]
+        {
+            let mut cmd = tokio::process::Command::new("ping");
+            cmd.args(["-n", "10", "127.0.0.1"]);
+            cmd
+        }
+        #[cfg(not(windows))]
+        {
+            let mut cmd = tokio::process::Command::new("sleep");
+            cmd.arg("10");
+            cmd
+        }
+    }
+
+    #[tokio::test]
+    async fn output_with_timeout_reports_timed_out_commands() {
+        let mut cmd = slow_command();
+        cmd.hide_window();
+
+        let error = output_with_timeout(&mut cmd, Duration::from_millis(50))
+            .await
+            .expect_err("slow command should time out");
+
+        assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+    }
 
     #[test]
     fn std_command_hide_window_is_chainable() {
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+/// Bytes in a megabyte, as free space and download sizes are shown.
+pub const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Free bytes available to the current user on the volume holding `path`.
+///
+/// `path` doesn't have to exist yet; its nearest existing ancestor is
+/// measured instead, so a data directory that will be created on first
+/// install can still be checked.
+///
+/// # Errors
+/// Returns an error when no ancestor of `path` exists or the volume can't be
+/// queried.
+pub fn available_space(path: &Path) -> std::io::Result<u64> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} does not exist", path.display()),
+            )
+        })?;
+    fs2::available_space(existing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::available_space;
+
+    #[test]
+    fn missing_directories_are_measured_through_their_parent() {
+        let missing = std::env::temp_dir().join("versi-missing").join("data");
+
+        assert!(available_space(&missing).is_ok());
+    }
+}
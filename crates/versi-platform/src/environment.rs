@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -30,6 +32,40 @@ impl EnvironmentId {
             EnvironmentId::Wsl { distro, .. } => format!("WSL: {distro}"),
         }
     }
+
+    /// Translate `path` into one the Windows host can open.
+    #[must_use]
+    pub fn host_path(&self, path: &Path) -> PathBuf {
+        match self {
+            EnvironmentId::Native => path.to_path_buf(),
+            EnvironmentId::Wsl { distro, .. } => wsl_path_to_unc(distro, path),
+        }
+    }
+}
+
+/// Convert a Linux path inside `distro` to its `\\wsl$\<distro>\...` UNC form.
+///
+/// Paths that are already Windows paths (drive-letter or UNC) are returned
+/// unchanged.
+#[must_use]
+pub fn wsl_path_to_unc(distro: &str, path: &Path) -> PathBuf {
+    let raw = path.to_string_lossy();
+    if is_windows_path(&raw) {
+        return path.to_path_buf();
+    }
+
+    let mut unc = format!("\\\\wsl$\\{distro}");
+    for component in raw.split('/').filter(|part| !part.is_empty()) {
+        unc.push('\\');
+        unc.push_str(component);
+    }
+    PathBuf::from(unc)
+}
+
+fn is_windows_path(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let has_drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+    has_drive || raw.starts_with("\\\\")
 }
 
 #[derive(Debug, Clone)]
@@ -66,7 +102,9 @@ impl Environment {
 
 #[cfg(test)]
 mod tests {
-    use super::{Environment, EnvironmentId};
+    use std::path::{Path, PathBuf};
+
+    use super::{Environment, EnvironmentId, wsl_path_to_unc};
 
     fn native_label() -> &'static str {
         if cfg!(target_os = "macos") {
@@ -116,4 +154,48 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn wsl_path_to_unc_converts_linux_paths() {
+        assert_eq!(
+            wsl_path_to_unc("Ubuntu", Path::new("/home/user/.local/share/fnm")),
+            PathBuf::from("\\\\wsl$\\Ubuntu\\home\\user\\.local\\share\\fnm")
+        );
+        assert_eq!(
+            wsl_path_to_unc("Debian", Path::new("/etc/profile")),
+            PathBuf::from("\\\\wsl$\\Debian\\etc\\profile")
+        );
+        assert_eq!(
+            wsl_path_to_unc("Ubuntu", Path::new("/")),
+            PathBuf::from("\\\\wsl$\\Ubuntu")
+        );
+    }
+
+    #[test]
+    fn wsl_path_to_unc_leaves_windows_paths_unchanged() {
+        for raw in [
+            "C:\\Users\\user\\AppData\\Roaming\\versi\\settings.json",
+            "\\\\wsl$\\Ubuntu\\home\\user",
+        ] {
+            assert_eq!(
+                wsl_path_to_unc("Ubuntu", Path::new(raw)),
+                PathBuf::from(raw)
+            );
+        }
+    }
+
+    #[test]
+    fn host_path_only_translates_wsl_environments() {
+        let path = Path::new("/home/user/.nvmrc");
+        let wsl = EnvironmentId::Wsl {
+            distro: "Ubuntu".to_string(),
+            backend_path: "/usr/bin/fnm".to_string(),
+        };
+
+        assert_eq!(EnvironmentId::Native.host_path(path), path);
+        assert_eq!(
+            wsl.host_path(path),
+            PathBuf::from("\\\\wsl$\\Ubuntu\\home\\user\\.nvmrc")
+        );
+    }
 }
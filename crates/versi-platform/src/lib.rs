@@ -1,13 +1,19 @@
 mod commands;
+mod disk;
 mod environment;
+mod motion;
 mod paths;
+mod terminal;
 
 #[cfg(target_os = "windows")]
 mod wsl;
 
-pub use commands::HideWindow;
-pub use environment::{Environment, EnvironmentId};
-pub use paths::{AppPaths, AppPathsError};
+pub use commands::{HideWindow, output_with_timeout};
+pub use disk::{BYTES_PER_MB, available_space};
+pub use environment::{Environment, EnvironmentId, wsl_path_to_unc};
+pub use motion::prefers_reduced_motion;
+pub use paths::{AppPaths, AppPathsError, DATA_DIR_ENV};
+pub use terminal::{open_terminal, user_shell};
 
 #[cfg(target_os = "windows")]
 pub use wsl::{WslDistro, detect_wsl_distros, execute_in_wsl};
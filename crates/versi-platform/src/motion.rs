@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use tokio::process::Command;
+
+use crate::commands::{HideWindow, output_with_timeout};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Whether the desktop asks applications to minimise animations.
+///
+/// Returns `false` when the preference cannot be read.
+pub async fn prefers_reduced_motion() -> bool {
+    let Some((program, args)) = PREFERENCE_QUERY else {
+        return false;
+    };
+    let mut command = Command::new(program);
+    command.args(args);
+    command.hide_window();
+    match output_with_timeout(&mut command, PROBE_TIMEOUT).await {
+        Ok(output) if output.status.success() => {
+            reduced_motion_from_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => false,
+    }
+}
+
+type PreferenceQuery = Option<(&'static str, &'static [&'static str])>;
+
+#[cfg(target_os = "macos")]
+const PREFERENCE_QUERY: PreferenceQuery = Some((
+    "defaults",
+    &["read", "com.apple.universalaccess", "reduceMotion"],
+));
+
+#[cfg(target_os = "windows")]
+const PREFERENCE_QUERY: PreferenceQuery = Some((
+    "reg",
+    &[
+        "query",
+        r"HKCU\Control Panel\Desktop\WindowMetrics",
+        "/v",
+        "MinAnimate",
+    ],
+));
+
+#[cfg(target_os = "linux")]
+const PREFERENCE_QUERY: PreferenceQuery = Some((
+    "gsettings",
+    &["get", "org.gnome.desktop.interface", "enable-animations"],
+));
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+const PREFERENCE_QUERY: PreferenceQuery = None;
+
+/// Interpret the output of the platform's preference query: macOS prints `1`
+/// when motion is reduced, while Windows (`MinAnimate`) and GNOME
+/// (`enable-animations`) report whether animations are enabled.
+fn reduced_motion_from_output(output: &str) -> bool {
+    let value = output.split_whitespace().last().unwrap_or_default();
+    if cfg!(target_os = "macos") {
+        value == "1"
+    } else {
+        value == "0" || value == "false"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reduced_motion_from_output;
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn gnome_disabled_animations_mean_reduced_motion() {
+        assert!(reduced_motion_from_output("false\n"));
+        assert!(!reduced_motion_from_output("true\n"));
+    }
+
+    #[test]
+    #[cfg(target_os = "windows")]
+    fn windows_min_animate_off_means_reduced_motion() {
+        let output = "\r\nHKEY_CURRENT_USER\\Control Panel\\Desktop\\WindowMetrics\r\n    MinAnimate    REG_SZ    0\r\n";
+        assert!(reduced_motion_from_output(output));
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn macos_reduce_motion_flag_means_reduced_motion() {
+        assert!(reduced_motion_from_output("1\n"));
+        assert!(!reduced_motion_from_output("0\n"));
+    }
+
+    #[test]
+    fn empty_output_does_not_reduce_motion() {
+        assert!(!reduced_motion_from_output(""));
+    }
+}
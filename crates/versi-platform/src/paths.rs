@@ -1,6 +1,11 @@
+use std::ffi::OsString;
 use std::path::PathBuf;
 use thiserror::Error;
 
+/// Environment variable that points every app directory at one root, for
+/// portable installs.
+pub const DATA_DIR_ENV: &str = "VERSI_DATA_DIR";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum AppPathsError {
     #[error("Could not determine home directory")]
@@ -13,19 +18,39 @@ pub enum AppPathsError {
     DataDirUnavailable,
 }
 
+impl AppPathsError {
+    /// The base directory that couldn't be determined, e.g. `"config"`.
+    #[must_use]
+    pub fn directory(self) -> &'static str {
+        match self {
+            Self::HomeDirUnavailable => "home",
+            Self::ConfigDirUnavailable => "config",
+            Self::CacheDirUnavailable => "cache",
+            Self::DataDirUnavailable => "data",
+        }
+    }
+}
+
 pub struct AppPaths {
     pub config_dir: PathBuf,
     pub cache_dir: PathBuf,
     pub data_dir: PathBuf,
+    /// Where fnm-ui kept its config before the rename to Versi, if known.
+    pub legacy_config_dir: Option<PathBuf>,
 }
 
 impl AppPaths {
-    /// Build application paths for the current platform.
+    /// Build application paths for the current platform, or under
+    /// [`DATA_DIR_ENV`] when it's set.
     ///
     /// # Errors
     /// Returns an error when a required base directory (for example the user
     /// home/config/cache/data directory) cannot be determined.
     pub fn new() -> Result<Self, AppPathsError> {
+        if let Some(paths) = Self::from_override(std::env::var_os(DATA_DIR_ENV)) {
+            return Ok(paths);
+        }
+
         #[cfg(target_os = "macos")]
         {
             let home = dirs::home_dir().ok_or(AppPathsError::HomeDirUnavailable)?;
@@ -33,45 +58,78 @@ impl AppPaths {
                 config_dir: home.join("Library/Application Support/versi"),
                 cache_dir: home.join("Library/Caches/versi"),
                 data_dir: home.join("Library/Application Support/versi"),
+                legacy_config_dir: Some(home.join("Library/Application Support/fnm-ui")),
             })
         }
 
         #[cfg(target_os = "windows")]
         {
+            let config_base = dirs::config_dir().ok_or(AppPathsError::ConfigDirUnavailable)?;
             Ok(Self {
-                config_dir: dirs::config_dir()
-                    .ok_or(AppPathsError::ConfigDirUnavailable)?
-                    .join("versi"),
+                config_dir: config_base.join("versi"),
                 cache_dir: dirs::cache_dir()
                     .ok_or(AppPathsError::CacheDirUnavailable)?
                     .join("versi"),
                 data_dir: dirs::data_dir()
                     .ok_or(AppPathsError::DataDirUnavailable)?
                     .join("versi"),
+                legacy_config_dir: Some(config_base.join("fnm-ui")),
             })
         }
 
         #[cfg(all(unix, not(target_os = "macos")))]
         {
+            let config_base = dirs::config_dir().ok_or(AppPathsError::ConfigDirUnavailable)?;
             Ok(Self {
-                config_dir: dirs::config_dir()
-                    .ok_or(AppPathsError::ConfigDirUnavailable)?
-                    .join("versi"),
+                config_dir: config_base.join("versi"),
                 cache_dir: dirs::cache_dir()
                     .ok_or(AppPathsError::CacheDirUnavailable)?
                     .join("versi"),
                 data_dir: dirs::data_dir()
                     .ok_or(AppPathsError::DataDirUnavailable)?
                     .join("versi"),
+                legacy_config_dir: Some(config_base.join("fnm-ui")),
             })
         }
     }
 
+    /// Paths rooted at a single directory. Settings, cache and logs all
+    /// live directly inside `root`.
+    #[must_use]
+    pub fn from_root(root: PathBuf) -> Self {
+        Self {
+            config_dir: root.clone(),
+            cache_dir: root.clone(),
+            data_dir: root,
+            legacy_config_dir: None,
+        }
+    }
+
+    fn from_override(value: Option<OsString>) -> Option<Self> {
+        value
+            .filter(|value| !value.is_empty())
+            .map(|value| Self::from_root(PathBuf::from(value)))
+    }
+
+    /// Whether the paths came from [`DATA_DIR_ENV`].
+    #[must_use]
+    pub fn is_overridden() -> bool {
+        std::env::var_os(DATA_DIR_ENV).is_some_and(|value| !value.is_empty())
+    }
+
     #[must_use]
     pub fn settings_file(&self) -> PathBuf {
         self.config_dir.join("settings.json")
     }
 
+    /// The settings file fnm-ui wrote, for a one-time import.
+    #[must_use]
+    pub fn legacy_settings_file(&self) -> Option<PathBuf> {
+        self.legacy_config_dir
+            .as_ref()
+            .map(|dir| dir.join("settings.json"))
+    }
+
     #[must_use]
     pub fn version_cache_file(&self) -> PathBuf {
         self.cache_dir.join("versions.json")
@@ -82,6 +140,11 @@ impl AppPaths {
         self.data_dir.join("debug.log")
     }
 
+    #[must_use]
+    pub fn audit_log_file(&self) -> PathBuf {
+        self.data_dir.join("audit.jsonl")
+    }
+
     /// Ensure all application directories exist on disk.
     ///
     /// # Errors
@@ -92,13 +155,27 @@ impl AppPaths {
         std::fs::create_dir_all(&self.data_dir)?;
         Ok(())
     }
+
+    /// Create the directories and check a file can be written in each.
+    ///
+    /// # Errors
+    /// Returns an error if a directory can't be created or written to.
+    pub fn check_writable(&self) -> std::io::Result<()> {
+        self.ensure_dirs()?;
+        for dir in [&self.config_dir, &self.cache_dir, &self.data_dir] {
+            let probe = dir.join(".versi-write-test");
+            std::fs::write(&probe, b"")?;
+            std::fs::remove_file(&probe)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use super::AppPaths;
+    use super::{AppPaths, AppPathsError};
 
     fn test_paths() -> AppPaths {
         let nonce = SystemTime::now()
@@ -114,6 +191,7 @@ mod tests {
             config_dir: root.join("config"),
             cache_dir: root.join("cache"),
             data_dir: root.join("data"),
+            legacy_config_dir: Some(root.join("fnm-ui")),
         }
     }
 
@@ -136,6 +214,62 @@ mod tests {
                 .log_file()
                 .ends_with(std::path::Path::new("data").join("debug.log"))
         );
+        assert!(
+            paths
+                .audit_log_file()
+                .ends_with(std::path::Path::new("data").join("audit.jsonl"))
+        );
+        assert!(paths.legacy_settings_file().is_some_and(|path| {
+            path.ends_with(std::path::Path::new("fnm-ui").join("settings.json"))
+        }));
+    }
+
+    #[test]
+    fn data_dir_override_redirects_every_path() {
+        let root = std::env::temp_dir().join("versi-portable");
+        let paths = AppPaths::from_override(Some(root.clone().into_os_string()))
+            .expect("a non-empty override should be used");
+
+        assert_eq!(paths.settings_file(), root.join("settings.json"));
+        assert_eq!(paths.version_cache_file(), root.join("versions.json"));
+        assert_eq!(paths.log_file(), root.join("debug.log"));
+        assert_eq!(paths.audit_log_file(), root.join("audit.jsonl"));
+        assert_eq!(paths.cache_dir, root);
+        assert_eq!(paths.legacy_settings_file(), None);
+    }
+
+    #[test]
+    fn empty_data_dir_override_is_ignored() {
+        assert!(AppPaths::from_override(Some(std::ffi::OsString::new())).is_none());
+        assert!(AppPaths::from_override(None).is_none());
+    }
+
+    #[test]
+    fn check_writable_accepts_a_fresh_directory() {
+        let paths = test_paths();
+        let root = paths
+            .config_dir
+            .parent()
+            .expect("config dir should have a parent")
+            .to_path_buf();
+
+        paths
+            .check_writable()
+            .expect("a fresh temp directory should be writable");
+        assert!(!paths.data_dir.join(".versi-write-test").exists());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn errors_name_the_missing_directory() {
+        assert_eq!(AppPathsError::HomeDirUnavailable.directory(), "home");
+        assert_eq!(AppPathsError::DataDirUnavailable.directory(), "data");
+        assert!(
+            AppPathsError::ConfigDirUnavailable
+                .to_string()
+                .contains(AppPathsError::ConfigDirUnavailable.directory())
+        );
     }
 
     #[test]
@@ -0,0 +1,181 @@
+use std::process::Command;
+
+/// The user's login shell, used as the program a new terminal should run.
+#[must_use]
+pub fn user_shell() -> String {
+    if cfg!(target_os = "windows") {
+        "powershell".to_string()
+    } else {
+        std::env::var("SHELL")
+            .ok()
+            .filter(|shell| !shell.is_empty())
+            .unwrap_or_else(|| "/bin/bash".to_string())
+    }
+}
+
+/// Open a new terminal window running `command` (program then arguments).
+///
+/// Best effort: on Linux the first terminal emulator that can be started is
+/// used, starting with `$TERMINAL`.
+///
+/// # Errors
+/// Returns an error when `command` is empty or no terminal could be started.
+pub fn open_terminal(command: &[String]) -> std::io::Result<()> {
+    if command.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "no command to run in the terminal",
+        ));
+    }
+    spawn_terminal(command)
+}
+
+#[cfg(target_os = "macos")]
+fn spawn_terminal(command: &[String]) -> std::io::Result<()> {
+    Command::new("osascript")
+        .args(macos_terminal_args(command))
+        .spawn()
+        .map(reap)
+}
+
+/// Wait for `child` in the background so it doesn't linger as a zombie once
+/// the terminal closes.
+fn reap(mut child: std::process::Child) {
+    std::thread::spawn(move || {
+        if let Err(error) = child.wait() {
+            log::debug!("Could not wait for the terminal process: {error}");
+        }
+    });
+}
+
+#[cfg(target_os = "macos")]
+fn macos_terminal_args(command: &[String]) -> Vec<String> {
+    let script = command
+        .iter()
+        .map(|arg| posix_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    vec![
+        "-e".to_string(),
+        format!("tell application \"Terminal\" to do script \"{script}\""),
+        "-e".to_string(),
+        "tell application \"Terminal\" to activate".to_string(),
+    ]
+}
+
+#[cfg(target_os = "macos")]
+fn posix_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+#[cfg(target_os = "linux")]
+const LINUX_TERMINALS: &[&str] = &[
+    "x-terminal-emulator",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "kitty",
+    "alacritty",
+    "xterm",
+];
+
+#[cfg(target_os = "linux")]
+fn spawn_terminal(command: &[String]) -> std::io::Result<()> {
+    let preferred = std::env::var("TERMINAL")
+        .ok()
+        .filter(|terminal| !terminal.is_empty());
+    let mut last_error = None;
+    for terminal in preferred
+        .iter()
+        .map(String::as_str)
+        .chain(LINUX_TERMINALS.iter().copied())
+    {
+        match Command::new(terminal)
+            .args(linux_terminal_args(terminal, command))
+            .spawn()
+        {
+            Ok(child) => {
+                reap(child);
+                return Ok(());
+            }
+            Err(error) => {
+                log::debug!("Could not start {terminal}: {error}");
+                last_error = Some(error);
+            }
+        }
+    }
+    Err(last_error.unwrap_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound)))
+}
+
+/// Arguments that make `terminal` run `command`, for the emulators that
+/// don't take the usual `-e`.
+#[cfg(target_os = "linux")]
+fn linux_terminal_args(terminal: &str, command: &[String]) -> Vec<String> {
+    let name = std::path::Path::new(terminal)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(terminal);
+    let separator = match name {
+        "gnome-terminal" => Some("--"),
+        "xfce4-terminal" => Some("-x"),
+        "kitty" => None,
+        _ => Some("-e"),
+    };
+    separator
+        .map(str::to_string)
+        .into_iter()
+        .chain(command.iter().cloned())
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn spawn_terminal(command: &[String]) -> std::io::Result<()> {
+    Command::new("cmd")
+        .args(["/C", "start", ""])
+        .args(command)
+        .spawn()
+        .map(reap)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn linux_terminals_get_the_flag_they_expect() {
+        use super::linux_terminal_args;
+
+        let command = vec![
+            "fnm".to_string(),
+            "exec".to_string(),
+            "--using".to_string(),
+            "v20.11.0".to_string(),
+            "/bin/zsh".to_string(),
+        ];
+
+        assert_eq!(
+            linux_terminal_args("/usr/bin/gnome-terminal", &command),
+            ["--", "fnm", "exec", "--using", "v20.11.0", "/bin/zsh"]
+        );
+        assert_eq!(
+            linux_terminal_args("xterm", &command),
+            ["-e", "fnm", "exec", "--using", "v20.11.0", "/bin/zsh"]
+        );
+        assert_eq!(
+            linux_terminal_args("kitty", &command),
+            ["fnm", "exec", "--using", "v20.11.0", "/bin/zsh"]
+        );
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn macos_script_quotes_arguments() {
+        let args = super::macos_terminal_args(&["fnm".to_string(), "it's".to_string()]);
+
+        assert_eq!(
+            args[1],
+            "tell application \"Terminal\" to do script \"'fnm' 'it'\\\\''s'\""
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use log::{debug, error, info, trace, warn};
-use std::process::Command;
+use std::process::Output;
+use std::time::Duration;
 use thiserror::Error;
+use tokio::process::Command;
 
-use crate::HideWindow;
+use crate::{HideWindow, output_with_timeout};
 
 #[derive(Debug, Clone)]
 pub struct WslDistro {
@@ -11,6 +13,8 @@ pub struct WslDistro {
     pub version: u8,
     pub backend_path: Option<String>,
     pub is_running: bool,
+    /// The distro is running but did not answer the backend probe in time.
+    pub timed_out: bool,
 }
 
 #[derive(Error, Debug)]
@@ -21,21 +25,41 @@ pub enum WslError {
     #[error("Command failed: {stderr}")]
     CommandFailed { stderr: String },
 
+    #[error("WSL did not respond within {seconds}s")]
+    Timeout { seconds: u64 },
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 }
 
-pub fn detect_wsl_distros(search_paths: &[&str]) -> Vec<WslDistro> {
+impl WslError {
+    fn from_io(error: std::io::Error, timeout: Duration) -> Self {
+        if error.kind() == std::io::ErrorKind::TimedOut {
+            Self::Timeout {
+                seconds: timeout.as_secs(),
+            }
+        } else {
+            Self::IoError(error)
+        }
+    }
+}
+
+async fn run_wsl(args: &[&str], timeout: Duration) -> Result<Output, WslError> {
+    let mut cmd = Command::new("wsl.exe");
+    cmd.args(args).hide_window();
+    output_with_timeout(&mut cmd, timeout)
+        .await
+        .map_err(|e| WslError::from_io(e, timeout))
+}
+
+pub async fn detect_wsl_distros(search_paths: &[&str], timeout: Duration) -> Vec<WslDistro> {
     info!("Detecting WSL distros...");
 
-    let running_distros = get_running_distro_names();
+    let running_distros = get_running_distro_names(timeout).await;
     debug!("Running distros: {:?}", running_distros);
 
     debug!("Running: wsl.exe --list --verbose");
-    let output = Command::new("wsl.exe")
-        .args(["--list", "--verbose"])
-        .hide_window()
-        .output();
+    let output = run_wsl(&["--list", "--verbose"], timeout).await;
 
     match output {
         Ok(output) => {
@@ -56,7 +80,13 @@ pub fn detect_wsl_distros(search_paths: &[&str]) -> Vec<WslDistro> {
                 for distro in &mut distros {
                     if distro.is_running {
                         debug!("Checking for backend in running distro: {}", distro.name);
-                        distro.backend_path = find_backend_path(&distro.name, search_paths);
+                        match find_backend_path(&distro.name, search_paths, timeout).await {
+                            Ok(path) => distro.backend_path = path,
+                            Err(e) => {
+                                warn!("Backend probe in {} failed: {}", distro.name, e);
+                                distro.timed_out = matches!(e, WslError::Timeout { .. });
+                            }
+                        }
                         if let Some(ref path) = distro.backend_path {
                             info!("Found backend in {}: {}", distro.name, path);
                         } else {
@@ -99,13 +129,8 @@ pub fn detect_wsl_distros(search_paths: &[&str]) -> Vec<WslDistro> {
     }
 }
 
-fn get_running_distro_names() -> Vec<String> {
-    let output = Command::new("wsl.exe")
-        .args(["--list", "--running", "--quiet"])
-        .hide_window()
-        .output();
-
-    match output {
+async fn get_running_distro_names(timeout: Duration) -> Vec<String> {
+    match run_wsl(&["--list", "--running", "--quiet"], timeout).await {
         Ok(output) if output.status.success() => {
             let stdout = decode_wsl_output(&output.stdout);
             stdout
@@ -118,9 +143,13 @@ fn get_running_distro_names() -> Vec<String> {
     }
 }
 
-fn find_backend_path(distro: &str, search_paths: &[&str]) -> Option<String> {
+async fn find_backend_path(
+    distro: &str,
+    search_paths: &[&str],
+    timeout: Duration,
+) -> Result<Option<String>, WslError> {
     if search_paths.is_empty() {
-        return None;
+        return Ok(None);
     }
 
     let check_cmd = search_paths
@@ -134,52 +163,42 @@ fn find_backend_path(distro: &str, search_paths: &[&str]) -> Option<String> {
         distro, distro, check_cmd
     );
 
-    let output = Command::new("wsl.exe")
-        .args(["-d", distro, "--", "sh", "-c", &check_cmd])
-        .hide_window()
-        .output();
+    let output = run_wsl(&["-d", distro, "--", "sh", "-c", &check_cmd], timeout).await?;
 
-    match output {
-        Ok(output) => {
-            debug!(
-                "Backend path detection for {} - exit status: {:?}",
-                distro, output.status
-            );
-            trace!(
-                "Backend path detection stdout: {:?}",
-                String::from_utf8_lossy(&output.stdout)
-            );
-            trace!(
-                "Backend path detection stderr: {:?}",
-                String::from_utf8_lossy(&output.stderr)
-            );
+    debug!(
+        "Backend path detection for {} - exit status: {:?}",
+        distro, output.status
+    );
+    trace!(
+        "Backend path detection stdout: {:?}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    trace!(
+        "Backend path detection stderr: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
 
-            if output.status.success() {
-                let path = String::from_utf8_lossy(&output.stdout)
-                    .lines()
-                    .next()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty());
-
-                if let Some(ref p) = path {
-                    debug!("Backend found at: {}", p);
-                    return path;
-                }
-                debug!("Backend path detection returned empty output");
-            } else {
-                warn!(
-                    "Backend path detection failed for {}: {}",
-                    distro,
-                    String::from_utf8_lossy(&output.stderr)
-                );
-            }
-        }
-        Err(e) => {
-            error!("Failed to run backend path detection for {}: {}", distro, e);
+    if output.status.success() {
+        let path = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        if let Some(ref p) = path {
+            debug!("Backend found at: {}", p);
+            return Ok(path);
         }
+        debug!("Backend path detection returned empty output");
+    } else {
+        warn!(
+            "Backend path detection failed for {}: {}",
+            distro,
+            String::from_utf8_lossy(&output.stderr)
+        );
     }
 
-    None
+    Ok(None)
 }
 
 fn decode_wsl_output(bytes: &[u8]) -> String {
@@ -232,6 +251,7 @@ fn parse_wsl_list(output: &str, running_distros: &[String]) -> Vec<WslDistro> {
                 version,
                 backend_path: None,
                 is_running,
+                timed_out: false,
             })
         })
         .collect()
@@ -398,20 +418,38 @@ mod tests {
         let distros = parse_wsl_list(output, &running);
 
         assert!(distros[0].backend_path.is_none());
+        assert!(!distros[0].timed_out);
+    }
+
+    #[test]
+    fn timed_out_io_errors_map_to_timeout() {
+        let timeout = Duration::from_secs(30);
+        let error = WslError::from_io(
+            std::io::Error::new(std::io::ErrorKind::TimedOut, "slow"),
+            timeout,
+        );
+        assert!(matches!(error, WslError::Timeout { seconds: 30 }));
+        assert_eq!(error.to_string(), "WSL did not respond within 30s");
+
+        let error = WslError::from_io(
+            std::io::Error::new(std::io::ErrorKind::NotFound, "missing"),
+            timeout,
+        );
+        assert!(matches!(error, WslError::IoError(_)));
     }
 }
 
-pub async fn execute_in_wsl(distro: &str, command: &str) -> Result<String, WslError> {
+pub async fn execute_in_wsl(
+    distro: &str,
+    command: &str,
+    timeout: Duration,
+) -> Result<String, WslError> {
     debug!(
         "Executing in WSL {}: wsl.exe -d {} -- sh -c \"{}\"",
         distro, distro, command
     );
 
-    let output = tokio::process::Command::new("wsl.exe")
-        .args(["-d", distro, "--", "sh", "-c", command])
-        .hide_window()
-        .output()
-        .await?;
+    let output = run_wsl(&["-d", distro, "--", "sh", "-c", command], timeout).await?;
 
     debug!("WSL command exit status: {:?}", output.status);
     trace!(
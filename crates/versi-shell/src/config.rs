@@ -1,7 +1,7 @@
 use crate::detect::ShellType;
 use std::fmt::Write as _;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use versi_backend::ShellInitOptions;
 
@@ -108,6 +108,130 @@ impl ShellConfig {
         }
     }
 
+    /// Collapse duplicate init blocks for `marker` into one and drop blocks
+    /// whose backend binary no longer exists.
+    ///
+    /// A block is a run of consecutive lines containing `marker` (nvm's
+    /// standard snippet spans three `NVM_DIR` lines) plus the `# {label}`
+    /// comment [`Self::add_init`] writes above it. The last block that still
+    /// points at an existing binary is kept as the canonical one. Line
+    /// endings are preserved.
+    #[must_use]
+    pub fn repair(&self, marker: &str, label: &str) -> ShellConfigEdit {
+        let lines: Vec<&str> = self.content.lines().collect();
+        let header = format!("# {label}");
+        let blocks = Self::init_blocks(&lines, marker);
+        let keep = blocks
+            .iter()
+            .rev()
+            .find(|block| block.missing.is_none())
+            .map(|block| block.first);
+
+        let mut removed = vec![false; lines.len()];
+        let mut duplicates = 0;
+        let mut changes = Vec::new();
+        for block in &blocks {
+            if Some(block.first) == keep {
+                continue;
+            }
+            match &block.missing {
+                Some(path) => changes.push(format!(
+                    "Removed initialization using missing {}",
+                    path.display()
+                )),
+                None => duplicates += 1,
+            }
+
+            let mut start = block.first;
+            if start > 0 && lines[start - 1].trim() == header {
+                start -= 1;
+            }
+            if start > 0 && lines[start - 1].trim().is_empty() {
+                start -= 1;
+            }
+            for flag in &mut removed[start..=block.last] {
+                *flag = true;
+            }
+        }
+        if duplicates > 0 {
+            changes.insert(
+                0,
+                format!("Removed {duplicates} duplicate initialization(s)"),
+            );
+        }
+
+        if changes.is_empty() {
+            return ShellConfigEdit {
+                original: self.content.clone(),
+                modified: self.content.clone(),
+                changes,
+            };
+        }
+
+        let newline = if self.content.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let mut modified = String::new();
+        for (line, _) in lines.iter().zip(&removed).filter(|(_, removed)| !**removed) {
+            modified.push_str(line);
+            modified.push_str(newline);
+        }
+        if !self.content.ends_with('\n') && modified.ends_with(newline) {
+            modified.truncate(modified.len() - newline.len());
+        }
+
+        ShellConfigEdit {
+            original: self.content.clone(),
+            modified,
+            changes,
+        }
+    }
+
+    /// Group consecutive lines containing `marker` into init blocks.
+    fn init_blocks(lines: &[&str], marker: &str) -> Vec<InitBlock> {
+        let mut blocks: Vec<InitBlock> = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            if !line.contains(marker) {
+                continue;
+            }
+            let missing = Self::missing_binary(line);
+            match blocks.last_mut() {
+                Some(block) if block.last + 1 == index => {
+                    block.last = index;
+                    if block.missing.is_none() {
+                        block.missing = missing;
+                    }
+                }
+                _ => blocks.push(InitBlock {
+                    first: index,
+                    last: index,
+                    missing,
+                }),
+            }
+        }
+        blocks
+    }
+
+    /// The first absolute path on an init line that doesn't exist, e.g. the
+    /// binary in `eval "$(/opt/fnm/fnm env)"` after fnm moved.
+    fn missing_binary(line: &str) -> Option<PathBuf> {
+        line.split(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '(' | ')' | '`'))
+            .filter_map(Self::absolute_path)
+            .find(|path| !path.exists())
+    }
+
+    fn absolute_path(token: &str) -> Option<PathBuf> {
+        if let Some(rest) = token.strip_prefix("~/") {
+            return dirs::home_dir().map(|home| home.join(rest));
+        }
+        let bytes = token.as_bytes();
+        let is_absolute = token.starts_with('/')
+            || (bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':');
+        is_absolute.then(|| Path::new(token).to_path_buf())
+    }
+
     /// Persist an edit to disk and update in-memory content.
     ///
     /// # Errors
@@ -197,6 +321,13 @@ impl ShellConfig {
     }
 }
 
+/// Line range of one init block found by [`ShellConfig::repair`].
+struct InitBlock {
+    first: usize,
+    last: usize,
+    missing: Option<PathBuf>,
+}
+
 pub struct ShellConfigEdit {
     pub original: String,
     pub modified: String,
@@ -442,4 +573,86 @@ mod tests {
         let preview = edit.diff_preview();
         assert_eq!(preview, "No changes needed.");
     }
+
+    #[test]
+    fn test_repair_collapses_duplicate_blocks() {
+        let config = create_test_config(
+            "# My bashrc\n\n# fnm\neval \"$(fnm env --shell bash)\"\nalias ll='ls -l'\n\n# fnm\neval \"$(fnm env --use-on-cd --shell bash)\"\n",
+        );
+
+        let edit = config.repair("fnm env", "fnm");
+
+        assert!(edit.has_changes());
+        assert_eq!(edit.modified.matches("fnm env").count(), 1);
+        assert_eq!(edit.modified.matches("# fnm\n").count(), 1);
+        assert_eq!(
+            edit.modified,
+            "# My bashrc\nalias ll='ls -l'\n\n# fnm\neval \"$(fnm env --use-on-cd --shell bash)\"\n"
+        );
+    }
+
+    #[test]
+    fn test_repair_removes_block_with_missing_binary() {
+        let config = create_test_config(
+            "# fnm\neval \"$(fnm env --shell bash)\"\n\n# fnm\neval \"$(/nonexistent/versi-test/fnm env --shell bash)\"\n",
+        );
+
+        let edit = config.repair("fnm env", "fnm");
+
+        assert_eq!(edit.modified, "# fnm\neval \"$(fnm env --shell bash)\"\n");
+        assert!(
+            edit.changes
+                .iter()
+                .any(|change| change.contains("/nonexistent/versi-test/fnm"))
+        );
+    }
+
+    #[test]
+    fn test_repair_keeps_multi_line_nvm_snippet() {
+        let snippet = "export NVM_DIR=\"$HOME/.nvm\"\n[ -s \"$NVM_DIR/nvm.sh\" ] && \\. \"$NVM_DIR/nvm.sh\"  # This loads nvm\n[ -s \"$NVM_DIR/bash_completion\" ] && \\. \"$NVM_DIR/bash_completion\"\n";
+        let config = create_test_config(&format!("# My bashrc\n{snippet}"));
+
+        let edit = config.repair("NVM_DIR", "nvm (Node Version Manager)");
+
+        assert!(!edit.has_changes());
+
+        let duplicated = create_test_config(&format!(
+            "{snippet}\n# nvm (Node Version Manager)\n{snippet}"
+        ));
+
+        let edit = duplicated.repair("NVM_DIR", "nvm (Node Version Manager)");
+
+        assert_eq!(
+            edit.changes,
+            vec!["Removed 1 duplicate initialization(s)".to_string()]
+        );
+        assert_eq!(
+            edit.modified,
+            format!("\n# nvm (Node Version Manager)\n{snippet}")
+        );
+    }
+
+    #[test]
+    fn test_repair_preserves_crlf_line_endings() {
+        let config = create_test_config(
+            "# fnm\r\neval \"$(fnm env --shell bash)\"\r\n\r\n# fnm\r\neval \"$(fnm env --shell bash)\"\r\n",
+        );
+
+        let edit = config.repair("fnm env", "fnm");
+
+        assert_eq!(
+            edit.modified,
+            "\r\n# fnm\r\neval \"$(fnm env --shell bash)\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_repair_leaves_single_block_untouched() {
+        let config = create_test_config("# fnm\neval \"$(fnm env --shell bash)\"\n");
+
+        let edit = config.repair("fnm env", "fnm");
+
+        assert!(!edit.has_changes());
+        assert_eq!(edit.modified, edit.original);
+    }
 }
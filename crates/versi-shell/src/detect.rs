@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use which::which;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -34,11 +35,49 @@ impl ShellType {
         }
     }
 
+    /// Recognise a shell from its program name or path, such as `$SHELL`.
+    #[must_use]
+    pub fn from_program(program: &str) -> Option<Self> {
+        let stem = Path::new(program)
+            .file_stem()?
+            .to_str()?
+            .to_ascii_lowercase();
+        match stem.as_str() {
+            "bash" => Some(ShellType::Bash),
+            "zsh" => Some(ShellType::Zsh),
+            "fish" => Some(ShellType::Fish),
+            "pwsh" | "powershell" => Some(ShellType::PowerShell),
+            "cmd" => Some(ShellType::Cmd),
+            _ => None,
+        }
+    }
+
+    /// Candidate config files, most preferred first.
+    ///
+    /// Honors `ZDOTDIR` for zsh, `__fish_config_dir` and `XDG_CONFIG_HOME`
+    /// for fish, and `XDG_CONFIG_HOME` for `PowerShell` on Unix, falling back
+    /// to the usual dotfiles in the home directory.
     #[must_use]
     pub fn config_files(&self) -> Vec<PathBuf> {
+        self.config_files_with(dirs::home_dir().as_deref(), |name| std::env::var_os(name))
+    }
+
+    fn config_files_with(
+        &self,
+        home: Option<&Path>,
+        var: impl Fn(&str) -> Option<OsString>,
+    ) -> Vec<PathBuf> {
+        let dir_var = |name: &str| {
+            var(name)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+        };
+        let xdg_config =
+            || dir_var("XDG_CONFIG_HOME").or_else(|| home.map(|home| home.join(".config")));
+
         match self {
             ShellType::Bash => {
-                let Some(home) = dirs::home_dir() else {
+                let Some(home) = home else {
                     return vec![];
                 };
                 vec![
@@ -48,16 +87,18 @@ impl ShellType {
                 ]
             }
             ShellType::Zsh => {
-                let Some(home) = dirs::home_dir() else {
+                let Some(dir) = dir_var("ZDOTDIR").or_else(|| home.map(Path::to_path_buf)) else {
                     return vec![];
                 };
-                vec![home.join(".zshrc"), home.join(".zprofile")]
+                vec![dir.join(".zshrc"), dir.join(".zprofile")]
             }
             ShellType::Fish => {
-                let Some(home) = dirs::home_dir() else {
+                let Some(dir) =
+                    dir_var("__fish_config_dir").or_else(|| xdg_config().map(|c| c.join("fish")))
+                else {
                     return vec![];
                 };
-                vec![home.join(".config/fish/config.fish")]
+                vec![dir.join("config.fish")]
             }
             ShellType::PowerShell => {
                 #[cfg(target_os = "windows")]
@@ -73,10 +114,10 @@ impl ShellType {
                 }
                 #[cfg(not(target_os = "windows"))]
                 {
-                    let Some(home) = dirs::home_dir() else {
+                    let Some(config) = xdg_config() else {
                         return vec![];
                     };
-                    vec![home.join(".config/powershell/Microsoft.PowerShell_profile.ps1")]
+                    vec![config.join("powershell/Microsoft.PowerShell_profile.ps1")]
                 }
             }
             ShellType::Cmd => vec![],
@@ -270,6 +311,20 @@ mod tests {
         assert_eq!(ShellType::Cmd.shell_arg(), "cmd");
     }
 
+    #[test]
+    fn shell_type_from_program_reads_the_file_name() {
+        assert_eq!(ShellType::from_program("/bin/zsh"), Some(ShellType::Zsh));
+        assert_eq!(
+            ShellType::from_program("/usr/local/bin/fish"),
+            Some(ShellType::Fish)
+        );
+        assert_eq!(
+            ShellType::from_program("powershell"),
+            Some(ShellType::PowerShell)
+        );
+        assert_eq!(ShellType::from_program("/bin/tcsh"), None);
+    }
+
     #[test]
     fn test_config_files_bash() {
         let files = ShellType::Bash.config_files();
@@ -309,4 +364,65 @@ mod tests {
         let cloned = shell.clone();
         assert_eq!(shell, cloned);
     }
+
+    fn vars(pairs: &[(&'static str, &'static str)]) -> impl Fn(&str) -> Option<OsString> {
+        let pairs = pairs.to_vec();
+        move |name| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| OsString::from(value))
+        }
+    }
+
+    #[test]
+    fn zsh_config_follows_zdotdir() {
+        let home = Path::new("/home/me");
+
+        assert_eq!(
+            ShellType::Zsh
+                .config_files_with(Some(home), vars(&[("ZDOTDIR", "/home/me/.config/zsh")]))[0],
+            PathBuf::from("/home/me/.config/zsh/.zshrc")
+        );
+        assert_eq!(
+            ShellType::Zsh.config_files_with(Some(home), vars(&[]))[0],
+            PathBuf::from("/home/me/.zshrc")
+        );
+    }
+
+    #[test]
+    fn fish_config_follows_fish_config_dir_then_xdg() {
+        let home = Path::new("/home/me");
+
+        assert_eq!(
+            ShellType::Fish.config_files_with(
+                Some(home),
+                vars(&[
+                    ("__fish_config_dir", "/opt/fish-config"),
+                    ("XDG_CONFIG_HOME", "/home/me/xdg"),
+                ])
+            ),
+            vec![PathBuf::from("/opt/fish-config/config.fish")]
+        );
+        assert_eq!(
+            ShellType::Fish
+                .config_files_with(Some(home), vars(&[("XDG_CONFIG_HOME", "/home/me/xdg")])),
+            vec![PathBuf::from("/home/me/xdg/fish/config.fish")]
+        );
+        assert_eq!(
+            ShellType::Fish.config_files_with(Some(home), vars(&[("XDG_CONFIG_HOME", "")])),
+            vec![PathBuf::from("/home/me/.config/fish/config.fish")]
+        );
+    }
+
+    #[test]
+    fn bash_config_ignores_xdg() {
+        assert_eq!(
+            ShellType::Bash.config_files_with(
+                Some(Path::new("/home/me")),
+                vars(&[("XDG_CONFIG_HOME", "/home/me/xdg")])
+            )[0],
+            PathBuf::from("/home/me/.bashrc")
+        );
+    }
 }
@@ -8,9 +8,12 @@
 //! - Detect native shells (and WSL shells on Windows).
 //! - Load/update shell config files with idempotent edits.
 //! - Verify whether shell integration is configured and functional.
+//! - Probe a shell to confirm the init command actually resolves `node`.
+//! - Find `node` executables that shadow the managed one on PATH.
 
 mod config;
 mod detect;
+mod node_path;
 mod verify;
 
 pub mod shells;
@@ -19,11 +22,14 @@ pub mod shells;
 pub use config::{ConfigError, ShellConfig, ShellConfigEdit};
 /// Shell detection models and entry points.
 pub use detect::{ShellInfo, ShellType, detect_native_shells, detect_shells, detect_wsl_shells};
+/// PATH-ordered `node` lookup for spotting system installs that win over the
+/// managed version.
+pub use node_path::{NodePathConflict, find_path_conflict, node_candidates, parse_node_candidates};
 /// Verification and configuration helpers used by the app layer.
 pub use verify::{
-    ShellConfigLoadError, VerificationError, VerificationResult, WslShellConfigError,
-    configure_wsl_shell_config, get_or_create_config_path, verify_shell_config,
-    verify_wsl_shell_config,
+    ProbeError, ShellConfigLoadError, VerificationError, VerificationResult, WslShellConfigError,
+    configure_wsl_shell_config, get_or_create_config_path, probe_shell_integration,
+    verify_shell_config, verify_wsl_shell_config,
 };
 /// Shared shell initialization options used across backend integrations.
 pub use versi_backend::ShellInitOptions;
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::process::Command;
+use versi_platform::{HideWindow, output_with_timeout};
+
+use crate::detect::ShellType;
+
+/// A `node` that the shell finds before the version manager's, so the
+/// manager's default never takes effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodePathConflict {
+    pub shadowing: PathBuf,
+    pub managed: PathBuf,
+}
+
+/// List every `node` executable `shell_type` can see, in PATH order.
+///
+/// Runs the shell the same way a terminal session would start (login and
+/// interactive), so its config (and therefore the manager's PATH entries) is
+/// loaded. Returns an empty list when the shell can't be run or doesn't
+/// finish within `timeout`.
+pub async fn node_candidates(shell_type: &ShellType, timeout: Duration) -> Vec<PathBuf> {
+    let (program, args): (&str, &[&str]) = match shell_type {
+        ShellType::Bash => ("bash", &["-l", "-i", "-c", "which -a node"]),
+        ShellType::Zsh => ("zsh", &["-l", "-i", "-c", "which -a node"]),
+        ShellType::Fish => ("fish", &["-c", "type -a -p node"]),
+        ShellType::PowerShell => (
+            if which::which("pwsh").is_ok() {
+                "pwsh"
+            } else {
+                "powershell"
+            },
+            &[
+                "-Command",
+                "(Get-Command node -All -CommandType Application).Source",
+            ],
+        ),
+        ShellType::Cmd => ("cmd", &["/C", "where node"]),
+    };
+
+    let mut command = Command::new(program);
+    command.args(args).hide_window();
+    match output_with_timeout(&mut command, timeout).await {
+        Ok(output) => parse_node_candidates(&String::from_utf8_lossy(&output.stdout)),
+        Err(error) => {
+            log::debug!("Could not list node executables with {program}: {error}");
+            Vec::new()
+        }
+    }
+}
+
+/// Parse `which -a`-style output into paths, skipping shell chatter such as
+/// alias descriptions and repeated entries.
+pub fn parse_node_candidates(output: &str) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if !looks_like_path(line) {
+            continue;
+        }
+        let path = PathBuf::from(line);
+        if !candidates.contains(&path) {
+            candidates.push(path);
+        }
+    }
+    candidates
+}
+
+fn looks_like_path(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    line.starts_with('/')
+        || line.starts_with("\\\\")
+        || (bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+}
+
+/// Report the first unmanaged `node` when it comes before a managed one.
+pub fn find_path_conflict(
+    candidates: &[PathBuf],
+    is_managed: impl Fn(&Path) -> bool,
+) -> Option<NodePathConflict> {
+    let managed = candidates.iter().position(|path| is_managed(path))?;
+    let shadowing = candidates[..managed]
+        .iter()
+        .find(|path| !is_managed(path))?;
+    Some(NodePathConflict {
+        shadowing: shadowing.clone(),
+        managed: candidates[managed].clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{NodePathConflict, find_path_conflict, parse_node_candidates};
+
+    fn is_fnm(path: &Path) -> bool {
+        path.to_string_lossy().contains("fnm")
+    }
+
+    #[test]
+    fn parses_which_all_output_in_order() {
+        let output = "node: aliased to /opt/node/bin/node\n\
+                      /usr/bin/node\n\
+                      /home/me/.local/state/fnm_multishells/123_456/bin/node\n\
+                      /usr/bin/node\n\n";
+
+        assert_eq!(
+            parse_node_candidates(output),
+            vec![
+                PathBuf::from("/usr/bin/node"),
+                PathBuf::from("/home/me/.local/state/fnm_multishells/123_456/bin/node"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_windows_where_output() {
+        let output = "C:\\Program Files\\nodejs\\node.exe\r\n";
+
+        assert_eq!(
+            parse_node_candidates(output),
+            vec![PathBuf::from("C:\\Program Files\\nodejs\\node.exe")]
+        );
+    }
+
+    #[test]
+    fn system_node_before_managed_node_is_a_conflict() {
+        let candidates = parse_node_candidates(
+            "/usr/bin/node\n/home/me/.local/state/fnm_multishells/1_2/bin/node\n",
+        );
+
+        assert_eq!(
+            find_path_conflict(&candidates, is_fnm),
+            Some(NodePathConflict {
+                shadowing: PathBuf::from("/usr/bin/node"),
+                managed: PathBuf::from("/home/me/.local/state/fnm_multishells/1_2/bin/node"),
+            })
+        );
+    }
+
+    #[test]
+    fn managed_node_first_or_missing_is_not_a_conflict() {
+        let managed_first = parse_node_candidates(
+            "/home/me/.local/state/fnm_multishells/1_2/bin/node\n/usr/bin/node\n",
+        );
+        assert_eq!(find_path_conflict(&managed_first, is_fnm), None);
+
+        let system_only = parse_node_candidates("/usr/bin/node\n");
+        assert_eq!(find_path_conflict(&system_only, is_fnm), None);
+    }
+}
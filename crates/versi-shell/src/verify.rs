@@ -1,10 +1,11 @@
 use crate::config::ShellConfig;
 use crate::detect::ShellType;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::process::Command;
 use versi_backend::ShellInitOptions;
-use versi_platform::HideWindow;
+use versi_platform::{HideWindow, output_with_timeout};
 
 #[cfg(target_os = "windows")]
 use std::process::Stdio;
@@ -151,6 +152,109 @@ async fn functional_test(shell_type: &ShellType, backend_binary: &str) -> bool {
     }
 }
 
+#[derive(Debug, Error)]
+pub enum ProbeError {
+    #[error("Shell integration can't be tested for {0}")]
+    UnsupportedShell(&'static str),
+    #[error("Failed to start {program}")]
+    Spawn {
+        program: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Shell did not finish within {0} seconds")]
+    TimedOut(u64),
+    #[error("node was not found after running the init command: {0}")]
+    NodeNotFound(String),
+}
+
+/// Start `shell_type` non-interactively, run `init_command` and report where
+/// `node` resolves to afterwards.
+///
+/// Unlike [`verify_shell_config`], this doesn't read any config file: it
+/// proves the init command itself puts a managed `node` on PATH.
+///
+/// # Errors
+/// Returns an error if `shell_type` can't be probed, the shell fails to
+/// start, it doesn't finish within `timeout`, or `node` doesn't resolve after
+/// the init command runs.
+pub async fn probe_shell_integration(
+    shell_type: &ShellType,
+    init_command: &str,
+    timeout: Duration,
+) -> Result<PathBuf, ProbeError> {
+    let (program, args) = probe_command(shell_type, init_command)?;
+    run_probe(program, &args, timeout).await
+}
+
+fn probe_command(
+    shell_type: &ShellType,
+    init_command: &str,
+) -> Result<(&'static str, Vec<String>), ProbeError> {
+    let posix_script = format!("{init_command}\ncommand -v node");
+    match shell_type {
+        ShellType::Bash => Ok(("bash", vec!["-c".to_string(), posix_script])),
+        ShellType::Zsh => Ok((
+            "zsh",
+            vec!["-f".to_string(), "-c".to_string(), posix_script],
+        )),
+        ShellType::Fish => Ok((
+            "fish",
+            vec!["--no-config".to_string(), "-c".to_string(), posix_script],
+        )),
+        ShellType::PowerShell => Ok((
+            if which::which("pwsh").is_ok() {
+                "pwsh"
+            } else {
+                "powershell"
+            },
+            vec![
+                "-NoProfile".to_string(),
+                "-Command".to_string(),
+                format!("{init_command}; (Get-Command node -CommandType Application).Source"),
+            ],
+        )),
+        ShellType::Cmd => Err(ProbeError::UnsupportedShell(shell_type.name())),
+    }
+}
+
+async fn run_probe(
+    program: &str,
+    args: &[String],
+    timeout: Duration,
+) -> Result<PathBuf, ProbeError> {
+    let mut command = Command::new(program);
+    command.args(args).hide_window();
+
+    let output = match output_with_timeout(&mut command, timeout).await {
+        Ok(output) => output,
+        Err(error) if error.kind() == std::io::ErrorKind::TimedOut => {
+            return Err(ProbeError::TimedOut(timeout.as_secs()));
+        }
+        Err(source) => {
+            return Err(ProbeError::Spawn {
+                program: program.to_string(),
+                source,
+            });
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let resolved = stdout.lines().map(str::trim).rfind(|line| !line.is_empty());
+    match resolved {
+        Some(path) if output.status.success() => Ok(PathBuf::from(path)),
+        _ => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let details = stderr.trim();
+            Err(ProbeError::NodeNotFound(if details.is_empty() {
+                format!("exited with {}", output.status)
+            } else {
+                details.to_string()
+            }))
+        }
+    }
+}
+
 pub fn get_config_path_for_shell(shell_type: &ShellType) -> Option<PathBuf> {
     shell_type.config_files().into_iter().find(|p| p.exists())
 }
@@ -446,11 +550,13 @@ pub async fn configure_wsl_shell_config(
 mod tests {
     use crate::detect::ShellType;
 
+    use super::{
+        ProbeError, get_config_path_for_shell, get_or_create_config_path, probe_command, run_probe,
+    };
     #[cfg(not(target_os = "windows"))]
     use super::{
         VerificationError, WslShellConfigError, configure_wsl_shell_config, verify_wsl_shell_config,
     };
-    use super::{get_config_path_for_shell, get_or_create_config_path};
     #[cfg(not(target_os = "windows"))]
     use versi_backend::ShellInitOptions;
 
@@ -460,6 +566,86 @@ mod tests {
         assert!(get_or_create_config_path(&ShellType::Cmd).is_none());
     }
 
+    #[test]
+    fn cmd_shell_cannot_be_probed() {
+        assert!(matches!(
+            probe_command(&ShellType::Cmd, "fnm env --use-on-cd | Invoke-Expression"),
+            Err(ProbeError::UnsupportedShell("Command Prompt"))
+        ));
+    }
+
+    #[test]
+    fn probe_runs_init_before_resolving_node() {
+        let (program, args) =
+            probe_command(&ShellType::Bash, "eval \"$(fnm env --use-on-cd)\"").unwrap();
+
+        assert_eq!(program, "bash");
+        assert_eq!(
+            args,
+            vec!["-c", "eval \"$(fnm env --use-on-cd)\"\ncommand -v node"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_reports_last_resolved_path() {
+        let args = vec![
+            "-c".to_string(),
+            "echo 'Using Node v22.0.0'; echo /home/me/.fnm/node-versions/v22.0.0/bin/node"
+                .to_string(),
+        ];
+
+        let result = run_probe("sh", &args, std::time::Duration::from_secs(5)).await;
+
+        assert_eq!(
+            result.unwrap(),
+            std::path::PathBuf::from("/home/me/.fnm/node-versions/v22.0.0/bin/node")
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_captures_stderr_when_node_is_missing() {
+        let args = vec![
+            "-c".to_string(),
+            "echo 'fnm: command not found' >&2; exit 127".to_string(),
+        ];
+
+        let result = run_probe("sh", &args, std::time::Duration::from_secs(5)).await;
+
+        assert!(matches!(
+            result,
+            Err(ProbeError::NodeNotFound(details)) if details == "fnm: command not found"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_is_bounded_by_timeout() {
+        let args = vec!["-c".to_string(), "sleep 5".to_string()];
+
+        let result = run_probe("sh", &args, std::time::Duration::from_secs(1)).await;
+
+        assert!(matches!(result, Err(ProbeError::TimedOut(1))));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_keeps_spawn_error_as_source() {
+        let result = run_probe(
+            "versi-missing-shell",
+            &[],
+            std::time::Duration::from_secs(5),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ProbeError::Spawn { ref source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
+
     #[cfg(not(target_os = "windows"))]
     #[tokio::test]
     async fn wsl_verify_returns_platform_error_on_non_windows() {
@@ -9,6 +9,20 @@ fn main() {
 
     println!("cargo:rerun-if-changed={}", icon_path.display());
 
+    // Honour SOURCE_DATE_EPOCH so reproducible builds get a stable value.
+    // Otherwise restamp whenever the sources change; the icon alone would
+    // leave the timestamp at whenever this script first ran.
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let build_timestamp = std::env::var("SOURCE_DATE_EPOCH").unwrap_or_else(|_| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs().to_string())
+            .unwrap_or_default()
+    });
+    println!("cargo:rustc-env=VERSI_BUILD_TIMESTAMP={build_timestamp}");
+
     #[cfg(windows)]
     {
         let mut res = winresource::WindowsResource::new();
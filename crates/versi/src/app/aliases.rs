@@ -0,0 +1,313 @@
+//! Backend alias listing and management.
+//!
+//! Handles messages: `AliasesLoaded`, `RequestCreateAlias`, `AliasNameChanged`,
+//! `ConfirmCreateAlias`, `RemoveAlias`, `ConfirmRemoveAlias`, `AliasChanged`
+//!
+//! Alias changes run as exclusive operations on the operation queue, so they
+//! never race an uninstall of the version they point at.
+
+use std::time::Duration;
+
+use log::{debug, info};
+
+use iced::Task;
+
+use versi_backend::{BackendError, VersionAlias};
+use versi_platform::EnvironmentId;
+
+use crate::error::AppError;
+use crate::message::Message;
+use crate::state::{AppState, Modal, Operation, Toast};
+
+use super::Versi;
+use super::async_helpers::run_with_timeout;
+use super::operations::enqueue_exclusive_if_busy;
+
+impl Versi {
+    pub(super) fn load_aliases(&self, env_id: &EnvironmentId) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        if &state.active_environment().id != env_id {
+            return Task::none();
+        }
+        self.reload_aliases(env_id)
+    }
+
+    /// List `env_id`'s aliases with its own backend, whether or not it's
+    /// still the active environment.
+    fn reload_aliases(&self, env_id: &EnvironmentId) -> Task<Message> {
+        let Some(backend) = self.backend_for(env_id) else {
+            return Task::none();
+        };
+        let env_id = env_id.clone();
+        Task::perform(
+            async move {
+                backend.list_aliases().await.unwrap_or_else(|error| {
+                    debug!("Could not list aliases: {error}");
+                    Vec::new()
+                })
+            },
+            move |aliases| Message::AliasesLoaded {
+                env_id: env_id.clone(),
+                aliases,
+            },
+        )
+    }
+
+    pub(super) fn handle_aliases_loaded(
+        &mut self,
+        env_id: &EnvironmentId,
+        aliases: Vec<VersionAlias>,
+    ) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(env) = state.environments.iter_mut().find(|e| &e.id == env_id)
+        {
+            env.aliases = aliases;
+        }
+    }
+
+    pub(super) fn handle_request_create_alias(&mut self, version: String) {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = Some(Modal::CreateAlias {
+                version,
+                name: String::new(),
+            });
+        }
+    }
+
+    pub(super) fn handle_alias_name_changed(&mut self, value: String) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::CreateAlias { name, .. }) = &mut state.modal
+        {
+            *name = value;
+        }
+    }
+
+    pub(super) fn handle_confirm_create_alias(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(Modal::CreateAlias { version, name }) = state.modal.take() else {
+            return Task::none();
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            state.modal = Some(Modal::CreateAlias { version, name });
+            return Task::none();
+        }
+
+        info!("Creating alias {name} -> {version}");
+        let operation = Operation::SetAlias {
+            environment: state.active_environment().id.clone(),
+            name,
+            version,
+        };
+        if enqueue_exclusive_if_busy(state, operation.clone()) {
+            return Task::none();
+        }
+        self.start_alias_operation(operation)
+    }
+
+    /// Ask before removing an alias; `default` in particular changes which
+    /// version new shells get.
+    pub(super) fn handle_remove_alias(&mut self, name: String) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let Some(alias) = state
+            .active_environment()
+            .aliases
+            .iter()
+            .find(|alias| alias.name == name)
+        else {
+            return;
+        };
+        state.modal = Some(Modal::ConfirmRemoveAlias {
+            version: alias.version.to_string(),
+            name,
+        });
+    }
+
+    pub(super) fn handle_confirm_remove_alias(&mut self, name: &str) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(Modal::ConfirmRemoveAlias {
+            name: pending,
+            version,
+        }) = state.modal.take()
+        else {
+            return Task::none();
+        };
+        if pending != name {
+            return Task::none();
+        }
+
+        info!("Removing alias {name}");
+        let operation = Operation::RemoveAlias {
+            environment: state.active_environment().id.clone(),
+            name: pending,
+            version,
+        };
+        if enqueue_exclusive_if_busy(state, operation.clone()) {
+            return Task::none();
+        }
+        self.start_alias_operation(operation)
+    }
+
+    /// Run an alias change with the backend of the environment it was
+    /// requested in, even if the user has switched away while it was queued.
+    pub(super) fn start_alias_operation(&mut self, operation: Operation) -> Task<Message> {
+        let (Operation::SetAlias { environment, .. } | Operation::RemoveAlias { environment, .. }) =
+            &operation
+        else {
+            return Task::none();
+        };
+        let Some(backend) = self.backend_for(environment) else {
+            info!(
+                "Dropping {} for an environment that no longer exists",
+                operation.label()
+            );
+            return self.process_next_operation();
+        };
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        state.operation_queue.start_exclusive(operation.clone());
+
+        let timeout = Duration::from_secs(self.settings.set_default_timeout_secs);
+        let label = operation.label();
+        Task::perform(
+            async move {
+                let change = async {
+                    match &operation {
+                        Operation::SetAlias { name, version, .. } => {
+                            backend.set_alias(name, version).await
+                        }
+                        Operation::RemoveAlias { name, .. } => backend.remove_alias(name).await,
+                        _ => Ok(()),
+                    }
+                };
+                run_with_timeout(timeout, label, change, |error: BackendError| {
+                    AppError::operation_failed(label, error)
+                })
+                .await
+            },
+            Message::AliasChanged,
+        )
+    }
+
+    pub(super) fn handle_alias_changed(&mut self, result: Result<(), AppError>) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let env_id = match &state.operation_queue.exclusive_op {
+            Some(
+                Operation::SetAlias { environment, .. }
+                | Operation::RemoveAlias { environment, .. },
+            ) => environment.clone(),
+            _ => state.active_environment().id.clone(),
+        };
+        state.operation_queue.complete_exclusive();
+        if let Err(error) = result {
+            let id = state.next_toast_id();
+            state.add_toast(Toast::error(id, error.to_string()));
+        }
+        Task::batch([self.reload_aliases(&env_id), self.process_next_operation()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+
+    #[test]
+    fn alias_name_edits_the_open_create_alias_modal() {
+        let mut app = test_app_with_two_environments();
+        app.handle_request_create_alias("v20.11.0".to_string());
+        app.handle_alias_name_changed("project".to_string());
+
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::CreateAlias { version, name })
+                if version == "v20.11.0" && name == "project"
+        ));
+    }
+
+    #[test]
+    fn blank_alias_name_keeps_the_modal_open() {
+        let mut app = test_app_with_two_environments();
+        app.handle_request_create_alias("v20.11.0".to_string());
+        let _ = app.handle_confirm_create_alias();
+
+        assert!(matches!(
+            app.main_state().modal,
+            Some(Modal::CreateAlias { .. })
+        ));
+    }
+
+    #[test]
+    fn removing_an_alias_asks_for_confirmation() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().active_environment_mut().aliases = vec![VersionAlias {
+            name: "default".to_string(),
+            version: versi_backend::NodeVersion::new(20, 11, 0),
+        }];
+
+        app.handle_remove_alias("default".to_string());
+
+        let state = app.main_state();
+        assert!(matches!(
+            &state.modal,
+            Some(Modal::ConfirmRemoveAlias { name, version })
+                if name == "default" && version == "v20.11.0"
+        ));
+        assert!(state.operation_queue.exclusive_op.is_none());
+    }
+
+    #[test]
+    fn confirmed_alias_removal_waits_for_a_busy_queue() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.operation_queue.start_exclusive(Operation::Uninstall {
+            version: "v18.0.0".to_string(),
+        });
+        state.modal = Some(Modal::ConfirmRemoveAlias {
+            name: "project".to_string(),
+            version: "v20.11.0".to_string(),
+        });
+
+        let _ = app.handle_confirm_remove_alias("project");
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(matches!(
+            state.operation_queue.pending.front(),
+            Some(Operation::RemoveAlias { name, .. }) if name == "project"
+        ));
+    }
+
+    #[test]
+    fn queued_alias_change_keeps_the_environment_it_was_requested_in() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.operation_queue.start_exclusive(Operation::Uninstall {
+            version: "v18.0.0".to_string(),
+        });
+        state.modal = Some(Modal::CreateAlias {
+            version: "v20.11.0".to_string(),
+            name: "project".to_string(),
+        });
+        let _ = app.handle_confirm_create_alias();
+
+        let _ = app.handle_environment_selected(1);
+
+        assert!(matches!(
+            app.main_state().operation_queue.pending.front(),
+            Some(Operation::SetAlias { environment: EnvironmentId::Native, name, .. })
+                if name == "project"
+        ));
+    }
+}
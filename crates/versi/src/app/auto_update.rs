@@ -1,11 +1,12 @@
 //! Application self-update: download, apply, and restart.
 //!
-//! Handles messages: `StartAppUpdate`, `AppUpdateProgress`, `AppUpdateExtracting`,
-//! `AppUpdateApplying`, `AppUpdateComplete`, `RestartApp`
+//! Handles messages: `StartAppUpdate`, `CancelAppUpdate`, `AppUpdateProgress`,
+//! `AppUpdateExtracting`, `AppUpdateApplying`, `AppUpdateComplete`, `RestartApp`
 
 use iced::Task;
 use iced::futures::SinkExt;
 use log::info;
+use tokio_util::sync::CancellationToken;
 
 use versi_core::auto_update::{ApplyResult, UpdateProgress};
 
@@ -38,11 +39,16 @@ impl Versi {
 
         let url = url.clone();
         let expected_sha256 = update.download_sha256.clone();
+        let expected_version = update.latest_version.clone();
+        let download_size = update.download_size;
+        let download_dir = self.settings.update_download_dir.clone();
         state.app_update_state = AppUpdateState::Downloading {
             downloaded: 0,
             total: update.download_size.unwrap_or(0),
         };
 
+        let cancel_token = CancellationToken::new();
+        state.app_update_cancel_token = Some(cancel_token.clone());
         let client = self.http_client.clone();
 
         Task::run(
@@ -56,12 +62,25 @@ impl Versi {
                             &client,
                             &url,
                             expected_sha256.as_deref(),
+                            &expected_version,
+                            download_size,
+                            download_dir.as_deref(),
                             tx,
                         )
                         .await
                     });
 
-                    while let Some(progress) = rx.recv().await {
+                    loop {
+                        let progress = tokio::select! {
+                            () = cancel_token.cancelled() => {
+                                download_handle.abort();
+                                break;
+                            }
+                            progress = rx.recv() => progress,
+                        };
+                        let Some(progress) = progress else {
+                            break;
+                        };
                         let msg = match progress {
                             UpdateProgress::Downloading { downloaded, total } => {
                                 Message::AppUpdateProgress { downloaded, total }
@@ -77,6 +96,10 @@ impl Versi {
                         Ok(result) => {
                             result.map_err(|error| AppError::auto_update_failed("apply", error))
                         }
+                        Err(error) if error.is_cancelled() => {
+                            info!("App update download cancelled");
+                            return;
+                        }
                         Err(error) => Err(AppError::auto_update_failed(
                             "task join",
                             format!("update task panicked: {error}"),
@@ -92,8 +115,24 @@ impl Versi {
         )
     }
 
+    /// Stop an in-progress download and go back to offering the update.
+    pub(super) fn handle_cancel_app_update(&mut self) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        if !matches!(state.app_update_state, AppUpdateState::Downloading { .. }) {
+            return;
+        }
+        if let Some(token) = state.app_update_cancel_token.take() {
+            token.cancel();
+        }
+        state.app_update_state = AppUpdateState::Idle;
+    }
+
     pub(super) fn handle_app_update_progress(&mut self, downloaded: u64, total: u64) {
-        if let AppState::Main(state) = &mut self.state {
+        if let AppState::Main(state) = &mut self.state
+            && state.app_update_cancel_token.is_some()
+        {
             state.app_update_state = AppUpdateState::Downloading { downloaded, total };
         }
     }
@@ -115,6 +154,7 @@ impl Versi {
         result: Result<ApplyResult, AppError>,
     ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            state.app_update_cancel_token = None;
             match result {
                 Ok(ApplyResult::RestartRequired) => {
                     state.app_update_state = AppUpdateState::RestartRequired;
@@ -213,6 +253,7 @@ mod tests {
     #[test]
     fn app_update_progress_handlers_update_state_variants() {
         let mut app = test_app_with_two_environments();
+        app.main_state_mut().app_update_cancel_token = Some(CancellationToken::new());
 
         app.handle_app_update_progress(10, 100);
         let state = app.main_state();
@@ -233,6 +274,52 @@ mod tests {
         assert!(matches!(state.app_update_state, AppUpdateState::Applying));
     }
 
+    #[test]
+    fn cancelling_a_download_reverts_to_idle() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.app_update = Some(sample_update(
+            Some("https://example.com/download.zip"),
+            Some(42),
+        ));
+        let _ = app.handle_start_app_update();
+        let token = app
+            .main_state()
+            .app_update_cancel_token
+            .clone()
+            .expect("download should be cancellable");
+
+        app.handle_cancel_app_update();
+
+        let state = app.main_state();
+        assert!(token.is_cancelled());
+        assert!(state.app_update_cancel_token.is_none());
+        assert!(matches!(state.app_update_state, AppUpdateState::Idle));
+
+        app.handle_app_update_progress(10, 42);
+        assert!(matches!(
+            app.main_state().app_update_state,
+            AppUpdateState::Idle
+        ));
+    }
+
+    #[test]
+    fn cancel_is_ignored_once_the_update_is_being_applied() {
+        let mut app = test_app_with_two_environments();
+        let token = CancellationToken::new();
+        let state = app.main_state_mut();
+        state.app_update_state = AppUpdateState::Applying;
+        state.app_update_cancel_token = Some(token.clone());
+
+        app.handle_cancel_app_update();
+
+        assert!(!token.is_cancelled());
+        assert!(matches!(
+            app.main_state().app_update_state,
+            AppUpdateState::Applying
+        ));
+    }
+
     #[test]
     fn app_update_complete_sets_restart_required_or_failed() {
         let mut app = test_app_with_two_environments();
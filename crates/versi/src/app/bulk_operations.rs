@@ -2,7 +2,7 @@ use iced::Task;
 use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion};
 
 use crate::message::Message;
-use crate::state::{AppState, Modal, Operation};
+use crate::state::{AppState, MainState, Modal, Operation};
 
 use super::Versi;
 
@@ -77,6 +77,22 @@ fn versions_to_uninstall_except_latest(
     Some((removing, keeping))
 }
 
+/// Split the selection into versions for the bulk confirmation and the
+/// environment's default version, if it was selected.
+fn split_selected_default(state: &MainState) -> (Vec<String>, Option<String>) {
+    let default_version = state.active_environment().default_version.as_ref();
+    let mut versions = Vec::new();
+    let mut default = None;
+    for installed in state.selected_installed_versions() {
+        if default_version == Some(&installed.version) {
+            default = Some(installed.version.to_string());
+        } else {
+            versions.push(installed.version.to_string());
+        }
+    }
+    (versions, default)
+}
+
 impl Versi {
     pub(super) fn handle_request_bulk_update_majors(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
@@ -103,7 +119,7 @@ impl Versi {
             let eol_versions: Vec<String> = env
                 .installed_versions
                 .iter()
-                .filter(|v| schedule.is_some_and(|s| !s.is_active(v.version.major)))
+                .filter(|v| schedule.is_some_and(|s| !s.is_active_at(v.version.major, state.now)))
                 .map(|v| v.version.to_string())
                 .collect();
 
@@ -176,6 +192,85 @@ impl Versi {
         Task::none()
     }
 
+    pub(super) fn handle_request_apply_retention_policy(&mut self) -> Task<Message> {
+        let Some(keep) = self.settings.patches_to_keep_per_major else {
+            return Task::none();
+        };
+        if let AppState::Main(state) = &mut self.state {
+            // The Prune and Apply Now buttons are disabled without candidates.
+            let versions = state.banner_stats.retention_candidates.clone();
+            if versions.is_empty() {
+                return Task::none();
+            }
+
+            state.modal = Some(Modal::ConfirmApplyRetentionPolicy { keep, versions });
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_confirm_apply_retention_policy(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmApplyRetentionPolicy { versions, .. }) = state.modal.take()
+        {
+            for version in versions {
+                state
+                    .operation_queue
+                    .enqueue(Operation::Uninstall { version });
+            }
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_selection_mode_toggled(&mut self, enabled: bool) {
+        if let AppState::Main(state) = &mut self.state {
+            state.set_selection_mode(enabled);
+        }
+    }
+
+    pub(super) fn handle_version_selection_toggled(&mut self, version: &str) {
+        if let AppState::Main(state) = &mut self.state
+            && state.selection_mode
+        {
+            state.toggle_version_selected(version);
+        }
+    }
+
+    pub(super) fn handle_request_bulk_uninstall_selected(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let (versions, default) = split_selected_default(state);
+
+        if versions.is_empty() {
+            // Only the default is selected, so its own confirmation is all
+            // that's needed.
+            return default.map_or_else(Task::none, |version| self.handle_uninstall(version));
+        }
+
+        state.modal = Some(Modal::ConfirmBulkUninstallSelected { versions, default });
+        Task::none()
+    }
+
+    pub(super) fn handle_confirm_bulk_uninstall_selected(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmBulkUninstallSelected { versions, default }) =
+                state.modal.take()
+        {
+            for version in versions {
+                state
+                    .operation_queue
+                    .enqueue(Operation::Uninstall { version });
+            }
+            state.set_selection_mode(false);
+            if let Some(version) = default {
+                state.modal = Some(Modal::ConfirmUninstallDefault { version });
+            }
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
     pub(super) fn handle_request_bulk_uninstall_major_except_latest(
         &mut self,
         major: u32,
@@ -224,7 +319,9 @@ mod tests {
     use chrono::Utc;
     use versi_backend::{InstalledVersion, RemoteVersion};
 
+    use super::super::test_app_with_two_environments;
     use super::{compute_major_updates, versions_for_major, versions_to_uninstall_except_latest};
+    use crate::state::Modal;
 
     fn installed(version: &str) -> InstalledVersion {
         InstalledVersion {
@@ -298,4 +395,96 @@ mod tests {
         let installed = vec![installed("v22.9.0")];
         assert!(versions_to_uninstall_except_latest(&installed, 22).is_none());
     }
+
+    #[test]
+    fn selection_accumulates_only_in_selection_mode() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_version_selection_toggled("v20.11.0");
+        assert!(app.main_state().selected_versions.is_empty());
+
+        app.handle_selection_mode_toggled(true);
+        app.handle_version_selection_toggled("v20.11.0");
+        app.handle_version_selection_toggled("v18.19.0");
+        app.handle_version_selection_toggled("v22.1.0");
+        app.handle_version_selection_toggled("v18.19.0");
+        assert_eq!(app.main_state().selected_versions.len(), 2);
+
+        app.handle_selection_mode_toggled(false);
+        assert!(app.main_state().selected_versions.is_empty());
+    }
+
+    #[test]
+    fn uninstall_selected_holds_back_default_for_its_own_confirmation() {
+        let mut app = test_app_with_two_environments();
+        let mut default = installed("v20.11.0");
+        default.is_default = true;
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed("v22.1.0"), default, installed("v18.19.0")]);
+        app.handle_selection_mode_toggled(true);
+        for version in ["v22.1.0", "v20.11.0", "v18.19.0"] {
+            app.handle_version_selection_toggled(version);
+        }
+
+        let _ = app.handle_request_bulk_uninstall_selected();
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::ConfirmBulkUninstallSelected { versions, default })
+                if versions == &["v22.1.0".to_string(), "v18.19.0".to_string()]
+                    && default.as_deref() == Some("v20.11.0")
+        ));
+
+        let _ = app.handle_confirm_bulk_uninstall_selected();
+        let state = app.main_state();
+        assert!(matches!(
+            &state.modal,
+            Some(Modal::ConfirmUninstallDefault { version }) if version == "v20.11.0"
+        ));
+        assert!(!state.selection_mode);
+        assert!(!state.operation_queue.has_pending_for_version("v20.11.0"));
+        assert!(["v22.1.0", "v18.19.0"].iter().all(|version| {
+            state
+                .operation_queue
+                .active_operation_for(version)
+                .is_some()
+                || state.operation_queue.has_pending_for_version(version)
+        }));
+    }
+
+    #[test]
+    fn retention_policy_change_refreshes_prune_candidates() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed("v22.2.0"), installed("v22.1.0")]);
+
+        let _ = app.update(crate::message::Message::PatchRetentionChanged(Some(1)));
+        assert_eq!(
+            app.main_state().banner_stats.retention_candidates,
+            vec!["v22.1.0".to_string()]
+        );
+
+        let _ = app.handle_request_apply_retention_policy();
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::ConfirmApplyRetentionPolicy { keep: 1, versions })
+                if versions == &["v22.1.0".to_string()]
+        ));
+    }
+
+    #[test]
+    fn nothing_to_prune_opens_nothing() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed("v22.2.0")]);
+        let _ = app.update(crate::message::Message::PatchRetentionChanged(Some(1)));
+
+        let _ = app.handle_request_apply_retention_policy();
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(state.toasts.is_empty());
+    }
 }
@@ -0,0 +1,103 @@
+//! Opening the release notes for a version.
+//!
+//! Handles messages: `OpenChangelog`
+
+use iced::Task;
+use versi_backend::NodeVersion;
+
+use crate::message::Message;
+use crate::state::{AppState, Toast};
+
+use super::Versi;
+
+const NODE_REPO_URL: &str = "https://github.com/nodejs/node";
+
+/// GitHub release page for exactly `version`.
+fn release_notes_url(version: &NodeVersion) -> String {
+    format!("{NODE_REPO_URL}/releases/tag/{version}")
+}
+
+/// Changelog covering every release of `version`'s major line. The 0.x lines
+/// are split by minor (`CHANGELOG_V010.md`, `CHANGELOG_V012.md`).
+fn major_changelog_url(version: &NodeVersion) -> String {
+    let line = if version.major == 0 {
+        format!("0{}", version.minor)
+    } else {
+        version.major.to_string()
+    };
+    format!("{NODE_REPO_URL}/blob/main/doc/changelogs/CHANGELOG_V{line}.md")
+}
+
+async fn url_exists(client: &reqwest::Client, url: &str) -> bool {
+    client
+        .head(url)
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success())
+}
+
+impl Versi {
+    pub(super) fn handle_open_changelog(&mut self, version: &str) -> Task<Message> {
+        let Ok(parsed) = version.parse::<NodeVersion>() else {
+            if let AppState::Main(state) = &mut self.state {
+                let id = state.next_toast_id();
+                state.add_toast(Toast::error(
+                    id,
+                    format!("No release notes available for {version}"),
+                ));
+            }
+            return Task::none();
+        };
+
+        let client = self.http_client.clone();
+        let release_url = release_notes_url(&parsed);
+        let fallback_url = major_changelog_url(&parsed);
+        Task::perform(
+            async move {
+                let url = if url_exists(&client, &release_url).await {
+                    release_url
+                } else {
+                    log::debug!("No release page at {release_url}, opening {fallback_url}");
+                    fallback_url
+                };
+                let _ = open::that(&url);
+            },
+            |()| Message::NoOp,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+
+    #[test]
+    fn release_notes_url_uses_the_v_prefixed_tag() {
+        assert_eq!(
+            release_notes_url(&NodeVersion::new(20, 11, 1)),
+            "https://github.com/nodejs/node/releases/tag/v20.11.1"
+        );
+    }
+
+    #[test]
+    fn major_changelog_url_covers_the_major_line() {
+        assert_eq!(
+            major_changelog_url(&NodeVersion::new(20, 11, 1)),
+            "https://github.com/nodejs/node/blob/main/doc/changelogs/CHANGELOG_V20.md"
+        );
+        assert_eq!(
+            major_changelog_url(&NodeVersion::new(0, 12, 18)),
+            "https://github.com/nodejs/node/blob/main/doc/changelogs/CHANGELOG_V012.md"
+        );
+    }
+
+    #[test]
+    fn open_changelog_rejects_unparseable_versions() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_open_changelog("lts/iron");
+
+        assert_eq!(app.main_state().toasts.len(), 1);
+    }
+}
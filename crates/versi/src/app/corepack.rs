@@ -0,0 +1,101 @@
+//! Enabling and disabling corepack for an installed version.
+//!
+//! Handles messages: `SetCorepack`, `CorepackChanged`
+
+use std::time::Duration;
+
+use log::info;
+
+use iced::Task;
+
+use versi_backend::BackendError;
+
+use crate::error::AppError;
+use crate::message::Message;
+use crate::state::{AppState, Toast};
+
+use super::Versi;
+use super::async_helpers::run_with_timeout;
+
+impl Versi {
+    pub(super) fn handle_set_corepack(&self, version: String, enabled: bool) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+
+        info!(
+            "{} corepack for {version}",
+            if enabled { "Enabling" } else { "Disabling" }
+        );
+        let backend = state.backend.clone();
+        let timeout = Duration::from_secs(self.settings.set_default_timeout_secs);
+        Task::perform(
+            async move {
+                let result = run_with_timeout(
+                    timeout,
+                    "Corepack",
+                    backend.set_corepack(&version, enabled),
+                    |error: BackendError| AppError::operation_failed("Corepack", error),
+                )
+                .await;
+                (version, enabled, result)
+            },
+            |(version, enabled, result)| Message::CorepackChanged {
+                version,
+                enabled,
+                result,
+            },
+        )
+    }
+
+    pub(super) fn handle_corepack_changed(
+        &mut self,
+        version: &str,
+        enabled: bool,
+        result: Result<(), AppError>,
+    ) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let id = state.next_toast_id();
+        match result {
+            Ok(()) if enabled => state.add_toast(Toast::info(
+                id,
+                format!("Corepack enabled for Node {version}: yarn and pnpm are ready"),
+            )),
+            Ok(()) => state.add_toast(Toast::info(
+                id,
+                format!("Corepack disabled for Node {version}"),
+            )),
+            Err(error) => state.add_toast(Toast::error(id, error.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+
+    #[test]
+    fn corepack_failure_is_reported_in_a_toast() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_corepack_changed(
+            "v22.11.0",
+            true,
+            Err(AppError::operation_failed(
+                "Corepack",
+                "corepack: command not found",
+            )),
+        );
+
+        let state = app.main_state();
+        assert!(
+            state
+                .toasts
+                .iter()
+                .any(|toast| toast.message.contains("corepack: command not found"))
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! Environment switching, version loading, and search.
 //!
-//! Handles messages: `EnvironmentSelected`, `EnvironmentLoaded`, `RefreshEnvironment`,
-//! `VersionGroupToggled`, `SearchChanged`
-
+//! Handles messages: `EnvironmentSelected`, `EnvironmentLoaded`,
+//! `CurrentVersionLoaded`, `InstalledVersionsValidated`, `RefreshEnvironment`,
+//! `RefreshWslEnvironments`, `WslEnvironmentsRefreshed`, `VersionGroupToggled`,
+//! `SearchGroupToggled`, `ShowMoreSearchResults`, `SearchChanged`, `SearchFilterToggled`,
+//! `SearchFilterShortcut`, `ClearSearchFilters`,
+//! `SortModeChanged`, `TogglePinnedVersion`, `UpdatableViewToggled`
+
+use std::sync::Arc;
 use std::time::Duration;
 
 use log::{debug, info, trace};
@@ -10,17 +15,36 @@ use log::{debug, info, trace};
 use iced::Task;
 use tokio_util::sync::CancellationToken;
 
+use versi_backend::VersionManager;
 use versi_platform::EnvironmentId;
 
 use crate::error::AppError;
-use crate::message::Message;
-use crate::state::{AppState, MainViewKind, SearchFilter};
+use crate::message::{EnvironmentInfo, Message};
+use crate::state::{AppState, MainViewKind, SearchFilter, VersionSortMode};
 
 use super::Versi;
 use super::async_helpers::run_with_timeout;
-use super::init::create_backend_for_environment;
+use super::init::{create_backend_for_environment, environment_state_from_info};
 
 impl Versi {
+    /// Backend for `env_id`: the active one, or a new one for an environment
+    /// the user has switched away from since an operation started there.
+    pub(super) fn backend_for(&self, env_id: &EnvironmentId) -> Option<Arc<dyn VersionManager>> {
+        let AppState::Main(state) = &self.state else {
+            return None;
+        };
+        if &state.active_environment().id == env_id {
+            return Some(state.backend.clone());
+        }
+        let env = state.environments.iter().find(|env| &env.id == env_id)?;
+        Some(create_backend_for_environment(
+            env_id,
+            &self.backend_path,
+            self.backend_dir.as_ref(),
+            &self.provider_for_kind(env.backend_name),
+        ))
+    }
+
     pub(super) fn handle_environment_loaded(
         &mut self,
         env_id: &EnvironmentId,
@@ -60,16 +84,27 @@ impl Versi {
             env.load_cancel_token = None;
 
             match result {
-                Ok(versions) => env.update_versions(versions),
+                Ok(versions) => {
+                    env.update_versions(versions);
+                    env.sort_groups(state.sort_mode);
+                }
                 Err(error) => {
                     env.loading = false;
                     env.error = Some(error);
                 }
             }
 
-            state.recompute_banner_stats();
+            state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
         }
+        self.prune_stale_pins();
         self.update_tray_menu();
+        let installed = self.installed_version_strings(env_id);
+        let validate_task = Task::batch([
+            self.load_current_version(env_id),
+            self.load_aliases(env_id),
+            self.validate_installed_versions(env_id),
+            self.ensure_metadata_for(&installed),
+        ]);
 
         if self.pending_minimize
             && !self.pending_show
@@ -81,10 +116,131 @@ impl Versi {
             } else {
                 iced::window::set_mode(id, iced::window::Mode::Hidden)
             };
-            return Task::batch([Task::done(Message::HideDockIcon), hide_task]);
+            return Task::batch([Task::done(Message::HideDockIcon), hide_task, validate_task]);
         }
 
-        Task::none()
+        validate_task
+    }
+
+    fn installed_version_strings(&self, env_id: &EnvironmentId) -> Vec<String> {
+        let AppState::Main(state) = &self.state else {
+            return Vec::new();
+        };
+        state
+            .environments
+            .iter()
+            .find(|env| &env.id == env_id)
+            .map(|env| {
+                env.installed_versions
+                    .iter()
+                    .map(|installed| installed.version.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn load_current_version(&self, env_id: &EnvironmentId) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        if &state.active_environment().id != env_id {
+            return Task::none();
+        }
+
+        let backend = state.backend.clone();
+        let probe_shell = *env_id == EnvironmentId::Native;
+        let timeout = Duration::from_secs(self.settings.fetch_timeout_secs);
+        let env_id = env_id.clone();
+        Task::perform(
+            async move {
+                if probe_shell && let Some(version) = shell_node_version(timeout).await {
+                    return Some(version);
+                }
+                backend.current_version().await.unwrap_or_else(|error| {
+                    debug!("Could not read the active version: {error}");
+                    None
+                })
+            },
+            move |version| Message::CurrentVersionLoaded {
+                env_id: env_id.clone(),
+                version,
+            },
+        )
+    }
+
+    pub(super) fn handle_current_version_loaded(
+        &mut self,
+        env_id: &EnvironmentId,
+        version: Option<versi_backend::NodeVersion>,
+    ) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(env) = state.environments.iter_mut().find(|e| &e.id == env_id)
+        {
+            env.active_version = version;
+        }
+    }
+
+    /// Check in the background that each installed version of the active
+    /// environment still runs, after the listing has been shown. Versions
+    /// checked on an earlier load are skipped.
+    fn validate_installed_versions(&mut self, env_id: &EnvironmentId) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let backend = state.backend.clone();
+        let env = state.active_environment_mut();
+        if &env.id != env_id {
+            return Task::none();
+        }
+
+        let versions: Vec<_> = env
+            .installed_versions
+            .iter()
+            .map(|v| v.version.clone())
+            .filter(|version| !env.validated_versions.contains(version))
+            .collect();
+        if versions.is_empty() {
+            return Task::none();
+        }
+        env.validated_versions.extend(versions.iter().cloned());
+
+        let env_id = env_id.clone();
+        Task::perform(
+            async move {
+                match backend.validate_installed(&versions).await {
+                    Ok(broken) => broken,
+                    Err(error) => {
+                        debug!("Skipping installed version validation: {error}");
+                        Vec::new()
+                    }
+                }
+            },
+            move |broken| Message::InstalledVersionsValidated {
+                env_id: env_id.clone(),
+                broken,
+            },
+        )
+    }
+
+    pub(super) fn handle_installed_versions_validated(
+        &mut self,
+        env_id: &EnvironmentId,
+        broken: Vec<versi_backend::NodeVersion>,
+    ) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let Some(env) = state.environments.iter_mut().find(|e| &e.id == env_id) else {
+            return;
+        };
+        if !broken.is_empty() {
+            info!("Broken installed versions in {env_id:?}: {broken:?}");
+        }
+        env.broken_versions.extend(
+            broken
+                .into_iter()
+                .filter(|version| env.installed_set.contains(version)),
+        );
     }
 
     pub(super) fn handle_environment_selected(&mut self, idx: usize) -> Task<Message> {
@@ -99,10 +255,13 @@ impl Versi {
 
             info!("Switching to environment {idx}");
             state.active_environment_idx = idx;
-            state.recompute_banner_stats();
+            state.default_history.clear();
+            state.dismiss_other_environment_actions();
+            state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
 
             let env = &state.environments[idx];
             let env_id = env.id.clone();
+            let selected_id = env_id.clone();
             debug!("Selected environment: {env_id:?}");
 
             let needs_load = env.loading || env.installed_versions.is_empty();
@@ -124,8 +283,6 @@ impl Versi {
             state.backend = new_backend;
             state.backend_name = env.backend_name;
 
-            state.backend_update = None;
-
             let in_settings = state.view == MainViewKind::Settings;
             if in_settings {
                 state.settings_state.checking_shells = true;
@@ -181,14 +338,16 @@ impl Versi {
                 Task::none()
             };
 
-            let backend_update_task = self.handle_check_for_backend_update();
+            self.settings.last_environment = Some(selected_id);
+            self.save_settings_with_log();
+
             let shell_task = if in_settings {
                 self.handle_check_shell_setup()
             } else {
                 Task::none()
             };
 
-            return Task::batch([load_task, backend_update_task, shell_task]);
+            return Task::batch([load_task, shell_task]);
         }
         Task::none()
     }
@@ -236,6 +395,79 @@ impl Versi {
         Task::none()
     }
 
+    pub(super) fn handle_refresh_wsl_environments(&self) -> Task<Message> {
+        if !matches!(self.state, AppState::Main(_)) {
+            return Task::none();
+        }
+
+        #[cfg(windows)]
+        if let AppState::Main(state) = &self.state {
+            let providers = self.all_providers();
+            let native_backend_name = state.environments[0].backend_name;
+            let preferred_name = self
+                .settings
+                .preferred_backend
+                .unwrap_or(crate::backend_kind::BackendKind::DEFAULT);
+            let wsl_timeout = Duration::from_secs(self.settings.fetch_timeout_secs);
+            return Task::perform(
+                async move {
+                    super::init::detect_wsl_environments(
+                        &providers,
+                        native_backend_name,
+                        preferred_name,
+                        wsl_timeout,
+                    )
+                    .await
+                },
+                Message::WslEnvironmentsRefreshed,
+            );
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_wsl_environments_refreshed(
+        &mut self,
+        environments: &[EnvironmentInfo],
+    ) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        info!("Reconciling {} WSL environments", environments.len());
+        let detected = environments
+            .iter()
+            .map(environment_state_from_info)
+            .collect();
+        let active_changed = state.reconcile_wsl_environments(detected);
+        if active_changed {
+            state.dismiss_other_environment_actions();
+        }
+        state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
+
+        let task = if active_changed {
+            let env = state.active_environment();
+            let env_id = env.id.clone();
+            let backend_name = env.backend_name;
+            let env_provider = self.provider_for_kind(backend_name);
+            self.provider = env_provider.clone();
+            if let AppState::Main(state) = &mut self.state {
+                state.backend = create_backend_for_environment(
+                    &env_id,
+                    &self.backend_path,
+                    self.backend_dir.as_ref(),
+                    &env_provider,
+                );
+                state.backend_name = backend_name;
+            }
+            self.handle_refresh_environment()
+        } else {
+            Task::none()
+        };
+
+        self.update_tray_menu();
+        Task::batch([task, self.handle_check_for_backend_update()])
+    }
+
     pub(super) fn handle_version_group_toggled(&mut self, major: u32) {
         if let AppState::Main(state) = &mut self.state {
             let env = state.active_environment_mut();
@@ -245,41 +477,174 @@ impl Versi {
         }
     }
 
+    pub(super) fn handle_search_group_toggled(&mut self, major: u32) {
+        if let AppState::Main(state) = &mut self.state
+            && !state.collapsed_search_majors.remove(&major)
+        {
+            state.collapsed_search_majors.insert(major);
+        }
+    }
+
+    pub(super) fn handle_show_more_search_results(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            let limit = self.settings.search_results_limit;
+            state.search_limit_override = Some(state.effective_search_limit(limit) + limit);
+        }
+    }
+
     pub(super) fn handle_search_changed(&mut self, query: String) {
         if let AppState::Main(state) = &mut self.state {
             if query.is_empty() {
-                state.active_filters.clear();
+                state.active_filters = self.settings.persisted_filters.iter().copied().collect();
+            }
+            if query != state.search_query {
+                state.search_limit_override = None;
             }
             state.search_query = query;
         }
     }
 
+    pub(super) fn handle_updatable_view_toggled(&mut self, enabled: bool) {
+        if let AppState::Main(state) = &mut self.state {
+            state.updatable_only = enabled;
+            if enabled {
+                state.search_query.clear();
+                state.search_limit_override = None;
+                state.active_filters = self.settings.persisted_filters.iter().copied().collect();
+            }
+        }
+    }
+
+    pub(super) fn handle_sort_mode_changed(&mut self, mode: VersionSortMode) {
+        if let AppState::Main(state) = &mut self.state {
+            state.sort_mode = mode;
+            for env in &mut state.environments {
+                env.sort_groups(mode);
+            }
+        }
+    }
+
+    pub(super) fn handle_toggle_pinned_version(&mut self, version: &str) {
+        self.settings.toggle_pinned_version(version);
+        self.save_settings_with_log();
+    }
+
+    /// Forget pins for versions that are not installed in any environment.
+    /// Waits until every environment has loaded so a pending or failed load
+    /// doesn't look like an uninstall.
+    fn prune_stale_pins(&mut self) {
+        let AppState::Main(state) = &self.state else {
+            return;
+        };
+        if self.settings.pinned_versions.is_empty()
+            || state
+                .environments
+                .iter()
+                .any(|env| env.available && (env.loading || env.error.is_some()))
+        {
+            return;
+        }
+
+        let environments = &state.environments;
+        let removed = self.settings.retain_installed_pins(|pinned| {
+            environments.iter().any(|env| {
+                env.installed_versions
+                    .iter()
+                    .any(|installed| installed.version.to_string() == pinned)
+            })
+        });
+        if removed {
+            self.save_settings_with_log();
+        }
+    }
+
     pub(super) fn handle_search_filter_toggled(&mut self, filter: SearchFilter) {
         if let AppState::Main(state) = &mut self.state {
             if state.active_filters.contains(&filter) {
                 state.active_filters.remove(&filter);
             } else {
-                match filter {
-                    SearchFilter::Installed => {
-                        state.active_filters.remove(&SearchFilter::NotInstalled);
-                    }
-                    SearchFilter::NotInstalled => {
-                        state.active_filters.remove(&SearchFilter::Installed);
-                    }
-                    SearchFilter::Eol => {
-                        state.active_filters.remove(&SearchFilter::Active);
-                    }
-                    SearchFilter::Active => {
-                        state.active_filters.remove(&SearchFilter::Eol);
-                    }
-                    SearchFilter::Lts => {}
+                if let Some(opposite) = filter.excludes() {
+                    state.active_filters.remove(&opposite);
                 }
                 state.active_filters.insert(filter);
             }
         }
+        self.persist_search_filters();
+    }
+
+    /// Number-key shortcut for a filter chip; only the versions list shows
+    /// the chips, so it does nothing in other views or behind a modal.
+    pub(super) fn handle_search_filter_shortcut(&mut self, filter: SearchFilter) {
+        let AppState::Main(state) = &self.state else {
+            return;
+        };
+        if state.view == MainViewKind::Versions && state.modal.is_none() {
+            self.handle_search_filter_toggled(filter);
+        }
+    }
+
+    pub(super) fn handle_clear_search_filters(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.active_filters.clear();
+        }
+        self.persist_search_filters();
+    }
+
+    fn persist_search_filters(&mut self) {
+        let AppState::Main(state) = &self.state else {
+            return;
+        };
+        let filters: Vec<SearchFilter> = SearchFilter::ALL
+            .into_iter()
+            .filter(|filter| state.active_filters.contains(filter))
+            .collect();
+        if filters != self.settings.persisted_filters {
+            self.settings.persisted_filters = filters;
+            self.save_settings_with_log();
+        }
     }
 }
 
+/// Version of `node` a newly opened terminal would run, read from the user's
+/// login shell. The app's own environment lacks the shell hooks that select
+/// a version, so asking the backend directly only sees the default.
+async fn shell_node_version(timeout: Duration) -> Option<versi_backend::NodeVersion> {
+    use versi_core::HideWindow;
+
+    let shell = versi_platform::user_shell();
+    let mut cmd = tokio::process::Command::new(&shell);
+    if cfg!(windows) {
+        cmd.args(["-NoLogo", "-Command", "node --version"]);
+    } else {
+        cmd.args(["-l", "-i", "-c", "node --version"]);
+    }
+    cmd.stdin(std::process::Stdio::null()).hide_window();
+
+    let output = match versi_platform::output_with_timeout(&mut cmd, timeout).await {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => {
+            debug!("{shell} could not run node: {}", output.status);
+            return None;
+        }
+        Err(error) => {
+            debug!("Could not ask {shell} for the active node version: {error}");
+            return None;
+        }
+    };
+    parse_shell_node_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Interactive shells may print a banner first, so only the last line is
+/// taken as `node --version` output.
+fn parse_shell_node_version(stdout: &str) -> Option<versi_backend::NodeVersion> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())?
+        .parse()
+        .ok()
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -289,6 +654,28 @@ mod tests {
     use super::super::test_app_with_two_environments;
     use super::*;
 
+    #[test]
+    fn shell_node_version_reads_the_last_line() {
+        assert_eq!(
+            parse_shell_node_version("Welcome back!\nv20.11.0\n"),
+            Some(versi_backend::NodeVersion::new(20, 11, 0))
+        );
+        assert_eq!(parse_shell_node_version(""), None);
+        assert_eq!(parse_shell_node_version("command not found: node\n"), None);
+    }
+
+    #[test]
+    fn backend_for_builds_the_backend_of_an_inactive_environment() {
+        let mut app = test_app_with_two_environments();
+        let _ = app.handle_environment_selected(1);
+
+        assert_eq!(app.main_state().backend.name(), "nvm");
+        let native = app
+            .backend_for(&EnvironmentId::Native)
+            .expect("native environment should still resolve a backend");
+        assert_eq!(native.name(), "fnm");
+    }
+
     #[test]
     fn search_changed_clears_filters_when_query_becomes_empty() {
         let mut app = test_app_with_two_environments();
@@ -327,6 +714,95 @@ mod tests {
         assert!(state.active_filters.contains(&SearchFilter::Eol));
     }
 
+    #[test]
+    fn search_filter_toggle_clears_the_opposite_of_each_pair_member() {
+        let pairs = [
+            (SearchFilter::Installed, SearchFilter::NotInstalled),
+            (SearchFilter::Eol, SearchFilter::Active),
+        ];
+        for (first, second) in pairs {
+            for (on, toggled) in [(first, second), (second, first)] {
+                let mut app = test_app_with_two_environments();
+                app.handle_search_filter_toggled(SearchFilter::Lts);
+                app.handle_search_filter_toggled(on);
+
+                app.handle_search_filter_toggled(toggled);
+
+                let state = app.main_state();
+                assert_eq!(
+                    state.active_filters,
+                    HashSet::from([SearchFilter::Lts, toggled]),
+                    "toggling {toggled:?} while {on:?} is on"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn toggled_filters_are_persisted_and_restored_when_search_is_cleared() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().search_query = "20".to_string();
+
+        app.handle_search_filter_toggled(SearchFilter::Active);
+        app.handle_search_filter_toggled(SearchFilter::Lts);
+        assert_eq!(
+            app.settings.persisted_filters,
+            vec![SearchFilter::Lts, SearchFilter::Active]
+        );
+
+        app.handle_search_changed(String::new());
+        assert_eq!(
+            app.main_state().active_filters,
+            HashSet::from([SearchFilter::Lts, SearchFilter::Active])
+        );
+    }
+
+    #[test]
+    fn filter_shortcuts_only_apply_to_the_versions_view_without_a_modal() {
+        let mut app = test_app_with_two_environments();
+
+        app.main_state_mut().view = MainViewKind::Settings;
+        app.handle_search_filter_shortcut(SearchFilter::Lts);
+        assert!(app.main_state().active_filters.is_empty());
+
+        app.main_state_mut().view = MainViewKind::Versions;
+        app.main_state_mut().modal = Some(crate::state::Modal::KeyboardShortcuts);
+        app.handle_search_filter_shortcut(SearchFilter::Lts);
+        assert!(app.main_state().active_filters.is_empty());
+
+        app.main_state_mut().modal = None;
+        app.handle_search_filter_shortcut(SearchFilter::Lts);
+        assert!(app.main_state().active_filters.contains(&SearchFilter::Lts));
+    }
+
+    #[test]
+    fn updatable_view_clears_the_search_query() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().search_query = "20".to_string();
+
+        let _ = app.update(Message::UpdatableViewToggled(true));
+        let state = app.main_state();
+        assert!(state.updatable_only);
+        assert!(state.search_query.is_empty());
+
+        let _ = app.update(Message::UpdatableViewToggled(false));
+        assert!(!app.main_state().updatable_only);
+    }
+
+    #[test]
+    fn clear_search_filters_empties_the_active_set() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.search_query = "20".to_string();
+        state.active_filters = HashSet::from([SearchFilter::Lts, SearchFilter::Installed]);
+
+        let _ = app.update(Message::ClearSearchFilters);
+
+        let state = app.main_state();
+        assert!(state.active_filters.is_empty());
+        assert_eq!(state.search_query, "20");
+    }
+
     #[test]
     fn version_group_toggled_flips_target_group_only() {
         let mut app = test_app_with_two_environments();
@@ -351,6 +827,46 @@ mod tests {
         assert!(groups.iter().any(|g| g.major == 22 && g.is_expanded));
     }
 
+    #[test]
+    fn search_group_toggled_collapses_and_expands() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_search_group_toggled(22);
+        assert!(app.main_state().collapsed_search_majors.contains(&22));
+
+        app.handle_search_group_toggled(22);
+        assert!(app.main_state().collapsed_search_majors.is_empty());
+    }
+
+    #[test]
+    fn show_more_raises_limit_until_query_changes() {
+        let mut app = test_app_with_two_environments();
+        app.settings.search_results_limit = 2;
+        app.main_state_mut().available_versions.set_versions(
+            ["v22.3.0", "v22.2.0", "v22.1.0", "v20.1.0", "v20.0.0"]
+                .into_iter()
+                .map(|version| versi_backend::RemoteVersion {
+                    version: version.parse().expect("test version should parse"),
+                    lts_codename: None,
+                    is_latest: false,
+                })
+                .collect(),
+        );
+        app.handle_search_changed("v2".to_string());
+        assert_eq!(app.main_state().navigable_versions(2).len(), 2);
+
+        app.handle_show_more_search_results();
+        assert_eq!(app.main_state().effective_search_limit(2), 4);
+        assert_eq!(app.main_state().navigable_versions(2).len(), 4);
+
+        app.handle_search_changed("v2".to_string());
+        assert_eq!(app.main_state().effective_search_limit(2), 4);
+
+        app.handle_search_changed("v22".to_string());
+        assert!(app.main_state().search_limit_override.is_none());
+        assert_eq!(app.main_state().effective_search_limit(2), 2);
+    }
+
     #[test]
     fn refresh_environment_cancels_previous_load_token() {
         let mut app = test_app_with_two_environments();
@@ -365,4 +881,100 @@ mod tests {
         let state = app.main_state();
         assert!(state.active_environment().load_cancel_token.is_some());
     }
+
+    #[test]
+    fn installed_versions_validated_marks_broken_versions() {
+        use versi_backend::{InstalledVersion, NodeVersion};
+
+        let mut app = test_app_with_two_environments();
+        let installed = |version: NodeVersion| InstalledVersion {
+            version,
+            is_default: false,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+        };
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![
+                installed(NodeVersion::new(22, 1, 0)),
+                installed(NodeVersion::new(20, 11, 0)),
+            ]);
+
+        app.handle_installed_versions_validated(
+            &EnvironmentId::Native,
+            vec![NodeVersion::new(20, 11, 0), NodeVersion::new(18, 0, 0)],
+        );
+
+        let env = app.main_state().active_environment();
+        assert_eq!(
+            env.broken_versions,
+            HashSet::from([NodeVersion::new(20, 11, 0)])
+        );
+    }
+
+    #[test]
+    fn validation_skips_versions_checked_on_an_earlier_load() {
+        use versi_backend::{InstalledVersion, NodeVersion};
+
+        let mut app = test_app_with_two_environments();
+        let installed = |version: NodeVersion| InstalledVersion {
+            version,
+            is_default: false,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+        };
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed(NodeVersion::new(22, 1, 0))]);
+
+        let _ = app.validate_installed_versions(&EnvironmentId::Native);
+        assert_eq!(
+            app.main_state().active_environment().validated_versions,
+            HashSet::from([NodeVersion::new(22, 1, 0)])
+        );
+
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed(NodeVersion::new(20, 11, 0))]);
+        let env = app.main_state().active_environment();
+        assert!(env.validated_versions.is_empty());
+    }
+
+    #[test]
+    fn toggle_pinned_version_pins_then_unpins() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_toggle_pinned_version("v20.11.0");
+        assert!(app.settings.is_pinned("v20.11.0"));
+
+        app.handle_toggle_pinned_version("v20.11.0");
+        assert!(app.settings.pinned_versions.is_empty());
+    }
+
+    #[test]
+    fn environment_load_drops_pins_for_uninstalled_versions() {
+        use versi_backend::{InstalledVersion, NodeVersion};
+
+        let mut app = test_app_with_two_environments();
+        app.settings.pinned_versions = vec!["v20.11.0".to_string(), "v16.20.2".to_string()];
+        for env in &mut app.main_state_mut().environments {
+            env.loading = false;
+        }
+
+        let _ = app.handle_environment_loaded(
+            &EnvironmentId::Native,
+            0,
+            Ok(vec![InstalledVersion {
+                version: NodeVersion::new(20, 11, 0),
+                is_default: false,
+                lts_codename: None,
+                install_date: None,
+                disk_size: None,
+            }]),
+        );
+
+        assert_eq!(app.settings.pinned_versions, vec!["v20.11.0".to_string()]);
+    }
 }
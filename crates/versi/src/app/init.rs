@@ -1,18 +1,19 @@
 use log::{debug, info, trace};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use iced::Task;
 
 use versi_backend::{BackendDetection, BackendProvider, VersionManager};
-use versi_platform::EnvironmentId;
+use versi_platform::{AppPathsError, EnvironmentId};
 use versi_shell::detect_shells;
 
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
 use crate::message::{EnvironmentInfo, InitResult, Message};
 use crate::state::{
-    AppState, BackendOption, EnvironmentState, MainState, OnboardingState, ShellConfigStatus,
+    AppState, BackendOption, EnvironmentState, MainState, OnboardingState, ShellConfigStatus, Toast,
 };
 
 use super::Versi;
@@ -44,7 +45,13 @@ impl Versi {
             active_backend_name,
         );
         main_state.detected_backends = result.detected_backends;
+        main_state.active_filters = self.settings.persisted_filters.iter().copied().collect();
+        main_state.tray_availability = crate::tray::tray_availability();
         load_disk_cache_into_state(&mut main_state);
+        if let Some(message) = degraded_mode_message(self.paths_error.take()) {
+            let id = main_state.next_toast_id();
+            main_state.add_toast(Toast::error(id, message));
+        }
 
         self.state = AppState::Main(Box::new(main_state));
 
@@ -55,9 +62,51 @@ impl Versi {
         );
         tasks.extend(self.build_post_init_tasks());
 
+        if let AppState::Main(state) = &self.state {
+            let idx = restore_environment_index(
+                &state.environments,
+                self.settings.last_environment.as_ref(),
+            );
+            if idx != state.active_environment_idx {
+                tasks.push(self.handle_environment_selected(idx));
+            }
+        }
+
         Task::batch(tasks)
     }
 
+    fn build_post_init_tasks(&mut self) -> Vec<Task<Message>> {
+        let ttl = Duration::from_secs(self.settings.cache_ttl_hours.saturating_mul(3600));
+        let (stale, has_schedule, has_metadata) = match &self.state {
+            AppState::Main(state) => (
+                state.available_versions.is_stale(ttl),
+                state.available_versions.schedule.is_some(),
+                state.available_versions.metadata.is_some(),
+            ),
+            _ => (true, false, false),
+        };
+
+        let mut tasks = Vec::with_capacity(6);
+        if stale {
+            debug!("Version cache is stale, refreshing from network");
+            tasks.push(self.handle_fetch_remote_versions());
+        } else {
+            debug!("Version cache is fresh, skipping startup refresh");
+        }
+        if stale || !has_schedule {
+            tasks.push(self.handle_fetch_release_schedule());
+        }
+        if !self.settings.fetch_version_metadata {
+            debug!("Version metadata fetching is disabled");
+        } else if stale || !has_metadata {
+            tasks.push(self.handle_fetch_version_metadata());
+        }
+        tasks.push(self.handle_check_for_app_update());
+        tasks.push(self.handle_check_for_backend_update());
+        tasks.push(project_version_file_task());
+        tasks
+    }
+
     fn enter_onboarding_flow(&mut self) -> Task<Message> {
         info!("No backend found, entering onboarding flow");
         let shell_statuses = detect_onboarding_shell_statuses();
@@ -65,7 +114,7 @@ impl Versi {
         onboarding.detected_shells = shell_statuses;
         onboarding.available_backends = self.available_backend_options_for_onboarding();
         self.state = AppState::Onboarding(onboarding);
-        Task::none()
+        scan_version_files_task()
     }
 
     fn available_backend_options_for_onboarding(&self) -> Vec<BackendOption> {
@@ -179,16 +228,35 @@ impl Versi {
         env.load_request_seq = env.load_request_seq.wrapping_add(1);
         Some(env.load_request_seq)
     }
+}
 
-    fn build_post_init_tasks(&mut self) -> [Task<Message>; 5] {
-        [
-            self.handle_fetch_remote_versions(),
-            self.handle_fetch_release_schedule(),
-            self.handle_fetch_version_metadata(),
-            self.handle_check_for_app_update(),
-            self.handle_check_for_backend_update(),
-        ]
-    }
+fn scan_version_files_task() -> Task<Message> {
+    Task::perform(
+        async {
+            let home = dirs::home_dir()?;
+            let deadline = std::time::Instant::now() + crate::version_files::SCAN_TIME_BUDGET;
+            tokio::task::spawn_blocking(move || {
+                crate::version_files::scan_for_suggestion(&home, deadline)
+            })
+            .await
+            .ok()
+            .flatten()
+        },
+        Message::OnboardingVersionSuggestionFound,
+    )
+}
+
+fn project_version_file_task() -> Task<Message> {
+    Task::perform(
+        async {
+            let dir = std::env::current_dir().ok()?;
+            tokio::task::spawn_blocking(move || crate::version_files::find_in_dir(&dir))
+                .await
+                .ok()
+                .flatten()
+        },
+        Message::ProjectVersionFileFound,
+    )
 }
 
 fn detect_onboarding_shell_statuses() -> Vec<ShellConfigStatus> {
@@ -211,27 +279,39 @@ fn build_environment_states(result: &InitResult) -> Vec<EnvironmentState> {
     result
         .environments
         .iter()
-        .map(|env_info| {
-            if env_info.available {
-                EnvironmentState::new(
-                    env_info.id.clone(),
-                    env_info.backend_name,
-                    env_info.backend_version.clone(),
-                )
-            } else {
-                EnvironmentState::unavailable(
-                    env_info.id.clone(),
-                    env_info.backend_name,
-                    env_info
-                        .unavailable_reason
-                        .as_deref()
-                        .unwrap_or("Unavailable"),
-                )
-            }
-        })
+        .map(environment_state_from_info)
         .collect()
 }
 
+pub(super) fn environment_state_from_info(env_info: &EnvironmentInfo) -> EnvironmentState {
+    if env_info.available {
+        EnvironmentState::new(
+            env_info.id.clone(),
+            env_info.backend_name,
+            env_info.backend_version.clone(),
+        )
+    } else {
+        EnvironmentState::unavailable(
+            env_info.id.clone(),
+            env_info.backend_name,
+            env_info
+                .unavailable_reason
+                .as_deref()
+                .unwrap_or("Unavailable"),
+        )
+    }
+}
+
+/// The startup warning to show when app directories couldn't be resolved
+/// and nothing will be persisted this session.
+fn degraded_mode_message(error: Option<AppPathsError>) -> Option<String> {
+    let error = error?;
+    Some(format!(
+        "Could not determine the {} directory. Settings, cache and logs won't be saved this session.",
+        error.directory()
+    ))
+}
+
 fn load_disk_cache_into_state(main_state: &mut MainState) {
     match crate::cache::DiskCache::load() {
         Ok(Some(disk_cache)) => {
@@ -265,6 +345,7 @@ fn load_disk_cache_into_state(main_state: &mut MainState) {
 pub(super) async fn initialize(
     providers: Vec<Arc<dyn BackendProvider>>,
     preferred: Option<BackendKind>,
+    wsl_timeout: Duration,
 ) -> InitResult {
     info!(
         "Initializing application with {} providers...",
@@ -283,11 +364,20 @@ pub(super) async fn initialize(
     let native_env = native_environment(*backend_name, detection.version.clone());
 
     #[cfg(not(windows))]
-    let environments = vec![native_env];
+    let environments = {
+        let _ = wsl_timeout;
+        vec![native_env]
+    };
 
     #[cfg(windows)]
-    let environments =
-        build_windows_environments(native_env, &providers, *backend_name, preferred_name).await;
+    let environments = build_windows_environments(
+        native_env,
+        &providers,
+        *backend_name,
+        preferred_name,
+        wsl_timeout,
+    )
+    .await;
 
     log_detected_environments(&environments);
 
@@ -301,6 +391,21 @@ pub(super) async fn initialize(
     }
 }
 
+/// Detect the preferred backend on the native environment and build its
+/// manager, without any of the GUI's environment bookkeeping.
+pub(crate) async fn detect_native_manager(
+    providers: &[Arc<dyn BackendProvider>],
+    preferred: Option<BackendKind>,
+) -> Option<Arc<dyn VersionManager>> {
+    let detections = detect_backends(providers).await;
+    let (kind, detection) =
+        choose_backend_detection(&detections, preferred.unwrap_or(BackendKind::DEFAULT))?;
+    let provider = providers
+        .iter()
+        .find(|provider| BackendKind::from_name(provider.name()) == Some(*kind))?;
+    Some(provider.create_manager(detection))
+}
+
 async fn detect_backends(
     providers: &[Arc<dyn BackendProvider>],
 ) -> Vec<(BackendKind, BackendDetection)> {
@@ -323,6 +428,20 @@ async fn detect_backends(
     detections
 }
 
+/// Index of the environment that was active last time, or the first one when
+/// it is gone or unavailable.
+fn restore_environment_index(
+    environments: &[EnvironmentState],
+    last: Option<&EnvironmentId>,
+) -> usize {
+    last.and_then(|last| {
+        environments
+            .iter()
+            .position(|env| &env.id == last && env.available)
+    })
+    .unwrap_or(0)
+}
+
 fn collect_detected_backends(detections: &[(BackendKind, BackendDetection)]) -> Vec<BackendKind> {
     detections
         .iter()
@@ -389,24 +508,40 @@ async fn build_windows_environments(
     providers: &[Arc<dyn BackendProvider>],
     native_backend_name: BackendKind,
     preferred_name: BackendKind,
+    wsl_timeout: Duration,
 ) -> Vec<EnvironmentInfo> {
-    use versi_platform::detect_wsl_distros;
-
     info!("Running on Windows, detecting WSL distros...");
 
     let mut environments = vec![native_env];
+    environments.extend(
+        detect_wsl_environments(providers, native_backend_name, preferred_name, wsl_timeout).await,
+    );
+    environments
+}
+
+#[cfg(windows)]
+pub(super) async fn detect_wsl_environments(
+    providers: &[Arc<dyn BackendProvider>],
+    native_backend_name: BackendKind,
+    preferred_name: BackendKind,
+    wsl_timeout: Duration,
+) -> Vec<EnvironmentInfo> {
+    use versi_platform::detect_wsl_distros;
+
     let search_paths = collect_wsl_search_paths(providers);
-    let distros = detect_wsl_distros(&search_paths);
+    let distros = detect_wsl_distros(&search_paths, wsl_timeout).await;
 
     debug!(
         "WSL distros found: {:?}",
         distros.iter().map(|d| &d.name).collect::<Vec<_>>()
     );
 
+    let mut environments = Vec::with_capacity(distros.len());
     for distro in distros {
-        environments.push(build_wsl_environment(distro, native_backend_name, preferred_name).await);
+        environments.push(
+            build_wsl_environment(distro, native_backend_name, preferred_name, wsl_timeout).await,
+        );
     }
-
     environments
 }
 
@@ -426,6 +561,7 @@ async fn build_wsl_environment(
     distro: versi_platform::WslDistro,
     native_backend_name: BackendKind,
     preferred_name: BackendKind,
+    wsl_timeout: Duration,
 ) -> EnvironmentInfo {
     if !distro.is_running {
         info!(
@@ -435,13 +571,35 @@ async fn build_wsl_environment(
         return unavailable_wsl_environment(distro.name, native_backend_name, "Not running");
     }
 
+    if distro.timed_out {
+        info!(
+            "Adding unavailable WSL environment: {} (not responding)",
+            distro.name
+        );
+        return unavailable_wsl_environment(
+            distro.name,
+            native_backend_name,
+            &not_responding_reason(wsl_timeout),
+        );
+    }
+
     if let Some(backend_path) = distro.backend_path {
         let backend_name = determine_wsl_backend(&backend_path, preferred_name);
         info!(
             "Adding WSL environment: {} ({} at {})",
             distro.name, backend_name, backend_path
         );
-        let backend_version = get_wsl_backend_version(&distro.name, &backend_path).await;
+        let backend_version =
+            match get_wsl_backend_version(&distro.name, &backend_path, wsl_timeout).await {
+                Ok(version) => version,
+                Err(_) => {
+                    return unavailable_wsl_environment(
+                        distro.name,
+                        native_backend_name,
+                        &not_responding_reason(wsl_timeout),
+                    );
+                }
+            };
         return EnvironmentInfo {
             id: EnvironmentId::Wsl {
                 distro: distro.name,
@@ -491,16 +649,32 @@ fn determine_wsl_backend(path: &str, default_name: BackendKind) -> BackendKind {
 }
 
 #[cfg(windows)]
-async fn get_wsl_backend_version(distro: &str, backend_path: &str) -> Option<String> {
+fn not_responding_reason(timeout: Duration) -> String {
+    format!("Not responding (timed out after {}s)", timeout.as_secs())
+}
+
+/// Returns a [`std::io::ErrorKind::TimedOut`] error when the distro does not
+/// answer within `timeout`.
+#[cfg(windows)]
+pub(super) async fn get_wsl_backend_version(
+    distro: &str,
+    backend_path: &str,
+    timeout: Duration,
+) -> std::io::Result<Option<String>> {
     use tokio::process::Command;
     use versi_core::HideWindow;
 
-    let output = Command::new("wsl.exe")
-        .args(["-d", distro, "--", backend_path, "--version"])
-        .hide_window()
-        .output()
-        .await
-        .ok()?;
+    let mut cmd = Command::new("wsl.exe");
+    cmd.args(["-d", distro, "--", backend_path, "--version"])
+        .hide_window();
+    let output = match versi_platform::output_with_timeout(&mut cmd, timeout).await {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+            info!("WSL {distro} did not report a backend version: {e}");
+            return Err(e);
+        }
+        Err(_) => return Ok(None),
+    };
 
     if output.status.success() {
         let version_str = String::from_utf8_lossy(&output.stdout);
@@ -510,9 +684,9 @@ async fn get_wsl_backend_version(distro: &str, backend_path: &str) -> Option<Str
             .unwrap_or(version_str.trim())
             .to_string();
         debug!("WSL {} backend version: {}", distro, version);
-        Some(version)
+        Ok(Some(version))
     } else {
-        None
+        Ok(None)
     }
 }
 
@@ -550,7 +724,8 @@ mod tests {
 
     use super::{
         build_environment_states, choose_backend_detection, collect_detected_backends,
-        create_backend_for_environment, native_environment, no_backend_init_result,
+        create_backend_for_environment, degraded_mode_message, native_environment,
+        no_backend_init_result, restore_environment_index,
     };
     use crate::backend_kind::BackendKind;
     use crate::message::EnvironmentInfo;
@@ -565,6 +740,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn degraded_mode_is_only_reported_when_paths_failed() {
+        assert_eq!(degraded_mode_message(None), None);
+
+        let message =
+            degraded_mode_message(Some(versi_platform::AppPathsError::ConfigDirUnavailable))
+                .expect("a paths error should produce a warning");
+        assert!(message.contains("config directory"));
+    }
+
     #[test]
     fn collect_detected_backends_returns_only_found_entries() {
         let detections = vec![
@@ -703,4 +888,53 @@ mod tests {
         assert!(env.available);
         assert!(env.unavailable_reason.is_none());
     }
+
+    #[test]
+    fn restore_environment_index_matches_by_id_and_falls_back_to_first() {
+        let ubuntu = EnvironmentId::Wsl {
+            distro: "Ubuntu".to_string(),
+            backend_path: "/home/user/.local/bin/fnm".to_string(),
+        };
+        let debian = EnvironmentId::Wsl {
+            distro: "Debian".to_string(),
+            backend_path: "/home/user/.local/bin/fnm".to_string(),
+        };
+        let mut environments = vec![
+            crate::state::EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None),
+            crate::state::EnvironmentState::new(ubuntu.clone(), BackendKind::Fnm, None),
+        ];
+
+        assert_eq!(restore_environment_index(&environments, Some(&ubuntu)), 1);
+        assert_eq!(restore_environment_index(&environments, Some(&debian)), 0);
+        assert_eq!(restore_environment_index(&environments, None), 0);
+
+        environments[1].available = false;
+        assert_eq!(restore_environment_index(&environments, Some(&ubuntu)), 0);
+    }
+
+    #[test]
+    fn post_init_tasks_skip_metadata_fetch_when_disabled() {
+        let mut app = super::super::test_app_with_two_environments();
+        app.settings.fetch_version_metadata = false;
+
+        let _ = app.build_post_init_tasks();
+
+        let state = app.main_state();
+        assert!(state.available_versions.remote.is_in_flight());
+        assert!(!state.available_versions.metadata_fetch.is_in_flight());
+    }
+
+    #[test]
+    fn post_init_tasks_fetch_metadata_by_default() {
+        let mut app = super::super::test_app_with_two_environments();
+
+        let _ = app.build_post_init_tasks();
+
+        assert!(
+            app.main_state()
+                .available_versions
+                .metadata_fetch
+                .is_in_flight()
+        );
+    }
 }
@@ -0,0 +1,430 @@
+//! Moving installed versions from one engine or environment to another.
+//!
+//! Handles messages: `RequestMigration`, `MigrationSourceLoaded`,
+//! `MigrationSetDefaultToggled`, `ConfirmMigration`,
+//! `RequestCloneEnvironment`, `ConfirmCloneEnvironment`
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use log::info;
+
+use iced::Task;
+
+use versi_backend::{BackendError, InstalledVersion, NodeVersion};
+use versi_platform::EnvironmentId;
+
+use crate::backend_kind::BackendKind;
+use crate::error::AppError;
+use crate::message::Message;
+use crate::state::{
+    AppState, EnvironmentState, MainState, MigrationPlan, MigrationProgress, Modal, Operation,
+    Toast,
+};
+
+use super::Versi;
+use super::async_helpers::run_with_timeout;
+
+/// Versions `source` has that `target` lacks, plus the source's default.
+fn plan_migration(source: &[InstalledVersion], target: &HashSet<NodeVersion>) -> MigrationPlan {
+    let mut missing: Vec<&NodeVersion> = source
+        .iter()
+        .map(|installed| &installed.version)
+        .filter(|version| !target.contains(version))
+        .collect();
+    missing.sort();
+    missing.dedup();
+
+    MigrationPlan {
+        install: missing.into_iter().map(ToString::to_string).collect(),
+        default: source
+            .iter()
+            .find(|installed| installed.is_default)
+            .map(|installed| installed.version.to_string()),
+    }
+}
+
+/// Count a finished install towards the open migration's progress, whether
+/// it succeeded or not.
+pub(super) fn record_migration_install(state: &mut MainState, version: &str, success: bool) {
+    if let Some(Modal::Migration {
+        plan,
+        started: true,
+        progress,
+        ..
+    }) = &mut state.modal
+        && plan.install.iter().any(|planned| planned == version)
+    {
+        if success {
+            progress.succeeded += 1;
+        } else {
+            progress.failed += 1;
+        }
+    }
+}
+
+/// Versions installed in `source` that `target` is missing, oldest first.
+fn plan_environment_clone(source: &EnvironmentState, target: &EnvironmentState) -> Vec<String> {
+    plan_migration(&source.installed_versions, &target.installed_set).install
+}
+
+impl Versi {
+    /// Offer to install the versions environment `source_id` has into the
+    /// active environment, through the active environment's backend.
+    ///
+    /// The "Copy from" button is disabled while [`EnvironmentState::clone_blocker`]
+    /// reports a reason, so this only re-checks it.
+    pub(super) fn handle_request_clone_environment(&mut self, source_id: &EnvironmentId) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let target = state.active_environment();
+        let Some(source) = state
+            .environments
+            .iter()
+            .find(|env| &env.id == source_id && env.available)
+        else {
+            return;
+        };
+        if source.id == target.id || source.clone_blocker(target).is_some() {
+            return;
+        }
+
+        state.modal = Some(Modal::ConfirmCloneEnvironment {
+            source: source.name.clone(),
+            target: target.name.clone(),
+            versions: plan_environment_clone(source, target),
+        });
+    }
+
+    pub(super) fn handle_confirm_clone_environment(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmCloneEnvironment {
+                source, versions, ..
+            }) = state.modal.take()
+        {
+            info!(
+                "Installing {} version(s) from {source} into {}",
+                versions.len(),
+                state.active_environment().name
+            );
+            for version in versions {
+                state
+                    .operation_queue
+                    .enqueue(Operation::Install { version });
+            }
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_request_migration(&self, source: BackendKind) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        if source == state.backend_name || state.active_environment().id != EnvironmentId::Native {
+            return Task::none();
+        }
+
+        info!("Reading installed versions from {source} for migration");
+        let provider = self.provider_for_kind(source);
+        let timeout = Duration::from_secs(self.settings.fetch_timeout_secs);
+        Task::perform(
+            async move {
+                let detection = provider.detect().await;
+                if !detection.found {
+                    return Err(AppError::operation_failed(
+                        "Migration",
+                        format!("{source} is not installed"),
+                    ));
+                }
+                let manager = provider.create_manager(&detection);
+                run_with_timeout(
+                    timeout,
+                    "Migration",
+                    manager.list_installed(),
+                    |error: BackendError| AppError::operation_failed("Migration", error),
+                )
+                .await
+            },
+            move |result| Message::MigrationSourceLoaded { source, result },
+        )
+    }
+
+    pub(super) fn handle_migration_source_loaded(
+        &mut self,
+        source: BackendKind,
+        result: Result<Vec<InstalledVersion>, AppError>,
+    ) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let installed = match result {
+            Ok(installed) => installed,
+            Err(error) => {
+                let id = state.next_toast_id();
+                state.add_toast(Toast::error(id, error.to_string()));
+                return;
+            }
+        };
+
+        let plan = plan_migration(&installed, &state.active_environment().installed_set);
+        state.migration_source = Some((source, installed));
+        // With nothing to migrate the Import button is disabled instead.
+        if plan.install.is_empty() {
+            return;
+        }
+
+        let set_default = plan.default.is_some();
+        state.modal = Some(Modal::Migration {
+            source,
+            plan,
+            set_default,
+            started: false,
+            progress: MigrationProgress::default(),
+        });
+    }
+
+    pub(super) fn handle_migration_set_default_toggled(&mut self, enabled: bool) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::Migration {
+                set_default,
+                started: false,
+                ..
+            }) = &mut state.modal
+        {
+            *set_default = enabled;
+        }
+    }
+
+    pub(super) fn handle_confirm_migration(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(Modal::Migration {
+            source,
+            plan,
+            set_default,
+            started,
+            ..
+        }) = &mut state.modal
+        else {
+            return Task::none();
+        };
+        if *started {
+            return Task::none();
+        }
+
+        info!(
+            "Migrating {} version(s) from {source} to {}",
+            plan.install.len(),
+            state.backend_name
+        );
+        *started = true;
+        for version in &plan.install {
+            state.operation_queue.enqueue(Operation::Install {
+                version: version.clone(),
+            });
+        }
+        if *set_default && let Some(version) = &plan.default {
+            state.operation_queue.enqueue(Operation::SetDefault {
+                version: version.clone(),
+            });
+        }
+        self.process_next_operation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+
+    fn installed(version: &str, is_default: bool) -> InstalledVersion {
+        InstalledVersion {
+            version: version.parse().expect("test version should parse"),
+            is_default,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+        }
+    }
+
+    #[test]
+    fn migration_plan_installs_only_missing_versions_oldest_first() {
+        let source = vec![
+            installed("v22.11.0", true),
+            installed("v18.20.4", false),
+            installed("v20.18.0", false),
+        ];
+        let target: HashSet<NodeVersion> = ["v20.18.0", "v23.0.0"]
+            .iter()
+            .map(|version| version.parse().expect("test version should parse"))
+            .collect();
+
+        let plan = plan_migration(&source, &target);
+
+        assert_eq!(
+            plan,
+            MigrationPlan {
+                install: vec!["v18.20.4".to_string(), "v22.11.0".to_string()],
+                default: Some("v22.11.0".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn confirming_migration_queues_installs_then_default() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().modal = Some(Modal::Migration {
+            source: BackendKind::Nvm,
+            plan: MigrationPlan {
+                install: vec!["v18.20.4".to_string(), "v22.11.0".to_string()],
+                default: Some("v22.11.0".to_string()),
+            },
+            set_default: true,
+            started: false,
+            progress: MigrationProgress::default(),
+        });
+
+        let _ = app.handle_confirm_migration();
+
+        let state = app.main_state();
+        assert!(matches!(
+            state.modal,
+            Some(Modal::Migration { started: true, .. })
+        ));
+        let installing: Vec<&str> = state
+            .operation_queue
+            .active_installs
+            .iter()
+            .map(Operation::version)
+            .collect();
+        assert_eq!(installing, vec!["v18.20.4", "v22.11.0"]);
+        assert!(state.operation_queue.has_pending_set_default("v22.11.0"));
+    }
+
+    #[test]
+    fn migration_progress_counts_failed_installs_as_completed() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().modal = Some(Modal::Migration {
+            source: BackendKind::Nvm,
+            plan: MigrationPlan {
+                install: vec!["v18.20.4".to_string(), "v22.11.0".to_string()],
+                default: None,
+            },
+            set_default: false,
+            started: true,
+            progress: MigrationProgress::default(),
+        });
+
+        let _ = app.handle_install_complete("v18.20.4", true, None);
+        let _ = app.handle_install_complete("v22.11.0", false, None);
+        let _ = app.handle_install_complete("v16.20.2", true, None);
+
+        assert!(matches!(
+            app.main_state().modal,
+            Some(Modal::Migration {
+                progress: MigrationProgress {
+                    succeeded: 1,
+                    failed: 1
+                },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn migration_with_nothing_to_install_disables_import_instead_of_opening() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .active_environment_mut()
+            .update_versions(vec![installed("v20.18.0", true)]);
+
+        app.handle_migration_source_loaded(BackendKind::Nvm, Ok(vec![installed("v20.18.0", true)]));
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(state.toasts.is_empty());
+        assert!(state.nothing_to_migrate_from(BackendKind::Nvm));
+    }
+
+    #[test]
+    fn environment_clone_plan_lists_versions_missing_from_target() {
+        let mut native = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        native.update_versions(vec![
+            installed("v22.11.0", true),
+            installed("v20.18.0", false),
+        ]);
+        let mut wsl = EnvironmentState::new(
+            EnvironmentId::Wsl {
+                distro: "Ubuntu".to_string(),
+                backend_path: "/home/user/.local/bin/fnm".to_string(),
+            },
+            BackendKind::Fnm,
+            None,
+        );
+        wsl.update_versions(vec![installed("v20.18.0", true)]);
+
+        assert_eq!(
+            plan_environment_clone(&native, &wsl),
+            vec!["v22.11.0".to_string()]
+        );
+        assert!(plan_environment_clone(&wsl, &native).is_empty());
+    }
+
+    #[test]
+    fn environment_that_failed_to_load_cannot_be_cloned() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.environments[0].update_versions(vec![installed("v20.18.0", true)]);
+        state.environments[1].loading = false;
+        state.environments[1].error = Some(AppError::from("wsl.exe exited with 1"));
+        let source_id = state.environments[1].id.clone();
+
+        assert_eq!(
+            state.environments[1].clone_blocker(&state.environments[0]),
+            Some("Installed versions in WSL: Ubuntu failed to load".to_string())
+        );
+
+        app.handle_request_clone_environment(&source_id);
+
+        assert!(app.main_state().modal.is_none());
+    }
+
+    #[test]
+    fn requesting_environment_clone_opens_confirmation() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.environments[0].update_versions(vec![installed("v20.18.0", true)]);
+        state.environments[1].update_versions(vec![
+            installed("v22.11.0", true),
+            installed("v20.18.0", false),
+        ]);
+        let source_id = state.environments[1].id.clone();
+
+        app.handle_request_clone_environment(&source_id);
+
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::ConfirmCloneEnvironment { versions, .. })
+                if versions == &["v22.11.0".to_string()]
+        ));
+    }
+
+    #[test]
+    fn confirming_environment_clone_queues_installs() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().modal = Some(Modal::ConfirmCloneEnvironment {
+            source: "Native".to_string(),
+            target: "Ubuntu".to_string(),
+            versions: vec!["v22.11.0".to_string()],
+        });
+
+        let _ = app.handle_confirm_clone_environment();
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(state.operation_queue.has_active_install("v22.11.0"));
+    }
+}
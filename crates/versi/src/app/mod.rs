@@ -1,16 +1,22 @@
+mod aliases;
 mod async_helpers;
 mod auto_update;
 mod bulk_operations;
+mod changelog;
+mod corepack;
 mod environment;
 mod init;
+mod migration;
 mod onboarding;
 mod operations;
 mod platform;
 mod settings_io;
 mod settings_save;
 mod shell;
+mod terminal;
 mod tray_handlers;
 mod update;
+mod version_notes;
 mod versions;
 mod window;
 
@@ -25,7 +31,7 @@ use versi_backend::BackendProvider;
 use crate::backend_kind::BackendKind;
 use crate::message::Message;
 use crate::settings::{AppSettings, ThemeSetting, TrayBehavior};
-use crate::state::{AppState, MainViewKind};
+use crate::state::{AppState, MainViewKind, SearchFilter};
 #[cfg(test)]
 use crate::state::{EnvironmentState, MainState, OnboardingState};
 use crate::theme::{dark_theme, light_theme};
@@ -36,6 +42,9 @@ use versi_backend::BackendDetection;
 #[cfg(test)]
 use versi_platform::EnvironmentId;
 
+pub(crate) use init::detect_native_manager;
+pub(crate) use settings_io::export_settings_to_path;
+
 #[cfg(target_os = "linux")]
 const TICK_INTERVAL_FAST_MS: u64 = 100;
 const TICK_INTERVAL_DEFAULT_MS: u64 = 1000;
@@ -50,6 +59,8 @@ fn should_dismiss_context_menu(message: &Message) -> bool {
             | Message::VersionRowHovered(_)
             | Message::WindowEvent(_)
             | Message::SystemThemeChanged(_)
+            | Message::SystemReducedMotionDetected(_)
+            | Message::CycleModalFocus
             | Message::CloseContextMenu
             | Message::ShowContextMenu { .. }
     )
@@ -70,6 +81,12 @@ pub struct Versi {
     pub(crate) providers: HashMap<BackendKind, Arc<dyn BackendProvider>>,
     pub(crate) provider: Arc<dyn BackendProvider>,
     pub(crate) system_theme_mode: iced::theme::Mode,
+    pub(crate) system_reduced_motion: bool,
+    /// Language used while the setting follows the system.
+    pub(crate) system_language: crate::strings::Language,
+    /// Why app directories couldn't be resolved; taken once init completes
+    /// so the warning is only shown once.
+    pub(crate) paths_error: Option<versi_platform::AppPathsError>,
 }
 
 impl Versi {
@@ -120,17 +137,25 @@ impl Versi {
             providers: providers.clone(),
             provider: active_provider,
             system_theme_mode: iced::theme::Mode::None,
+            system_reduced_motion: false,
+            system_language: crate::strings::Language::system(),
+            paths_error: versi_platform::AppPaths::new().err(),
         };
 
         let all_providers: Vec<Arc<dyn BackendProvider>> = providers.values().cloned().collect();
         let preferred_backend = app.settings.preferred_backend;
+        let wsl_timeout = std::time::Duration::from_secs(app.settings.fetch_timeout_secs);
         let init_task = Task::perform(
-            init::initialize(all_providers, preferred_backend),
+            init::initialize(all_providers, preferred_backend, wsl_timeout),
             |result| Message::Initialized(Box::new(result)),
         );
         let theme_task = iced::system::theme().map(Message::SystemThemeChanged);
+        let motion_task = Task::perform(
+            versi_platform::prefers_reduced_motion(),
+            Message::SystemReducedMotionDetected,
+        );
 
-        (app, Task::batch([init_task, theme_task]))
+        (app, Task::batch([init_task, theme_task, motion_task]))
     }
 
     pub fn title(&self) -> String {
@@ -157,25 +182,43 @@ impl Versi {
             AppState::Main(state) => {
                 use iced::widget::{column, container};
 
+                let language = self.language();
                 let tab_row = views::main_view::tabs::environment_tabs_view(state);
-                let has_tabs = tab_row.is_some();
+                let rescan = views::main_view::tabs::rescan_wsl_button(language);
+                let has_tabs = tab_row.is_some() || rescan.is_some();
 
                 let inner = match state.view {
-                    MainViewKind::Versions => {
-                        views::main_view::view(state, &self.settings, has_tabs)
-                    }
+                    MainViewKind::Versions => views::main_view::view(
+                        state,
+                        &self.settings,
+                        language,
+                        has_tabs,
+                        self.reduced_motion(),
+                    ),
                     MainViewKind::Settings => views::settings_view::view(
                         &state.settings_state,
                         &self.settings,
                         state,
+                        language,
                         has_tabs,
                         self.is_system_dark(),
+                        self.reduced_motion(),
                     ),
-                    MainViewKind::About => views::about_view::view(state, has_tabs),
+                    MainViewKind::About => {
+                        views::about_view::view(state, language, has_tabs, self.reduced_motion())
+                    }
                 };
 
-                if let Some(tabs) = tab_row {
-                    let tabs_container = container(tabs).padding(
+                if has_tabs {
+                    let mut bar = iced::widget::row![].align_y(iced::Alignment::Center);
+                    if let Some(tabs) = tab_row {
+                        bar = bar.push(tabs);
+                    }
+                    bar = bar.push(iced::widget::Space::new().width(iced::Length::Fill));
+                    if let Some(rescan) = rescan {
+                        bar = bar.push(rescan);
+                    }
+                    let tabs_container = container(bar).padding(
                         iced::Padding::new(0.0)
                             .top(12.0)
                             .left(crate::theme::tokens::INSET_RIGHT)
@@ -225,7 +268,7 @@ impl Versi {
         let tick =
             iced::time::every(std::time::Duration::from_millis(tick_ms)).map(|_| Message::Tick);
 
-        let keyboard = iced::event::listen_with(|event, _status, _id| {
+        let keyboard = iced::event::listen_with(|event, status, _id| {
             if let iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
                 key, modifiers, ..
             }) = event
@@ -256,6 +299,14 @@ impl Versi {
                     return Some(Message::ShowKeyboardShortcuts);
                 }
 
+                if !cmd
+                    && status == iced::event::Status::Ignored
+                    && let iced::keyboard::Key::Character(c) = &key
+                    && let Some(filter) = SearchFilter::from_shortcut_key(c.as_str())
+                {
+                    return Some(Message::SearchFilterShortcut(filter));
+                }
+
                 if let iced::keyboard::Key::Named(named) = &key {
                     match named {
                         iced::keyboard::key::Named::ArrowUp => {
@@ -264,7 +315,9 @@ impl Versi {
                         iced::keyboard::key::Named::ArrowDown => {
                             return Some(Message::SelectNextVersion);
                         }
-                        iced::keyboard::key::Named::Enter => {
+                        iced::keyboard::key::Named::Enter
+                            if status == iced::event::Status::Ignored =>
+                        {
                             return Some(Message::ActivateSelectedVersion);
                         }
                         iced::keyboard::key::Named::Tab if cmd && modifiers.shift() => {
@@ -273,6 +326,11 @@ impl Versi {
                         iced::keyboard::key::Named::Tab if cmd => {
                             return Some(Message::SelectNextEnvironment);
                         }
+                        iced::keyboard::key::Named::Tab
+                            if status == iced::event::Status::Ignored =>
+                        {
+                            return Some(Message::CycleModalFocus);
+                        }
                         _ => {}
                     }
                 }
@@ -320,6 +378,9 @@ impl Versi {
     }
 
     fn is_refresh_animating(&self) -> bool {
+        if self.reduced_motion() {
+            return false;
+        }
         if let AppState::Main(state) = &self.state {
             state.refresh_rotation != 0.0
         } else {
@@ -327,6 +388,14 @@ impl Versi {
         }
     }
 
+    fn reduced_motion(&self) -> bool {
+        self.settings.reduced_motion || self.system_reduced_motion
+    }
+
+    pub(crate) fn language(&self) -> crate::strings::Language {
+        self.settings.language.unwrap_or(self.system_language)
+    }
+
     fn handle_preferred_backend_changed(&mut self, name: BackendKind) -> Task<Message> {
         self.settings.preferred_backend = Some(name);
         self.save_settings_with_log();
@@ -339,10 +408,12 @@ impl Versi {
                 }
                 let all_providers = self.all_providers();
                 let preferred = self.settings.preferred_backend;
+                let wsl_timeout = std::time::Duration::from_secs(self.settings.fetch_timeout_secs);
                 self.state = AppState::Loading;
-                return Task::perform(init::initialize(all_providers, preferred), |result| {
-                    Message::Initialized(Box::new(result))
-                });
+                return Task::perform(
+                    init::initialize(all_providers, preferred, wsl_timeout),
+                    |result| Message::Initialized(Box::new(result)),
+                );
             }
         }
 
@@ -404,7 +475,10 @@ fn test_app_with_two_environments() -> Versi {
         BackendKind::Nvm,
         None,
     );
-    let main_state = MainState::new_with_environments(backend, vec![native, wsl], BackendKind::Fnm);
+    let mut main_state =
+        MainState::new_with_environments(backend, vec![native, wsl], BackendKind::Fnm);
+    // Handlers under test shouldn't write to the real audit log.
+    main_state.audit_sink = std::sync::mpsc::channel().0;
 
     Versi {
         state: AppState::Main(Box::new(main_state)),
@@ -421,6 +495,9 @@ fn test_app_with_two_environments() -> Versi {
         providers,
         provider: fnm_provider,
         system_theme_mode: iced::theme::Mode::None,
+        system_reduced_motion: false,
+        system_language: crate::strings::Language::English,
+        paths_error: None,
     }
 }
 
@@ -460,6 +537,20 @@ mod tests {
     use crate::state::{MainViewKind, Modal, Operation};
     use crate::tray::TrayMessage;
 
+    #[test]
+    fn reduced_motion_suppresses_refresh_animation() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().refresh_rotation = 0.5;
+        assert!(app.is_refresh_animating());
+
+        app.settings.reduced_motion = true;
+        assert!(!app.is_refresh_animating());
+
+        app.settings.reduced_motion = false;
+        app.system_reduced_motion = true;
+        assert!(!app.is_refresh_animating());
+    }
+
     #[test]
     fn context_menu_is_dismissed_for_unrelated_messages() {
         assert!(should_dismiss_context_menu(&Message::NavigateToSettings));
@@ -488,6 +579,10 @@ mod tests {
         assert_eq!(state.active_environment_idx, 1);
         assert_eq!(state.backend_name, BackendKind::Nvm);
         assert_eq!(app.provider.name(), BackendKind::Nvm.as_str());
+        assert_eq!(
+            app.settings.last_environment.as_ref(),
+            Some(&state.environments[1].id)
+        );
     }
 
     #[test]
@@ -623,7 +718,7 @@ mod tests {
         app.main_state_mut().available_versions.loading = true;
         app.main_state_mut().available_versions.remote.request_seq = 2;
 
-        app.handle_remote_versions_fetched(
+        let _ = app.handle_remote_versions_fetched(
             1,
             Ok(vec![RemoteVersion {
                 version: NodeVersion::new(22, 1, 0),
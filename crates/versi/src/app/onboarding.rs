@@ -1,9 +1,11 @@
 use iced::Task;
+use log::info;
 
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
 use crate::message::Message;
-use crate::state::{AppState, OnboardingStep};
+use crate::state::{AppState, OnboardingStep, ShellReloadHint};
+use crate::version_files::VersionFileSuggestion;
 
 use super::Versi;
 
@@ -61,10 +63,11 @@ impl Versi {
 
             let provider = self.provider.clone();
             let backend_name = provider.name();
+            let retry_delays_secs = self.settings.retry_delays_secs.clone();
             return Task::perform(
                 async move {
                     provider
-                        .install_backend()
+                        .install_backend(&retry_delays_secs)
                         .await
                         .map_err(|error| AppError::backend_install_failed(backend_name, error))
                 },
@@ -165,7 +168,11 @@ impl Versi {
         Task::none()
     }
 
-    pub(super) fn handle_onboarding_shell_config_result(&mut self, result: &Result<(), AppError>) {
+    pub(super) fn handle_onboarding_shell_config_result(
+        &mut self,
+        result: &Result<(), AppError>,
+    ) -> Task<Message> {
+        let mut configured_shell = None;
         if let AppState::Onboarding(state) = &mut self.state {
             for shell in &mut state.detected_shells {
                 if shell.configuring {
@@ -174,6 +181,7 @@ impl Versi {
                         Ok(()) => {
                             shell.configured = true;
                             shell.error = None;
+                            configured_shell = Some(shell.shell_type.clone());
                         }
                         Err(error) => {
                             shell.error = Some(error.clone());
@@ -183,16 +191,123 @@ impl Versi {
                 }
             }
         }
+
+        configured_shell.map_or_else(Task::none, |shell_type| {
+            self.verify_onboarding_shell(shell_type)
+        })
+    }
+
+    fn verify_onboarding_shell(&self, shell_type: versi_shell::ShellType) -> Task<Message> {
+        let provider = self.provider.clone();
+        let marker = provider.shell_config_marker().to_string();
+        let backend_name = provider.name().to_string();
+
+        Task::perform(
+            async move {
+                let result =
+                    versi_shell::verify_shell_config(&shell_type, &marker, &backend_name).await;
+                let in_current_session = provider.detect().await.in_path;
+                (shell_type, result, in_current_session)
+            },
+            |(shell_type, result, in_current_session)| Message::OnboardingShellVerified {
+                shell_type,
+                result,
+                in_current_session,
+            },
+        )
+    }
+
+    pub(super) fn handle_onboarding_shell_verified(
+        &mut self,
+        shell_type: &versi_shell::ShellType,
+        result: &versi_shell::VerificationResult,
+        in_current_session: bool,
+    ) {
+        let AppState::Onboarding(state) = &mut self.state else {
+            return;
+        };
+        let config_path = state
+            .detected_shells
+            .iter()
+            .find(|shell| &shell.shell_type == shell_type)
+            .and_then(|shell| shell.config_path.as_deref());
+
+        if let Some(hint) =
+            ShellReloadHint::select(shell_type, config_path, result, in_current_session)
+        {
+            state.shell_reload_hint = Some(hint);
+        }
+    }
+
+    pub(super) fn handle_onboarding_version_suggestion(
+        &mut self,
+        suggestion: Option<VersionFileSuggestion>,
+    ) {
+        if let AppState::Onboarding(state) = &mut self.state {
+            if let Some(suggestion) = &suggestion {
+                info!(
+                    "Found version file {} requesting {}",
+                    suggestion.path.display(),
+                    suggestion.spec
+                );
+            }
+            state.install_suggested_version = suggestion.is_some();
+            state.suggested_version = suggestion;
+        }
+    }
+
+    pub(super) fn handle_onboarding_install_suggested_toggled(&mut self, value: bool) {
+        if let AppState::Onboarding(state) = &mut self.state {
+            state.install_suggested_version = value && state.suggested_version.is_some();
+        }
+    }
+
+    pub(super) fn handle_onboarding_install_latest_lts_toggled(&mut self, value: bool) {
+        if let AppState::Onboarding(state) = &mut self.state {
+            state.install_latest_lts = value;
+        }
     }
 
     pub(super) fn handle_onboarding_complete(&mut self) -> Task<Message> {
         let all_providers = self.all_providers();
         let preferred = self.settings.preferred_backend;
-        Task::perform(
-            super::init::initialize(all_providers, preferred),
+        let wsl_timeout = std::time::Duration::from_secs(self.settings.fetch_timeout_secs);
+        let init_task = Task::perform(
+            super::init::initialize(all_providers, preferred, wsl_timeout),
             |result| Message::Initialized(Box::new(result)),
+        );
+
+        if let Some(spec) = self.suggested_install_spec() {
+            init_task.chain(Task::done(Message::StartInstall(spec)))
+        } else if self.install_latest_lts_requested() {
+            init_task.chain(Task::done(Message::InstallLatestLts))
+        } else {
+            init_task
+        }
+    }
+
+    /// A version file's request takes precedence, so the toggle only counts
+    /// when no version file was found.
+    fn install_latest_lts_requested(&self) -> bool {
+        matches!(
+            &self.state,
+            AppState::Onboarding(state)
+                if state.install_latest_lts && state.suggested_version.is_none()
         )
     }
+
+    fn suggested_install_spec(&self) -> Option<String> {
+        let AppState::Onboarding(state) = &self.state else {
+            return None;
+        };
+        if !state.install_suggested_version {
+            return None;
+        }
+        state
+            .suggested_version
+            .as_ref()
+            .map(|suggestion| suggestion.spec.clone())
+    }
 }
 
 fn shell_type_to_str(shell_type: &versi_shell::ShellType) -> &'static str {
@@ -220,6 +335,7 @@ mod tests {
     use crate::state::{
         AppState, BackendOption, OnboardingState, OnboardingStep, ShellConfigStatus,
     };
+    use crate::version_files::VersionFileSuggestion;
 
     fn test_onboarding_app(backend_count: usize) -> Versi {
         let fnm_provider: Arc<dyn BackendProvider> = Arc::new(versi_fnm::FnmProvider::new());
@@ -266,9 +382,56 @@ mod tests {
             providers,
             provider: fnm_provider,
             system_theme_mode: iced::theme::Mode::None,
+            system_reduced_motion: false,
+            system_language: crate::strings::Language::English,
+            paths_error: None,
         }
     }
 
+    #[test]
+    fn version_suggestion_is_preselected_and_can_be_declined() {
+        let mut app = test_onboarding_app(1);
+        app.handle_onboarding_version_suggestion(Some(VersionFileSuggestion {
+            path: PathBuf::from("/home/user/projects/app/.nvmrc"),
+            spec: "v20.11.0".to_string(),
+            version: Some(versi_backend::NodeVersion::new(20, 11, 0)),
+        }));
+
+        assert!(app.onboarding_state().install_suggested_version);
+        assert_eq!(app.suggested_install_spec(), Some("v20.11.0".to_string()));
+
+        app.handle_onboarding_install_suggested_toggled(false);
+        assert!(!app.onboarding_state().install_suggested_version);
+        assert_eq!(app.suggested_install_spec(), None);
+    }
+
+    #[test]
+    fn latest_lts_toggle_yields_to_a_version_file_suggestion() {
+        let mut app = test_onboarding_app(1);
+        app.handle_onboarding_install_latest_lts_toggled(true);
+        assert!(app.install_latest_lts_requested());
+
+        app.handle_onboarding_version_suggestion(Some(VersionFileSuggestion {
+            path: PathBuf::from("/home/user/projects/app/.nvmrc"),
+            spec: "v20.11.0".to_string(),
+            version: Some(versi_backend::NodeVersion::new(20, 11, 0)),
+        }));
+
+        assert!(!app.install_latest_lts_requested());
+        assert_eq!(app.suggested_install_spec(), Some("v20.11.0".to_string()));
+    }
+
+    #[test]
+    fn install_suggestion_cannot_be_enabled_without_a_version_file() {
+        let mut app = test_onboarding_app(1);
+        app.handle_onboarding_version_suggestion(None);
+
+        app.handle_onboarding_install_suggested_toggled(true);
+
+        assert!(!app.onboarding_state().install_suggested_version);
+        assert_eq!(app.suggested_install_spec(), None);
+    }
+
     #[test]
     fn onboarding_next_from_welcome_uses_backend_count() {
         let mut multi = test_onboarding_app(2);
@@ -357,7 +520,7 @@ mod tests {
             ];
         }
 
-        app.handle_onboarding_shell_config_result(&Ok(()));
+        let _ = app.handle_onboarding_shell_config_result(&Ok(()));
         let state = app.onboarding_state();
         assert!(state.detected_shells[0].configured);
         assert!(!state.detected_shells[0].configuring);
@@ -377,7 +540,7 @@ mod tests {
         }
 
         let err = AppError::shell_config_failed("Fish", "write config", "config failed");
-        app.handle_onboarding_shell_config_result(&Err(err.clone()));
+        let _ = app.handle_onboarding_shell_config_result(&Err(err.clone()));
         let state = app.onboarding_state();
         assert_eq!(state.detected_shells[0].error, Some(err));
         assert!(!state.detected_shells[0].configured);
@@ -1,16 +1,25 @@
 //! Install, uninstall, and set-default operations with queuing.
 //!
-//! Handles messages: `StartInstall`, `InstallComplete`, Uninstall, `UninstallComplete`,
-//! `SetDefault`, `DefaultChanged`, `CloseModal`
-
+//! Handles messages: `StartInstall`, `InstallLatestLts`, `InstallComplete`, `InstallVerified`,
+//! Uninstall, `UninstallComplete`, `UndoUninstall`, `ReinstallVersion`,
+//! `ConfirmReinstall`, `ConfirmUninstall`, `ConfirmUninstallDefault`, `SetDefault`, `ConfirmEolDefault`,
+//! `DefaultChanged`, `RevertDefault`, `ShowLastError`, `AcknowledgeLastError`,
+//! `CloseModal`, `RequestInstallFromArchive`, `InstallArchivePicked`,
+//! `InstallArchiveInspected`, `ArchiveInstallComplete`
+
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use iced::Task;
-use versi_backend::NodeVersion;
+use versi_backend::{BackendError, InstalledVersion, NodeArchive, NodeVersion};
+use versi_platform::{BYTES_PER_MB, EnvironmentId};
 
-use crate::error::AppError;
+use crate::audit_log::{self, AuditAction, AuditRecord};
+use crate::error::{AppError, AppErrorDetail};
 use crate::message::Message;
-use crate::state::{AppState, MainState, Modal, Operation, Toast};
+use crate::settings::{ConfirmUninstall, DefaultOnInstall};
+use crate::state::{AppState, MainState, Modal, Operation, Toast, ToastAction};
+use crate::version_query::resolve_alias_with_index;
 
 use super::Versi;
 use super::async_helpers::run_with_timeout;
@@ -30,7 +39,7 @@ fn enqueue_install_if_busy(state: &mut MainState, version: &str) -> bool {
     false
 }
 
-fn enqueue_exclusive_if_busy(state: &mut MainState, request: Operation) -> bool {
+pub(super) fn enqueue_exclusive_if_busy(state: &mut MainState, request: Operation) -> bool {
     if state.operation_queue.is_busy_for_exclusive() {
         state.operation_queue.enqueue(request);
         return true;
@@ -38,7 +47,30 @@ fn enqueue_exclusive_if_busy(state: &mut MainState, request: Operation) -> bool
     false
 }
 
-fn should_confirm_default_uninstall(state: &MainState, version: &str) -> bool {
+/// Queue a set-default behind any running installs, per the user's policy.
+/// Skipped when `environment`, where the install ran, is no longer active:
+/// the default would otherwise change in the wrong environment.
+fn enqueue_default_after_install(
+    state: &mut MainState,
+    environment: &EnvironmentId,
+    policy: DefaultOnInstall,
+    version: &str,
+) {
+    if environment != &state.active_environment().id {
+        log::info!("Not setting {version} as default: its environment is no longer active");
+        return;
+    }
+    let has_default = state.active_environment().default_version.is_some();
+    if policy.should_set_default(has_default)
+        && !state.operation_queue.has_pending_set_default(version)
+    {
+        state.operation_queue.enqueue(Operation::SetDefault {
+            version: version.to_string(),
+        });
+    }
+}
+
+fn is_default_version(state: &MainState, version: &str) -> bool {
     let Ok(version) = version.parse::<NodeVersion>() else {
         return false;
     };
@@ -49,6 +81,145 @@ fn should_confirm_default_uninstall(state: &MainState, version: &str) -> bool {
         .is_some_and(|dv| dv == &version)
 }
 
+fn uninstall_confirmation(
+    state: &MainState,
+    policy: ConfirmUninstall,
+    version: &str,
+) -> Option<Modal> {
+    let is_default = is_default_version(state, version);
+    if !policy.should_confirm(is_default) {
+        return None;
+    }
+    let version = version.to_string();
+    Some(if is_default {
+        Modal::ConfirmUninstallDefault { version }
+    } else {
+        Modal::ConfirmUninstall { version }
+    })
+}
+
+/// Offer to reinstall `version` into `environment`, the one it was just
+/// removed from. Nothing is offered once the user has switched away.
+fn offer_undo_uninstall(
+    state: &mut MainState,
+    environment: EnvironmentId,
+    version: &str,
+    window_secs: u64,
+) {
+    if window_secs == 0 || environment != state.active_environment().id {
+        return;
+    }
+    state.record_uninstall(environment.clone(), version);
+    let id = state.next_toast_id();
+    state.add_toast(
+        Toast::info(id, format!("Uninstalled Node {version}")).with_action(
+            ToastAction::UndoUninstall {
+                environment,
+                version: version.to_string(),
+            },
+            window_secs,
+        ),
+    );
+}
+
+/// Whether the schedule says `version`'s major line has reached end-of-life.
+fn is_end_of_life(state: &MainState, version: &str) -> bool {
+    let Ok(version) = version.parse::<NodeVersion>() else {
+        return false;
+    };
+    state
+        .available_versions
+        .schedule
+        .as_ref()
+        .and_then(|schedule| schedule.release_phase_at(version.major, state.now))
+        == Some(versi_core::ReleasePhase::EndOfLife)
+}
+
+/// How long the "Revert to …" action stays offered after a default change.
+const REVERT_DEFAULT_TOAST_SECS: u64 = 30;
+
+/// Remember the default `target` replaced in `environment` and offer to
+/// switch back to it. Nothing is offered once the user has switched away.
+fn offer_revert_default(state: &mut MainState, environment: EnvironmentId, target: &str) {
+    if environment != state.active_environment().id {
+        return;
+    }
+    let Some(previous) = state
+        .active_environment()
+        .default_version
+        .as_ref()
+        .map(ToString::to_string)
+        .filter(|previous| previous != target)
+    else {
+        return;
+    };
+    state.push_previous_default(previous.clone());
+    let id = state.next_toast_id();
+    state.add_toast(
+        Toast::info(id, format!("Default set to Node {target}")).with_action(
+            ToastAction::RevertDefault {
+                environment,
+                version: previous,
+            },
+            REVERT_DEFAULT_TOAST_SECS,
+        ),
+    );
+}
+
+/// How long a retry toast stays up; longer than a plain error so the
+/// action can be found after a long install.
+const RETRY_TOAST_SECS: u64 = 60;
+
+/// The timeout to offer for a retry when `error` is a timeout: twice the
+/// one that ran out.
+fn escalated_timeout_secs(error: Option<&AppError>) -> Option<u64> {
+    match error {
+        Some(AppError::Timeout { seconds, .. }) => Some(seconds.saturating_mul(2)),
+        _ => None,
+    }
+}
+
+/// Typical unpacked size of a Node release, for majors not installed yet.
+const FALLBACK_INSTALL_BYTES: u64 = 200 * BYTES_PER_MB;
+
+/// Free space to ask for before installing `version`: the largest installed
+/// release of the same major, or a typical one, plus a third for the archive
+/// being unpacked.
+fn estimated_install_bytes(version: &str, installed: &[InstalledVersion]) -> u64 {
+    let major = version
+        .parse::<NodeVersion>()
+        .ok()
+        .map(|version| version.major);
+    let size = installed
+        .iter()
+        .filter(|installed| Some(installed.version.major) == major)
+        .filter_map(|installed| installed.disk_size)
+        .max()
+        .unwrap_or(FALLBACK_INSTALL_BYTES);
+    size.saturating_add(size / 3)
+}
+
+/// Fail fast when `dir` can't fit an install; an unreadable free-space figure
+/// lets the install go ahead.
+fn check_install_space(
+    dir: &Path,
+    needed: u64,
+    available: std::io::Result<u64>,
+) -> Result<(), AppError> {
+    match available {
+        Ok(available) if available < needed => Err(AppError::InsufficientSpace {
+            dir: dir.to_path_buf(),
+            needed,
+            available,
+        }),
+        Ok(_) => Ok(()),
+        Err(error) => {
+            log::debug!("Could not read free space for {}: {error}", dir.display());
+            Ok(())
+        }
+    }
+}
+
 fn error_text(error: Option<AppError>) -> String {
     error.map_or_else(|| "unknown error".to_string(), |e| e.to_string())
 }
@@ -65,6 +236,67 @@ fn set_default_failure_message(error: Option<AppError>) -> String {
     format!("Failed to set default: {}", error_text(error))
 }
 
+fn verification_failure_message(version: &str, error: &AppError) -> Option<String> {
+    if matches!(
+        error,
+        AppError::OperationFailed {
+            details: AppErrorDetail::Backend(versi_backend::BackendError::Unsupported { .. }),
+            ..
+        }
+    ) {
+        return None;
+    }
+    Some(format!("Could not verify Node {version}: {error}"))
+}
+
+/// Note that the operation on `version` runs in the active environment.
+fn mark_started_in_active(state: &mut MainState, version: &str) {
+    let environment = state.active_environment().id.clone();
+    state.operation_queue.set_started_in(version, environment);
+}
+
+/// Environment the operation on `version` was started in, or the active one
+/// if it wasn't recorded.
+fn take_started_in(state: &mut MainState, version: &str) -> EnvironmentId {
+    state
+        .operation_queue
+        .take_started_in(version)
+        .unwrap_or_else(|| state.active_environment().id.clone())
+}
+
+fn record_audit(
+    state: &MainState,
+    environment: &EnvironmentId,
+    action: AuditAction,
+    version: &str,
+    success: bool,
+    error: Option<&AppError>,
+    max_log_size: u64,
+) {
+    let failure =
+        (!success).then(|| error.map_or_else(|| "unknown error".to_string(), ToString::to_string));
+    let name = state
+        .environments
+        .iter()
+        .find(|env| &env.id == environment)
+        .unwrap_or_else(|| state.active_environment())
+        .name
+        .as_str();
+    audit_log::record(
+        &state.audit_sink,
+        AuditRecord::new(action, version, name, failure),
+        max_log_size,
+    );
+}
+
+/// Keep `operation`'s failure around for the header indicator.
+fn remember_failure(state: &mut MainState, operation: Operation, error: Option<&AppError>) {
+    let error = error
+        .cloned()
+        .unwrap_or_else(|| AppError::Message("unknown error".to_string()));
+    state.last_error = Some((operation, error));
+}
+
 fn add_failure_toast(state: &mut MainState, message: String) {
     let toast_id = state.next_toast_id();
     state.add_toast(Toast::error(toast_id, message));
@@ -97,12 +329,32 @@ impl Versi {
     pub(super) fn start_install_internal(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.operation_queue.start_install(version.clone());
+            mark_started_in_active(state, &version);
 
             let backend = state.backend.clone();
-            let timeout = Duration::from_secs(self.settings.install_timeout_secs);
+            let timeout = Duration::from_secs(
+                state
+                    .install_timeouts
+                    .get(&version)
+                    .copied()
+                    .unwrap_or(self.settings.install_timeout_secs),
+            );
+            let env = state.active_environment();
+            let data_dir = if env.id == EnvironmentId::Native {
+                backend.backend_info().data_dir.clone()
+            } else {
+                None
+            };
+            let needed = estimated_install_bytes(&version, &env.installed_versions);
 
             return Task::perform(
                 async move {
+                    if let Some(dir) = data_dir
+                        && let Err(error) =
+                            check_install_space(&dir, needed, versi_platform::available_space(&dir))
+                    {
+                        return (version, false, Some(error));
+                    }
                     match run_with_timeout(
                         timeout,
                         "Installation",
@@ -131,25 +383,327 @@ impl Versi {
         success: bool,
         error: Option<AppError>,
     ) -> Task<Message> {
+        let mut installed_in = None;
         if let AppState::Main(state) = &mut self.state {
+            let environment = take_started_in(state, version);
             state.operation_queue.remove_completed_install(version);
+            state.install_timeouts.remove(version);
+            super::migration::record_migration_install(state, version, success);
+            record_audit(
+                state,
+                &environment,
+                AuditAction::Install,
+                version,
+                success,
+                error.as_ref(),
+                self.settings.max_log_size_bytes,
+            );
 
-            if !success {
-                add_failure_toast(state, install_failure_message(version, error));
+            if success {
+                enqueue_default_after_install(
+                    state,
+                    &environment,
+                    self.settings.set_default_on_install,
+                    version,
+                );
+            } else {
+                state.operation_queue.cancel_pending_set_default(version);
+                remember_failure(
+                    state,
+                    Operation::Install {
+                        version: version.to_string(),
+                    },
+                    error.as_ref(),
+                );
+                let retry = escalated_timeout_secs(error.as_ref());
+                let message = install_failure_message(version, error);
+                if let Some(timeout_secs) = retry {
+                    let id = state.next_toast_id();
+                    state.add_toast(Toast::error(id, message).with_action(
+                        ToastAction::RetryInstall {
+                            version: version.to_string(),
+                            timeout_secs,
+                        },
+                        RETRY_TOAST_SECS,
+                    ));
+                } else {
+                    add_failure_toast(state, message);
+                }
             }
+            installed_in = Some(environment);
         }
 
+        let verify_task = match installed_in {
+            Some(environment) if success => self.verify_install_task(&environment, version),
+            _ => Task::none(),
+        };
         let next_task = self.process_next_operation();
         let refresh_task = self.handle_refresh_environment();
-        Task::batch([refresh_task, next_task])
+        Task::batch([refresh_task, next_task, verify_task])
     }
 
-    pub(super) fn handle_uninstall(&mut self, version: String) -> Task<Message> {
+    /// Verify `version` with the backend of `environment`, the one that
+    /// installed it, even if the user has switched away since.
+    fn verify_install_task(&self, environment: &EnvironmentId, version: &str) -> Task<Message> {
+        if !self.settings.verify_installs {
+            return Task::none();
+        }
+        let Ok(parsed) = version.parse::<NodeVersion>() else {
+            log::debug!("Skipping checksum verification for unresolved version {version}");
+            return Task::none();
+        };
+        let Some(backend) = self.backend_for(environment) else {
+            return Task::none();
+        };
+
+        let client = self.http_client.clone();
+        let timeout = Duration::from_secs(self.settings.install_timeout_secs);
+        let version = version.to_string();
+        Task::perform(
+            async move {
+                run_with_timeout(
+                    timeout,
+                    "Install verification",
+                    backend.verify_installed(&client, &parsed),
+                    |error| AppError::operation_failed("Verify install", error),
+                )
+                .await
+            },
+            move |result| Message::InstallVerified { version, result },
+        )
+    }
+
+    pub(super) fn handle_install_verified(
+        &mut self,
+        version: &str,
+        result: Result<bool, AppError>,
+    ) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        match result {
+            Ok(true) => log::info!("Checksum verification passed for Node {version}"),
+            Ok(false) => add_failure_toast(
+                state,
+                format!(
+                    "Checksum verification FAILED for Node {version}. The installed files do not match the official release; uninstall it and reinstall from a trusted source."
+                ),
+            ),
+            Err(error) => match verification_failure_message(version, &error) {
+                Some(message) => add_failure_toast(state, message),
+                None => log::debug!("Backend cannot verify Node {version}: {error}"),
+            },
+        }
+    }
+
+    pub(super) fn handle_install_latest_lts(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(latest) = resolve_alias_with_index(
+            &state.available_versions.versions,
+            Some(&state.available_versions.search_index),
+            "lts/*",
+        ) else {
+            // Onboarding asks for this before the first fetch completes.
+            if state.available_versions.versions.is_empty() {
+                state.install_latest_lts_when_loaded = true;
+            }
+            return Task::none();
+        };
+        let version = latest.version.to_string();
+
+        let install_task = self.handle_start_install(version.clone());
+        if let AppState::Main(state) = &mut self.state
+            && !state.operation_queue.has_pending_set_default(&version)
+        {
+            state
+                .operation_queue
+                .enqueue(Operation::SetDefault { version });
+        }
+        install_task
+    }
+
+    pub(super) fn handle_request_install_from_archive(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        if !state.backend.capabilities().supports_archive_install {
+            let message = format!(
+                "{} in this environment does not support installing from a local archive",
+                state.backend_name
+            );
+            add_failure_toast(state, message);
+            return Task::none();
+        }
+
+        Task::perform(
+            async {
+                rfd::AsyncFileDialog::new()
+                    .set_title("Install Node.js from archive")
+                    .add_filter("Node.js archive", &["gz", "tgz", "xz", "zip"])
+                    .pick_file()
+                    .await
+                    .map(|handle| handle.path().to_path_buf())
+            },
+            Message::InstallArchivePicked,
+        )
+    }
+
+    pub(super) fn handle_install_archive_picked(path: Option<PathBuf>) -> Task<Message> {
+        let Some(path) = path else {
+            return Task::none();
+        };
+
+        // Inspecting reads the file header, so keep it off the UI thread.
+        let inspected = path.clone();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || NodeArchive::inspect(&inspected))
+                    .await
+                    .unwrap_or_else(|error| Err(std::io::Error::other(error).into()))
+            },
+            move |result| Message::InstallArchiveInspected {
+                path: path.clone(),
+                result,
+            },
+        )
+    }
+
+    pub(super) fn handle_install_archive_inspected(
+        &mut self,
+        path: &Path,
+        result: Result<NodeArchive, BackendError>,
+    ) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        let archive = match result {
+            Ok(archive) => archive,
+            Err(error) => {
+                add_failure_toast(
+                    state,
+                    format!("Cannot install from {}: {error}", path.display()),
+                );
+                return Task::none();
+            }
+        };
+
+        let version = archive.version.to_string();
+        if has_duplicate_install_request(state, &version) {
+            return Task::none();
+        }
+
+        if enqueue_exclusive_if_busy(
+            state,
+            Operation::InstallArchive {
+                version: version.clone(),
+                path: archive.path.clone(),
+            },
+        ) {
+            return Task::none();
+        }
+
+        self.start_install_archive_internal(version, archive.path)
+    }
+
+    pub(super) fn start_install_archive_internal(
+        &mut self,
+        version: String,
+        path: PathBuf,
+    ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
-            if should_confirm_default_uninstall(state, &version) {
-                state.modal = Some(Modal::ConfirmUninstallDefault {
+            state
+                .operation_queue
+                .start_exclusive(Operation::InstallArchive {
                     version: version.clone(),
+                    path: path.clone(),
                 });
+            mark_started_in_active(state, &version);
+
+            let backend = state.backend.clone();
+            let timeout = Duration::from_secs(self.settings.install_timeout_secs);
+
+            return Task::perform(
+                async move {
+                    match run_with_timeout(
+                        timeout,
+                        "Installation",
+                        backend.install_from_archive(&path),
+                        |error| AppError::operation_failed("Install", error),
+                    )
+                    .await
+                    {
+                        Ok(()) => (version, true, None),
+                        Err(error) => (version, false, Some(error)),
+                    }
+                },
+                |(version, success, error)| Message::ArchiveInstallComplete {
+                    version,
+                    success,
+                    error,
+                },
+            );
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_archive_install_complete(
+        &mut self,
+        version: &str,
+        success: bool,
+        error: Option<AppError>,
+    ) -> Task<Message> {
+        let mut installed_in = None;
+        if let AppState::Main(state) = &mut self.state {
+            let environment = take_started_in(state, version);
+            state.operation_queue.complete_exclusive();
+            record_audit(
+                state,
+                &environment,
+                AuditAction::Install,
+                version,
+                success,
+                error.as_ref(),
+                self.settings.max_log_size_bytes,
+            );
+
+            if success {
+                enqueue_default_after_install(
+                    state,
+                    &environment,
+                    self.settings.set_default_on_install,
+                    version,
+                );
+            } else {
+                remember_failure(
+                    state,
+                    Operation::Install {
+                        version: version.to_string(),
+                    },
+                    error.as_ref(),
+                );
+                add_failure_toast(state, install_failure_message(version, error));
+            }
+            installed_in = Some(environment);
+        }
+
+        let verify_task = match installed_in {
+            Some(environment) if success => self.verify_install_task(&environment, version),
+            _ => Task::none(),
+        };
+        let next_task = self.process_next_operation();
+        let refresh_task = self.handle_refresh_environment();
+        Task::batch([refresh_task, next_task, verify_task])
+    }
+
+    pub(super) fn handle_uninstall(&mut self, version: String) -> Task<Message> {
+        let policy = self.settings.confirm_uninstall;
+        if let AppState::Main(state) = &mut self.state {
+            if let Some(modal) = uninstall_confirmation(state, policy, &version) {
+                state.modal = Some(modal);
                 return Task::none();
             }
 
@@ -167,7 +721,42 @@ impl Versi {
         Task::none()
     }
 
-    pub(super) fn handle_confirm_uninstall_default(&mut self, version: String) -> Task<Message> {
+    pub(super) fn handle_reinstall(&mut self, version: String) -> Task<Message> {
+        let policy = self.settings.confirm_uninstall;
+        if let AppState::Main(state) = &mut self.state {
+            let is_default = is_default_version(state, &version);
+            if policy.should_confirm(is_default) {
+                state.modal = Some(Modal::ConfirmReinstall {
+                    version,
+                    is_default,
+                });
+                return Task::none();
+            }
+        }
+        self.enqueue_reinstall(version)
+    }
+
+    pub(super) fn handle_confirm_reinstall(&mut self, version: String) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = None;
+        }
+        self.enqueue_reinstall(version)
+    }
+
+    fn enqueue_reinstall(&mut self, version: String) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.operation_queue.enqueue(Operation::Uninstall {
+                version: version.clone(),
+            });
+            state
+                .operation_queue
+                .enqueue(Operation::Install { version });
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_confirm_uninstall(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.modal = None;
 
@@ -190,6 +779,7 @@ impl Versi {
             state.operation_queue.start_exclusive(Operation::Uninstall {
                 version: version.clone(),
             });
+            mark_started_in_active(state, &version);
 
             let backend = state.backend.clone();
             let timeout = Duration::from_secs(self.settings.uninstall_timeout_secs);
@@ -225,9 +815,33 @@ impl Versi {
         error: Option<AppError>,
     ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            let environment = take_started_in(state, version);
             state.operation_queue.complete_exclusive();
+            record_audit(
+                state,
+                &environment,
+                AuditAction::Uninstall,
+                version,
+                success,
+                error.as_ref(),
+                self.settings.max_log_size_bytes,
+            );
 
-            if !success {
+            if success {
+                offer_undo_uninstall(
+                    state,
+                    environment,
+                    version,
+                    self.settings.undo_uninstall_secs,
+                );
+            } else {
+                remember_failure(
+                    state,
+                    Operation::Uninstall {
+                        version: version.to_string(),
+                    },
+                    error.as_ref(),
+                );
                 add_failure_toast(state, uninstall_failure_message(version, error));
             }
         }
@@ -237,7 +851,81 @@ impl Versi {
         Task::batch([refresh_task, next_task])
     }
 
+    pub(super) fn handle_retry_install(
+        &mut self,
+        version: String,
+        timeout_secs: u64,
+    ) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.toasts.retain(|toast| {
+                !matches!(
+                    &toast.action,
+                    Some(ToastAction::RetryInstall { version: v, .. }) if *v == version
+                )
+            });
+            if has_duplicate_install_request(state, &version) {
+                return Task::none();
+            }
+            log::info!("Retrying install of {version} with a {timeout_secs}s timeout");
+            state.install_timeouts.insert(version.clone(), timeout_secs);
+        }
+        self.handle_start_install(version)
+    }
+
+    pub(super) fn handle_undo_uninstall(
+        &mut self,
+        environment: &EnvironmentId,
+        version: String,
+    ) -> Task<Message> {
+        let window = Duration::from_secs(self.settings.undo_uninstall_secs);
+        if let AppState::Main(state) = &mut self.state {
+            let action = ToastAction::UndoUninstall {
+                environment: environment.clone(),
+                version: version.clone(),
+            };
+            state
+                .toasts
+                .retain(|toast| toast.action.as_ref() != Some(&action));
+
+            if environment != &state.active_environment().id {
+                log::info!(
+                    "Not undoing uninstall of {version}: its environment is no longer active"
+                );
+                return Task::none();
+            }
+            if !state.take_undoable_uninstall(environment, &version, window)
+                || has_duplicate_install_request(state, &version)
+            {
+                return Task::none();
+            }
+
+            state
+                .operation_queue
+                .enqueue(Operation::Install { version });
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
     pub(super) fn handle_set_default(&mut self, version: String) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && self.settings.confirm_eol_default
+            && is_end_of_life(state, &version)
+        {
+            state.modal = Some(Modal::ConfirmEolDefault { version });
+            return Task::none();
+        }
+        self.set_default_confirmed(version)
+    }
+
+    pub(super) fn handle_confirm_eol_default(&mut self, version: String) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = None;
+        }
+        self.set_default_confirmed(version)
+    }
+
+    fn set_default_confirmed(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             if enqueue_exclusive_if_busy(
                 state,
@@ -253,6 +941,26 @@ impl Versi {
         Task::none()
     }
 
+    pub(super) fn handle_revert_default(
+        &mut self,
+        environment: &EnvironmentId,
+        version: String,
+    ) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            if environment != &state.active_environment().id {
+                log::info!(
+                    "Not reverting default to {version}: its environment is no longer active"
+                );
+                return Task::none();
+            }
+            if state.default_history.last() == Some(&version) {
+                state.pop_previous_default();
+            }
+            state.pending_default_revert = Some(version.clone());
+        }
+        self.handle_set_default(version)
+    }
+
     pub(super) fn start_set_default_internal(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state
@@ -260,6 +968,7 @@ impl Versi {
                 .start_exclusive(Operation::SetDefault {
                     version: version.clone(),
                 });
+            mark_started_in_active(state, &version);
 
             let backend = state.backend.clone();
             let timeout = Duration::from_secs(self.settings.set_default_timeout_secs);
@@ -290,10 +999,36 @@ impl Versi {
         error: Option<AppError>,
     ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            let target = match &state.operation_queue.exclusive_op {
+                Some(Operation::SetDefault { version }) => Some(version.clone()),
+                _ => None,
+            };
+            let environment = target
+                .as_deref()
+                .map(|target| take_started_in(state, target));
             state.operation_queue.complete_exclusive();
+            let reverted = state.pending_default_revert.take();
+            if let (Some(target), Some(environment)) = (&target, &environment) {
+                record_audit(
+                    state,
+                    environment,
+                    AuditAction::SetDefault,
+                    target,
+                    success,
+                    error.as_ref(),
+                    self.settings.max_log_size_bytes,
+                );
+            }
 
             if !success {
+                if let Some(version) = target {
+                    remember_failure(state, Operation::SetDefault { version }, error.as_ref());
+                }
                 add_failure_toast(state, set_default_failure_message(error));
+            } else if let (Some(target), Some(environment)) = (target, environment)
+                && reverted.as_ref() != Some(&target)
+            {
+                offer_revert_default(state, environment, &target);
             }
         }
 
@@ -302,6 +1037,23 @@ impl Versi {
         Task::batch([refresh_task, next_task])
     }
 
+    pub(super) fn handle_show_last_error(&mut self) {
+        if let AppState::Main(state) = &mut self.state
+            && state.last_error.is_some()
+        {
+            state.modal = Some(Modal::LastOperationError);
+        }
+    }
+
+    pub(super) fn handle_acknowledge_last_error(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.last_error = None;
+            if matches!(state.modal, Some(Modal::LastOperationError)) {
+                state.modal = None;
+            }
+        }
+    }
+
     pub(super) fn process_next_operation(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             let (install_versions, exclusive_request) = state.operation_queue.drain_next();
@@ -325,6 +1077,12 @@ impl Versi {
         match request {
             Operation::Uninstall { version } => self.start_uninstall_internal(version),
             Operation::SetDefault { version } => self.start_set_default_internal(version),
+            Operation::InstallArchive { version, path } => {
+                self.start_install_archive_internal(version, path)
+            }
+            Operation::SetAlias { .. } | Operation::RemoveAlias { .. } => {
+                self.start_alias_operation(request)
+            }
             Operation::Install { .. } => Task::none(),
         }
     }
@@ -346,35 +1104,186 @@ mod tests {
         assert!(state.modal.is_none());
     }
 
-    #[test]
-    fn start_install_ignores_duplicate_active_version() {
-        let mut app = test_app_with_two_environments();
-        app.main_state_mut()
-            .operation_queue
-            .start_install("v20.11.0".to_string());
-
-        let _ = app.handle_start_install("v20.11.0".to_string());
+    fn schedule_with_eol_16() -> versi_core::ReleaseSchedule {
+        serde_json::from_value(serde_json::json!({
+            "versions": {
+                "16": {
+                    "start": "2021-04-20",
+                    "lts": "2021-10-26",
+                    "maintenance": "2022-10-18",
+                    "end": "2023-09-11",
+                    "codename": "Gallium"
+                },
+                "24": {
+                    "start": "2025-05-06",
+                    "lts": "2025-10-28",
+                    "maintenance": "2026-10-20",
+                    "end": "2028-04-30",
+                    "codename": "Krypton"
+                }
+            }
+        }))
+        .expect("schedule fixture should deserialize")
+    }
 
-        let state = app.main_state();
-        assert_eq!(state.operation_queue.active_installs.len(), 1);
-        assert!(state.operation_queue.pending.is_empty());
+    /// Pin the app's date so the fixture's phases don't drift with the clock.
+    fn pin_today(app: &mut Versi, date: &str) {
+        app.main_state_mut().now = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .expect("fixture date should parse")
+            .and_hms_opt(12, 0, 0)
+            .expect("noon should be a valid time")
+            .and_utc();
     }
 
     #[test]
-    fn start_install_queues_when_exclusive_operation_is_active() {
+    fn set_default_to_eol_version_asks_for_confirmation() {
         let mut app = test_app_with_two_environments();
-        app.main_state_mut()
-            .operation_queue
-            .start_exclusive(Operation::SetDefault {
-                version: "v20.11.0".to_string(),
-            });
+        app.main_state_mut().available_versions.schedule = Some(schedule_with_eol_16());
+        pin_today(&mut app, "2026-10-16");
 
-        let _ = app.handle_start_install("v22.1.0".to_string());
+        let _ = app.handle_set_default("v16.20.2".to_string());
 
         let state = app.main_state();
-        assert_eq!(state.operation_queue.pending.len(), 1);
         assert!(matches!(
-            state.operation_queue.pending.front(),
+            state.modal,
+            Some(Modal::ConfirmEolDefault { ref version }) if version == "v16.20.2"
+        ));
+        assert!(state.operation_queue.exclusive_op.is_none());
+
+        let _ = app.handle_confirm_eol_default("v16.20.2".to_string());
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(matches!(
+            state.operation_queue.exclusive_op,
+            Some(Operation::SetDefault { ref version }) if version == "v16.20.2"
+        ));
+    }
+
+    #[test]
+    fn set_default_to_active_version_skips_confirmation() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().available_versions.schedule = Some(schedule_with_eol_16());
+        pin_today(&mut app, "2026-10-16");
+
+        let _ = app.handle_set_default("v24.1.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert!(matches!(
+            state.operation_queue.exclusive_op,
+            Some(Operation::SetDefault { ref version }) if version == "v24.1.0"
+        ));
+    }
+
+    #[test]
+    fn eol_default_confirmation_can_be_turned_off() {
+        let mut app = test_app_with_two_environments();
+        app.settings.confirm_eol_default = false;
+        app.main_state_mut().available_versions.schedule = Some(schedule_with_eol_16());
+        pin_today(&mut app, "2026-10-16");
+
+        let _ = app.handle_set_default("v16.20.2".to_string());
+
+        assert!(app.main_state().modal.is_none());
+    }
+
+    #[test]
+    fn default_change_offers_revert_and_revert_is_not_recorded_again() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.active_environment_mut().default_version = Some(NodeVersion::new(20, 11, 0));
+        state
+            .operation_queue
+            .start_exclusive(Operation::SetDefault {
+                version: "v22.1.0".to_string(),
+            });
+
+        let _ = app.handle_default_changed(true, None);
+
+        let state = app.main_state();
+        assert_eq!(state.default_history, vec!["v20.11.0".to_string()]);
+        assert_eq!(
+            state.toasts.last().and_then(|toast| toast.action.clone()),
+            Some(ToastAction::RevertDefault {
+                environment: EnvironmentId::Native,
+                version: "v20.11.0".to_string()
+            })
+        );
+
+        let _ = app.handle_revert_default(&EnvironmentId::Native, "v20.11.0".to_string());
+        let state = app.main_state_mut();
+        assert!(state.default_history.is_empty());
+        assert!(matches!(
+            state.operation_queue.exclusive_op,
+            Some(Operation::SetDefault { ref version }) if version == "v20.11.0"
+        ));
+        state.active_environment_mut().default_version = Some(NodeVersion::new(22, 1, 0));
+        let toasts_before = state.toasts.len();
+
+        let _ = app.handle_default_changed(true, None);
+
+        let state = app.main_state();
+        assert!(state.default_history.is_empty());
+        assert!(state.pending_default_revert.is_none());
+        assert_eq!(state.toasts.len(), toasts_before);
+    }
+
+    #[test]
+    fn revert_default_is_dropped_after_switching_environments() {
+        let mut app = test_app_with_two_environments();
+        let state = app.main_state_mut();
+        state.active_environment_mut().default_version = Some(NodeVersion::new(20, 11, 0));
+        state
+            .operation_queue
+            .start_exclusive(Operation::SetDefault {
+                version: "v22.1.0".to_string(),
+            });
+        let _ = app.handle_default_changed(true, None);
+
+        let _ = app.handle_environment_selected(1);
+        assert!(
+            app.main_state()
+                .toasts
+                .iter()
+                .all(|toast| toast.action.is_none())
+        );
+
+        let _ = app.handle_revert_default(&EnvironmentId::Native, "v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(state.pending_default_revert.is_none());
+    }
+
+    #[test]
+    fn start_install_ignores_duplicate_active_version() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_install("v20.11.0".to_string());
+
+        let _ = app.handle_start_install("v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert_eq!(state.operation_queue.active_installs.len(), 1);
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn start_install_queues_when_exclusive_operation_is_active() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_exclusive(Operation::SetDefault {
+                version: "v20.11.0".to_string(),
+            });
+
+        let _ = app.handle_start_install("v22.1.0".to_string());
+
+        let state = app.main_state();
+        assert_eq!(state.operation_queue.pending.len(), 1);
+        assert!(matches!(
+            state.operation_queue.pending.front(),
             Some(Operation::Install { version }) if version == "v22.1.0"
         ));
     }
@@ -399,6 +1308,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn reinstall_default_opens_confirmation_modal() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .active_environment_mut()
+            .default_version = Some(
+            "v20.11.0"
+                .parse()
+                .expect("test default version should parse"),
+        );
+
+        let _ = app.handle_reinstall("v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(matches!(
+            state.modal,
+            Some(Modal::ConfirmReinstall { ref version, is_default: true }) if version == "v20.11.0"
+        ));
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
     #[test]
     fn uninstall_queues_when_exclusive_queue_is_busy() {
         let mut app = test_app_with_two_environments();
@@ -434,4 +1364,519 @@ mod tests {
             Some(Operation::SetDefault { version }) if version == "v22.0.0"
         ));
     }
+
+    #[test]
+    fn install_from_archive_reports_unsupported_backend() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_request_install_from_archive();
+
+        let state = app.main_state();
+        assert_eq!(state.toasts.len(), 1);
+        assert!(state.toasts[0].message.contains("local archive"));
+    }
+
+    #[test]
+    fn install_archive_inspected_rejects_non_node_archive() {
+        let mut app = test_app_with_two_environments();
+
+        let path = PathBuf::from("/tmp/notes.txt");
+        let _ = app.handle_install_archive_inspected(&path, NodeArchive::inspect(&path));
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert_eq!(state.toasts.len(), 1);
+        assert!(
+            state.toasts[0]
+                .message
+                .contains("Not a Node.js distribution archive")
+        );
+    }
+
+    #[test]
+    fn archive_install_completion_releases_exclusive_slot() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Never;
+        app.main_state_mut()
+            .operation_queue
+            .start_exclusive(Operation::InstallArchive {
+                version: "v22.11.0".to_string(),
+                path: PathBuf::from("/tmp/node-v22.11.0-linux-x64.tar.xz"),
+            });
+
+        let _ = app.handle_archive_install_complete("v22.11.0", true, None);
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(state.toasts.is_empty());
+    }
+
+    #[test]
+    fn failed_operation_is_kept_until_acknowledged() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_install("v22.1.0".to_string());
+
+        let error = AppError::operation_failed("Install", "download interrupted");
+        let _ = app.handle_install_complete("v22.1.0", false, Some(error.clone()));
+
+        assert!(matches!(
+            &app.main_state().last_error,
+            Some((Operation::Install { version }, recorded))
+                if version == "v22.1.0" && *recorded == error
+        ));
+
+        app.handle_show_last_error();
+        assert!(matches!(
+            app.main_state().modal,
+            Some(Modal::LastOperationError)
+        ));
+
+        app.handle_acknowledge_last_error();
+        let state = app.main_state();
+        assert!(state.last_error.is_none());
+        assert!(state.modal.is_none());
+    }
+
+    #[test]
+    fn operation_is_audited_in_the_environment_it_started_in() {
+        let mut app = test_app_with_two_environments();
+        let started_in = app.main_state().environments[0].id.clone();
+
+        let _ = app.start_uninstall_internal("v20.11.0".to_string());
+        app.main_state_mut().active_environment_idx = 1;
+
+        assert_eq!(
+            take_started_in(app.main_state_mut(), "v20.11.0"),
+            started_in
+        );
+    }
+
+    #[test]
+    fn install_finished_after_switching_environments_leaves_default_alone() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Always;
+        let _ = app.start_install_internal("v22.1.0".to_string());
+
+        let _ = app.handle_environment_selected(1);
+        let _ = app.handle_install_complete("v22.1.0", true, None);
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(!state.operation_queue.has_pending_set_default("v22.1.0"));
+    }
+
+    #[test]
+    fn install_audit_record_names_the_environment_it_started_in() {
+        let mut app = test_app_with_two_environments();
+        let (sink, records) = std::sync::mpsc::channel();
+        app.main_state_mut().audit_sink = sink;
+        let native_name = app.main_state().environments[0].name.clone();
+        let _ = app.start_install_internal("v22.1.0".to_string());
+
+        let _ = app.handle_environment_selected(1);
+        let _ = app.handle_install_complete("v22.1.0", true, None);
+
+        let (record, _) = records.try_recv().expect("install should be audited");
+        assert_eq!(record.action, AuditAction::Install);
+        assert_eq!(record.version, "v22.1.0");
+        assert_eq!(record.environment, native_name);
+        assert!(record.succeeded());
+    }
+
+    #[test]
+    fn install_complete_sets_default_when_policy_is_always() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Always;
+        app.main_state_mut()
+            .active_environment_mut()
+            .default_version = Some("v20.11.0".parse().expect("test version should parse"));
+        app.main_state_mut()
+            .operation_queue
+            .start_install("v22.1.0".to_string());
+
+        let _ = app.handle_install_complete("v22.1.0", true, None);
+
+        let state = app.main_state();
+        assert!(matches!(
+            &state.operation_queue.exclusive_op,
+            Some(Operation::SetDefault { version }) if version == "v22.1.0"
+        ));
+    }
+
+    #[test]
+    fn install_complete_auto_policy_only_sets_default_when_missing() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Auto;
+        app.main_state_mut()
+            .active_environment_mut()
+            .default_version = Some("v20.11.0".parse().expect("test version should parse"));
+
+        let _ = app.handle_install_complete("v22.1.0", true, None);
+        assert!(app.main_state().operation_queue.exclusive_op.is_none());
+
+        app.main_state_mut()
+            .active_environment_mut()
+            .default_version = None;
+        let _ = app.handle_install_complete("v22.2.0", true, None);
+        assert!(matches!(
+            &app.main_state().operation_queue.exclusive_op,
+            Some(Operation::SetDefault { version }) if version == "v22.2.0"
+        ));
+    }
+
+    #[test]
+    fn install_complete_set_default_waits_for_other_installs() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Always;
+        let queue = &mut app.main_state_mut().operation_queue;
+        queue.start_install("v22.1.0".to_string());
+        queue.start_install("v20.12.0".to_string());
+
+        let _ = app.handle_install_complete("v22.1.0", true, None);
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(matches!(
+            state.operation_queue.pending.front(),
+            Some(Operation::SetDefault { version }) if version == "v22.1.0"
+        ));
+    }
+
+    #[test]
+    fn failed_install_never_sets_default() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_default_on_install = DefaultOnInstall::Always;
+
+        let _ = app.handle_install_complete("v22.1.0", false, None);
+
+        let state = app.main_state();
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn install_latest_lts_queues_install_then_default() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().available_versions.versions = vec![
+            versi_backend::RemoteVersion {
+                version: "v24.1.0".parse().expect("test version should parse"),
+                lts_codename: None,
+                is_latest: true,
+            },
+            versi_backend::RemoteVersion {
+                version: "v22.11.0".parse().expect("test version should parse"),
+                lts_codename: Some("Jod".to_string()),
+                is_latest: false,
+            },
+        ];
+
+        let _ = app.handle_install_latest_lts();
+
+        let state = app.main_state();
+        assert!(state.operation_queue.has_active_install("v22.11.0"));
+        assert!(state.operation_queue.has_pending_set_default("v22.11.0"));
+
+        let _ = app.handle_install_complete("v22.11.0", true, None);
+
+        let state = app.main_state();
+        assert!(matches!(
+            &state.operation_queue.exclusive_op,
+            Some(Operation::SetDefault { version }) if version == "v22.11.0"
+        ));
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn install_latest_lts_does_nothing_before_versions_load() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_install_latest_lts();
+
+        let state = app.main_state();
+        assert!(state.operation_queue.active_installs.is_empty());
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn install_latest_lts_requested_before_versions_load_runs_once_they_arrive() {
+        let mut app = test_app_with_two_environments();
+        let _ = app.handle_install_latest_lts();
+        assert!(app.main_state().install_latest_lts_when_loaded);
+
+        let state = app.main_state_mut();
+        state.available_versions.loading = true;
+        state.available_versions.remote.request_seq = 3;
+        let _ = app.handle_remote_versions_fetched(
+            3,
+            Ok(vec![versi_backend::RemoteVersion {
+                version: "v22.11.0".parse().expect("test version should parse"),
+                lts_codename: Some("Jod".to_string()),
+                is_latest: true,
+            }]),
+        );
+
+        let state = app.main_state();
+        assert!(!state.install_latest_lts_when_loaded);
+        assert!(state.operation_queue.has_active_install("v22.11.0"));
+        assert!(state.operation_queue.has_pending_set_default("v22.11.0"));
+    }
+
+    #[test]
+    fn failed_install_drops_queued_set_default() {
+        let mut app = test_app_with_two_environments();
+        let queue = &mut app.main_state_mut().operation_queue;
+        queue.start_install("v22.11.0".to_string());
+        queue.enqueue(Operation::SetDefault {
+            version: "v22.11.0".to_string(),
+        });
+
+        let _ = app.handle_install_complete("v22.11.0", false, None);
+
+        let state = app.main_state();
+        assert!(state.operation_queue.pending.is_empty());
+        assert!(state.operation_queue.exclusive_op.is_none());
+    }
+
+    #[test]
+    fn install_verified_mismatch_shows_toast() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_install_verified("v22.11.0", Ok(true));
+        assert!(app.main_state().toasts.is_empty());
+
+        app.handle_install_verified("v22.11.0", Ok(false));
+        let toasts = &app.main_state().toasts;
+        assert_eq!(toasts.len(), 1);
+        assert!(toasts[0].message.contains("Checksum verification FAILED"));
+    }
+
+    #[test]
+    fn install_verified_ignores_unsupported_backends() {
+        let mut app = test_app_with_two_environments();
+
+        app.handle_install_verified(
+            "v22.11.0",
+            Err(AppError::operation_failed(
+                "Verify install",
+                versi_backend::BackendError::Unsupported {
+                    operation: "verify_installed",
+                },
+            )),
+        );
+        assert!(app.main_state().toasts.is_empty());
+
+        app.handle_install_verified(
+            "v22.11.0",
+            Err(AppError::operation_failed("Verify install", "offline")),
+        );
+        assert_eq!(app.main_state().toasts.len(), 1);
+    }
+
+    #[test]
+    fn undo_uninstall_enqueues_reinstall_of_the_same_version() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_exclusive(Operation::Uninstall {
+                version: "v20.11.0".to_string(),
+            });
+
+        let _ = app.handle_uninstall_complete("v20.11.0", true, None);
+        assert!(app.main_state().toasts.iter().any(|toast| {
+            toast.action
+                == Some(ToastAction::UndoUninstall {
+                    environment: EnvironmentId::Native,
+                    version: "v20.11.0".to_string(),
+                })
+        }));
+
+        let _ = app.handle_undo_uninstall(&EnvironmentId::Native, "v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.operation_queue.has_active_install("v20.11.0"));
+        assert!(state.toasts.iter().all(|toast| toast.action.is_none()));
+        assert!(state.recently_uninstalled.is_empty());
+    }
+
+    #[test]
+    fn undo_uninstall_does_not_reinstall_into_another_environment() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_exclusive(Operation::Uninstall {
+                version: "v20.11.0".to_string(),
+            });
+        let _ = app.handle_uninstall_complete("v20.11.0", true, None);
+
+        let _ = app.handle_environment_selected(1);
+        assert!(
+            app.main_state()
+                .toasts
+                .iter()
+                .all(|toast| toast.action.is_none())
+        );
+
+        let _ = app.handle_undo_uninstall(&EnvironmentId::Native, "v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.operation_queue.active_installs.is_empty());
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn undo_uninstall_is_ignored_outside_the_window() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_undo_uninstall(&EnvironmentId::Native, "v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.operation_queue.active_installs.is_empty());
+        assert!(state.operation_queue.pending.is_empty());
+    }
+
+    #[test]
+    fn uninstall_confirmation_follows_policy_and_always_guards_default() {
+        let cases = [
+            (ConfirmUninstall::Always, true, true),
+            (ConfirmUninstall::Always, false, true),
+            (ConfirmUninstall::DefaultOnly, true, true),
+            (ConfirmUninstall::DefaultOnly, false, false),
+            (ConfirmUninstall::Never, true, true),
+            (ConfirmUninstall::Never, false, false),
+        ];
+
+        for (policy, is_default, expect_modal) in cases {
+            let mut app = test_app_with_two_environments();
+            app.settings.confirm_uninstall = policy;
+            app.main_state_mut()
+                .active_environment_mut()
+                .default_version = Some(
+                "v20.11.0"
+                    .parse()
+                    .expect("test default version should parse"),
+            );
+            let version = if is_default { "v20.11.0" } else { "v18.19.0" };
+
+            let _ = app.handle_uninstall(version.to_string());
+
+            let state = app.main_state();
+            match &state.modal {
+                Some(Modal::ConfirmUninstallDefault { version: shown }) => {
+                    assert!(expect_modal && is_default, "{policy:?}/{version}");
+                    assert_eq!(shown, version);
+                }
+                Some(Modal::ConfirmUninstall { version: shown }) => {
+                    assert!(expect_modal && !is_default, "{policy:?}/{version}");
+                    assert_eq!(shown, version);
+                }
+                None => {
+                    assert!(!expect_modal, "{policy:?}/{version}");
+                    assert!(
+                        state
+                            .operation_queue
+                            .active_operation_for(version)
+                            .is_some()
+                    );
+                }
+                other => panic!("unexpected modal {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn timed_out_install_offers_retry_with_doubled_timeout() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut()
+            .operation_queue
+            .start_install("v22.11.0".to_string());
+
+        let _ = app.handle_install_complete(
+            "v22.11.0",
+            false,
+            Some(AppError::timeout("Installation", 600)),
+        );
+        let action = app
+            .main_state()
+            .toasts
+            .iter()
+            .find_map(|toast| toast.action.clone())
+            .expect("a timed-out install should offer a retry");
+        assert_eq!(
+            action,
+            ToastAction::RetryInstall {
+                version: "v22.11.0".to_string(),
+                timeout_secs: 1200,
+            }
+        );
+
+        let _ = app.handle_retry_install("v22.11.0".to_string(), 1200);
+
+        let state = app.main_state();
+        assert_eq!(state.install_timeouts.get("v22.11.0"), Some(&1200));
+        assert!(state.operation_queue.has_active_install("v22.11.0"));
+        assert!(state.toasts.iter().all(|toast| toast.action.is_none()));
+    }
+
+    #[test]
+    fn other_install_failures_do_not_offer_a_retry() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_install_complete(
+            "v22.11.0",
+            false,
+            Some(AppError::operation_failed("Install", "checksum mismatch")),
+        );
+
+        let state = app.main_state();
+        assert_eq!(state.toasts.len(), 1);
+        assert!(state.toasts[0].action.is_none());
+    }
+
+    #[test]
+    fn install_estimate_uses_largest_release_of_the_same_major() {
+        let installed = |version: &str, disk_size: Option<u64>| InstalledVersion {
+            version: version.parse().expect("test version should parse"),
+            is_default: false,
+            lts_codename: None,
+            install_date: None,
+            disk_size,
+        };
+        let versions = vec![
+            installed("v20.10.0", Some(150 * BYTES_PER_MB)),
+            installed("v20.11.0", Some(180 * BYTES_PER_MB)),
+            installed("v22.11.0", Some(300 * BYTES_PER_MB)),
+        ];
+
+        assert_eq!(
+            estimated_install_bytes("v20.12.0", &versions),
+            240 * BYTES_PER_MB
+        );
+        assert_eq!(
+            estimated_install_bytes("v18.20.4", &versions),
+            FALLBACK_INSTALL_BYTES + FALLBACK_INSTALL_BYTES / 3
+        );
+    }
+
+    #[test]
+    fn install_space_check_fails_only_when_space_is_known_to_be_short() {
+        let dir = Path::new("/data/fnm");
+        let needed = 240 * BYTES_PER_MB;
+
+        let error = check_install_space(dir, needed, Ok(100 * BYTES_PER_MB))
+            .expect_err("a full disk should stop the install");
+        assert!(matches!(
+            &error,
+            AppError::InsufficientSpace { available, .. } if *available == 100 * BYTES_PER_MB
+        ));
+        assert!(
+            error
+                .to_string()
+                .contains("about 240 MB needed, 100 MB available")
+        );
+
+        assert!(check_install_space(dir, needed, Ok(needed)).is_ok());
+        assert!(check_install_space(dir, needed, Err(std::io::Error::other("statvfs"))).is_ok());
+    }
 }
@@ -1,12 +1,14 @@
 //! Settings import/export flows.
 //!
-//! Handles messages: `ExportSettings`, `SettingsExported`, `ImportSettings`, `SettingsImported`
+//! Handles messages: `ExportSettings`, `SettingsExported`, `ImportSettings`, `SettingsImported`,
+//! `SettingsImportSectionToggled`, `ConfirmSettingsImport`
 
 use iced::Task;
 
 use crate::error::AppError;
 use crate::message::Message;
-use crate::state::AppState;
+use crate::settings::{AppSettings, SettingsSection};
+use crate::state::{AppState, Modal, Toast};
 
 use super::Versi;
 
@@ -54,13 +56,7 @@ impl Versi {
                     .pick_file()
                     .await;
                 match dialog {
-                    Some(handle) => {
-                        let imported = import_settings_from_path(handle.path()).await?;
-                        imported
-                            .save()
-                            .map_err(|error| AppError::settings_import_failed("save", error))?;
-                        Ok(())
-                    }
+                    Some(handle) => import_settings_from_path(handle.path()).await.map(Box::new),
                     None => Err(AppError::settings_dialog_cancelled()),
                 }
             },
@@ -68,34 +64,103 @@ impl Versi {
         )
     }
 
+    /// Show what the imported settings would change before applying them.
     pub(super) fn handle_settings_imported(
         &mut self,
-        result: Result<(), AppError>,
+        result: Result<Box<AppSettings>, AppError>,
     ) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
         match result {
-            Ok(()) => {
-                self.settings = crate::settings::AppSettings::load();
-            }
-            Err(e) if !is_settings_dialog_cancelled(&e) => {
-                if let AppState::Main(state) = &mut self.state {
+            Ok(imported) => {
+                let changes = self.settings.diff(&imported);
+                if changes.is_empty() {
                     let id = state.next_toast_id();
-                    state.add_toast(crate::state::Toast::error(
+                    state.add_toast(Toast::info(
                         id,
-                        format!("Import failed: {e}"),
+                        "Imported settings match the current ones".to_string(),
                     ));
+                } else {
+                    let sections = changes.iter().map(|change| change.section).collect();
+                    state.modal = Some(Modal::ConfirmSettingsImport {
+                        imported,
+                        changes,
+                        sections,
+                    });
                 }
             }
-            _ => {}
+            Err(e) if !is_settings_dialog_cancelled(&e) => {
+                let id = state.next_toast_id();
+                state.add_toast(Toast::error(id, format!("Import failed: {e}")));
+            }
+            Err(_) => {}
         }
         Task::none()
     }
+
+    pub(super) fn handle_settings_import_section_toggled(
+        &mut self,
+        section: SettingsSection,
+        selected: bool,
+    ) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmSettingsImport { sections, .. }) = &mut state.modal
+        {
+            if selected {
+                sections.insert(section);
+            } else {
+                sections.remove(&section);
+            }
+        }
+    }
+
+    /// Apply the sections picked in the import preview.
+    pub(super) fn handle_confirm_settings_import(&mut self) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let Some(Modal::ConfirmSettingsImport {
+            imported,
+            changes,
+            sections,
+        }) = &state.modal
+        else {
+            return;
+        };
+        if sections.is_empty() {
+            return;
+        }
+
+        let applied = changes
+            .iter()
+            .filter(|change| sections.contains(&change.section))
+            .count();
+        let merged = self.settings.merge_sections(imported, sections);
+        state.modal = None;
+        let id = state.next_toast_id();
+        match merged {
+            Ok(merged) => {
+                self.settings = merged;
+                state.add_toast(Toast::info(
+                    id,
+                    format!("Imported {applied} changed settings"),
+                ));
+                self.save_settings_with_log();
+            }
+            Err(error) => {
+                let error = AppError::settings_import_failed("merge", error);
+                state.add_toast(Toast::error(id, format!("Import failed: {error}")));
+            }
+        }
+    }
 }
 
 fn is_settings_dialog_cancelled(error: &AppError) -> bool {
     matches!(error, AppError::SettingsDialogCancelled)
 }
 
-async fn export_settings_to_path(
+pub(crate) async fn export_settings_to_path(
     settings: &crate::settings::AppSettings,
     path: &std::path::Path,
 ) -> Result<std::path::PathBuf, AppError> {
@@ -113,15 +178,73 @@ async fn import_settings_from_path(
     let content = tokio::fs::read_to_string(path)
         .await
         .map_err(|error| AppError::settings_import_failed("read", error))?;
-    serde_json::from_str(&content).map_err(|error| AppError::settings_import_failed("parse", error))
+    let mut settings = AppSettings::from_json(&content)
+        .map_err(|error| AppError::settings_import_failed("parse", error))?;
+    settings.sanitize_in_place();
+    Ok(settings)
 }
 
 #[cfg(test)]
 mod tests {
     use tempfile::tempdir;
 
+    use super::super::test_app_with_two_environments;
     use super::{export_settings_to_path, import_settings_from_path};
-    use crate::settings::{AppSettings, ThemeSetting, TrayBehavior};
+    use crate::settings::{AppSettings, SettingsSection, ThemeSetting, TrayBehavior};
+    use crate::state::Modal;
+
+    #[test]
+    fn imported_settings_wait_for_confirmation() {
+        let mut app = test_app_with_two_environments();
+        let imported = AppSettings {
+            theme: ThemeSetting::Dark,
+            ..AppSettings::default()
+        };
+
+        let _ = app.handle_settings_imported(Ok(Box::new(imported)));
+
+        assert!(matches!(app.settings.theme, ThemeSetting::System));
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::ConfirmSettingsImport { changes, .. })
+                if changes.len() == 1 && changes[0].field == "theme"
+        ));
+
+        app.handle_confirm_settings_import();
+
+        assert!(matches!(app.settings.theme, ThemeSetting::Dark));
+        assert!(app.main_state().modal.is_none());
+    }
+
+    #[test]
+    fn deselected_sections_are_not_imported() {
+        let mut app = test_app_with_two_environments();
+        let imported = AppSettings {
+            theme: ThemeSetting::Dark,
+            fetch_timeout_secs: 42,
+            ..AppSettings::default()
+        };
+        let _ = app.handle_settings_imported(Ok(Box::new(imported)));
+
+        app.handle_settings_import_section_toggled(SettingsSection::Timeouts, false);
+        app.handle_confirm_settings_import();
+
+        assert!(matches!(app.settings.theme, ThemeSetting::Dark));
+        assert_eq!(
+            app.settings.fetch_timeout_secs,
+            AppSettings::default().fetch_timeout_secs
+        );
+    }
+
+    #[test]
+    fn importing_identical_settings_skips_the_preview() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_settings_imported(Ok(Box::new(AppSettings::default())));
+
+        assert!(app.main_state().modal.is_none());
+        assert_eq!(app.main_state().toasts.len(), 1);
+    }
 
     #[tokio::test]
     async fn export_then_import_roundtrips_settings_file() {
@@ -6,9 +6,23 @@ use crate::settings::AppSettings;
 const SETTINGS_SAVE_DEBOUNCE: Duration = Duration::from_millis(250);
 
 pub(super) fn enqueue_settings_save(settings: AppSettings) {
+    #[cfg(test)]
+    SAVED_SETTINGS.with_borrow_mut(|saved| saved.push(settings.clone()));
     let _ = settings_save_sender().send(settings);
 }
 
+#[cfg(test)]
+thread_local! {
+    static SAVED_SETTINGS: std::cell::RefCell<Vec<AppSettings>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Settings enqueued for saving on this thread since the last call.
+#[cfg(test)]
+pub(super) fn take_saved_settings() -> Vec<AppSettings> {
+    SAVED_SETTINGS.with_borrow_mut(std::mem::take)
+}
+
 fn settings_save_sender() -> &'static mpsc::Sender<AppSettings> {
     static SETTINGS_SAVER: OnceLock<mpsc::Sender<AppSettings>> = OnceLock::new();
 
@@ -1,7 +1,10 @@
 //! Shell configuration detection, setup, and flag updates.
 //!
-//! Handles messages: `ShellSetupChecked`, `ConfigureShell`, `ShellConfigured`,
-//! `ShellFlagsUpdated`
+//! Handles messages: `ShellSetupChecked`, `NodePathChecked`, `ConfigureShell`,
+//! `ShellConfigured`, `RepairShellConfig`, `ShellConfigRepaired`,
+//! `TestShellIntegration`, `ShellIntegrationTested`, `ShellFlagsUpdated`
+
+use std::time::Duration;
 
 use iced::Task;
 
@@ -11,7 +14,9 @@ use versi_shell::{ShellInitOptions, detect_shells};
 
 use crate::error::AppError;
 use crate::message::Message;
-use crate::state::{AppState, ShellSetupStatus, ShellVerificationStatus};
+use crate::state::{
+    AppState, ShellRepairOutcome, ShellSetupStatus, ShellTestOutcome, ShellVerificationStatus,
+};
 
 use super::Versi;
 
@@ -32,7 +37,7 @@ impl Versi {
         let marker = provider.shell_config_marker().to_string();
         let backend_name = provider.name().to_string();
 
-        Task::perform(
+        let verify_task = Task::perform(
             async move {
                 #[cfg(target_os = "windows")]
                 let (shells, wsl_distro) = match &env_id {
@@ -65,9 +70,50 @@ impl Versi {
                 results
             },
             Message::ShellSetupChecked,
+        );
+        Task::batch([verify_task, self.check_node_path_conflict()])
+    }
+
+    /// Look for a system `node` that the user's login shell finds before the
+    /// manager's. Only native environments are checked.
+    fn check_node_path_conflict(&self) -> Task<Message> {
+        if let AppState::Main(state) = &self.state
+            && matches!(
+                state.active_environment().id,
+                versi_platform::EnvironmentId::Wsl { .. }
+            )
+        {
+            return Task::none();
+        }
+
+        let backend_name = self.active_provider().name().to_string();
+        let timeout = Duration::from_secs(self.settings.fetch_timeout_secs);
+        Task::perform(
+            async move {
+                let user_shell = versi_platform::user_shell();
+                let shell_type =
+                    versi_shell::ShellType::from_program(&user_shell).or_else(|| {
+                        let shell = versi_shell::detect_native_shells().into_iter().next()?;
+                        Some(shell.shell_type)
+                    })?;
+                let candidates = versi_shell::node_candidates(&shell_type, timeout).await;
+                versi_shell::find_path_conflict(&candidates, |path| {
+                    is_managed_node(path, &backend_name)
+                })
+            },
+            Message::NodePathChecked,
         )
     }
 
+    pub(super) fn handle_node_path_checked(
+        &mut self,
+        conflict: Option<versi_shell::NodePathConflict>,
+    ) {
+        if let AppState::Main(state) = &mut self.state {
+            state.settings_state.node_path_conflict = conflict;
+        }
+    }
+
     pub(super) fn handle_shell_setup_checked(
         &mut self,
         results: Vec<(versi_shell::ShellType, versi_shell::VerificationResult)>,
@@ -76,6 +122,7 @@ impl Versi {
 
         if let AppState::Main(state) = &mut self.state {
             state.settings_state.checking_shells = false;
+            let previous = std::mem::take(&mut state.settings_state.shell_statuses);
             state.settings_state.shell_statuses = results
                 .into_iter()
                 .map(|(shell_type, result)| {
@@ -97,11 +144,23 @@ impl Versi {
                         }
                         versi_shell::VerificationResult::Error(_) => ShellVerificationStatus::Error,
                     };
+                    let repair_outcome = previous
+                        .iter()
+                        .find(|shell| shell.shell_type == shell_type)
+                        .and_then(|shell| shell.repair_outcome.clone());
+                    let test_outcome = previous
+                        .iter()
+                        .find(|shell| shell.shell_type == shell_type)
+                        .and_then(|shell| shell.test_outcome.clone());
                     ShellSetupStatus {
                         shell_name: shell_type.name().to_string(),
                         shell_type,
                         status,
                         configuring: false,
+                        testing: false,
+                        repairing: false,
+                        repair_outcome,
+                        test_outcome,
                     }
                 })
                 .collect();
@@ -244,6 +303,168 @@ impl Versi {
         }
     }
 
+    pub(super) fn handle_repair_shell_config(
+        &mut self,
+        shell_type: versi_shell::ShellType,
+    ) -> Task<Message> {
+        let shell_name = shell_type.name();
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        // The Repair button is disabled for WSL environments.
+        if matches!(
+            state.active_environment().id,
+            versi_platform::EnvironmentId::Wsl { .. }
+        ) {
+            return Task::none();
+        }
+        if let Some(shell) = state
+            .settings_state
+            .shell_statuses
+            .iter_mut()
+            .find(|s| s.shell_type == shell_type)
+        {
+            shell.repairing = true;
+            shell.repair_outcome = None;
+        }
+
+        let provider = self.active_provider();
+        let marker = provider.shell_config_marker().to_string();
+        let label = provider.shell_config_label().to_string();
+        let shell_type_for_callback = shell_type.clone();
+
+        Task::perform(
+            async move {
+                use versi_shell::{ShellConfig, get_or_create_config_path};
+
+                let config_path = get_or_create_config_path(&shell_type)
+                    .ok_or_else(|| AppError::shell_config_path_not_found(shell_name))?;
+                let mut config = ShellConfig::load(shell_type, config_path)
+                    .map_err(|e| AppError::shell_config_failed(shell_name, "load config", e))?;
+
+                let edit = config.repair(&marker, &label);
+                if edit.has_changes() {
+                    config.apply_edit(&edit).map_err(|e| {
+                        AppError::shell_config_failed(shell_name, "repair config", e)
+                    })?;
+                }
+                Ok(edit.changes)
+            },
+            move |result| Message::ShellConfigRepaired(shell_type_for_callback.clone(), result),
+        )
+    }
+
+    pub(super) fn handle_shell_config_repaired(
+        &mut self,
+        shell_type: &versi_shell::ShellType,
+        result: Result<Vec<String>, AppError>,
+    ) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        let repaired = matches!(&result, Ok(changes) if !changes.is_empty());
+        if let Some(shell) = state
+            .settings_state
+            .shell_statuses
+            .iter_mut()
+            .find(|s| &s.shell_type == shell_type)
+        {
+            shell.repairing = false;
+            shell.repair_outcome = Some(match result {
+                Ok(changes) if changes.is_empty() => ShellRepairOutcome::NothingToRepair,
+                Ok(changes) => ShellRepairOutcome::Repaired(changes),
+                Err(error) => ShellRepairOutcome::Failed(error),
+            });
+        }
+
+        if repaired {
+            self.handle_check_shell_setup()
+        } else {
+            Task::none()
+        }
+    }
+
+    pub(super) fn handle_test_shell_integration(
+        &mut self,
+        shell_type: versi_shell::ShellType,
+    ) -> Task<Message> {
+        let shell_name = shell_type.name();
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        // The Test button is disabled for WSL environments.
+        if matches!(
+            state.active_environment().id,
+            versi_platform::EnvironmentId::Wsl { .. }
+        ) {
+            return Task::none();
+        }
+        if let Some(shell) = state
+            .settings_state
+            .shell_statuses
+            .iter_mut()
+            .find(|s| s.shell_type == shell_type)
+        {
+            shell.testing = true;
+            shell.test_outcome = None;
+        }
+
+        let provider = self.active_provider();
+        let backend_opts = self
+            .settings
+            .shell_options_for(backend_kind_from_provider(&provider));
+        let options = ShellInitOptions {
+            use_on_cd: backend_opts.use_on_cd,
+            resolve_engines: backend_opts.resolve_engines,
+            corepack_enabled: backend_opts.corepack_enabled,
+        };
+        let init_command = provider
+            .create_manager(&versi_backend::BackendDetection {
+                found: true,
+                path: None,
+                version: None,
+                in_path: true,
+                data_dir: None,
+            })
+            .shell_init_command(shell_type.shell_arg(), &options);
+        let timeout = Duration::from_secs(self.settings.fetch_timeout_secs);
+
+        let shell_type_for_callback = shell_type.clone();
+        Task::perform(
+            async move {
+                let init_command =
+                    init_command.ok_or_else(|| AppError::shell_not_supported(shell_name))?;
+                versi_shell::probe_shell_integration(&shell_type, &init_command, timeout)
+                    .await
+                    .map_err(|e| AppError::shell_config_failed(shell_name, "integration test", e))
+            },
+            move |result| Message::ShellIntegrationTested(shell_type_for_callback.clone(), result),
+        )
+    }
+
+    pub(super) fn handle_shell_integration_tested(
+        &mut self,
+        shell_type: &versi_shell::ShellType,
+        result: Result<std::path::PathBuf, AppError>,
+    ) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        if let Some(shell) = state
+            .settings_state
+            .shell_statuses
+            .iter_mut()
+            .find(|s| &s.shell_type == shell_type)
+        {
+            shell.testing = false;
+            shell.test_outcome = Some(match result {
+                Ok(node) => ShellTestOutcome::Resolved(node),
+                Err(error) => ShellTestOutcome::Failed(error),
+            });
+        }
+    }
+
     pub(super) fn update_shell_flags(&self) -> Task<Message> {
         let provider = self.active_provider();
         let backend_opts = self
@@ -285,6 +506,18 @@ impl Versi {
     }
 }
 
+/// Whether `path` lives under the backend's own directories, e.g.
+/// `fnm_multishells` or `.nvm/versions`.
+fn is_managed_node(path: &std::path::Path, backend_name: &str) -> bool {
+    path.components().any(|component| {
+        component
+            .as_os_str()
+            .to_string_lossy()
+            .to_ascii_lowercase()
+            .contains(backend_name)
+    })
+}
+
 fn backend_kind_from_provider(
     provider: &std::sync::Arc<dyn versi_backend::BackendProvider>,
 ) -> crate::backend_kind::BackendKind {
@@ -380,4 +613,81 @@ mod tests {
             crate::backend_kind::BackendKind::Nvm
         );
     }
+
+    #[test]
+    fn managed_node_is_recognised_by_backend_directory() {
+        use std::path::Path;
+
+        assert!(is_managed_node(
+            Path::new("/home/me/.local/state/fnm_multishells/1_2/bin/node"),
+            "fnm"
+        ));
+        assert!(is_managed_node(
+            Path::new("/home/me/.nvm/versions/node/v20.11.0/bin/node"),
+            "nvm"
+        ));
+        assert!(!is_managed_node(Path::new("/usr/bin/node"), "fnm"));
+    }
+
+    #[test]
+    fn shell_config_repaired_records_outcome_inline() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().settings_state.shell_statuses = vec![ShellSetupStatus {
+            shell_type: versi_shell::ShellType::Bash,
+            shell_name: "Bash".to_string(),
+            status: ShellVerificationStatus::Configured,
+            configuring: false,
+            testing: false,
+            repairing: true,
+            repair_outcome: None,
+            test_outcome: None,
+        }];
+
+        let _ = app.handle_shell_config_repaired(
+            &versi_shell::ShellType::Bash,
+            Ok(vec!["Removed 1 duplicate initialization(s)".to_string()]),
+        );
+
+        let state = app.main_state();
+        let shell = &state.settings_state.shell_statuses[0];
+        assert!(!shell.repairing);
+        assert!(matches!(
+            &shell.repair_outcome,
+            Some(ShellRepairOutcome::Repaired(changes))
+                if changes == &["Removed 1 duplicate initialization(s)".to_string()]
+        ));
+        assert!(state.toasts.is_empty());
+    }
+
+    #[test]
+    fn shell_integration_tested_records_outcome_inline() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().settings_state.shell_statuses = vec![ShellSetupStatus {
+            shell_type: versi_shell::ShellType::Zsh,
+            shell_name: "Zsh".to_string(),
+            status: ShellVerificationStatus::Configured,
+            configuring: false,
+            testing: true,
+            repairing: false,
+            repair_outcome: None,
+            test_outcome: None,
+        }];
+
+        app.handle_shell_integration_tested(
+            &versi_shell::ShellType::Zsh,
+            Ok(std::path::PathBuf::from(
+                "/home/me/.fnm/aliases/default/bin/node",
+            )),
+        );
+
+        let state = app.main_state();
+        let shell = &state.settings_state.shell_statuses[0];
+        assert!(!shell.testing);
+        assert!(matches!(
+            &shell.test_outcome,
+            Some(ShellTestOutcome::Resolved(node))
+                if node == std::path::Path::new("/home/me/.fnm/aliases/default/bin/node")
+        ));
+        assert!(state.toasts.is_empty());
+    }
 }
@@ -0,0 +1,95 @@
+//! Opening a terminal with a version active.
+//!
+//! Handles messages: `OpenTerminalWithVersion`, `TerminalOpened`
+
+use iced::Task;
+
+use versi_platform::EnvironmentId;
+
+use crate::error::AppError;
+use crate::message::Message;
+use crate::state::{AppState, MainState, Toast};
+
+use super::Versi;
+
+/// Command that opens the user's shell with `version` active, when the
+/// active environment supports it. WSL environments are not supported.
+fn terminal_command(state: &MainState, version: &str) -> Option<Vec<String>> {
+    if state.active_environment().id != EnvironmentId::Native {
+        return None;
+    }
+    state
+        .backend
+        .version_shell_command(version, &versi_platform::user_shell())
+}
+
+pub(super) fn can_open_terminal(state: &MainState, version: &str) -> bool {
+    terminal_command(state, version).is_some()
+}
+
+impl Versi {
+    pub(super) fn handle_open_terminal_with_version(&mut self, version: &str) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        let Some(command) = terminal_command(state, version) else {
+            let id = state.next_toast_id();
+            state.add_toast(Toast::error(
+                id,
+                format!("Opening a terminal with Node {version} is not supported here"),
+            ));
+            return Task::none();
+        };
+
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || versi_platform::open_terminal(&command))
+                    .await
+                    .unwrap_or_else(|error| Err(std::io::Error::other(error)))
+                    .map_err(|error| AppError::operation_failed("Open terminal", error))
+            },
+            Message::TerminalOpened,
+        )
+    }
+
+    pub(super) fn handle_terminal_opened(&mut self, result: Result<(), AppError>) -> Task<Message> {
+        if let Err(error) = result
+            && let AppState::Main(state) = &mut self.state
+        {
+            let id = state.next_toast_id();
+            state.add_toast(Toast::error(id, error.to_string()));
+        }
+        Task::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+
+    #[test]
+    fn open_terminal_in_wsl_environment_shows_error_toast() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().active_environment_idx = 1;
+
+        let _ = app.handle_open_terminal_with_version("v20.11.0");
+
+        let state = app.main_state();
+        assert!(!can_open_terminal(state, "v20.11.0"));
+        assert_eq!(state.toasts.len(), 1);
+    }
+
+    #[test]
+    fn terminal_opened_error_shows_toast() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.handle_terminal_opened(Err(AppError::operation_failed(
+            "Open terminal",
+            "no terminal emulator found",
+        )));
+
+        assert_eq!(app.main_state().toasts.len(), 1);
+    }
+}
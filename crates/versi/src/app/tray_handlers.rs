@@ -1,6 +1,6 @@
 //! System tray event handling and menu updates.
 //!
-//! Handles messages: `TrayEvent`, `TrayBehaviorChanged`
+//! Handles messages: `TrayEvent`, `ConfirmTraySetDefault`, `TrayBehaviorChanged`
 
 use log::error;
 
@@ -8,7 +8,7 @@ use iced::Task;
 
 use crate::message::Message;
 use crate::settings::TrayBehavior;
-use crate::state::{AppState, MainViewKind};
+use crate::state::{AppState, MainViewKind, Modal, Toast};
 use crate::tray::{self, TrayMenuData, TrayMessage};
 
 use super::Versi;
@@ -80,12 +80,70 @@ impl Versi {
         env_id: &versi_platform::EnvironmentId,
         version: String,
     ) -> Task<Message> {
-        if let Some((resolved_env_id, backend_name)) = self.switch_environment_from_tray(env_id) {
-            self.activate_tray_environment_backend(&resolved_env_id, backend_name);
-            return self.handle_set_default(version);
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some((idx, env)) = state
+            .environments
+            .iter()
+            .enumerate()
+            .find(|(_, env)| &env.id == env_id)
+        else {
+            log::warn!("Ignoring tray set-default request for unknown environment: {env_id:?}");
+            return Task::none();
+        };
+
+        if idx != state.active_environment_idx && self.settings.confirm_tray_environment_switch {
+            state.modal = Some(Modal::ConfirmTraySetDefault {
+                env_id: env.id.clone(),
+                env_name: env.name.clone(),
+                version,
+            });
+            return self.show_and_focus_window_from_tray();
         }
-        log::warn!("Ignoring tray set-default request for unknown environment: {env_id:?}");
-        Task::none()
+
+        self.tray_switch_and_set_default(env_id, version)
+    }
+
+    pub(super) fn handle_confirm_tray_set_default(
+        &mut self,
+        env_id: &versi_platform::EnvironmentId,
+        version: String,
+    ) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = None;
+        }
+        self.tray_switch_and_set_default(env_id, version)
+    }
+
+    fn tray_switch_and_set_default(
+        &mut self,
+        env_id: &versi_platform::EnvironmentId,
+        version: String,
+    ) -> Task<Message> {
+        let previous_idx = match &self.state {
+            AppState::Main(state) => state.active_environment_idx,
+            _ => return Task::none(),
+        };
+        let Some((resolved_env_id, backend_name)) = self.switch_environment_from_tray(env_id)
+        else {
+            log::warn!("Ignoring tray set-default request for unknown environment: {env_id:?}");
+            return Task::none();
+        };
+        self.activate_tray_environment_backend(&resolved_env_id, backend_name);
+
+        if let AppState::Main(state) = &mut self.state
+            && state.active_environment_idx != previous_idx
+        {
+            let id = state.next_toast_id();
+            let message = format!(
+                "Switched to {} and set default to {version}",
+                state.active_environment().name
+            );
+            state.add_toast(Toast::info(id, message));
+        }
+
+        self.handle_set_default(version)
     }
 
     fn switch_environment_from_tray(
@@ -106,7 +164,7 @@ impl Versi {
             if target_idx != state.active_environment_idx {
                 state.active_environment_idx = target_idx;
                 state.backend_name = target_backend;
-                state.backend_update = None;
+                state.dismiss_other_environment_actions();
             }
             return Some((target_env_id, target_backend));
         }
@@ -176,6 +234,14 @@ impl Versi {
     }
 
     pub(super) fn handle_tray_behavior_changed(&mut self, behavior: TrayBehavior) -> Task<Message> {
+        self.apply_tray_behavior(behavior);
+        self.save_settings_with_log();
+        Task::none()
+    }
+
+    /// Switch the tray to `behavior` without saving settings. Launch at
+    /// login is turned off unless the tray keeps running.
+    pub(super) fn apply_tray_behavior(&mut self, behavior: TrayBehavior) {
         let old_behavior = self.settings.tray_behavior;
         self.settings.tray_behavior = behavior;
 
@@ -186,8 +252,6 @@ impl Versi {
             }
         }
 
-        self.save_settings_with_log();
-
         if old_behavior == TrayBehavior::Disabled && behavior != TrayBehavior::Disabled {
             if let Err(e) = tray::init_tray(behavior) {
                 error!("Failed to initialize tray: {e}");
@@ -197,8 +261,14 @@ impl Versi {
         } else if behavior == TrayBehavior::Disabled {
             tray::destroy_tray();
         }
+        self.sync_tray_availability();
+    }
 
-        Task::none()
+    /// Copy the tray's current availability into state for the views.
+    pub(super) fn sync_tray_availability(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.tray_availability = tray::tray_availability();
+        }
     }
 
     pub(super) fn update_tray_menu(&self) {
@@ -270,6 +340,62 @@ mod tests {
         assert_eq!(app.provider.name(), "nvm");
     }
 
+    #[test]
+    fn tray_set_default_in_other_environment_toasts_the_switch() {
+        let mut app = test_app_with_two_environments();
+        let target_env_id = app.main_state().environments[1].id.clone();
+        let target_name = app.main_state().environments[1].name.clone();
+        let _ = app.handle_tray_event(TrayMessage::SetDefault {
+            env_id: target_env_id,
+            version: "v20.11.0".to_string(),
+        });
+
+        let state = app.main_state();
+        assert_eq!(state.toasts.len(), 1);
+        assert_eq!(
+            state.toasts[0].message,
+            format!("Switched to {target_name} and set default to v20.11.0")
+        );
+    }
+
+    #[test]
+    fn tray_set_default_in_active_environment_does_not_toast() {
+        let mut app = test_app_with_two_environments();
+        let active_env_id = app.main_state().active_environment().id.clone();
+        let _ = app.handle_tray_event(TrayMessage::SetDefault {
+            env_id: active_env_id,
+            version: "v20.11.0".to_string(),
+        });
+
+        assert!(app.main_state().toasts.is_empty());
+    }
+
+    #[test]
+    fn tray_set_default_asks_for_confirmation_when_enabled() {
+        let mut app = test_app_with_two_environments();
+        app.settings.confirm_tray_environment_switch = true;
+        let target_env_id = app.main_state().environments[1].id.clone();
+        let _ = app.handle_tray_event(TrayMessage::SetDefault {
+            env_id: target_env_id.clone(),
+            version: "v20.11.0".to_string(),
+        });
+
+        let state = app.main_state();
+        assert_eq!(state.active_environment_idx, 0);
+        assert!(state.operation_queue.exclusive_op.is_none());
+        assert!(matches!(
+            state.modal,
+            Some(Modal::ConfirmTraySetDefault { ref version, .. }) if version == "v20.11.0"
+        ));
+
+        let _ = app.handle_confirm_tray_set_default(&target_env_id, "v20.11.0".to_string());
+
+        let state = app.main_state();
+        assert!(state.modal.is_none());
+        assert_eq!(state.active_environment_idx, 1);
+        assert_eq!(state.toasts.len(), 1);
+    }
+
     #[test]
     fn tray_set_default_ignores_unknown_environment() {
         let mut app = test_app_with_two_environments();
@@ -6,7 +6,7 @@ mod system;
 use iced::Task;
 
 use crate::message::Message;
-use crate::state::AppState;
+use crate::state::{AppState, ModalFocus};
 
 use super::{Versi, should_dismiss_context_menu};
 
@@ -15,6 +15,7 @@ type DispatchResult = Result<Task<Message>, Box<Message>>;
 impl Versi {
     pub fn update(&mut self, message: Message) -> Task<Message> {
         self.dismiss_context_menu_if_needed(&message);
+        self.reset_modal_focus_if_closed();
 
         let message = match self.dispatch_navigation(message) {
             Ok(task) => return task,
@@ -25,7 +26,11 @@ impl Versi {
             Err(message) => *message,
         };
         let message = match self.dispatch_settings(message) {
-            Ok(task) => return task,
+            Ok(task) => {
+                self.refresh_settings_changes();
+                self.refresh_banner_stats();
+                return task;
+            }
             Err(message) => *message,
         };
         let message = match self.dispatch_system(message) {
@@ -37,6 +42,14 @@ impl Versi {
         Task::none()
     }
 
+    fn reset_modal_focus_if_closed(&mut self) {
+        if let AppState::Main(state) = &mut self.state
+            && state.modal.is_none()
+        {
+            state.modal_focus = ModalFocus::default();
+        }
+    }
+
     fn dismiss_context_menu_if_needed(&mut self, message: &Message) {
         if let AppState::Main(state) = &mut self.state
             && state.context_menu.is_some()
@@ -67,6 +80,8 @@ mod tests {
             version: "v20.11.0".to_string(),
             is_installed: true,
             is_default: false,
+            can_open_terminal: false,
+            can_create_alias: true,
             position: iced::Point::new(10.0, 20.0),
         }
     }
@@ -1,7 +1,7 @@
 use iced::Task;
 
 use crate::message::Message;
-use crate::state::{AppState, MainViewKind};
+use crate::state::{AppState, MainViewKind, Modal, ModalFocus};
 
 use super::super::Versi;
 
@@ -14,8 +14,28 @@ impl Versi {
                 request_seq,
                 result,
             } => Ok(self.handle_environment_loaded(&env_id, request_seq, result)),
+            Message::CurrentVersionLoaded { env_id, version } => {
+                self.handle_current_version_loaded(&env_id, version);
+                Ok(Task::none())
+            }
+            Message::InstalledVersionsValidated { env_id, broken } => {
+                self.handle_installed_versions_validated(&env_id, broken);
+                Ok(Task::none())
+            }
+            Message::AliasesLoaded { env_id, aliases } => {
+                self.handle_aliases_loaded(&env_id, aliases);
+                Ok(Task::none())
+            }
             Message::RefreshEnvironment => Ok(self.handle_refresh_environment()),
+            Message::RefreshWslEnvironments => Ok(self.handle_refresh_wsl_environments()),
+            Message::WslEnvironmentsRefreshed(environments) => {
+                Ok(self.handle_wsl_environments_refreshed(&environments))
+            }
             Message::FocusSearch => Ok(self.focus_search()),
+            Message::CycleModalFocus => {
+                self.cycle_modal_focus();
+                Ok(Task::none())
+            }
             other => self.dispatch_navigation_selection(other),
         }
     }
@@ -35,6 +55,14 @@ impl Versi {
                 self.handle_version_group_toggled(major);
                 Ok(Task::none())
             }
+            Message::SearchGroupToggled { major } => {
+                self.handle_search_group_toggled(major);
+                Ok(Task::none())
+            }
+            Message::ShowMoreSearchResults => {
+                self.handle_show_more_search_results();
+                Ok(Task::none())
+            }
             Message::SearchChanged(query) => {
                 self.handle_search_changed(query);
                 Ok(Task::none())
@@ -43,6 +71,26 @@ impl Versi {
                 self.handle_search_filter_toggled(filter);
                 Ok(Task::none())
             }
+            Message::SearchFilterShortcut(filter) => {
+                self.handle_search_filter_shortcut(filter);
+                Ok(Task::none())
+            }
+            Message::UpdatableViewToggled(enabled) => {
+                self.handle_updatable_view_toggled(enabled);
+                Ok(Task::none())
+            }
+            Message::ClearSearchFilters => {
+                self.handle_clear_search_filters();
+                Ok(Task::none())
+            }
+            Message::SortModeChanged(mode) => {
+                self.handle_sort_mode_changed(mode);
+                Ok(Task::none())
+            }
+            Message::TogglePinnedVersion(version) => {
+                self.handle_toggle_pinned_version(&version);
+                Ok(Task::none())
+            }
             other => self.dispatch_navigation_data(other),
         }
     }
@@ -50,13 +98,20 @@ impl Versi {
     fn dispatch_navigation_data(&mut self, message: Message) -> super::DispatchResult {
         match message {
             Message::FetchRemoteVersions => Ok(self.handle_fetch_remote_versions()),
+            Message::ClearVersionCache => {
+                self.handle_clear_version_cache();
+                Ok(Task::none())
+            }
+            Message::ConfirmClearVersionCache => Ok(self.handle_confirm_clear_version_cache()),
+            Message::VersionCacheCleared(result) => Ok(self.handle_version_cache_cleared(result)),
+            Message::ProjectVersionFileFound(suggestion) => {
+                self.handle_project_version_file_found(suggestion);
+                Ok(Task::none())
+            }
             Message::RemoteVersionsFetched {
                 request_seq,
                 result,
-            } => {
-                self.handle_remote_versions_fetched(request_seq, result);
-                Ok(Task::none())
-            }
+            } => Ok(self.handle_remote_versions_fetched(request_seq, result)),
             Message::ReleaseScheduleFetched {
                 request_seq,
                 result,
@@ -73,17 +128,26 @@ impl Versi {
             }
             Message::ShowVersionDetail(version) => {
                 if let AppState::Main(state) = &mut self.state {
-                    state.modal = Some(crate::state::Modal::VersionDetail { version });
+                    state.modal = Some(crate::state::Modal::VersionDetail {
+                        version: version.clone(),
+                        note: self
+                            .settings
+                            .version_note(&version)
+                            .unwrap_or_default()
+                            .to_string(),
+                    });
                 }
-                Ok(Task::none())
+                Ok(self.ensure_metadata_for(&[version]))
             }
             Message::CloseModal => {
                 self.close_modal_or_return_to_versions();
                 Ok(Task::none())
             }
-            Message::OpenChangelog(version) => Ok(super::open_url_task(format!(
-                "https://nodejs.org/en/blog/release/{version}"
-            ))),
+            Message::OpenChangelog(version) => Ok(self.handle_open_changelog(&version)),
+            Message::OpenTerminalWithVersion(version) => {
+                Ok(self.handle_open_terminal_with_version(&version))
+            }
+            Message::TerminalOpened(result) => Ok(self.handle_terminal_opened(result)),
             Message::EnvironmentSelected(idx) => Ok(self.handle_environment_selected(idx)),
             Message::SelectNextEnvironment => Ok(self.select_environment_by_step(true)),
             Message::SelectPreviousEnvironment => Ok(self.select_environment_by_step(false)),
@@ -133,6 +197,11 @@ impl Versi {
     }
 
     fn activate_hovered_version(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &self.state
+            && let Some(modal) = &state.modal
+        {
+            return self.update(modal_action(modal, state.modal_focus));
+        }
         if let AppState::Main(state) = &self.state
             && state.view == MainViewKind::Versions
             && state.modal.is_none()
@@ -162,7 +231,20 @@ impl Versi {
         Task::none()
     }
 
+    fn cycle_modal_focus(&mut self) {
+        if let AppState::Main(state) = &mut self.state
+            && state.modal.is_some()
+        {
+            state.modal_focus = state.modal_focus.toggled();
+        }
+    }
+
     fn close_modal_or_return_to_versions(&mut self) {
+        if let AppState::Main(state) = &self.state
+            && matches!(state.modal, Some(Modal::VersionDetail { .. }))
+        {
+            self.handle_save_version_note();
+        }
         if let AppState::Main(state) = &mut self.state {
             if state.modal.is_some() {
                 state.modal = None;
@@ -173,6 +255,47 @@ impl Versi {
     }
 }
 
+/// Message sent when Enter is pressed in `modal` with `focus` on a button.
+fn modal_action(modal: &Modal, focus: ModalFocus) -> Message {
+    if focus == ModalFocus::Cancel {
+        return Message::CloseModal;
+    }
+    match modal {
+        Modal::ConfirmBulkUpdateMajors { .. } => Message::ConfirmBulkUpdateMajors,
+        Modal::ConfirmBulkUninstallEOL { .. } => Message::ConfirmBulkUninstallEOL,
+        Modal::ConfirmBulkUninstallMajor { major, .. } => {
+            Message::ConfirmBulkUninstallMajor { major: *major }
+        }
+        Modal::ConfirmBulkUninstallMajorExceptLatest { major, .. } => {
+            Message::ConfirmBulkUninstallMajorExceptLatest { major: *major }
+        }
+        Modal::ConfirmApplyRetentionPolicy { .. } => Message::ConfirmApplyRetentionPolicy,
+        Modal::ConfirmBulkUninstallSelected { .. } => Message::ConfirmBulkUninstallSelected,
+        Modal::ConfirmUninstall { version } => Message::ConfirmUninstall(version.clone()),
+        Modal::ConfirmUninstallDefault { version } => {
+            Message::ConfirmUninstallDefault(version.clone())
+        }
+        Modal::ConfirmReinstall { version, .. } => Message::ConfirmReinstall(version.clone()),
+        Modal::ConfirmEolDefault { version } => Message::ConfirmEolDefault(version.clone()),
+        Modal::ConfirmClearVersionCache => Message::ConfirmClearVersionCache,
+        Modal::ConfirmTraySetDefault {
+            env_id, version, ..
+        } => Message::ConfirmTraySetDefault {
+            env_id: env_id.clone(),
+            version: version.clone(),
+        },
+        Modal::CreateAlias { .. } => Message::ConfirmCreateAlias,
+        Modal::ConfirmRemoveAlias { name, .. } => Message::ConfirmRemoveAlias(name.clone()),
+        Modal::KeyboardShortcuts => Message::CloseModal,
+        Modal::LastOperationError => Message::AcknowledgeLastError,
+        Modal::ConfirmSettingsImport { .. } => Message::ConfirmSettingsImport,
+        Modal::VersionDetail { version, .. } => Message::OpenChangelog(version.clone()),
+        Modal::ConfirmCloneEnvironment { .. } => Message::ConfirmCloneEnvironment,
+        Modal::Migration { started: false, .. } => Message::ConfirmMigration,
+        Modal::Migration { started: true, .. } => Message::CloseModal,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use versi_backend::InstalledVersion;
@@ -209,7 +332,7 @@ mod tests {
         let state = app.main_state();
         assert!(matches!(
             state.modal,
-            Some(Modal::VersionDetail { ref version }) if version == "v20.11.0"
+            Some(Modal::VersionDetail { ref version, .. }) if version == "v20.11.0"
         ));
     }
 
@@ -294,4 +417,145 @@ mod tests {
                 .is_some()
         );
     }
+
+    #[test]
+    fn enter_activates_the_primary_action_of_each_modal() {
+        let env_id = versi_platform::EnvironmentId::Native;
+        let cases = [
+            (
+                Modal::ConfirmBulkUpdateMajors { versions: vec![] },
+                Message::ConfirmBulkUpdateMajors,
+            ),
+            (
+                Modal::ConfirmBulkUninstallEOL { versions: vec![] },
+                Message::ConfirmBulkUninstallEOL,
+            ),
+            (
+                Modal::ConfirmBulkUninstallMajor {
+                    major: 18,
+                    versions: vec![],
+                },
+                Message::ConfirmBulkUninstallMajor { major: 18 },
+            ),
+            (
+                Modal::ConfirmBulkUninstallMajorExceptLatest {
+                    major: 18,
+                    versions: vec![],
+                    keeping: "v18.20.4".to_string(),
+                },
+                Message::ConfirmBulkUninstallMajorExceptLatest { major: 18 },
+            ),
+            (
+                Modal::ConfirmApplyRetentionPolicy {
+                    keep: 1,
+                    versions: vec![],
+                },
+                Message::ConfirmApplyRetentionPolicy,
+            ),
+            (
+                Modal::ConfirmBulkUninstallSelected {
+                    versions: vec![],
+                    default: None,
+                },
+                Message::ConfirmBulkUninstallSelected,
+            ),
+            (
+                Modal::ConfirmUninstall {
+                    version: "v18.19.0".to_string(),
+                },
+                Message::ConfirmUninstall("v18.19.0".to_string()),
+            ),
+            (
+                Modal::ConfirmUninstallDefault {
+                    version: "v20.11.0".to_string(),
+                },
+                Message::ConfirmUninstallDefault("v20.11.0".to_string()),
+            ),
+            (
+                Modal::ConfirmReinstall {
+                    version: "v20.11.0".to_string(),
+                    is_default: true,
+                },
+                Message::ConfirmReinstall("v20.11.0".to_string()),
+            ),
+            (
+                Modal::ConfirmEolDefault {
+                    version: "v16.20.2".to_string(),
+                },
+                Message::ConfirmEolDefault("v16.20.2".to_string()),
+            ),
+            (
+                Modal::ConfirmClearVersionCache,
+                Message::ConfirmClearVersionCache,
+            ),
+            (
+                Modal::ConfirmTraySetDefault {
+                    env_id: env_id.clone(),
+                    env_name: "Native".to_string(),
+                    version: "v20.11.0".to_string(),
+                },
+                Message::ConfirmTraySetDefault {
+                    env_id,
+                    version: "v20.11.0".to_string(),
+                },
+            ),
+            (
+                Modal::CreateAlias {
+                    version: "v20.11.0".to_string(),
+                    name: "project".to_string(),
+                },
+                Message::ConfirmCreateAlias,
+            ),
+            (
+                Modal::ConfirmRemoveAlias {
+                    name: "default".to_string(),
+                    version: "v20.11.0".to_string(),
+                },
+                Message::ConfirmRemoveAlias("default".to_string()),
+            ),
+            (Modal::KeyboardShortcuts, Message::CloseModal),
+            (Modal::LastOperationError, Message::AcknowledgeLastError),
+            (
+                Modal::ConfirmSettingsImport {
+                    imported: Box::default(),
+                    changes: Vec::new(),
+                    sections: std::collections::BTreeSet::new(),
+                },
+                Message::ConfirmSettingsImport,
+            ),
+            (
+                Modal::VersionDetail {
+                    version: "v20.11.0".to_string(),
+                    note: String::new(),
+                },
+                Message::OpenChangelog("v20.11.0".to_string()),
+            ),
+        ];
+
+        for (modal, expected) in cases {
+            let action = modal_action(&modal, ModalFocus::Primary);
+            assert_eq!(
+                format!("{action:?}"),
+                format!("{expected:?}"),
+                "primary action for {modal:?}"
+            );
+            assert!(matches!(
+                modal_action(&modal, ModalFocus::Cancel),
+                Message::CloseModal
+            ));
+        }
+    }
+
+    #[test]
+    fn tab_cycles_focus_only_while_a_modal_is_open() {
+        let mut app = test_app_with_two_environments();
+        app.cycle_modal_focus();
+        assert_eq!(app.main_state().modal_focus, ModalFocus::Primary);
+
+        app.main_state_mut().modal = Some(Modal::ConfirmClearVersionCache);
+        app.cycle_modal_focus();
+        assert_eq!(app.main_state().modal_focus, ModalFocus::Cancel);
+        app.cycle_modal_focus();
+        assert_eq!(app.main_state().modal_focus, ModalFocus::Primary);
+    }
 }
@@ -8,20 +8,49 @@ impl Versi {
     pub(super) fn dispatch_operations(&mut self, message: Message) -> super::DispatchResult {
         match message {
             Message::StartInstall(version) => Ok(self.handle_start_install(version)),
+            Message::InstallLatestLts => Ok(self.handle_install_latest_lts()),
             Message::InstallComplete {
                 version,
                 success,
                 error,
             } => Ok(self.handle_install_complete(&version, success, error)),
+            Message::InstallVerified { version, result } => {
+                self.handle_install_verified(&version, result);
+                Ok(Task::none())
+            }
+            Message::RequestInstallFromArchive => Ok(self.handle_request_install_from_archive()),
+            Message::InstallArchivePicked(path) => Ok(Self::handle_install_archive_picked(path)),
+            Message::InstallArchiveInspected { path, result } => {
+                Ok(self.handle_install_archive_inspected(&path, result))
+            }
+            Message::ArchiveInstallComplete {
+                version,
+                success,
+                error,
+            } => Ok(self.handle_archive_install_complete(&version, success, error)),
             Message::RequestUninstall(version) => Ok(self.handle_uninstall(version)),
-            Message::ConfirmUninstallDefault(version) => {
-                Ok(self.handle_confirm_uninstall_default(version))
+            Message::ReinstallVersion(version) => Ok(self.handle_reinstall(version)),
+            Message::ConfirmReinstall(version) => Ok(self.handle_confirm_reinstall(version)),
+            Message::ConfirmUninstall(version) | Message::ConfirmUninstallDefault(version) => {
+                Ok(self.handle_confirm_uninstall(version))
             }
             Message::UninstallComplete {
                 version,
                 success,
                 error,
             } => Ok(self.handle_uninstall_complete(&version, success, error)),
+            Message::UndoUninstall {
+                environment,
+                version,
+            } => Ok(self.handle_undo_uninstall(&environment, version)),
+            Message::RevertDefault {
+                environment,
+                version,
+            } => Ok(self.handle_revert_default(&environment, version)),
+            Message::RetryInstall {
+                version,
+                timeout_secs,
+            } => Ok(self.handle_retry_install(version, timeout_secs)),
             Message::RequestBulkUpdateMajors => Ok(self.handle_request_bulk_update_majors()),
             Message::RequestBulkUninstallEOL => Ok(self.handle_request_bulk_uninstall_eol()),
             Message::RequestBulkUninstallMajor { major } => {
@@ -38,14 +67,92 @@ impl Versi {
             Message::ConfirmBulkUninstallMajorExceptLatest { major } => {
                 Ok(self.handle_confirm_bulk_uninstall_major_except_latest(major))
             }
+            Message::RequestApplyRetentionPolicy => {
+                Ok(self.handle_request_apply_retention_policy())
+            }
+            Message::ConfirmApplyRetentionPolicy => {
+                Ok(self.handle_confirm_apply_retention_policy())
+            }
+            Message::SelectionModeToggled(enabled) => {
+                self.handle_selection_mode_toggled(enabled);
+                Ok(Task::none())
+            }
+            Message::VersionSelectionToggled(version) => {
+                self.handle_version_selection_toggled(&version);
+                Ok(Task::none())
+            }
+            Message::RequestBulkUninstallSelected => {
+                Ok(self.handle_request_bulk_uninstall_selected())
+            }
+            Message::ConfirmBulkUninstallSelected => {
+                Ok(self.handle_confirm_bulk_uninstall_selected())
+            }
             Message::CancelBulkOperation => {
                 self.handle_close_modal();
                 Ok(Task::none())
             }
             Message::SetDefault(version) => Ok(self.handle_set_default(version)),
+            Message::ConfirmEolDefault(version) => Ok(self.handle_confirm_eol_default(version)),
+            Message::ShowLastError => {
+                self.handle_show_last_error();
+                Ok(Task::none())
+            }
+            Message::AcknowledgeLastError => {
+                self.handle_acknowledge_last_error();
+                Ok(Task::none())
+            }
             Message::DefaultChanged { success, error } => {
                 Ok(self.handle_default_changed(success, error))
             }
+            Message::RequestCreateAlias(version) => {
+                self.handle_request_create_alias(version);
+                Ok(Task::none())
+            }
+            Message::AliasNameChanged(name) => {
+                self.handle_alias_name_changed(name);
+                Ok(Task::none())
+            }
+            Message::ConfirmCreateAlias => Ok(self.handle_confirm_create_alias()),
+            Message::RemoveAlias(name) => {
+                self.handle_remove_alias(name);
+                Ok(Task::none())
+            }
+            Message::ConfirmRemoveAlias(name) => Ok(self.handle_confirm_remove_alias(&name)),
+            Message::AliasChanged(result) => Ok(self.handle_alias_changed(result)),
+            Message::VersionNoteChanged(note) => {
+                self.handle_version_note_changed(note);
+                Ok(Task::none())
+            }
+            Message::SaveVersionNote => {
+                self.handle_save_version_note();
+                Ok(Task::none())
+            }
+            Message::RequestCloneEnvironment(source_id) => {
+                self.handle_request_clone_environment(&source_id);
+                Ok(Task::none())
+            }
+            Message::ConfirmCloneEnvironment => Ok(self.handle_confirm_clone_environment()),
+            Message::RequestMigration(source) => Ok(self.handle_request_migration(source)),
+            Message::MigrationSourceLoaded { source, result } => {
+                self.handle_migration_source_loaded(source, result);
+                Ok(Task::none())
+            }
+            Message::MigrationSetDefaultToggled(enabled) => {
+                self.handle_migration_set_default_toggled(enabled);
+                Ok(Task::none())
+            }
+            Message::ConfirmMigration => Ok(self.handle_confirm_migration()),
+            Message::SetCorepack { version, enabled } => {
+                Ok(self.handle_set_corepack(version, enabled))
+            }
+            Message::CorepackChanged {
+                version,
+                enabled,
+                result,
+            } => {
+                self.handle_corepack_changed(&version, enabled, result);
+                Ok(Task::none())
+            }
             other => Err(Box::new(other)),
         }
     }
@@ -10,14 +10,88 @@ impl Versi {
     pub(super) fn dispatch_settings(&mut self, message: Message) -> super::DispatchResult {
         match message {
             Message::ToastDismiss(id) => Ok(self.handle_toast_dismiss(id)),
+            Message::DismissBanner(key) => {
+                self.settings.dismiss_banner(key);
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
             Message::NavigateToVersions => Ok(self.navigate_to_versions()),
             Message::NavigateToSettings => Ok(self.navigate_to_settings()),
             Message::NavigateToAbout => Ok(self.navigate_to_about()),
             Message::VersionRowHovered(version) => Ok(self.handle_version_row_hovered(version)),
             Message::ThemeChanged(theme) => Ok(self.handle_theme_changed(theme)),
+            Message::LanguageChanged(language) => {
+                self.settings.language = language;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::ReducedMotionToggled(value) => {
+                self.settings.reduced_motion = value;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::VersionPrefixToggled(value) => {
+                self.settings.version_prefix = value;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
             Message::AppUpdateBehaviorChanged(behavior) => {
                 Ok(self.handle_app_update_behavior_changed(behavior))
             }
+            Message::UpdateRepoInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.update_repo_input = Some(value);
+                }
+                Ok(Task::none())
+            }
+            Message::UpdateRepoSubmitted => Ok(self.handle_update_repo_submitted()),
+            Message::NumericInputChanged(setting, value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.numeric_inputs.insert(setting, value);
+                }
+                Ok(Task::none())
+            }
+            Message::DiscardSettingsChanges => Ok(self.handle_discard_settings_changes()),
+            Message::NumericSubmitted(setting) => {
+                self.handle_numeric_submitted(setting);
+                Ok(Task::none())
+            }
+            Message::UpdateChannelChanged(channel) => {
+                Ok(self.handle_update_channel_changed(channel))
+            }
+            Message::DefaultOnInstallChanged(policy) => {
+                self.settings.set_default_on_install = policy;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::ConfirmUninstallChanged(policy) => {
+                self.settings.confirm_uninstall = policy;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::ConfirmEolDefaultToggled(enabled) => {
+                self.settings.confirm_eol_default = enabled;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::VerifyInstallsToggled(enabled) => {
+                self.settings.verify_installs = enabled;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::FetchVersionMetadataToggled(enabled) => {
+                Ok(self.handle_fetch_version_metadata_toggled(enabled))
+            }
+            Message::MetadataFieldToggled(field, visible) => {
+                self.settings.set_metadata_field_visible(field, visible);
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
+            Message::PatchRetentionChanged(keep) => {
+                self.settings.patches_to_keep_per_major = keep;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
             Message::ShellOptionUseOnCdToggled(value) => {
                 Ok(self.update_active_shell_options(|options| options.use_on_cd = value))
             }
@@ -29,20 +103,73 @@ impl Versi {
             }
             Message::DebugLoggingToggled(value) => Ok(self.handle_debug_logging_toggled(value)),
             Message::CopyToClipboard(text) => Ok(iced::clipboard::write(text)),
+            Message::CopySearchResults => Ok(self.handle_copy_search_results()),
+            Message::CopyInstalledVersions => Ok(self.handle_copy_installed_versions()),
+            Message::CopyEnvironmentSnippet(kind) => Ok(self.handle_copy_environment_snippet(kind)),
             Message::ClearLogFile => Ok(Self::clear_log_file()),
             Message::LogFileCleared => Ok(self.handle_log_file_cleared()),
             Message::RevealLogFile => Ok(Self::reveal_log_file()),
             Message::RevealSettingsFile => Ok(self.reveal_settings_file()),
             Message::LogFileStatsLoaded(size) => Ok(self.handle_log_file_stats_loaded(size)),
+            Message::ShowAuditLog => Ok(Self::load_audit_log()),
+            Message::AuditLogLoaded(records) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.audit_log = Some(records);
+                }
+                Ok(Task::none())
+            }
+            Message::HideAuditLog => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.audit_log = None;
+                }
+                Ok(Task::none())
+            }
+            Message::CheckNpmRegistry => Ok(self.handle_check_npm_registry()),
+            Message::NpmRegistryChecked { registry, status } => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.checking_npm_registry = false;
+                    state.settings_state.npm_registry = Some(status);
+                    state.settings_state.npm_registry_url = Some(registry);
+                }
+                Ok(Task::none())
+            }
             Message::ShellFlagsUpdated => Ok(Task::none()),
             Message::ExportSettings => Ok(self.handle_export_settings()),
             Message::SettingsExported(result) => Ok(self.handle_settings_exported(result)),
             Message::ImportSettings => Ok(Self::handle_import_settings()),
             Message::SettingsImported(result) => Ok(self.handle_settings_imported(result)),
+            Message::SettingsImportSectionToggled(section, selected) => {
+                self.handle_settings_import_section_toggled(section, selected);
+                Ok(Task::none())
+            }
+            Message::ResetSettingsSection(section) => {
+                Ok(self.handle_reset_settings_section(section))
+            }
+            Message::ConfirmSettingsImport => {
+                self.handle_confirm_settings_import();
+                Ok(Task::none())
+            }
             Message::ShellSetupChecked(results) => {
                 Ok(self.handle_shell_setup_checked_message(results))
             }
+            Message::NodePathChecked(conflict) => {
+                self.handle_node_path_checked(conflict);
+                Ok(Task::none())
+            }
             Message::ConfigureShell(shell_type) => Ok(self.handle_configure_shell(shell_type)),
+            Message::RepairShellConfig(shell_type) => {
+                Ok(self.handle_repair_shell_config(shell_type))
+            }
+            Message::ShellConfigRepaired(shell_type, result) => {
+                Ok(self.handle_shell_config_repaired(&shell_type, result))
+            }
+            Message::TestShellIntegration(shell_type) => {
+                Ok(self.handle_test_shell_integration(shell_type))
+            }
+            Message::ShellIntegrationTested(shell_type, result) => {
+                self.handle_shell_integration_tested(&shell_type, result);
+                Ok(Task::none())
+            }
             Message::ShellConfigured(shell_type, result) => {
                 Ok(self.handle_shell_configured_message(&shell_type, &result))
             }
@@ -75,7 +202,26 @@ impl Versi {
                 Ok(self.handle_onboarding_configure_shell(shell_type))
             }
             Message::OnboardingShellConfigResult(result) => {
-                self.handle_onboarding_shell_config_result(&result);
+                Ok(self.handle_onboarding_shell_config_result(&result))
+            }
+            Message::OnboardingShellVerified {
+                shell_type,
+                result,
+                in_current_session,
+            } => {
+                self.handle_onboarding_shell_verified(&shell_type, &result, in_current_session);
+                Ok(Task::none())
+            }
+            Message::OnboardingVersionSuggestionFound(suggestion) => {
+                self.handle_onboarding_version_suggestion(suggestion);
+                Ok(Task::none())
+            }
+            Message::OnboardingInstallSuggestedToggled(value) => {
+                self.handle_onboarding_install_suggested_toggled(value);
+                Ok(Task::none())
+            }
+            Message::OnboardingInstallLatestLtsToggled(value) => {
+                self.handle_onboarding_install_latest_lts_toggled(value);
                 Ok(Task::none())
             }
             Message::OnboardingComplete => Ok(self.handle_onboarding_complete()),
@@ -87,11 +233,20 @@ impl Versi {
                 self.save_settings_with_log();
                 Ok(Task::none())
             }
+            Message::ConfirmTrayEnvironmentSwitchToggled(value) => {
+                self.settings.confirm_tray_environment_switch = value;
+                self.save_settings_with_log();
+                Ok(Task::none())
+            }
             Message::LaunchAtLoginToggled(value) => Ok(self.handle_launch_at_login_toggled(value)),
             Message::SystemThemeChanged(mode) => {
                 self.system_theme_mode = mode;
                 Ok(Task::none())
             }
+            Message::SystemReducedMotionDetected(reduced) => {
+                self.system_reduced_motion = reduced;
+                Ok(Task::none())
+            }
             other => Err(Box::new(other)),
         }
     }
@@ -112,6 +267,13 @@ impl Versi {
 
     fn navigate_to_settings(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            if state.view != MainViewKind::Settings {
+                state.settings_state.baseline = Some(self.settings.clone());
+                state.settings_state.has_changes = false;
+                state.settings_state.version_cache_cleared = false;
+                // Re-read the other engine on the next Import in case it changed.
+                state.migration_source = None;
+            }
             state.view = MainViewKind::Settings;
             state.settings_state.checking_shells = true;
         }
@@ -138,6 +300,122 @@ impl Versi {
         Task::none()
     }
 
+    fn handle_reset_settings_section(
+        &mut self,
+        section: crate::settings::SettingsSection,
+    ) -> Task<Message> {
+        use crate::settings::SettingsSection;
+
+        let tray_behavior = self.settings.tray_behavior;
+        if !self.settings.reset_section(section) {
+            return Task::none();
+        }
+        if let AppState::Main(state) = &mut self.state {
+            state
+                .settings_state
+                .numeric_inputs
+                .retain(|setting, _| setting.section() != section);
+        }
+        match section {
+            SettingsSection::Tray => {
+                let reset = std::mem::replace(&mut self.settings.tray_behavior, tray_behavior);
+                self.handle_tray_behavior_changed(reset)
+            }
+            SettingsSection::Updates => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.app_update = None;
+                    state.settings_state.update_repo_input = None;
+                }
+                self.handle_app_update_behavior_changed(self.settings.app_update_behavior)
+            }
+            SettingsSection::ShellOptions => {
+                self.save_settings_with_log();
+                self.update_shell_flags()
+            }
+            _ => {
+                self.save_settings_with_log();
+                Task::none()
+            }
+        }
+    }
+
+    /// Restore the settings captured when the Settings view was opened.
+    fn handle_discard_settings_changes(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(mut baseline) = state.settings_state.baseline.clone() else {
+            return Task::none();
+        };
+        state.settings_state.update_repo_input = None;
+        state.settings_state.numeric_inputs.clear();
+
+        // Not edited from this view; keep whatever they are now.
+        baseline.window_geometry = self.settings.window_geometry.clone();
+        baseline.last_environment = self.settings.last_environment.clone();
+
+        let tray_behavior =
+            std::mem::replace(&mut baseline.tray_behavior, self.settings.tray_behavior);
+        let backend = self.active_backend_kind();
+        let shell_options_changed =
+            self.settings.shell_options_for(backend) != baseline.shell_options_for(backend);
+        let launch_at_login_changed = self.settings.launch_at_login != baseline.launch_at_login;
+        self.settings = baseline;
+
+        if launch_at_login_changed
+            && let Err(e) = platform::set_launch_at_login(self.settings.launch_at_login)
+        {
+            log::error!("Failed to restore launch at login: {e}");
+        }
+        self.apply_tray_behavior(tray_behavior);
+        self.save_settings_with_log();
+
+        if shell_options_changed {
+            self.update_shell_flags()
+        } else {
+            Task::none()
+        }
+    }
+
+    /// Recompute whether settings differ from the copy taken on entering
+    /// Settings, so the view doesn't diff them on every render.
+    pub(super) fn refresh_settings_changes(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.settings_state.has_changes = state
+                .settings_state
+                .baseline
+                .as_ref()
+                .is_some_and(|baseline| !baseline.diff(&self.settings).is_empty());
+        }
+    }
+
+    /// Settings such as the retention policy feed the banners; keep them in
+    /// step after every settings change.
+    pub(super) fn refresh_banner_stats(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
+        }
+    }
+
+    /// Save the typed value if it's in range; otherwise keep the draft so
+    /// the view can show why.
+    fn handle_numeric_submitted(&mut self, setting: crate::settings::NumericSetting) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let Some(input) = state.settings_state.numeric_inputs.get(&setting) else {
+            return;
+        };
+        let Ok(value) = setting.validate(input) else {
+            return;
+        };
+        state.settings_state.numeric_inputs.remove(&setting);
+        if value != setting.get(&self.settings) {
+            setting.set(&mut self.settings, value);
+            self.save_settings_with_log();
+        }
+    }
+
     fn handle_theme_changed(&mut self, theme: crate::settings::ThemeSetting) -> Task<Message> {
         self.settings.theme = theme;
         self.save_settings_with_log();
@@ -168,6 +446,65 @@ impl Versi {
         self.handle_check_for_app_update()
     }
 
+    fn handle_update_repo_submitted(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(input) = &state.settings_state.update_repo_input else {
+            return Task::none();
+        };
+        let repo = input.trim().to_string();
+        if !versi_core::is_valid_update_repo(&repo) {
+            return Task::none();
+        }
+        state.settings_state.update_repo_input = None;
+        if repo == self.settings.update_repo {
+            return Task::none();
+        }
+
+        info!("App updates will be checked against {repo}");
+        state.app_update = None;
+        self.settings.update_repo = repo;
+        self.save_settings_with_log();
+        if self.settings.app_update_behavior == crate::settings::AppUpdateBehavior::DoNotCheck {
+            return Task::none();
+        }
+        self.handle_check_for_app_update()
+    }
+
+    fn handle_update_channel_changed(
+        &mut self,
+        channel: versi_core::UpdateChannel,
+    ) -> Task<Message> {
+        if self.settings.update_channel == channel {
+            return Task::none();
+        }
+        self.settings.update_channel = channel;
+        self.save_settings_with_log();
+
+        if let AppState::Main(state) = &mut self.state {
+            state.app_update = None;
+        }
+        if self.settings.app_update_behavior == crate::settings::AppUpdateBehavior::DoNotCheck {
+            return Task::none();
+        }
+        self.handle_check_for_app_update()
+    }
+
+    fn handle_fetch_version_metadata_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.settings.fetch_version_metadata = enabled;
+        self.save_settings_with_log();
+
+        let missing = matches!(
+            &self.state,
+            AppState::Main(state) if state.available_versions.metadata.is_none()
+        );
+        if enabled && missing {
+            return self.handle_fetch_version_metadata();
+        }
+        Task::none()
+    }
+
     fn handle_debug_logging_toggled(&mut self, enabled: bool) -> Task<Message> {
         self.settings.debug_logging = enabled;
         self.save_settings_with_log();
@@ -192,6 +529,26 @@ impl Versi {
         )
     }
 
+    fn load_audit_log() -> Task<Message> {
+        Task::perform(
+            async {
+                tokio::task::spawn_blocking(|| {
+                    versi_platform::AppPaths::new()
+                        .map(|paths| {
+                            crate::audit_log::read_records(
+                                &paths.audit_log_file(),
+                                crate::audit_log::AUDIT_VIEW_LIMIT,
+                            )
+                        })
+                        .unwrap_or_default()
+                })
+                .await
+                .unwrap_or_default()
+            },
+            Message::AuditLogLoaded,
+        )
+    }
+
     fn handle_log_file_cleared(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.settings_state.log_file_size = Some(0);
@@ -223,6 +580,71 @@ impl Versi {
         )
     }
 
+    fn handle_copy_environment_snippet(
+        &mut self,
+        kind: crate::backend_kind::BackendKind,
+    ) -> Task<Message> {
+        let provider = self.provider_for_kind(kind);
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        let env = state.active_environment();
+        if env.installed_versions.is_empty() {
+            return Task::none();
+        }
+
+        let snippet = env.setup_snippet(provider.as_ref());
+        let message = format!("Copied {kind} setup commands for {}", env.name);
+        let id = state.next_toast_id();
+        state.add_toast(crate::state::Toast::info(id, message));
+        iced::clipboard::write(snippet)
+    }
+
+    fn handle_copy_search_results(&self) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        let text = state.search_results_text(
+            self.settings.search_results_limit,
+            self.settings.version_prefix,
+        );
+        if text.is_empty() {
+            return Task::none();
+        }
+        iced::clipboard::write(text)
+    }
+
+    fn handle_copy_installed_versions(&self) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        let text = state.installed_versions_text(self.settings.version_prefix);
+        if text.is_empty() {
+            return Task::none();
+        }
+        iced::clipboard::write(text)
+    }
+
+    fn handle_check_npm_registry(&mut self) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        if state.settings_state.checking_npm_registry {
+            return Task::none();
+        }
+        state.settings_state.checking_npm_registry = true;
+
+        let client = self.http_client.clone();
+        Task::perform(
+            async move {
+                let registry = configured_npm_registry().await;
+                let status = versi_core::check_npm_registry(&client, &registry).await;
+                (registry, status)
+            },
+            |(registry, status)| Message::NpmRegistryChecked { registry, status },
+        )
+    }
+
     fn handle_log_file_stats_loaded(&mut self, size: Option<u64>) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.settings_state.log_file_size = size;
@@ -287,12 +709,48 @@ fn load_log_file_stats_task() -> Task<Message> {
     )
 }
 
+/// Registry npm is configured to use, from `npm_config_registry` or the
+/// user's `.npmrc` (`NPM_CONFIG_USERCONFIG`, else `~/.npmrc`).
+async fn configured_npm_registry() -> String {
+    let env_registry = std::env::var("npm_config_registry")
+        .or_else(|_| std::env::var("NPM_CONFIG_REGISTRY"))
+        .ok();
+    let npmrc_path = std::env::var_os("NPM_CONFIG_USERCONFIG")
+        .map(std::path::PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".npmrc")));
+    let npmrc = match npmrc_path {
+        Some(path) => tokio::fs::read_to_string(path).await.ok(),
+        None => None,
+    };
+    versi_core::configured_npm_registry(env_registry.as_deref(), npmrc.as_deref())
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::test_app_with_two_environments;
     use super::*;
     use crate::state::{MainViewKind, Modal, Toast};
 
+    #[test]
+    fn reset_settings_section_only_touches_that_section() {
+        let mut app = test_app_with_two_environments();
+        app.settings.install_timeout_secs = 1_200;
+        app.settings.theme = crate::settings::ThemeSetting::Dark;
+
+        let _ = app.update(Message::ResetSettingsSection(
+            crate::settings::SettingsSection::Timeouts,
+        ));
+
+        assert_eq!(
+            app.settings.install_timeout_secs,
+            crate::settings::AppSettings::default().install_timeout_secs
+        );
+        assert!(matches!(
+            app.settings.theme,
+            crate::settings::ThemeSetting::Dark
+        ));
+    }
+
     #[test]
     fn dispatch_settings_returns_err_for_unhandled_message() {
         let mut app = test_app_with_two_environments();
@@ -339,4 +797,116 @@ mod tests {
         assert_eq!(state.toasts.len(), 1);
         assert_eq!(state.toasts[0].id, 2);
     }
+
+    #[test]
+    fn update_repo_is_saved_only_when_valid() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.dispatch_settings(Message::UpdateRepoInputChanged("not-a-repo".to_string()));
+        let _ = app.dispatch_settings(Message::UpdateRepoSubmitted);
+        assert_eq!(app.settings.update_repo, versi_core::DEFAULT_UPDATE_REPO);
+        assert!(app.main_state().settings_state.update_repo_input.is_some());
+
+        let _ = app.dispatch_settings(Message::UpdateRepoInputChanged(
+            " someone/versi-fork ".to_string(),
+        ));
+        let _ = app.dispatch_settings(Message::UpdateRepoSubmitted);
+        assert_eq!(app.settings.update_repo, "someone/versi-fork");
+        assert!(app.main_state().settings_state.update_repo_input.is_none());
+    }
+
+    #[test]
+    fn discarding_settings_changes_restores_the_baseline() {
+        let mut app = test_app_with_two_environments();
+        let _ = app.dispatch_settings(Message::NavigateToSettings);
+        let _ = app.dispatch_settings(Message::ThemeChanged(crate::settings::ThemeSetting::Dark));
+        let _ = app.dispatch_settings(Message::VerifyInstallsToggled(true));
+        let baseline = app
+            .main_state()
+            .settings_state
+            .baseline
+            .clone()
+            .expect("opening settings should capture a baseline");
+        assert_eq!(baseline.diff(&app.settings).len(), 2);
+        let _ = crate::app::settings_save::take_saved_settings();
+
+        let _ = app.update(Message::DiscardSettingsChanges);
+
+        assert!(baseline.diff(&app.settings).is_empty());
+        let saved = crate::app::settings_save::take_saved_settings();
+        assert!(
+            saved
+                .last()
+                .is_some_and(|saved| baseline.diff(saved).is_empty()),
+            "discarding should save the restored settings"
+        );
+        assert!(!app.main_state().settings_state.has_changes);
+        assert!(matches!(
+            app.settings.theme,
+            crate::settings::ThemeSetting::System
+        ));
+    }
+
+    #[test]
+    fn out_of_range_numeric_values_are_not_saved() {
+        use crate::settings::NumericSetting;
+
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.dispatch_settings(Message::NumericInputChanged(
+            NumericSetting::HttpTimeout,
+            "500".to_string(),
+        ));
+        let _ = app.dispatch_settings(Message::NumericSubmitted(NumericSetting::HttpTimeout));
+        assert_eq!(app.settings.http_timeout_secs, 10);
+        assert!(
+            app.main_state()
+                .settings_state
+                .numeric_inputs
+                .contains_key(&NumericSetting::HttpTimeout)
+        );
+
+        let _ = app.dispatch_settings(Message::NumericInputChanged(
+            NumericSetting::HttpTimeout,
+            "45".to_string(),
+        ));
+        let _ = app.dispatch_settings(Message::NumericSubmitted(NumericSetting::HttpTimeout));
+        assert_eq!(app.settings.http_timeout_secs, 45);
+        assert!(app.main_state().settings_state.numeric_inputs.is_empty());
+    }
+
+    #[test]
+    fn log_size_is_typed_in_megabytes() {
+        use crate::settings::NumericSetting;
+
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.dispatch_settings(Message::NumericInputChanged(
+            NumericSetting::MaxLogSize,
+            "25".to_string(),
+        ));
+        let _ = app.dispatch_settings(Message::NumericSubmitted(NumericSetting::MaxLogSize));
+        assert_eq!(app.settings.max_log_size_bytes, 25 * 1_024 * 1_024);
+    }
+
+    #[test]
+    fn switching_update_channel_drops_the_offered_update() {
+        let mut app = test_app_with_two_environments();
+        app.main_state_mut().app_update = Some(versi_core::AppUpdate {
+            current_version: "1.2.0".to_string(),
+            latest_version: "1.2.1".to_string(),
+            release_url: "https://example.com/versi".to_string(),
+            release_notes: None,
+            download_url: None,
+            download_size: None,
+            download_sha256: None,
+        });
+
+        let _ = app.dispatch_settings(Message::UpdateChannelChanged(
+            versi_core::UpdateChannel::Beta,
+        ));
+
+        assert_eq!(app.settings.update_channel, versi_core::UpdateChannel::Beta);
+        assert!(app.main_state().app_update.is_none());
+    }
 }
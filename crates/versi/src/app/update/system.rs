@@ -5,9 +5,10 @@ use crate::message::Message;
 use crate::settings::AppUpdateBehavior;
 use crate::state::{AppState, AppUpdateState};
 
-use super::super::{Versi, platform};
+use super::super::{Versi, platform, terminal};
 
 const APP_UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60 * 6);
+const BACKEND_VERSION_RECHECK_INTERVAL: Duration = Duration::from_secs(60 * 30);
 
 impl Versi {
     pub(super) fn dispatch_system(&mut self, message: Message) -> super::DispatchResult {
@@ -33,6 +34,10 @@ impl Versi {
             Message::AppUpdateChecked(result) => Ok(self.handle_app_update_checked(*result)),
             Message::OpenAppUpdate => Ok(self.open_app_update_url()),
             Message::StartAppUpdate => Ok(self.handle_start_app_update()),
+            Message::CancelAppUpdate => {
+                self.handle_cancel_app_update();
+                Ok(Task::none())
+            }
             Message::AppUpdateProgress { downloaded, total } => {
                 self.handle_app_update_progress(downloaded, total);
                 Ok(Task::none())
@@ -47,11 +52,15 @@ impl Versi {
             }
             Message::AppUpdateComplete(result) => Ok(self.handle_app_update_complete(*result)),
             Message::RestartApp => Ok(self.handle_restart_app()),
-            Message::BackendUpdateChecked(result) => {
-                self.handle_backend_update_checked(*result);
+            Message::BackendUpdateChecked { env_id, result } => {
+                self.handle_backend_update_checked(&env_id, *result);
                 Ok(Task::none())
             }
             Message::OpenBackendUpdate => Ok(self.open_backend_update_url()),
+            Message::RecheckBackendVersion => Ok(self.handle_recheck_backend_version()),
+            Message::BackendVersionRechecked { env_id, version } => {
+                Ok(self.handle_backend_version_rechecked(&env_id, version))
+            }
             Message::VersionListCursorMoved(point) => Ok(self.handle_cursor_moved(point)),
             Message::ShowContextMenu {
                 version,
@@ -62,13 +71,16 @@ impl Versi {
             Message::ShowKeyboardShortcuts => Ok(self.show_keyboard_shortcuts()),
             Message::OpenLink(url) => Ok(super::open_url_task(url)),
             Message::TrayEvent(tray_msg) => Ok(self.handle_tray_event(tray_msg)),
+            Message::ConfirmTraySetDefault { env_id, version } => {
+                Ok(self.handle_confirm_tray_set_default(&env_id, version))
+            }
             other => Err(Box::new(other)),
         }
     }
 
     fn handle_animation_tick(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
-            let loading = state.active_environment().loading;
+            let loading = state.is_refreshing();
             state.refresh_rotation += std::f32::consts::TAU / 40.0;
             if !loading && state.refresh_rotation >= std::f32::consts::TAU {
                 state.refresh_rotation = 0.0;
@@ -80,6 +92,10 @@ impl Versi {
     fn handle_tick(&mut self) -> Task<Message> {
         #[cfg(target_os = "linux")]
         {
+            if crate::tray::refresh_tray_availability(self.settings.tray_behavior) {
+                self.update_tray_menu();
+            }
+            self.sync_tray_availability();
             if crate::tray::is_tray_active() {
                 while gtk::events_pending() {
                     gtk::main_iteration();
@@ -87,9 +103,20 @@ impl Versi {
             }
         }
 
+        let mut should_recheck_backend = false;
         let should_check_updates = if let AppState::Main(state) = &mut self.state {
+            let today = state.now.date_naive();
+            state.now = chrono::Utc::now();
+            if state.now.date_naive() != today {
+                state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
+            }
             let timeout = self.settings.toast_timeout_secs;
             state.toasts.retain(|t| !t.is_expired(timeout));
+            state.prune_recent_uninstalls(std::time::Duration::from_secs(
+                self.settings.undo_uninstall_secs,
+            ));
+            should_recheck_backend =
+                state.should_recheck_backend_version(BACKEND_VERSION_RECHECK_INTERVAL);
             self.settings.app_update_behavior != AppUpdateBehavior::DoNotCheck
                 && state.should_check_for_app_updates(APP_UPDATE_CHECK_INTERVAL)
                 && matches!(
@@ -100,11 +127,16 @@ impl Versi {
             false
         };
 
+        let recheck = if should_recheck_backend {
+            Task::done(Message::RecheckBackendVersion)
+        } else {
+            Task::none()
+        };
         if should_check_updates {
-            return self.handle_check_for_app_update();
+            return Task::batch([recheck, self.handle_check_for_app_update()]);
         }
 
-        Task::none()
+        recheck
     }
 
     fn handle_window_resized(&mut self, size: iced::Size) -> Task<Message> {
@@ -128,7 +160,7 @@ impl Versi {
 
     fn open_backend_update_url(&self) -> Task<Message> {
         if let AppState::Main(state) = &self.state
-            && let Some(update) = &state.backend_update
+            && let Some(update) = &state.active_environment().backend_update
         {
             return super::open_url_task(update.release_url.clone());
         }
@@ -149,10 +181,14 @@ impl Versi {
         is_default: bool,
     ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            let can_open_terminal = is_installed && terminal::can_open_terminal(state, &version);
+            let can_create_alias = state.backend.capabilities().supports_aliases;
             state.context_menu = Some(crate::state::ContextMenu {
                 version,
                 is_installed,
                 is_default,
+                can_open_terminal,
+                can_create_alias,
                 position: state.cursor_position,
             });
         }
@@ -224,6 +260,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn show_context_menu_offers_terminal_only_for_installed_native_versions() {
+        let mut app = test_app_with_two_environments();
+
+        let _ = app.dispatch_system(Message::ShowContextMenu {
+            version: "v20.11.0".to_string(),
+            is_installed: true,
+            is_default: false,
+        });
+        assert!(matches!(
+            app.main_state().context_menu,
+            Some(ref menu) if menu.can_open_terminal
+        ));
+
+        let _ = app.dispatch_system(Message::ShowContextMenu {
+            version: "v22.0.0".to_string(),
+            is_installed: false,
+            is_default: false,
+        });
+        assert!(matches!(
+            app.main_state().context_menu,
+            Some(ref menu) if !menu.can_open_terminal
+        ));
+
+        app.main_state_mut().active_environment_idx = 1;
+        let _ = app.dispatch_system(Message::ShowContextMenu {
+            version: "v20.11.0".to_string(),
+            is_installed: true,
+            is_default: false,
+        });
+        assert!(matches!(
+            app.main_state().context_menu,
+            Some(ref menu) if !menu.can_open_terminal
+        ));
+    }
+
     #[test]
     fn close_context_menu_clears_existing_menu() {
         let mut app = test_app_with_two_environments();
@@ -0,0 +1,95 @@
+//! Per-version notes, edited in the version detail modal. A note that hasn't
+//! been saved yet is saved when the modal closes.
+//!
+//! Handles messages: `VersionNoteChanged`, `SaveVersionNote`
+
+use crate::state::{AppState, Modal};
+
+use super::Versi;
+
+impl Versi {
+    pub(super) fn handle_version_note_changed(&mut self, value: String) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::VersionDetail { note, .. }) = &mut state.modal
+        {
+            *note = value;
+        }
+    }
+
+    pub(super) fn handle_save_version_note(&mut self) {
+        let AppState::Main(state) = &mut self.state else {
+            return;
+        };
+        let Some(Modal::VersionDetail { version, note }) = &mut state.modal else {
+            return;
+        };
+
+        if !self.settings.set_version_note(version, note) {
+            return;
+        }
+        // The Save button disables once the note matches, so no toast.
+        *note = self
+            .settings
+            .version_note(version)
+            .unwrap_or_default()
+            .to_string();
+        self.save_settings_with_log();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_app_with_two_environments;
+    use super::*;
+    use crate::message::Message;
+
+    #[test]
+    fn saving_a_note_trims_it_and_stores_it_by_version() {
+        let mut app = test_app_with_two_environments();
+        let _ = app.update(Message::ShowVersionDetail("v20.11.0".to_string()));
+
+        app.handle_version_note_changed("  used for project X ".to_string());
+        app.handle_save_version_note();
+
+        assert_eq!(
+            app.settings.version_note("v20.11.0"),
+            Some("used for project X")
+        );
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::VersionDetail { note, .. }) if note == "used for project X"
+        ));
+    }
+
+    #[test]
+    fn saving_a_blank_note_removes_it() {
+        let mut app = test_app_with_two_environments();
+        app.settings.set_version_note("v20.11.0", "old");
+        let _ = app.update(Message::ShowVersionDetail("v20.11.0".to_string()));
+        assert!(matches!(
+            &app.main_state().modal,
+            Some(Modal::VersionDetail { note, .. }) if note == "old"
+        ));
+
+        app.handle_version_note_changed(String::new());
+        app.handle_save_version_note();
+
+        assert_eq!(app.settings.version_note("v20.11.0"), None);
+    }
+
+    #[test]
+    fn closing_the_modal_saves_an_unsaved_note() {
+        let mut app = test_app_with_two_environments();
+        let _ = app.update(Message::ShowVersionDetail("v20.11.0".to_string()));
+
+        app.handle_version_note_changed("used for project X".to_string());
+        let _ = app.update(Message::CloseModal);
+
+        assert!(app.main_state().modal.is_none());
+        assert_eq!(
+            app.settings.version_note("v20.11.0"),
+            Some("used for project X")
+        );
+        assert!(app.main_state().toasts.is_empty());
+    }
+}
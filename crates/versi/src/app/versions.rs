@@ -1,12 +1,16 @@
 //! Remote version fetching, release schedule, and update checks.
 //!
 //! Handles messages: `RemoteVersionsFetched`, `ReleaseScheduleFetched`,
-//! `AppUpdateChecked`, `BackendUpdateChecked`
+//! `AppUpdateChecked`, `BackendUpdateChecked`, `ClearVersionCache`,
+//! `ConfirmClearVersionCache`, `VersionCacheCleared`, `RecheckBackendVersion`,
+//! `BackendVersionRechecked`, `ProjectVersionFileFound`
 
 use iced::Task;
 
 use crate::error::AppError;
 use crate::message::Message;
+use crate::state::{AppState, Modal, Toast};
+use crate::version_files::VersionFileSuggestion;
 
 use super::Versi;
 
@@ -23,8 +27,18 @@ impl Versi {
         &mut self,
         request_seq: u64,
         result: Result<Vec<versi_backend::RemoteVersion>, AppError>,
-    ) {
+    ) -> Task<Message> {
         fetch_handlers::handle_remote_versions_fetched(self, request_seq, result);
+
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+        if state.available_versions.loading
+            || !std::mem::take(&mut state.install_latest_lts_when_loaded)
+        {
+            return Task::none();
+        }
+        self.handle_install_latest_lts()
     }
 
     pub(super) fn handle_fetch_release_schedule(&mut self) -> Task<Message> {
@@ -51,6 +65,79 @@ impl Versi {
         fetch_handlers::handle_version_metadata_fetched(self, request_seq, result);
     }
 
+    pub(super) fn ensure_metadata_for(&mut self, versions: &[String]) -> Task<Message> {
+        fetch_handlers::ensure_metadata_for(self, versions)
+    }
+
+    pub(super) fn handle_clear_version_cache(&mut self) {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = Some(Modal::ConfirmClearVersionCache);
+        }
+    }
+
+    pub(super) fn handle_confirm_clear_version_cache(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = None;
+            state.settings_state.version_cache_cleared = false;
+        }
+        Task::perform(
+            async {
+                tokio::task::spawn_blocking(cache_save::clear_disk_cache)
+                    .await
+                    .unwrap_or_else(|error| Err(std::io::Error::other(error)))
+                    .map_err(|error| AppError::operation_failed("Clear version cache", error))
+            },
+            Message::VersionCacheCleared,
+        )
+    }
+
+    pub(super) fn handle_project_version_file_found(
+        &mut self,
+        suggestion: Option<VersionFileSuggestion>,
+    ) {
+        if let AppState::Main(state) = &mut self.state {
+            if let Some(suggestion) = &suggestion {
+                log::debug!(
+                    "Working directory pins Node {} via {}",
+                    suggestion.spec,
+                    suggestion.path.display()
+                );
+            }
+            state.project_version = suggestion;
+        }
+    }
+
+    pub(super) fn handle_version_cache_cleared(
+        &mut self,
+        result: Result<(), AppError>,
+    ) -> Task<Message> {
+        let AppState::Main(state) = &mut self.state else {
+            return Task::none();
+        };
+
+        if let Err(error) = result {
+            let id = state.next_toast_id();
+            state.add_toast(Toast::error(
+                id,
+                format!("Could not clear version cache: {error}"),
+            ));
+            return Task::none();
+        }
+
+        state.available_versions.clear();
+        state.recompute_banner_stats(self.settings.patches_to_keep_per_major);
+        state.settings_state.version_cache_cleared = true;
+
+        let mut tasks = vec![
+            self.handle_fetch_remote_versions(),
+            self.handle_fetch_release_schedule(),
+        ];
+        if self.settings.fetch_version_metadata {
+            tasks.push(self.handle_fetch_version_metadata());
+        }
+        Task::batch(tasks)
+    }
+
     pub(super) fn handle_check_for_app_update(&mut self) -> Task<Message> {
         update_checks::handle_check_for_app_update(self)
     }
@@ -68,9 +155,22 @@ impl Versi {
 
     pub(super) fn handle_backend_update_checked(
         &mut self,
+        env_id: &versi_platform::EnvironmentId,
         result: Result<Option<versi_backend::BackendUpdate>, AppError>,
     ) {
-        update_checks::handle_backend_update_checked(self, result);
+        update_checks::handle_backend_update_checked(self, env_id, result);
+    }
+
+    pub(super) fn handle_recheck_backend_version(&mut self) -> Task<Message> {
+        update_checks::handle_recheck_backend_version(self)
+    }
+
+    pub(super) fn handle_backend_version_rechecked(
+        &mut self,
+        env_id: &versi_platform::EnvironmentId,
+        version: Option<String>,
+    ) -> Task<Message> {
+        update_checks::handle_backend_version_rechecked(self, env_id, version)
     }
 }
 
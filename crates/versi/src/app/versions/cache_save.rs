@@ -13,6 +13,7 @@ enum CacheSaveMessage {
     RemoteVersions(Vec<RemoteVersion>),
     ReleaseSchedule(ReleaseSchedule),
     VersionMetadata(HashMap<String, VersionMeta>),
+    Clear(mpsc::Sender<std::io::Result<()>>),
 }
 
 #[derive(Default)]
@@ -31,12 +32,19 @@ impl CacheSnapshot {
         }
     }
 
-    fn apply_message(&mut self, message: CacheSaveMessage) {
+    /// Apply `message` and return whether the snapshot needs to be persisted.
+    fn apply_message(&mut self, message: CacheSaveMessage) -> bool {
         match message {
             CacheSaveMessage::RemoteVersions(versions) => self.remote_versions = versions,
             CacheSaveMessage::ReleaseSchedule(schedule) => self.release_schedule = Some(schedule),
             CacheSaveMessage::VersionMetadata(metadata) => self.version_metadata = Some(metadata),
+            CacheSaveMessage::Clear(reply) => {
+                *self = Self::default();
+                let _ = reply.send(crate::cache::remove_cache_file());
+                return false;
+            }
         }
+        true
     }
 
     fn persist(&self) {
@@ -61,6 +69,18 @@ pub(super) fn enqueue_cache_save_version_metadata(metadata: HashMap<String, Vers
     enqueue_cache_save(CacheSaveMessage::VersionMetadata(metadata));
 }
 
+/// Delete the disk cache on the save worker so it cannot race with a pending
+/// write, and drop the worker's in-memory snapshot. Blocks until done.
+pub(super) fn clear_disk_cache() -> std::io::Result<()> {
+    let (reply, result) = mpsc::channel();
+    cache_save_sender()
+        .send(CacheSaveMessage::Clear(reply))
+        .map_err(|_| std::io::Error::other("cache save worker disconnected"))?;
+    result
+        .recv()
+        .map_err(|_| std::io::Error::other("cache save worker disconnected"))?
+}
+
 fn enqueue_cache_save(message: CacheSaveMessage) {
     let sender = cache_save_sender();
     match sender.try_send(message) {
@@ -82,7 +102,7 @@ fn cache_save_sender() -> &'static mpsc::SyncSender<CacheSaveMessage> {
     CACHE_SAVER.get_or_init(|| {
         let (sender, receiver) = mpsc::sync_channel::<CacheSaveMessage>(CACHE_SAVE_QUEUE_CAPACITY);
         std::thread::spawn(move || {
-            let mut snapshot = match crate::cache::DiskCache::load() {
+            let snapshot = match crate::cache::DiskCache::load() {
                 Ok(Some(cache)) => CacheSnapshot::from_disk_cache(cache),
                 Ok(None) => CacheSnapshot::default(),
                 Err(error) => {
@@ -91,23 +111,96 @@ fn cache_save_sender() -> &'static mpsc::SyncSender<CacheSaveMessage> {
                 }
             };
 
-            while let Ok(message) = receiver.recv() {
-                snapshot.apply_message(message);
-                loop {
-                    match receiver.recv_timeout(CACHE_SAVE_DEBOUNCE) {
-                        Ok(next) => snapshot.apply_message(next),
-                        Err(mpsc::RecvTimeoutError::Timeout) => {
-                            snapshot.persist();
-                            break;
-                        }
-                        Err(mpsc::RecvTimeoutError::Disconnected) => {
-                            snapshot.persist();
-                            return;
-                        }
+            run_cache_save_worker(&receiver, snapshot, CacheSnapshot::persist);
+        });
+        sender
+    })
+}
+
+/// Merge queued updates into `snapshot` and call `persist` once the queue has
+/// been quiet for [`CACHE_SAVE_DEBOUNCE`], so bursts of fetch results produce a
+/// single write containing the newest value of every section.
+fn run_cache_save_worker(
+    receiver: &mpsc::Receiver<CacheSaveMessage>,
+    mut snapshot: CacheSnapshot,
+    mut persist: impl FnMut(&CacheSnapshot),
+) {
+    while let Ok(message) = receiver.recv() {
+        let mut dirty = snapshot.apply_message(message);
+        loop {
+            match receiver.recv_timeout(CACHE_SAVE_DEBOUNCE) {
+                Ok(next) => dirty = snapshot.apply_message(next),
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if dirty {
+                        persist(&snapshot);
                     }
+                    break;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    if dirty {
+                        persist(&snapshot);
+                    }
+                    return;
                 }
             }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::mpsc;
+
+    use versi_backend::RemoteVersion;
+
+    use super::{CacheSaveMessage, CacheSnapshot, run_cache_save_worker};
+
+    fn remote(version: &str) -> RemoteVersion {
+        RemoteVersion {
+            version: version.parse().expect("test version should parse"),
+            lts_codename: None,
+            is_latest: false,
+        }
+    }
+
+    #[test]
+    fn concurrent_updates_converge_to_latest_values() {
+        let (sender, receiver) = mpsc::sync_channel(super::CACHE_SAVE_QUEUE_CAPACITY);
+
+        let remote_sender = sender.clone();
+        let remote_writer = std::thread::spawn(move || {
+            for patch in 0..5 {
+                let versions = vec![remote(&format!("v22.{patch}.0"))];
+                remote_sender
+                    .send(CacheSaveMessage::RemoteVersions(versions))
+                    .expect("worker should accept remote versions");
+            }
         });
-        sender
-    })
+        let metadata_sender = sender.clone();
+        let metadata_writer = std::thread::spawn(move || {
+            metadata_sender
+                .send(CacheSaveMessage::VersionMetadata(HashMap::new()))
+                .expect("worker should accept metadata");
+        });
+        remote_writer.join().expect("remote writer should finish");
+        metadata_writer
+            .join()
+            .expect("metadata writer should finish");
+        drop(sender);
+
+        let mut writes = Vec::new();
+        run_cache_save_worker(&receiver, CacheSnapshot::default(), |snapshot| {
+            writes.push((
+                snapshot
+                    .remote_versions
+                    .iter()
+                    .map(|v| v.version.to_string())
+                    .collect::<Vec<_>>(),
+                snapshot.version_metadata.is_some(),
+            ));
+        });
+
+        assert_eq!(writes, vec![(vec!["v22.4.0".to_string()], true)]);
+    }
 }
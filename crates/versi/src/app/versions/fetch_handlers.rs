@@ -99,7 +99,7 @@ pub(super) fn handle_remote_versions_fetched(
             }
         }
 
-        state.recompute_banner_stats();
+        state.recompute_banner_stats(app.settings.patches_to_keep_per_major);
     }
 }
 
@@ -153,17 +153,27 @@ pub(super) fn handle_release_schedule_fetched(
         match result {
             Ok(schedule) => {
                 state.available_versions.schedule = Some(schedule.clone());
+                state.available_versions.schedule_is_bundled = false;
                 state.available_versions.schedule_fetch.error = None;
 
                 enqueue_cache_save_release_schedule(schedule);
             }
             Err(error) => {
                 debug!("Release schedule fetch failed: {error}");
+                if state.available_versions.schedule.is_none() {
+                    debug!(
+                        "Falling back to the bundled release schedule from {}",
+                        versi_core::BUNDLED_SCHEDULE_DATE
+                    );
+                    state.available_versions.schedule =
+                        Some(versi_core::ReleaseSchedule::bundled());
+                    state.available_versions.schedule_is_bundled = true;
+                }
                 state.available_versions.schedule_fetch.error = Some(error);
             }
         }
 
-        state.recompute_banner_stats();
+        state.recompute_banner_stats(app.settings.patches_to_keep_per_major);
     }
 }
 
@@ -229,3 +239,44 @@ pub(super) fn handle_version_metadata_fetched(
         }
     }
 }
+
+/// Refresh the cached release index when it lacks any of `versions`.
+///
+/// `nodejs.org` only serves the full index, so a missing version triggers
+/// one full metadata fetch rather than a per-version request. Each version
+/// triggers at most one refresh per session, so versions the index never
+/// lists (e.g. nightlies) don't refetch it on every load.
+pub(super) fn ensure_metadata_for(app: &mut Versi, versions: &[String]) -> Task<Message> {
+    let AppState::Main(state) = &mut app.state else {
+        return Task::none();
+    };
+    if !app.settings.fetch_version_metadata
+        || state.available_versions.metadata_fetch.is_in_flight()
+    {
+        return Task::none();
+    }
+
+    let cache = &mut state.available_versions;
+    let missing: Vec<&String> = versions
+        .iter()
+        .filter(|version| {
+            cache
+                .metadata
+                .as_ref()
+                .is_none_or(|metadata| !metadata.contains_key(*version))
+                && !cache.metadata_requested.contains(*version)
+        })
+        .collect();
+    if missing.is_empty() {
+        return Task::none();
+    }
+
+    debug!(
+        "Refreshing version metadata for {} uncached version(s)",
+        missing.len()
+    );
+    cache
+        .metadata_requested
+        .extend(missing.into_iter().cloned());
+    handle_fetch_version_metadata(app)
+}
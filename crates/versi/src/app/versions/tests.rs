@@ -48,7 +48,7 @@ fn remote_versions_fetched_updates_cache_on_success() {
     app.main_state_mut().available_versions.loading = true;
     app.main_state_mut().available_versions.remote.request_seq = 7;
 
-    app.handle_remote_versions_fetched(
+    let _ = app.handle_remote_versions_fetched(
         7,
         Ok(vec![remote("v22.10.0", true), remote("v22.9.0", false)]),
     );
@@ -107,6 +107,51 @@ fn release_schedule_fetched_sets_schedule_and_clears_error() {
     assert!(state.available_versions.schedule_fetch.error.is_none());
 }
 
+#[test]
+fn release_schedule_fetch_failure_without_cache_uses_bundled_schedule() {
+    let mut app = test_app_with_two_environments();
+    app.main_state_mut()
+        .available_versions
+        .schedule_fetch
+        .request_seq = 3;
+
+    app.handle_release_schedule_fetched(
+        3,
+        Err(AppError::version_fetch_failed(
+            "Release schedule",
+            "offline",
+        )),
+    );
+
+    let state = app.main_state();
+    assert!(state.available_versions.schedule_is_bundled);
+    assert!(state.available_versions.schedule_fetch.error.is_some());
+    let schedule = state
+        .available_versions
+        .schedule
+        .as_ref()
+        .expect("bundled schedule should be used");
+    assert_eq!(schedule.codename(22), Some("Jod"));
+}
+
+#[test]
+fn release_schedule_fetch_failure_keeps_cached_schedule() {
+    let mut app = test_app_with_two_environments();
+    let state = app.main_state_mut();
+    state.available_versions.schedule_fetch.request_seq = 3;
+    state.available_versions.schedule = Some(sample_schedule());
+
+    app.handle_release_schedule_fetched(
+        3,
+        Err(AppError::version_fetch_failed(
+            "Release schedule",
+            "offline",
+        )),
+    );
+
+    assert!(!app.main_state().available_versions.schedule_is_bundled);
+}
+
 #[test]
 fn version_metadata_fetched_ignores_stale_request() {
     let mut app = test_app_with_two_environments();
@@ -261,11 +306,13 @@ fn backend_update_checked_sets_update_on_success() {
         release_url: "https://example.com/backend".to_string(),
     };
 
-    app.handle_backend_update_checked(Ok(Some(update.clone())));
+    let env_id = app.main_state().active_environment().id.clone();
+    app.handle_backend_update_checked(&env_id, Ok(Some(update.clone())));
 
     let state = app.main_state();
     assert_eq!(
         state
+            .active_environment()
             .backend_update
             .as_ref()
             .map(|value| value.latest_version.as_str()),
@@ -273,6 +320,71 @@ fn backend_update_checked_sets_update_on_success() {
     );
 }
 
+#[test]
+fn backend_update_results_are_tracked_per_environment() {
+    let mut app = test_app_with_two_environments();
+    let state = app.main_state_mut();
+    state.environments[0].backend_version = Some("1.37.0".to_string());
+    state.environments[1].backend_version = Some("0.40.4".to_string());
+    let native_id = state.environments[0].id.clone();
+    let wsl_id = state.environments[1].id.clone();
+
+    app.handle_backend_update_checked(
+        &native_id,
+        Ok(Some(versi_backend::BackendUpdate {
+            current_version: "1.37.0".to_string(),
+            latest_version: "1.38.1".to_string(),
+            release_url: "https://example.com/fnm".to_string(),
+        })),
+    );
+    app.handle_backend_update_checked(&wsl_id, Ok(None));
+
+    let state = app.main_state();
+    assert_eq!(
+        state.environments[0]
+            .backend_update
+            .as_ref()
+            .map(|update| update.latest_version.as_str()),
+        Some("1.38.1")
+    );
+    assert!(state.environments[1].backend_update.is_none());
+}
+
+#[test]
+fn externally_updated_backend_version_replaces_stale_state() {
+    let mut app = test_app_with_two_environments();
+    let state = app.main_state_mut();
+    state.environments[0].backend_version = Some("1.37.0".to_string());
+    state.environments[0].backend_update = Some(versi_backend::BackendUpdate {
+        current_version: "1.37.0".to_string(),
+        latest_version: "1.38.1".to_string(),
+        release_url: "https://example.com/fnm".to_string(),
+    });
+    let native_id = state.environments[0].id.clone();
+
+    let _ = app.handle_backend_version_rechecked(&native_id, Some("1.38.1".to_string()));
+
+    let env = &app.main_state().environments[0];
+    assert_eq!(env.backend_version.as_deref(), Some("1.38.1"));
+    assert!(env.backend_update.is_none());
+}
+
+#[test]
+fn unchanged_or_unknown_backend_version_is_ignored() {
+    let mut app = test_app_with_two_environments();
+    let state = app.main_state_mut();
+    state.environments[1].backend_version = Some("0.40.4".to_string());
+    let wsl_id = state.environments[1].id.clone();
+
+    let _ = app.handle_backend_version_rechecked(&wsl_id, None);
+    let _ = app.handle_backend_version_rechecked(&wsl_id, Some("0.40.4".to_string()));
+
+    assert_eq!(
+        app.main_state().environments[1].backend_version.as_deref(),
+        Some("0.40.4")
+    );
+}
+
 #[test]
 fn fetch_release_schedule_cancels_previous_token() {
     let mut app = test_app_with_two_environments();
@@ -331,3 +443,90 @@ fn fetch_remote_versions_cancels_previous_token_when_loading() {
     assert!(state.available_versions.loading);
     assert!(state.available_versions.remote.cancel_token.is_some());
 }
+
+#[test]
+fn version_cache_cleared_resets_cache_and_refetches() {
+    let mut app = test_app_with_two_environments();
+    {
+        let state = app.main_state_mut();
+        state
+            .available_versions
+            .set_versions(vec![remote("v22.10.0", true)]);
+        state.available_versions.schedule = Some(sample_schedule());
+        state.available_versions.metadata = Some(sample_metadata());
+        state.available_versions.loaded_from_disk = true;
+    }
+
+    let _ = app.handle_version_cache_cleared(Ok(()));
+
+    let state = app.main_state();
+    assert!(state.available_versions.versions.is_empty());
+    assert!(state.available_versions.schedule.is_none());
+    assert!(state.available_versions.metadata.is_none());
+    assert!(!state.available_versions.loaded_from_disk);
+    assert!(state.available_versions.remote.is_in_flight());
+    assert!(state.available_versions.schedule_fetch.is_in_flight());
+    assert!(state.available_versions.metadata_fetch.is_in_flight());
+    assert!(state.settings_state.version_cache_cleared);
+    assert!(state.toasts.is_empty());
+}
+
+#[test]
+fn version_cache_clear_failure_keeps_cached_data() {
+    let mut app = test_app_with_two_environments();
+    app.main_state_mut()
+        .available_versions
+        .set_versions(vec![remote("v22.10.0", true)]);
+
+    let _ = app.handle_version_cache_cleared(Err(AppError::operation_failed(
+        "Clear version cache",
+        "permission denied",
+    )));
+
+    let state = app.main_state();
+    assert_eq!(state.available_versions.versions.len(), 1);
+    assert!(!state.available_versions.remote.is_in_flight());
+    assert!(!state.settings_state.version_cache_cleared);
+    assert_eq!(state.toasts.len(), 1);
+}
+
+#[test]
+fn cached_metadata_is_reused_for_shown_versions() {
+    let mut app = test_app_with_two_environments();
+    app.main_state_mut().available_versions.metadata = Some(sample_metadata());
+
+    let _ = app.ensure_metadata_for(&["v22.10.0".to_string()]);
+
+    let state = app.main_state();
+    assert!(!state.available_versions.metadata_fetch.is_in_flight());
+    assert!(state.available_versions.metadata_requested.is_empty());
+}
+
+#[test]
+fn uncached_version_refreshes_the_index_only_once() {
+    let mut app = test_app_with_two_environments();
+    app.main_state_mut().available_versions.metadata = Some(sample_metadata());
+
+    let _ = app.ensure_metadata_for(&["v24.1.0".to_string()]);
+    assert!(
+        app.main_state()
+            .available_versions
+            .metadata_fetch
+            .is_in_flight()
+    );
+
+    let request_seq = app
+        .main_state()
+        .available_versions
+        .metadata_fetch
+        .request_seq;
+    app.handle_version_metadata_fetched(request_seq, Ok(sample_metadata()));
+    let _ = app.ensure_metadata_for(&["v24.1.0".to_string()]);
+
+    assert!(
+        !app.main_state()
+            .available_versions
+            .metadata_fetch
+            .is_in_flight()
+    );
+}
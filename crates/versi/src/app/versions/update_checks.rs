@@ -1,7 +1,8 @@
 use iced::Task;
-use log::debug;
+use log::{debug, info};
 use versi_backend::BackendDetection;
 use versi_core::check_for_update;
+use versi_platform::EnvironmentId;
 
 use crate::error::AppError;
 use crate::message::Message;
@@ -24,9 +25,11 @@ pub(super) fn handle_check_for_app_update(app: &mut Versi) -> Task<Message> {
 
     let current_version = env!("CARGO_PKG_VERSION").to_string();
     let client = app.http_client.clone();
+    let repo = app.settings.update_repo.clone();
+    let channel = app.settings.update_channel;
     Task::perform(
         async move {
-            check_for_update(&client, &current_version)
+            check_for_update(&client, &current_version, &repo, channel)
                 .await
                 .map_err(|error| AppError::update_check_failed("App", error))
         },
@@ -68,40 +71,150 @@ pub(super) fn handle_app_update_checked(
 }
 
 pub(super) fn handle_check_for_backend_update(app: &mut Versi) -> Task<Message> {
-    if let AppState::Main(state) = &app.state
-        && let Some(version) = &state.active_environment().backend_version
-    {
-        let version = version.clone();
-        let client = app.http_client.clone();
-        let provider = app.provider_for_kind(state.backend_name);
-        let detection = BackendDetection {
-            found: true,
-            path: Some(app.backend_path.clone()),
-            version: Some(version.clone()),
-            in_path: true,
-            data_dir: app.backend_dir.clone(),
-        };
-        return Task::perform(
-            async move {
-                provider
-                    .check_for_update(&client, &version, &detection)
-                    .await
-                    .map_err(|error| AppError::update_check_failed("Backend", error))
-            },
-            |result| Message::BackendUpdateChecked(Box::new(result)),
-        );
-    }
-    Task::none()
+    let AppState::Main(state) = &app.state else {
+        return Task::none();
+    };
+
+    let tasks: Vec<_> = state
+        .environments
+        .iter()
+        .filter(|env| env.available)
+        .filter_map(|env| {
+            let version = env.backend_version.clone()?;
+            let detection = match &env.id {
+                EnvironmentId::Native => BackendDetection {
+                    found: true,
+                    path: Some(app.backend_path.clone()),
+                    version: Some(version.clone()),
+                    in_path: true,
+                    data_dir: app.backend_dir.clone(),
+                },
+                EnvironmentId::Wsl { backend_path, .. } => BackendDetection {
+                    found: true,
+                    path: Some(backend_path.into()),
+                    version: Some(version.clone()),
+                    in_path: false,
+                    data_dir: None,
+                },
+            };
+            let env_id = env.id.clone();
+            let client = app.http_client.clone();
+            let provider = app.provider_for_kind(env.backend_name);
+
+            Some(Task::perform(
+                async move {
+                    let result = provider
+                        .check_for_update(&client, &version, &detection)
+                        .await
+                        .map_err(|error| AppError::update_check_failed("Backend", error));
+                    (env_id, result)
+                },
+                |(env_id, result)| Message::BackendUpdateChecked {
+                    env_id,
+                    result: Box::new(result),
+                },
+            ))
+        })
+        .collect();
+
+    Task::batch(tasks)
 }
 
 pub(super) fn handle_backend_update_checked(
     app: &mut Versi,
+    env_id: &EnvironmentId,
     result: Result<Option<versi_backend::BackendUpdate>, AppError>,
 ) {
-    if let AppState::Main(state) = &mut app.state {
+    if let AppState::Main(state) = &mut app.state
+        && let Some(env) = state.environments.iter_mut().find(|env| &env.id == env_id)
+    {
         match result {
-            Ok(update) => state.backend_update = update,
-            Err(e) => debug!("Backend update check failed: {e}"),
+            Ok(update) => env.backend_update = update,
+            Err(e) => debug!("Backend update check failed for {env_id:?}: {e}"),
         }
     }
 }
+
+/// Re-read every available environment's backend version, so an upgrade
+/// made outside the app (e.g. `brew upgrade fnm`) is picked up.
+pub(super) fn handle_recheck_backend_version(app: &mut Versi) -> Task<Message> {
+    if let AppState::Main(state) = &mut app.state {
+        state.backend_version_checked_at = std::time::Instant::now();
+    }
+    let AppState::Main(state) = &app.state else {
+        return Task::none();
+    };
+
+    let tasks: Vec<_> = state
+        .environments
+        .iter()
+        .filter(|env| env.available)
+        .filter_map(|env| {
+            let env_id = env.id.clone();
+            match &env.id {
+                EnvironmentId::Native => {
+                    let provider = app.provider_for_kind(env.backend_name);
+                    Some(Task::perform(
+                        async move { provider.detect().await.version },
+                        move |version| Message::BackendVersionRechecked { env_id, version },
+                    ))
+                }
+                #[cfg(windows)]
+                EnvironmentId::Wsl {
+                    distro,
+                    backend_path,
+                } => {
+                    let distro = distro.clone();
+                    let backend_path = backend_path.clone();
+                    let timeout = std::time::Duration::from_secs(app.settings.fetch_timeout_secs);
+                    Some(Task::perform(
+                        async move {
+                            super::super::init::get_wsl_backend_version(
+                                &distro,
+                                &backend_path,
+                                timeout,
+                            )
+                            .await
+                            .ok()
+                            .flatten()
+                        },
+                        move |version| Message::BackendVersionRechecked { env_id, version },
+                    ))
+                }
+                #[cfg(not(windows))]
+                EnvironmentId::Wsl { .. } => None,
+            }
+        })
+        .collect();
+
+    Task::batch(tasks)
+}
+
+pub(super) fn handle_backend_version_rechecked(
+    app: &mut Versi,
+    env_id: &EnvironmentId,
+    version: Option<String>,
+) -> Task<Message> {
+    let AppState::Main(state) = &mut app.state else {
+        return Task::none();
+    };
+    let Some(version) = version else {
+        return Task::none();
+    };
+    let Some(env) = state.environments.iter_mut().find(|env| &env.id == env_id) else {
+        return Task::none();
+    };
+    if env.backend_version.as_deref() == Some(version.as_str()) {
+        return Task::none();
+    }
+
+    info!(
+        "{} changed from {} to {version} in {}",
+        env.backend_name,
+        env.backend_version.as_deref().unwrap_or("unknown"),
+        env.name
+    );
+    env.backend_version = Some(version);
+    env.backend_update = None;
+    handle_check_for_backend_update(app)
+}
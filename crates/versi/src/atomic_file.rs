@@ -0,0 +1,151 @@
+//! Crash-safe file replacement shared by the settings and version cache
+//! writers: data goes to a temp file in the same directory, is synced, and is
+//! then renamed over the destination.
+
+use std::io::Write;
+use std::path::Path;
+
+/// Atomically replace `path` with `data`.
+///
+/// A crash at any point leaves either the previous file or the new one in
+/// place, never a truncated mix. Temp files use a `.<name>.<pid>.<ts>.<n>.tmp`
+/// name so they are easy to recognise if the process dies mid-write.
+pub fn write_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let tmp_path = write_temp(path, data)?;
+
+    if let Err(error) = replace_file(&tmp_path, path) {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// First half of [`write_atomic`]: write and sync `data` to a fresh temp file
+/// next to `path`, leaving `path` itself untouched.
+pub(crate) fn write_temp(path: &Path, data: &[u8]) -> std::io::Result<std::path::PathBuf> {
+    let parent = path.parent().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "path has no parent")
+    })?;
+
+    let file_name = path
+        .file_name()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap_or("file");
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_nanos());
+    let pid = std::process::id();
+
+    let mut tmp_path = None;
+    for attempt in 0..16_u8 {
+        let candidate = parent.join(format!(".{file_name}.{pid}.{timestamp}.{attempt}.tmp"));
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(mut file) => {
+                if let Err(error) = file.write_all(data).and_then(|()| file.sync_all()) {
+                    drop(file);
+                    let _ = std::fs::remove_file(&candidate);
+                    return Err(error);
+                }
+                tmp_path = Some(candidate);
+                break;
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    tmp_path.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "failed to create unique temp file",
+        )
+    })
+}
+
+/// Rename `src` over `dst`, replacing it if it exists.
+pub fn replace_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        use windows_sys::Win32::Storage::FileSystem::{
+            MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH, MoveFileExW,
+        };
+
+        let src_utf16: Vec<u16> = src
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+        let dst_utf16: Vec<u16> = dst
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        // SAFETY: both paths are NUL-terminated UTF-16 buffers that live for
+        // the duration of the FFI call.
+        let moved = unsafe {
+            MoveFileExW(
+                src_utf16.as_ptr(),
+                dst_utf16.as_ptr(),
+                MOVEFILE_REPLACE_EXISTING | MOVEFILE_WRITE_THROUGH,
+            )
+        };
+        if moved != 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        std::fs::rename(src, dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_atomic;
+
+    fn temp_files(dir: &std::path::Path) -> usize {
+        std::fs::read_dir(dir)
+            .expect("read temp dir entries")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .count()
+    }
+
+    #[test]
+    fn write_atomic_replaces_existing_contents() {
+        let temp_dir = tempfile::tempdir().expect("temporary directory should be created");
+        let path = temp_dir.path().join("data.json");
+        std::fs::write(&path, "old").expect("existing file should be written");
+
+        write_atomic(&path, b"new").expect("atomic write should succeed");
+
+        assert_eq!(std::fs::read_to_string(&path).expect("read file"), "new");
+        assert_eq!(temp_files(temp_dir.path()), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failed_replace_leaves_destination_and_no_temp_file() {
+        let temp_dir = tempfile::tempdir().expect("temporary directory should be created");
+        // Renaming a file over a non-empty directory fails, which stands in for
+        // the process being interrupted before the rename happens.
+        let path = temp_dir.path().join("data.json");
+        std::fs::create_dir(&path).expect("blocking directory should be created");
+        std::fs::write(path.join("keep"), "keep").expect("blocking file should be written");
+
+        assert!(write_atomic(&path, b"new").is_err());
+
+        assert!(path.join("keep").exists());
+        assert_eq!(temp_files(temp_dir.path()), 0);
+    }
+}
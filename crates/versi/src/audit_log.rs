@@ -0,0 +1,165 @@
+//! Append-only record of install, uninstall and set-default operations.
+//!
+//! Kept apart from the debug log so it's there even with debug logging off.
+//! Each line of `audit.jsonl` is one [`AuditRecord`]; the file is trimmed to
+//! its newest half once it grows past the log size cap.
+
+use std::io::Write;
+use std::path::Path;
+use std::sync::{OnceLock, mpsc};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How many records the diagnostics viewer shows.
+pub const AUDIT_VIEW_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Install,
+    Uninstall,
+    SetDefault,
+}
+
+impl AuditAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Install => "Install",
+            Self::Uninstall => "Uninstall",
+            Self::SetDefault => "Set default",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub timestamp: DateTime<Utc>,
+    pub action: AuditAction,
+    pub version: String,
+    pub environment: String,
+    /// Why the operation failed, or `None` if it succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditRecord {
+    pub fn new(
+        action: AuditAction,
+        version: &str,
+        environment: &str,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            action,
+            version: version.to_string(),
+            environment: environment.to_string(),
+            error,
+        }
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Append `record` to `path`, trimming the file first if it's over
+/// `max_size` bytes.
+pub fn append_record(path: &Path, record: &AuditRecord, max_size: u64) -> std::io::Result<()> {
+    crate::logging::trim_log_file_if_oversized(path, max_size);
+    let mut line = serde_json::to_string(record).map_err(std::io::Error::other)?;
+    line.push('\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(line.as_bytes())
+}
+
+/// The newest `limit` records in `path`, oldest first. Lines that don't
+/// parse are skipped.
+pub fn read_records(path: &Path, limit: usize) -> Vec<AuditRecord> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let records: Vec<AuditRecord> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let skip = records.len().saturating_sub(limit);
+    records.into_iter().skip(skip).collect()
+}
+
+/// Where [`record`] sends records, along with the log size cap.
+pub type AuditSink = mpsc::Sender<(AuditRecord, u64)>;
+
+/// The sink that appends to the app's audit log off the UI thread.
+pub fn log_writer() -> AuditSink {
+    audit_sender().clone()
+}
+
+/// Queue `record` on `sink`.
+pub fn record(sink: &AuditSink, record: AuditRecord, max_size: u64) {
+    let _ = sink.send((record, max_size));
+}
+
+fn audit_sender() -> &'static mpsc::Sender<(AuditRecord, u64)> {
+    static AUDIT_WRITER: OnceLock<mpsc::Sender<(AuditRecord, u64)>> = OnceLock::new();
+
+    AUDIT_WRITER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<(AuditRecord, u64)>();
+        std::thread::spawn(move || {
+            while let Ok((record, max_size)) = receiver.recv() {
+                let Ok(paths) = versi_platform::AppPaths::new() else {
+                    continue;
+                };
+                let _ = paths.ensure_dirs();
+                if let Err(error) = append_record(&paths.audit_log_file(), &record, max_size) {
+                    log::error!("Failed to write audit log: {error}");
+                }
+            }
+        });
+        sender
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditAction, AuditRecord, append_record, read_records};
+
+    #[test]
+    fn appended_records_read_back_in_order() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("audit.jsonl");
+
+        let installed = AuditRecord::new(AuditAction::Install, "v20.11.0", "Native", None);
+        let failed = AuditRecord::new(
+            AuditAction::SetDefault,
+            "v22.1.0",
+            "Ubuntu",
+            Some("fnm exited with status 1".to_string()),
+        );
+        append_record(&path, &installed, 1_024 * 1_024).expect("append install");
+        append_record(&path, &failed, 1_024 * 1_024).expect("append set default");
+
+        let records = read_records(&path, 10);
+        assert_eq!(records, vec![installed, failed.clone()]);
+        assert!(!records[1].succeeded());
+
+        assert_eq!(read_records(&path, 1), vec![failed]);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("audit.jsonl");
+        std::fs::write(&path, "not json\n\n").expect("seed file");
+
+        let record = AuditRecord::new(AuditAction::Uninstall, "v18.19.0", "Native", None);
+        append_record(&path, &record, 1_024 * 1_024).expect("append");
+
+        assert_eq!(read_records(&path, 10), vec![record]);
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use versi_backend::ShellOptionSupport;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -24,6 +25,14 @@ impl BackendKind {
             _ => None,
         }
     }
+
+    /// Shell options this backend understands.
+    pub const fn shell_option_support(self) -> ShellOptionSupport {
+        match self {
+            Self::Fnm => versi_fnm::SHELL_OPTION_SUPPORT,
+            Self::Nvm => versi_nvm::SHELL_OPTION_SUPPORT,
+        }
+    }
 }
 
 impl std::fmt::Display for BackendKind {
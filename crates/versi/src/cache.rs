@@ -1,15 +1,22 @@
 use std::collections::HashMap;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use versi_backend::RemoteVersion;
 use versi_core::{ReleaseSchedule, VersionMeta};
 use versi_platform::AppPaths;
 
+use crate::schema::{self, Migration, current_version};
+
+/// Version cache schema migrations; see [`crate::schema`].
+const CACHE_MIGRATIONS: &[Migration] = &[migrate_cache_v1_to_v2];
+
 #[derive(Serialize, Deserialize)]
 pub struct DiskCache {
+    #[serde(default = "legacy_schema_version")]
+    pub version: u32,
     pub remote_versions: Vec<RemoteVersion>,
     pub release_schedule: Option<ReleaseSchedule>,
     #[serde(default)]
@@ -35,8 +42,18 @@ pub enum DiskCacheLoadError {
     },
 }
 
+fn legacy_schema_version() -> u32 {
+    1
+}
+
+/// v1 predates version metadata caching.
+fn migrate_cache_v1_to_v2(cache: &mut Map<String, Value>) {
+    cache.entry("version_metadata").or_insert(Value::Null);
+}
+
 #[derive(Serialize)]
 struct DiskCacheSnapshot<'a> {
+    version: u32,
     remote_versions: &'a [RemoteVersion],
     release_schedule: Option<&'a ReleaseSchedule>,
     version_metadata: Option<&'a HashMap<String, VersionMeta>>,
@@ -53,12 +70,13 @@ impl DiskCache {
             path: path.to_path_buf(),
             source,
         })?;
-        serde_json::from_str(&data)
-            .map(Some)
-            .map_err(|source| DiskCacheLoadError::Parse {
-                path: path.to_path_buf(),
-                source,
-            })
+        let parse_error = |source| DiskCacheLoadError::Parse {
+            path: path.to_path_buf(),
+            source,
+        };
+        let mut value: Value = serde_json::from_str(&data).map_err(parse_error)?;
+        schema::migrate(&mut value, CACHE_MIGRATIONS);
+        serde_json::from_value(value).map(Some).map_err(parse_error)
     }
 
     pub fn load() -> Result<Option<Self>, DiskCacheLoadError> {
@@ -103,63 +121,42 @@ fn save_snapshot_to_path(
     version_metadata: Option<&HashMap<String, VersionMeta>>,
     cached_at: DateTime<Utc>,
 ) {
-    let payload = DiskCacheSnapshot {
+    if let Ok(data) = snapshot_bytes(
         remote_versions,
         release_schedule,
         version_metadata,
         cached_at,
-    };
-    if let Ok(data) = serde_json::to_vec(&payload) {
-        let _ = write_atomic(path, &data);
+    ) {
+        let _ = crate::atomic_file::write_atomic(path, &data);
     }
 }
 
-fn write_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
-    let parent = path.parent().ok_or_else(|| {
-        std::io::Error::new(std::io::ErrorKind::InvalidInput, "cache path has no parent")
-    })?;
-
-    let file_name = path
-        .file_name()
-        .and_then(std::ffi::OsStr::to_str)
-        .unwrap_or("cache");
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map_or(0, |duration| duration.as_nanos());
-    let pid = std::process::id();
-
-    let mut tmp_path = None;
-    for attempt in 0..16_u8 {
-        let candidate = parent.join(format!(".{file_name}.{pid}.{timestamp}.{attempt}.tmp"));
-        match std::fs::OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(&candidate)
-        {
-            Ok(mut file) => {
-                file.write_all(data)?;
-                file.sync_all()?;
-                tmp_path = Some(candidate);
-                break;
-            }
-            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {}
-            Err(error) => return Err(error),
-        }
-    }
+fn snapshot_bytes(
+    remote_versions: &[RemoteVersion],
+    release_schedule: Option<&ReleaseSchedule>,
+    version_metadata: Option<&HashMap<String, VersionMeta>>,
+    cached_at: DateTime<Utc>,
+) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec(&DiskCacheSnapshot {
+        version: current_version(CACHE_MIGRATIONS),
+        remote_versions,
+        release_schedule,
+        version_metadata,
+        cached_at,
+    })
+}
 
-    let Some(tmp_path) = tmp_path else {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::AlreadyExists,
-            "failed to create unique cache temp file",
-        ));
-    };
+/// Delete the on-disk version cache. A missing file is not an error.
+pub fn remove_cache_file() -> std::io::Result<()> {
+    let paths = AppPaths::new().map_err(std::io::Error::other)?;
+    remove_cache_file_at(&paths.version_cache_file())
+}
 
-    if let Err(error) = replace_file(&tmp_path, path) {
-        let _ = std::fs::remove_file(&tmp_path);
-        return Err(error);
+fn remove_cache_file_at(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        result => result,
     }
-
-    Ok(())
 }
 
 fn quarantine_invalid_cache_file(path: &Path) {
@@ -201,47 +198,6 @@ fn quarantine_invalid_cache_file(path: &Path) {
     );
 }
 
-fn replace_file(src: &Path, dst: &Path) -> std::io::Result<()> {
-    #[cfg(target_os = "windows")]
-    {
-        use std::os::windows::ffi::OsStrExt;
-        use windows_sys::Win32::Storage::FileSystem::{
-            MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH, MoveFileExW,
-        };
-
-        let src_utf16: Vec<u16> = src
-            .as_os_str()
-            .encode_wide()
-            .chain(std::iter::once(0))
-            .collect();
-        let dst_utf16: Vec<u16> = dst
-            .as_os_str()
-            .encode_wide()
-            .chain(std::iter::once(0))
-            .collect();
-
-        // SAFETY: both paths are NUL-terminated UTF-16 buffers that live for
-        // the duration of the FFI call.
-        let moved = unsafe {
-            MoveFileExW(
-                src_utf16.as_ptr(),
-                dst_utf16.as_ptr(),
-                MOVEFILE_REPLACE_EXISTING | MOVEFILE_WRITE_THROUGH,
-            )
-        };
-        if moved != 0 {
-            Ok(())
-        } else {
-            Err(std::io::Error::last_os_error())
-        }
-    }
-
-    #[cfg(not(target_os = "windows"))]
-    {
-        std::fs::rename(src, dst)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -254,6 +210,7 @@ mod tests {
 
     fn sample_cache() -> DiskCache {
         DiskCache {
+            version: super::current_version(super::CACHE_MIGRATIONS),
             remote_versions: vec![RemoteVersion {
                 version: NodeVersion::new(22, 10, 0),
                 lts_codename: Some("LTS".to_string()),
@@ -349,4 +306,89 @@ mod tests {
             .count();
         assert_eq!(temp_files, 0);
     }
+
+    #[test]
+    fn remove_cache_file_at_deletes_file_and_ignores_missing() {
+        let temp_dir = tempfile::tempdir().expect("temporary directory should be created");
+        let path = temp_dir.path().join("versions.json");
+        std::fs::write(&path, "{}").expect("cache file should be written");
+
+        super::remove_cache_file_at(&path).expect("existing cache file should be removed");
+        assert!(!path.exists());
+        super::remove_cache_file_at(&path).expect("missing cache file is not an error");
+    }
+
+    #[test]
+    fn interrupted_write_leaves_canonical_cache_loadable() {
+        let temp_dir = tempfile::tempdir().expect("temporary directory should be created");
+        let path = temp_dir.path().join("versions.json");
+        let cache = sample_cache();
+        super::save_snapshot_to_path(
+            &path,
+            &cache.remote_versions,
+            cache.release_schedule.as_ref(),
+            cache.version_metadata.as_ref(),
+            cache.cached_at,
+        );
+
+        // The next save gets as far as its temp file, then the process dies
+        // before the rename.
+        let mut newer = cache.remote_versions.clone();
+        newer.push(RemoteVersion {
+            version: NodeVersion::new(23, 0, 0),
+            lts_codename: None,
+            is_latest: false,
+        });
+        let data = super::snapshot_bytes(&newer, None, None, cache.cached_at)
+            .expect("snapshot should serialize");
+        let staged =
+            crate::atomic_file::write_temp(&path, &data).expect("temp file should be written");
+        assert!(staged.exists());
+
+        let loaded = DiskCache::load_from_path(&path)
+            .expect("canonical cache should still parse")
+            .expect("canonical cache should still load");
+        assert_eq!(loaded.remote_versions.len(), 1);
+
+        // The leftover temp file doesn't get in the way of the next save.
+        super::save_snapshot_to_path(&path, &newer, None, None, cache.cached_at);
+        let loaded = DiskCache::load_from_path(&path)
+            .expect("cache should parse after the next save")
+            .expect("cache should load after the next save");
+        assert_eq!(loaded.remote_versions.len(), 2);
+    }
+
+    #[test]
+    fn v1_cache_migrates_to_current_schema() {
+        let temp_dir = tempfile::tempdir().expect("temporary directory should be created");
+        let path = temp_dir.path().join("versions.json");
+        let v1 = serde_json::json!({
+            "remote_versions": [
+                {
+                    "version": { "major": 22, "minor": 10, "patch": 0 },
+                    "lts_codename": "Jod",
+                    "is_latest": true
+                }
+            ],
+            "release_schedule": null,
+            "cached_at": "2026-01-01T00:00:00Z"
+        });
+        std::fs::write(&path, v1.to_string()).expect("v1 cache should be written");
+
+        let loaded = DiskCache::load_from_path(&path)
+            .expect("v1 cache should migrate")
+            .expect("v1 cache should load");
+
+        assert_eq!(
+            loaded.version,
+            super::current_version(super::CACHE_MIGRATIONS)
+        );
+        assert_eq!(loaded.remote_versions.len(), 1);
+        assert_eq!(
+            loaded.remote_versions[0].version,
+            NodeVersion::new(22, 10, 0)
+        );
+        assert!(loaded.version_metadata.is_none());
+        assert_eq!(loaded.cached_at.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+    }
 }
@@ -0,0 +1,635 @@
+//! Headless command-line entry points.
+//!
+//! When no arguments are given the GUI starts as usual; otherwise the
+//! requested command runs to completion and the process exits.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Serialize;
+use thiserror::Error;
+use versi_backend::{
+    BackendProvider, InstalledVersion, NodeVersion, RemoteVersion, VersionManager,
+};
+use versi_core::AppUpdate;
+
+use crate::settings::AppSettings;
+
+pub const USAGE: &str = "Usage: versi [--json] [--version | --export-settings <path> | --print-default | install <version> | list | list-remote | check-update]";
+
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+
+/// Reconnect stdout and stderr to the console `versi` was run from.
+///
+/// The release binary uses the GUI subsystem, so Windows starts it without a
+/// console and CLI output would otherwise go nowhere.
+#[cfg(windows)]
+pub fn attach_parent_console() {
+    use windows_sys::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
+
+    // SAFETY: `AttachConsole` takes no pointers. It fails harmlessly when the
+    // parent has no console, e.g. when launched from Explorer.
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Version,
+    ExportSettings { path: PathBuf },
+    PrintDefault,
+    Install { version: String },
+    ListInstalled,
+    ListRemote,
+    CheckUpdate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliInvocation {
+    pub command: CliCommand,
+    /// Emit machine-readable JSON instead of human-readable text.
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CliError {
+    #[error("missing value for {flag}")]
+    MissingValue { flag: &'static str },
+    #[error("unrecognized argument: {0}")]
+    UnknownArgument(String),
+    #[error("no command given")]
+    MissingCommand,
+}
+
+/// Parse process arguments (without the program name).
+///
+/// Returns `Ok(None)` when no arguments were given and the GUI should start.
+/// `--json` may appear anywhere in the argument list.
+///
+/// # Errors
+/// Returns an error for unknown arguments or flags missing their value.
+pub fn parse_args<I>(args: I) -> Result<Option<CliInvocation>, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args: Vec<String> = args.into_iter().collect();
+    if args.is_empty() {
+        return Ok(None);
+    }
+
+    let json = args.iter().any(|arg| arg == "--json");
+    args.retain(|arg| arg != "--json");
+    let mut args = args.into_iter();
+    let first = args.next().ok_or(CliError::MissingCommand)?;
+
+    let command = match first.as_str() {
+        "--version" => CliCommand::Version,
+        "--export-settings" => {
+            let path = args.next().ok_or(CliError::MissingValue {
+                flag: "--export-settings",
+            })?;
+            CliCommand::ExportSettings {
+                path: PathBuf::from(path),
+            }
+        }
+        "--print-default" => CliCommand::PrintDefault,
+        "install" => {
+            let version = args
+                .next()
+                .ok_or(CliError::MissingValue { flag: "install" })?;
+            CliCommand::Install { version }
+        }
+        "list" => CliCommand::ListInstalled,
+        "list-remote" => CliCommand::ListRemote,
+        "check-update" => CliCommand::CheckUpdate,
+        _ => return Err(CliError::UnknownArgument(first)),
+    };
+
+    if let Some(extra) = args.next() {
+        return Err(CliError::UnknownArgument(extra));
+    }
+
+    Ok(Some(CliInvocation { command, json }))
+}
+
+pub fn run(invocation: CliInvocation) -> i32 {
+    let json = invocation.json;
+    match invocation.command {
+        // Answered without loading settings, so probing a freshly installed
+        // binary can't migrate the settings file under the running version.
+        CliCommand::Version => {
+            println!("versi {}", env!("CARGO_PKG_VERSION"));
+            EXIT_SUCCESS
+        }
+        CliCommand::ExportSettings { path } => with_settings(|runtime, settings| {
+            match runtime.block_on(crate::app::export_settings_to_path(settings, &path)) {
+                Ok(path) => {
+                    if json {
+                        print_json(&serde_json::json!({ "path": path }))
+                    } else {
+                        println!("Settings exported to {}", path.display());
+                        EXIT_SUCCESS
+                    }
+                }
+                Err(error) => {
+                    eprintln!("Error: {error}");
+                    EXIT_FAILURE
+                }
+            }
+        }),
+        CliCommand::PrintDefault => {
+            with_settings(|runtime, settings| runtime.block_on(print_default(settings, json)))
+        }
+        CliCommand::Install { version } => {
+            with_settings(|runtime, settings| runtime.block_on(install(settings, &version)))
+        }
+        CliCommand::ListInstalled => {
+            with_settings(|runtime, settings| runtime.block_on(list_installed(settings, json)))
+        }
+        CliCommand::ListRemote => {
+            with_settings(|runtime, settings| runtime.block_on(list_remote(settings, json)))
+        }
+        CliCommand::CheckUpdate => {
+            with_settings(|runtime, settings| runtime.block_on(check_update(settings, json)))
+        }
+    }
+}
+
+/// Start an async runtime and load settings, then run `command` with both.
+fn with_settings(command: impl FnOnce(&tokio::runtime::Runtime, &AppSettings) -> i32) -> i32 {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            eprintln!("Error: failed to start async runtime: {error}");
+            return EXIT_FAILURE;
+        }
+    };
+
+    let settings = AppSettings::load();
+    command(&runtime, &settings)
+}
+
+async fn detect_manager(settings: &AppSettings) -> Option<Arc<dyn VersionManager>> {
+    let manager = crate::app::detect_native_manager(&providers(), settings.preferred_backend).await;
+    if manager.is_none() {
+        eprintln!("Error: no supported Node.js version manager was found");
+    }
+    manager
+}
+
+async fn print_default(settings: &AppSettings, json: bool) -> i32 {
+    let Some(manager) = detect_manager(settings).await else {
+        return EXIT_FAILURE;
+    };
+
+    match manager.default_version().await {
+        Ok(version) if json => print_json(&DefaultVersionOutput { default: version }),
+        Ok(Some(version)) => {
+            println!("{version}");
+            EXIT_SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("No default Node.js version is set");
+            EXIT_FAILURE
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+async fn install(settings: &AppSettings, version: &str) -> i32 {
+    let Some(manager) = detect_manager(settings).await else {
+        return EXIT_FAILURE;
+    };
+
+    install_with_manager(manager.as_ref(), version).await
+}
+
+async fn install_with_manager(manager: &dyn VersionManager, version: &str) -> i32 {
+    println!("Installing Node.js {version} with {}...", manager.name());
+    match manager.install(version).await {
+        Ok(()) => {
+            println!("Installed Node.js {version}");
+            EXIT_SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: failed to install Node.js {version}: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+async fn list_installed(settings: &AppSettings, json: bool) -> i32 {
+    let Some(manager) = detect_manager(settings).await else {
+        return EXIT_FAILURE;
+    };
+
+    match manager.list_installed().await {
+        Ok(mut versions) => {
+            versions.sort_by(|a, b| b.version.cmp(&a.version));
+            if json {
+                print_json(&versions)
+            } else {
+                print!("{}", format_installed(&versions));
+                EXIT_SUCCESS
+            }
+        }
+        Err(error) => {
+            eprintln!("Error: failed to list installed versions: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+async fn list_remote(settings: &AppSettings, json: bool) -> i32 {
+    let Some(manager) = detect_manager(settings).await else {
+        return EXIT_FAILURE;
+    };
+
+    match manager.list_remote().await {
+        Ok(mut versions) => {
+            versions.sort_by(|a, b| b.version.cmp(&a.version));
+            if json {
+                print_json(&versions)
+            } else {
+                print!("{}", format_remote(&versions));
+                EXIT_SUCCESS
+            }
+        }
+        Err(error) => {
+            eprintln!("Error: failed to list remote versions: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+async fn check_update(settings: &AppSettings, json: bool) -> i32 {
+    let client = match reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(settings.http_timeout_secs))
+        .user_agent(format!("versi/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+    {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("Error: failed to build HTTP client: {error}");
+            return EXIT_FAILURE;
+        }
+    };
+
+    let current_version = env!("CARGO_PKG_VERSION");
+    match versi_core::check_for_update(
+        &client,
+        current_version,
+        &settings.update_repo,
+        settings.update_channel,
+    )
+    .await
+    {
+        Ok(update) if json => print_json(&UpdateStatusOutput {
+            current_version,
+            update_available: update.is_some(),
+            update,
+        }),
+        Ok(Some(update)) => {
+            println!(
+                "Versi {} is available (current: {current_version})\n{}",
+                update.latest_version, update.release_url
+            );
+            EXIT_SUCCESS
+        }
+        Ok(None) => {
+            println!("Versi {current_version} is up to date");
+            EXIT_SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DefaultVersionOutput {
+    default: Option<NodeVersion>,
+}
+
+#[derive(Serialize)]
+struct UpdateStatusOutput<'a> {
+    current_version: &'a str,
+    update_available: bool,
+    update: Option<AppUpdate>,
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> i32 {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => {
+            println!("{json}");
+            EXIT_SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: failed to serialize output: {error}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+fn format_installed(versions: &[InstalledVersion]) -> String {
+    let mut output = String::new();
+    for installed in versions {
+        let _ = write!(output, "{}", installed.version);
+        if let Some(codename) = &installed.lts_codename {
+            let _ = write!(output, " (LTS: {codename})");
+        }
+        if installed.is_default {
+            output.push_str(" [default]");
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn format_remote(versions: &[RemoteVersion]) -> String {
+    let mut output = String::new();
+    for remote in versions {
+        let _ = write!(output, "{}", remote.version);
+        if let Some(codename) = &remote.lts_codename {
+            let _ = write!(output, " (LTS: {codename})");
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn providers() -> Vec<Arc<dyn BackendProvider>> {
+    vec![
+        Arc::new(versi_fnm::FnmProvider::new()),
+        Arc::new(versi_nvm::NvmProvider::new()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use versi_backend::{
+        BackendError, BackendInfo, InstalledVersion, ManagerCapabilities, NodeVersion,
+        RemoteVersion, ShellInitOptions, VersionManager,
+    };
+
+    use super::{
+        CliCommand, CliError, CliInvocation, EXIT_FAILURE, EXIT_SUCCESS, format_installed,
+        install_with_manager, parse_args,
+    };
+
+    struct RecordingManager {
+        info: BackendInfo,
+        fail: bool,
+        installs: Mutex<Vec<String>>,
+    }
+
+    impl RecordingManager {
+        fn new(fail: bool) -> Self {
+            Self {
+                info: BackendInfo {
+                    name: "mock",
+                    path: PathBuf::from("/tmp/mock-backend"),
+                    version: None,
+                    data_dir: None,
+                    in_path: true,
+                },
+                fail,
+                installs: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl VersionManager for RecordingManager {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn capabilities(&self) -> ManagerCapabilities {
+            ManagerCapabilities::default()
+        }
+
+        fn backend_info(&self) -> &BackendInfo {
+            &self.info
+        }
+
+        async fn list_installed(&self) -> Result<Vec<InstalledVersion>, BackendError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_remote(&self) -> Result<Vec<RemoteVersion>, BackendError> {
+            Ok(Vec::new())
+        }
+
+        async fn current_version(&self) -> Result<Option<NodeVersion>, BackendError> {
+            Ok(None)
+        }
+
+        async fn default_version(&self) -> Result<Option<NodeVersion>, BackendError> {
+            Ok(None)
+        }
+
+        async fn install(&self, version: &str) -> Result<(), BackendError> {
+            self.installs
+                .lock()
+                .expect("install log lock")
+                .push(version.to_string());
+            if self.fail {
+                Err(BackendError::install_failed("download", "network down"))
+            } else {
+                Ok(())
+            }
+        }
+
+        async fn uninstall(&self, _version: &str) -> Result<(), BackendError> {
+            Ok(())
+        }
+
+        async fn set_default(&self, _version: &str) -> Result<(), BackendError> {
+            Ok(())
+        }
+
+        fn shell_init_command(&self, _shell: &str, _options: &ShellInitOptions) -> Option<String> {
+            None
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_string()).collect()
+    }
+
+    fn human(command: CliCommand) -> Option<CliInvocation> {
+        Some(CliInvocation {
+            command,
+            json: false,
+        })
+    }
+
+    fn sample_installed() -> Vec<InstalledVersion> {
+        vec![
+            InstalledVersion {
+                version: NodeVersion::new(22, 11, 0),
+                is_default: true,
+                lts_codename: Some("Jod".to_string()),
+                install_date: None,
+                disk_size: Some(1024),
+            },
+            InstalledVersion {
+                version: NodeVersion::new(23, 1, 0),
+                is_default: false,
+                lts_codename: None,
+                install_date: None,
+                disk_size: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn no_arguments_launches_gui() {
+        assert_eq!(parse_args(args(&[])), Ok(None));
+    }
+
+    #[test]
+    fn export_settings_requires_path() {
+        assert_eq!(
+            parse_args(args(&["--export-settings", "/tmp/versi.json"])),
+            Ok(human(CliCommand::ExportSettings {
+                path: PathBuf::from("/tmp/versi.json")
+            }))
+        );
+        assert_eq!(
+            parse_args(args(&["--export-settings"])),
+            Err(CliError::MissingValue {
+                flag: "--export-settings"
+            })
+        );
+    }
+
+    #[test]
+    fn print_default_takes_no_value() {
+        assert_eq!(
+            parse_args(args(&["--print-default"])),
+            Ok(human(CliCommand::PrintDefault))
+        );
+        assert_eq!(
+            parse_args(args(&["--print-default", "extra"])),
+            Err(CliError::UnknownArgument("extra".to_string()))
+        );
+    }
+
+    #[test]
+    fn version_flag_is_a_command() {
+        assert_eq!(
+            parse_args(args(&["--version"])),
+            Ok(human(CliCommand::Version))
+        );
+    }
+
+    #[test]
+    fn unknown_arguments_are_rejected() {
+        assert_eq!(
+            parse_args(args(&["--bogus"])),
+            Err(CliError::UnknownArgument("--bogus".to_string()))
+        );
+    }
+
+    #[test]
+    fn install_subcommand_requires_version() {
+        assert_eq!(
+            parse_args(args(&["install", "22"])),
+            Ok(human(CliCommand::Install {
+                version: "22".to_string()
+            }))
+        );
+        assert_eq!(
+            parse_args(args(&["install"])),
+            Err(CliError::MissingValue { flag: "install" })
+        );
+    }
+
+    #[test]
+    fn json_flag_is_accepted_anywhere() {
+        let expected = Some(CliInvocation {
+            command: CliCommand::ListInstalled,
+            json: true,
+        });
+        assert_eq!(parse_args(args(&["--json", "list"])), Ok(expected.clone()));
+        assert_eq!(parse_args(args(&["list", "--json"])), Ok(expected));
+        assert_eq!(
+            parse_args(args(&["list-remote"])),
+            Ok(human(CliCommand::ListRemote))
+        );
+        assert_eq!(parse_args(args(&["--json"])), Err(CliError::MissingCommand));
+    }
+
+    #[test]
+    fn installed_versions_serialize_to_expected_shape() {
+        let value = serde_json::to_value(sample_installed()).expect("serialize installed versions");
+
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {
+                    "version": { "major": 22, "minor": 11, "patch": 0 },
+                    "is_default": true,
+                    "lts_codename": "Jod",
+                    "install_date": null,
+                    "disk_size": 1024
+                },
+                {
+                    "version": { "major": 23, "minor": 1, "patch": 0 },
+                    "is_default": false,
+                    "lts_codename": null,
+                    "install_date": null,
+                    "disk_size": null
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn human_output_lists_one_version_per_line() {
+        assert_eq!(
+            format_installed(&sample_installed()),
+            "v22.11.0 (LTS: Jod) [default]\nv23.1.0\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn install_dispatches_to_manager_and_reports_exit_code() {
+        let manager = RecordingManager::new(false);
+        assert_eq!(
+            install_with_manager(&manager, "v22.1.0").await,
+            EXIT_SUCCESS
+        );
+        assert_eq!(
+            *manager.installs.lock().expect("install log lock"),
+            vec!["v22.1.0".to_string()]
+        );
+
+        let failing = RecordingManager::new(true);
+        assert_eq!(
+            install_with_manager(&failing, "v20.0.0").await,
+            EXIT_FAILURE
+        );
+        assert_eq!(
+            *failing.installs.lock().expect("install log lock"),
+            vec!["v20.0.0".to_string()]
+        );
+    }
+}
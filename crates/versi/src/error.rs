@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use versi_platform::BYTES_PER_MB;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AppErrorDetail {
     Message(String),
@@ -71,6 +75,18 @@ impl From<versi_shell::WslShellConfigError> for AppErrorDetail {
     }
 }
 
+impl From<versi_shell::ProbeError> for AppErrorDetail {
+    fn from(value: versi_shell::ProbeError) -> Self {
+        match value {
+            versi_shell::ProbeError::Spawn { program, source } => Self::Io {
+                kind: source.kind(),
+                message: format!("failed to start {program}: {source}"),
+            },
+            other => Self::Message(other.to_string()),
+        }
+    }
+}
+
 impl From<versi_core::auto_update::AutoUpdateError> for AppErrorDetail {
     fn from(value: versi_core::auto_update::AutoUpdateError) -> Self {
         Self::Message(value.to_string())
@@ -157,6 +173,11 @@ pub enum AppError {
         target: &'static str,
         details: AppErrorDetail,
     },
+    InsufficientSpace {
+        dir: PathBuf,
+        needed: u64,
+        available: u64,
+    },
 }
 
 impl AppError {
@@ -321,6 +342,17 @@ impl std::fmt::Display for AppError {
             Self::UpdateCheckFailed { target, details } => {
                 write!(f, "{target} update check failed: {details}")
             }
+            Self::InsufficientSpace {
+                dir,
+                needed,
+                available,
+            } => write!(
+                f,
+                "Not enough free space in {} (about {} MB needed, {} MB available)",
+                dir.display(),
+                needed.div_ceil(BYTES_PER_MB),
+                available / BYTES_PER_MB
+            ),
         }
     }
 }
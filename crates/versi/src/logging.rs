@@ -69,7 +69,7 @@ impl Write for ResilientFileWriter {
     }
 }
 
-fn trim_log_file_if_oversized(log_path: &Path, max_log_size: u64) {
+pub(crate) fn trim_log_file_if_oversized(log_path: &Path, max_log_size: u64) {
     if let Ok(metadata) = std::fs::metadata(log_path)
         && metadata.len() > max_log_size
         && let Ok(contents) = std::fs::read(log_path)
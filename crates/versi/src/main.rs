@@ -3,17 +3,24 @@
 use iced::window;
 
 mod app;
+mod atomic_file;
+mod audit_log;
 mod backend_kind;
 mod cache;
+mod cli;
 mod error;
 mod icon;
 mod logging;
 mod message;
+mod schema;
 mod settings;
 mod single_instance;
 mod state;
+mod strings;
 mod theme;
 mod tray;
+mod version_display;
+mod version_files;
 mod version_query;
 mod views;
 mod widgets;
@@ -21,25 +28,55 @@ mod widgets;
 mod windows_window;
 
 fn main() -> iced::Result {
+    let invocation = cli::parse_args(std::env::args().skip(1));
+    #[cfg(windows)]
+    if !matches!(invocation, Ok(None)) {
+        cli::attach_parent_console();
+    }
+    match invocation {
+        Ok(None) => {}
+        Ok(Some(invocation)) => std::process::exit(cli::run(invocation)),
+        Err(error) => {
+            eprintln!("Error: {error}\n{}", cli::USAGE);
+            std::process::exit(cli::EXIT_USAGE);
+        }
+    }
+
+    match versi_platform::AppPaths::new() {
+        Ok(paths) if versi_platform::AppPaths::is_overridden() => {
+            if let Err(e) = paths.check_writable() {
+                eprintln!(
+                    "Error: {} ({}) is not writable: {e}",
+                    versi_platform::DATA_DIR_ENV,
+                    paths.data_dir.display()
+                );
+                std::process::exit(1);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!(
+                "Warning: {e}. Versi will run without saving settings, cache or logs. Please ensure your system environment is configured correctly."
+            );
+        }
+    }
+
     let _instance_guard = match single_instance::SingleInstance::acquire() {
-        Ok(guard) => guard,
+        Ok(guard) => Some(guard),
         Err(single_instance::AcquireError::AlreadyRunning) => {
             single_instance::bring_existing_window_to_front();
             return Ok(());
         }
+        Err(single_instance::AcquireError::Paths(error)) => {
+            eprintln!("Warning: {error}. Running without the single-instance lock.");
+            None
+        }
         Err(error) => {
             eprintln!("Error: failed to acquire single-instance lock: {error}");
             std::process::exit(1);
         }
     };
 
-    if let Err(e) = versi_platform::AppPaths::new() {
-        eprintln!(
-            "Error: {e}. Versi cannot determine where to store its data. Please ensure your system environment is configured correctly."
-        );
-        std::process::exit(1);
-    }
-
     let settings = settings::AppSettings::load();
     logging::init_logging(settings.debug_logging, settings.max_log_size_bytes);
 
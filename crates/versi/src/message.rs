@@ -1,15 +1,21 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use versi_backend::{BackendUpdate, InstalledVersion, RemoteVersion};
+use versi_backend::{
+    BackendError, BackendUpdate, InstalledVersion, NodeArchive, NodeVersion, RemoteVersion,
+    VersionAlias,
+};
 use versi_core::{AppUpdate, ReleaseSchedule, VersionMeta};
 use versi_platform::EnvironmentId;
 use versi_shell::ShellType;
 
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
-use crate::settings::{AppUpdateBehavior, TrayBehavior};
-use crate::state::SearchFilter;
+use crate::settings::{
+    AppSettings, AppUpdateBehavior, ConfirmUninstall, DefaultOnInstall, MetadataField,
+    NumericSetting, SettingsSection, TrayBehavior,
+};
+use crate::state::{SearchFilter, VersionSortMode};
 use crate::tray::TrayMessage;
 
 #[derive(Debug, Clone)]
@@ -25,8 +31,23 @@ pub enum Message {
         request_seq: u64,
         result: Result<Vec<InstalledVersion>, AppError>,
     },
+    CurrentVersionLoaded {
+        env_id: EnvironmentId,
+        version: Option<NodeVersion>,
+    },
+    InstalledVersionsValidated {
+        env_id: EnvironmentId,
+        broken: Vec<NodeVersion>,
+    },
+    AliasesLoaded {
+        env_id: EnvironmentId,
+        aliases: Vec<VersionAlias>,
+    },
     RefreshEnvironment,
+    RefreshWslEnvironments,
+    WslEnvironmentsRefreshed(Vec<EnvironmentInfo>),
     FocusSearch,
+    CycleModalFocus,
     SelectPreviousVersion,
     SelectNextVersion,
     ActivateSelectedVersion,
@@ -34,8 +55,16 @@ pub enum Message {
     VersionGroupToggled {
         major: u32,
     },
+    SearchGroupToggled {
+        major: u32,
+    },
+    ShowMoreSearchResults,
     SearchChanged(String),
     SearchFilterToggled(SearchFilter),
+    SearchFilterShortcut(SearchFilter),
+    ClearSearchFilters,
+    SortModeChanged(VersionSortMode),
+    TogglePinnedVersion(String),
 
     FetchRemoteVersions,
     RemoteVersionsFetched {
@@ -46,25 +75,64 @@ pub enum Message {
         request_seq: u64,
         result: Box<Result<ReleaseSchedule, AppError>>,
     },
+    ClearVersionCache,
+    ConfirmClearVersionCache,
+    VersionCacheCleared(Result<(), AppError>),
+    ProjectVersionFileFound(Option<crate::version_files::VersionFileSuggestion>),
 
     CloseModal,
     OpenChangelog(String),
+    OpenTerminalWithVersion(String),
+    TerminalOpened(Result<(), AppError>),
     StartInstall(String),
+    InstallLatestLts,
     InstallComplete {
         version: String,
         success: bool,
         error: Option<AppError>,
     },
+    InstallVerified {
+        version: String,
+        result: Result<bool, AppError>,
+    },
+
+    RequestInstallFromArchive,
+    InstallArchivePicked(Option<PathBuf>),
+    InstallArchiveInspected {
+        path: PathBuf,
+        result: Result<NodeArchive, BackendError>,
+    },
+    ArchiveInstallComplete {
+        version: String,
+        success: bool,
+        error: Option<AppError>,
+    },
 
     RequestUninstall(String),
+    ReinstallVersion(String),
+    ConfirmReinstall(String),
+    ConfirmUninstall(String),
     ConfirmUninstallDefault(String),
     UninstallComplete {
         version: String,
         success: bool,
         error: Option<AppError>,
     },
+    UndoUninstall {
+        environment: EnvironmentId,
+        version: String,
+    },
+    RevertDefault {
+        environment: EnvironmentId,
+        version: String,
+    },
+    RetryInstall {
+        version: String,
+        timeout_secs: u64,
+    },
 
     RequestBulkUpdateMajors,
+    UpdatableViewToggled(bool),
     RequestBulkUninstallEOL,
     RequestBulkUninstallMajor {
         major: u32,
@@ -80,41 +148,115 @@ pub enum Message {
     ConfirmBulkUninstallMajorExceptLatest {
         major: u32,
     },
+    RequestApplyRetentionPolicy,
+    ConfirmApplyRetentionPolicy,
+    SelectionModeToggled(bool),
+    VersionSelectionToggled(String),
+    RequestBulkUninstallSelected,
+    ConfirmBulkUninstallSelected,
     CancelBulkOperation,
 
     SetDefault(String),
+    ConfirmEolDefault(String),
+    ShowLastError,
+    AcknowledgeLastError,
     DefaultChanged {
         success: bool,
         error: Option<AppError>,
     },
 
+    RequestCreateAlias(String),
+    AliasNameChanged(String),
+    ConfirmCreateAlias,
+    RemoveAlias(String),
+    ConfirmRemoveAlias(String),
+    AliasChanged(Result<(), AppError>),
+
+    VersionNoteChanged(String),
+    SaveVersionNote,
+
+    RequestCloneEnvironment(EnvironmentId),
+    ConfirmCloneEnvironment,
+    RequestMigration(BackendKind),
+    MigrationSourceLoaded {
+        source: BackendKind,
+        result: Result<Vec<InstalledVersion>, AppError>,
+    },
+    MigrationSetDefaultToggled(bool),
+    ConfirmMigration,
+    SetCorepack {
+        version: String,
+        enabled: bool,
+    },
+    CorepackChanged {
+        version: String,
+        enabled: bool,
+        result: Result<(), AppError>,
+    },
+
     ToastDismiss(usize),
+    DismissBanner(String),
 
     NavigateToVersions,
     NavigateToSettings,
     NavigateToAbout,
     VersionRowHovered(Option<String>),
     ThemeChanged(crate::settings::ThemeSetting),
+    ReducedMotionToggled(bool),
+    VersionPrefixToggled(bool),
+    LanguageChanged(Option<crate::strings::Language>),
     AppUpdateBehaviorChanged(AppUpdateBehavior),
+    UpdateRepoInputChanged(String),
+    UpdateRepoSubmitted,
+    UpdateChannelChanged(versi_core::UpdateChannel),
+    DefaultOnInstallChanged(DefaultOnInstall),
+    ConfirmUninstallChanged(ConfirmUninstall),
+    ConfirmEolDefaultToggled(bool),
+    VerifyInstallsToggled(bool),
+    FetchVersionMetadataToggled(bool),
+    MetadataFieldToggled(MetadataField, bool),
+    PatchRetentionChanged(Option<usize>),
     ShellOptionUseOnCdToggled(bool),
     ShellOptionResolveEnginesToggled(bool),
     ShellOptionCorepackEnabledToggled(bool),
     DebugLoggingToggled(bool),
     CopyToClipboard(String),
+    CopySearchResults,
+    CopyInstalledVersions,
+    CopyEnvironmentSnippet(BackendKind),
     ClearLogFile,
     LogFileCleared,
     RevealLogFile,
     RevealSettingsFile,
     LogFileStatsLoaded(Option<u64>),
+    ShowAuditLog,
+    AuditLogLoaded(Vec<crate::audit_log::AuditRecord>),
+    HideAuditLog,
+    CheckNpmRegistry,
+    NpmRegistryChecked {
+        registry: String,
+        status: versi_core::RegistryStatus,
+    },
     ShellSetupChecked(Vec<(ShellType, versi_shell::VerificationResult)>),
     ConfigureShell(ShellType),
     ShellConfigured(ShellType, Result<(), AppError>),
+    RepairShellConfig(ShellType),
+    ShellConfigRepaired(ShellType, Result<Vec<String>, AppError>),
+    TestShellIntegration(ShellType),
+    ShellIntegrationTested(ShellType, Result<std::path::PathBuf, AppError>),
     ShellFlagsUpdated,
+    NodePathChecked(Option<versi_shell::NodePathConflict>),
 
     ExportSettings,
     SettingsExported(Result<std::path::PathBuf, AppError>),
     ImportSettings,
-    SettingsImported(Result<(), AppError>),
+    SettingsImported(Result<Box<AppSettings>, AppError>),
+    SettingsImportSectionToggled(SettingsSection, bool),
+    ResetSettingsSection(SettingsSection),
+    NumericInputChanged(NumericSetting, String),
+    NumericSubmitted(NumericSetting),
+    DiscardSettingsChanges,
+    ConfirmSettingsImport,
 
     PreferredBackendChanged(BackendKind),
 
@@ -125,6 +267,14 @@ pub enum Message {
     OnboardingBackendInstallResult(Result<(), AppError>),
     OnboardingConfigureShell(ShellType),
     OnboardingShellConfigResult(Result<(), AppError>),
+    OnboardingShellVerified {
+        shell_type: ShellType,
+        result: versi_shell::VerificationResult,
+        in_current_session: bool,
+    },
+    OnboardingVersionSuggestionFound(Option<crate::version_files::VersionFileSuggestion>),
+    OnboardingInstallSuggestedToggled(bool),
+    OnboardingInstallLatestLtsToggled(bool),
     OnboardingComplete,
 
     AnimationTick,
@@ -134,14 +284,20 @@ pub enum Message {
     HideDockIcon,
 
     TrayEvent(TrayMessage),
+    ConfirmTraySetDefault {
+        env_id: EnvironmentId,
+        version: String,
+    },
     TrayBehaviorChanged(TrayBehavior),
     StartMinimizedToggled(bool),
+    ConfirmTrayEnvironmentSwitchToggled(bool),
     LaunchAtLoginToggled(bool),
     WindowOpened(iced::window::Id),
 
     AppUpdateChecked(Box<Result<Option<AppUpdate>, AppError>>),
     OpenAppUpdate,
     StartAppUpdate,
+    CancelAppUpdate,
     AppUpdateProgress {
         downloaded: u64,
         total: u64,
@@ -150,8 +306,16 @@ pub enum Message {
     AppUpdateApplying,
     AppUpdateComplete(Box<Result<versi_core::auto_update::ApplyResult, AppError>>),
     RestartApp,
-    BackendUpdateChecked(Box<Result<Option<BackendUpdate>, AppError>>),
+    BackendUpdateChecked {
+        env_id: EnvironmentId,
+        result: Box<Result<Option<BackendUpdate>, AppError>>,
+    },
     OpenBackendUpdate,
+    RecheckBackendVersion,
+    BackendVersionRechecked {
+        env_id: EnvironmentId,
+        version: Option<String>,
+    },
 
     FetchReleaseSchedule,
     FetchVersionMetadata,
@@ -173,6 +337,7 @@ pub enum Message {
     OpenLink(String),
 
     SystemThemeChanged(iced::theme::Mode),
+    SystemReducedMotionDetected(bool),
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,94 @@
+//! Schema versioning for the JSON files Versi keeps on disk.
+//!
+//! Each file stores a `version` field. Files written before versioning existed
+//! have no field and are treated as version 1. On load, the raw JSON is passed
+//! through one migration per version bump before it is deserialized, so older
+//! files are upgraded instead of falling back to defaults.
+
+use serde_json::{Map, Value};
+
+pub const VERSION_KEY: &str = "version";
+
+/// Upgrades a document from version `n` to `n + 1`.
+pub type Migration = fn(&mut Map<String, Value>);
+
+/// The schema version written by this build for a file with `migrations`.
+pub fn current_version(migrations: &[Migration]) -> u32 {
+    u32::try_from(migrations.len()).map_or(u32::MAX, |count| count.saturating_add(1))
+}
+
+/// Run every migration newer than the document's version and stamp it with
+/// the resulting version. `migrations[0]` upgrades v1 to v2, and so on.
+///
+/// Documents from a newer build are left untouched. Returns the version the
+/// document had before migrating.
+pub fn migrate(value: &mut Value, migrations: &[Migration]) -> u32 {
+    let Some(object) = value.as_object_mut() else {
+        return 0;
+    };
+
+    let original = object
+        .get(VERSION_KEY)
+        .and_then(Value::as_u64)
+        .map_or(1, |version| u32::try_from(version).unwrap_or(u32::MAX))
+        .max(1);
+
+    let mut version = original;
+    while let Some(step) = usize::try_from(version - 1)
+        .ok()
+        .and_then(|index| migrations.get(index))
+    {
+        step(object);
+        version += 1;
+    }
+
+    if version != original {
+        log::info!("Migrated file schema from v{original} to v{version}");
+    }
+    object.insert(VERSION_KEY.to_string(), Value::from(version));
+    original
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Map, Value, json};
+
+    use super::{Migration, current_version, migrate};
+
+    fn rename_a_to_b(object: &mut Map<String, Value>) {
+        if let Some(value) = object.remove("a") {
+            object.insert("b".to_string(), value);
+        }
+    }
+
+    fn add_c(object: &mut Map<String, Value>) {
+        object.insert("c".to_string(), json!(true));
+    }
+
+    const MIGRATIONS: &[Migration] = &[rename_a_to_b, add_c];
+
+    #[test]
+    fn unversioned_documents_run_every_migration() {
+        let mut value = json!({ "a": 1 });
+
+        assert_eq!(migrate(&mut value, MIGRATIONS), 1);
+        assert_eq!(value, json!({ "b": 1, "c": true, "version": 3 }));
+        assert_eq!(current_version(MIGRATIONS), 3);
+    }
+
+    #[test]
+    fn migrations_start_from_the_stored_version() {
+        let mut value = json!({ "a": 1, "version": 2 });
+
+        assert_eq!(migrate(&mut value, MIGRATIONS), 2);
+        assert_eq!(value, json!({ "a": 1, "c": true, "version": 3 }));
+    }
+
+    #[test]
+    fn newer_documents_are_left_alone() {
+        let mut value = json!({ "a": 1, "version": 9 });
+
+        assert_eq!(migrate(&mut value, MIGRATIONS), 9);
+        assert_eq!(value, json!({ "a": 1, "version": 9 }));
+    }
+}
@@ -1,16 +1,42 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use versi_platform::AppPaths;
+use versi_backend::ShellOptionSupport;
+use versi_core::UpdateChannel;
+use versi_platform::BYTES_PER_MB;
+use versi_platform::{AppPaths, EnvironmentId};
 
 use crate::backend_kind::BackendKind;
+use crate::schema::{self, Migration, current_version};
+use crate::state::SearchFilter;
+use crate::strings::Language;
+
+/// Settings file schema migrations; see [`crate::schema`].
+const SETTINGS_MIGRATIONS: &[Migration] = &[migrate_settings_v1_to_v2];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
+    #[serde(default = "legacy_schema_version")]
+    pub version: u32,
+
     #[serde(default)]
     pub theme: ThemeSetting,
 
+    /// Replace animations with static indicators. The OS preference is
+    /// honoured as well when it can be detected.
+    #[serde(default)]
+    pub reduced_motion: bool,
+
+    /// Show versions as `v20.11.0` rather than `20.11.0`.
+    #[serde(default = "default_true")]
+    pub version_prefix: bool,
+
+    /// UI language. `None` follows the system locale.
+    #[serde(default)]
+    pub language: Option<Language>,
+
     #[serde(default = "default_cache_ttl")]
     pub cache_ttl_hours: u64,
 
@@ -23,9 +49,13 @@ pub struct AppSettings {
     #[serde(default)]
     pub launch_at_login: bool,
 
+    #[serde(default)]
+    pub confirm_tray_environment_switch: bool,
+
     #[serde(default)]
     pub fnm_dir: Option<PathBuf>,
 
+    /// Base URL for Node.js downloads; kept without trailing slashes.
     #[serde(default)]
     pub node_dist_mirror: Option<String>,
 
@@ -36,18 +66,75 @@ pub struct AppSettings {
     )]
     pub backend_shell_options: HashMap<BackendKind, ShellOptions>,
 
-    #[serde(default, skip_serializing)]
-    shell_options: Option<ShellOptions>,
-
     #[serde(default)]
     pub preferred_backend: Option<BackendKind>,
 
+    /// Environment that was active on exit, selected again at startup.
+    #[serde(default)]
+    pub last_environment: Option<EnvironmentId>,
+
     #[serde(default)]
     pub debug_logging: bool,
 
     #[serde(default)]
     pub app_update_behavior: AppUpdateBehavior,
 
+    /// GitHub `owner/name` that app updates are checked against.
+    #[serde(default = "default_update_repo")]
+    pub update_repo: String,
+
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+
+    /// Where app updates are downloaded, instead of the cache directory.
+    #[serde(default)]
+    pub update_download_dir: Option<PathBuf>,
+
+    #[serde(default)]
+    pub set_default_on_install: DefaultOnInstall,
+
+    #[serde(default)]
+    pub confirm_uninstall: ConfirmUninstall,
+
+    /// Ask before making an end-of-life version the default.
+    #[serde(default = "default_true")]
+    pub confirm_eol_default: bool,
+
+    #[serde(default)]
+    pub verify_installs: bool,
+
+    /// Download release metadata (npm, V8 and OpenSSL versions, release
+    /// dates). When off, the detail view only shows what the release list
+    /// and schedule provide.
+    #[serde(default = "default_true")]
+    pub fetch_version_metadata: bool,
+
+    /// Metadata rows left out of the version detail view.
+    #[serde(default)]
+    pub hidden_metadata_fields: Vec<MetadataField>,
+
+    /// Newest patch releases to keep per major when applying the retention
+    /// policy. `None` disables the policy.
+    #[serde(default)]
+    pub patches_to_keep_per_major: Option<usize>,
+
+    /// Search filters restored at startup. Unknown names are dropped on load.
+    #[serde(default, deserialize_with = "deserialize_search_filters")]
+    pub persisted_filters: Vec<SearchFilter>,
+
+    /// Installed versions shown in a "Pinned" section above the groups.
+    #[serde(default)]
+    pub pinned_versions: Vec<String>,
+
+    /// Free-form notes keyed by version string (`v20.11.0`).
+    #[serde(default)]
+    pub version_notes: HashMap<String, String>,
+
+    /// Banners the user closed, keyed by banner and the condition that
+    /// raised it (see [`AppSettings::dismiss_banner`]).
+    #[serde(default)]
+    pub dismissed_banners: BTreeSet<String>,
+
     #[serde(default)]
     pub window_geometry: Option<WindowGeometry>,
 
@@ -72,6 +159,11 @@ pub struct AppSettings {
     #[serde(default = "default_max_visible_toasts")]
     pub max_visible_toasts: usize,
 
+    /// How long an "Undo" action is offered after an uninstall. Zero turns
+    /// undo off.
+    #[serde(default = "default_undo_uninstall_secs")]
+    pub undo_uninstall_secs: u64,
+
     #[serde(default = "default_search_results_limit")]
     pub search_results_limit: usize,
 
@@ -85,7 +177,7 @@ pub struct AppSettings {
     pub retry_delays_secs: Vec<u64>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ShellOptions {
     #[serde(default = "default_true")]
     pub use_on_cd: bool,
@@ -101,6 +193,17 @@ fn default_true() -> bool {
     true
 }
 
+impl ShellOptions {
+    /// Clear flags `support` doesn't cover. Returns whether anything changed.
+    fn retain_supported(&mut self, support: ShellOptionSupport) -> bool {
+        let before = (self.use_on_cd, self.resolve_engines, self.corepack_enabled);
+        self.use_on_cd &= support.auto_switch;
+        self.resolve_engines &= support.resolve_engines;
+        self.corepack_enabled &= support.corepack;
+        before != (self.use_on_cd, self.resolve_engines, self.corepack_enabled)
+    }
+}
+
 impl Default for ShellOptions {
     fn default() -> Self {
         Self {
@@ -111,6 +214,27 @@ impl Default for ShellOptions {
     }
 }
 
+fn legacy_schema_version() -> u32 {
+    1
+}
+
+/// v1 stored a single `shell_options` object that applied to fnm, the only
+/// backend at the time. v2 keys shell options by backend.
+fn migrate_settings_v1_to_v2(settings: &mut Map<String, Value>) {
+    let Some(legacy) = settings.remove("shell_options") else {
+        return;
+    };
+    let has_backend_options = settings
+        .get("backend_shell_options")
+        .and_then(Value::as_object)
+        .is_some_and(|options| !options.is_empty());
+    if !has_backend_options && !legacy.is_null() {
+        let mut options = Map::new();
+        options.insert(BackendKind::Fnm.as_str().to_string(), legacy);
+        settings.insert("backend_shell_options".to_string(), Value::Object(options));
+    }
+}
+
 fn default_cache_ttl() -> u64 {
     1
 }
@@ -119,6 +243,10 @@ fn default_install_timeout() -> u64 {
     600
 }
 
+fn default_update_repo() -> String {
+    versi_core::DEFAULT_UPDATE_REPO.to_string()
+}
+
 fn default_operation_timeout() -> u64 {
     60
 }
@@ -135,6 +263,10 @@ fn default_toast_timeout() -> u64 {
     5
 }
 
+fn default_undo_uninstall_secs() -> u64 {
+    10
+}
+
 fn default_max_visible_toasts() -> usize {
     3
 }
@@ -155,18 +287,23 @@ fn default_retry_delays() -> Vec<u64> {
     vec![0, 2, 5, 15]
 }
 
-const CACHE_TTL_HOURS_RANGE: std::ops::RangeInclusive<u64> = 1..=168;
-const INSTALL_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 30..=7_200;
-const OPERATION_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 5..=900;
-const FETCH_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 5..=300;
-const HTTP_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 3..=120;
-const TOAST_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 1..=60;
-const MAX_VISIBLE_TOASTS_RANGE: std::ops::RangeInclusive<usize> = 1..=10;
-const SEARCH_RESULTS_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=200;
-const MODAL_PREVIEW_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=50;
-const MAX_LOG_SIZE_BYTES_RANGE: std::ops::RangeInclusive<u64> = 1_024 * 1_024..=100 * 1_024 * 1_024;
+// Allowed ranges, enforced by `sanitize_in_place` and shown next to the
+// matching inputs in the Settings view.
+pub const CACHE_TTL_HOURS_RANGE: std::ops::RangeInclusive<u64> = 1..=168;
+pub const INSTALL_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 30..=7_200;
+pub const OPERATION_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 5..=900;
+pub const FETCH_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 5..=300;
+pub const HTTP_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 3..=120;
+pub const TOAST_TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u64> = 1..=60;
+pub const UNDO_UNINSTALL_SECS_RANGE: std::ops::RangeInclusive<u64> = 0..=120;
+pub const MAX_VISIBLE_TOASTS_RANGE: std::ops::RangeInclusive<usize> = 1..=10;
+pub const SEARCH_RESULTS_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=200;
+pub const MODAL_PREVIEW_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=50;
+pub const MAX_LOG_SIZE_BYTES_RANGE: std::ops::RangeInclusive<u64> =
+    BYTES_PER_MB..=100 * BYTES_PER_MB;
 const MAX_RETRY_DELAY_SECS: u64 = 600;
 const MAX_RETRY_STEPS: usize = 8;
+const MAX_VERSION_NOTE_CHARS: usize = 200;
 
 fn deserialize_backend_shell_options<'de, D>(
     deserializer: D,
@@ -195,21 +332,60 @@ where
     raw.serialize(serializer)
 }
 
+fn deserialize_search_filters<'de, D>(deserializer: D) -> Result<Vec<SearchFilter>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::IntoDeserializer;
+
+    let raw = Vec::<String>::deserialize(deserializer)?;
+    let mut filters = Vec::with_capacity(raw.len());
+    for name in raw {
+        let parsed: Result<SearchFilter, serde::de::value::Error> =
+            SearchFilter::deserialize(name.as_str().into_deserializer());
+        match parsed {
+            Ok(filter) if !filters.contains(&filter) => filters.push(filter),
+            Ok(_) => {}
+            Err(_) => log::warn!("Ignoring unknown search filter {name:?} in settings"),
+        }
+    }
+    Ok(filters)
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
+            version: current_version(SETTINGS_MIGRATIONS),
             theme: ThemeSetting::System,
+            reduced_motion: false,
+            version_prefix: true,
+            language: None,
             cache_ttl_hours: 1,
             tray_behavior: TrayBehavior::WhenWindowOpen,
             start_minimized: false,
             launch_at_login: false,
+            confirm_tray_environment_switch: false,
             fnm_dir: None,
             node_dist_mirror: None,
             preferred_backend: None,
+            last_environment: None,
             backend_shell_options: HashMap::new(),
-            shell_options: None,
             debug_logging: false,
             app_update_behavior: AppUpdateBehavior::default(),
+            update_repo: default_update_repo(),
+            update_channel: UpdateChannel::default(),
+            update_download_dir: None,
+            set_default_on_install: DefaultOnInstall::default(),
+            confirm_uninstall: ConfirmUninstall::default(),
+            confirm_eol_default: true,
+            verify_installs: false,
+            fetch_version_metadata: true,
+            hidden_metadata_fields: Vec::new(),
+            persisted_filters: Vec::new(),
+            patches_to_keep_per_major: None,
+            pinned_versions: Vec::new(),
+            version_notes: HashMap::new(),
+            dismissed_banners: BTreeSet::new(),
             window_geometry: None,
             install_timeout_secs: default_install_timeout(),
             uninstall_timeout_secs: default_operation_timeout(),
@@ -218,6 +394,7 @@ impl Default for AppSettings {
             http_timeout_secs: default_http_timeout(),
             toast_timeout_secs: default_toast_timeout(),
             max_visible_toasts: default_max_visible_toasts(),
+            undo_uninstall_secs: default_undo_uninstall_secs(),
             search_results_limit: default_search_results_limit(),
             modal_preview_limit: default_modal_preview_limit(),
             max_log_size_bytes: default_max_log_size_bytes(),
@@ -234,12 +411,99 @@ pub enum AppUpdateBehavior {
     AutomaticallyUpdate,
 }
 
+/// Whether a freshly installed version becomes the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefaultOnInstall {
+    /// Only when the environment has no default yet.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl DefaultOnInstall {
+    pub fn should_set_default(self, has_default: bool) -> bool {
+        match self {
+            Self::Auto => !has_default,
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Which uninstalls ask for confirmation first. The default version is
+/// always confirmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfirmUninstall {
+    Always,
+    #[default]
+    DefaultOnly,
+    Never,
+}
+
+impl ConfirmUninstall {
+    pub fn should_confirm(self, is_default: bool) -> bool {
+        is_default || self == Self::Always
+    }
+}
+
+/// A bundled tool version shown in the version detail view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetadataField {
+    Npm,
+    V8,
+    OpenSsl,
+}
+
 impl AppSettings {
+    pub fn shows_metadata_field(&self, field: MetadataField) -> bool {
+        !self.hidden_metadata_fields.contains(&field)
+    }
+
+    pub fn set_metadata_field_visible(&mut self, field: MetadataField, visible: bool) {
+        self.hidden_metadata_fields
+            .retain(|hidden| *hidden != field);
+        if !visible {
+            self.hidden_metadata_fields.push(field);
+        }
+    }
+
+    /// Key for a banner raised by `condition`, e.g. the newest available
+    /// version. A new condition gives a new key, so the banner comes back.
+    pub fn banner_key(banner: &str, condition: &str) -> String {
+        format!("{banner}:{condition}")
+    }
+
+    pub fn is_banner_dismissed(&self, key: &str) -> bool {
+        self.dismissed_banners.contains(key)
+    }
+
+    /// Remember `key` as dismissed, dropping older dismissals of the same
+    /// banner since their conditions no longer apply.
+    pub fn dismiss_banner(&mut self, key: String) {
+        if let Some((banner, _)) = key.split_once(':') {
+            let prefix = format!("{banner}:");
+            self.dismissed_banners
+                .retain(|dismissed| !dismissed.starts_with(&prefix));
+        }
+        self.dismissed_banners.insert(key);
+    }
+
     pub fn load() -> Self {
         let Ok(paths) = AppPaths::new() else {
             return Self::default();
         };
-        Self::load_from_path(&paths.settings_file())
+        let settings_path = paths.settings_file();
+        if !settings_path.exists()
+            && let Some(legacy_path) = paths.legacy_settings_file()
+            && legacy_path.exists()
+        {
+            let _ = paths.ensure_dirs();
+            if let Some(settings) = Self::import_legacy(&legacy_path, &settings_path) {
+                return settings;
+            }
+        }
+        Self::load_from_path(&settings_path)
     }
 
     pub fn save(&self) -> Result<(), std::io::Error> {
@@ -260,10 +524,141 @@ impl AppSettings {
         self.backend_shell_options.entry(backend).or_default()
     }
 
+    /// `version` as it should be shown, honouring [`Self::version_prefix`].
+    #[must_use]
+    pub fn display_version(&self, version: &str) -> String {
+        crate::version_display::display_version(version, self.version_prefix)
+    }
+
+    #[must_use]
+    pub fn is_pinned(&self, version: &str) -> bool {
+        self.pinned_versions.iter().any(|pinned| pinned == version)
+    }
+
+    /// Pin `version`, or unpin it if it is already pinned.
+    pub fn toggle_pinned_version(&mut self, version: &str) {
+        if self.is_pinned(version) {
+            self.pinned_versions.retain(|pinned| pinned != version);
+        } else {
+            self.pinned_versions.push(version.to_string());
+        }
+    }
+
+    #[must_use]
+    pub fn version_note(&self, version: &str) -> Option<&str> {
+        self.version_notes.get(version).map(String::as_str)
+    }
+
+    /// Set the note for `version`, trimmed and capped in length. A blank
+    /// note removes it. Returns whether anything changed.
+    pub fn set_version_note(&mut self, version: &str, note: &str) -> bool {
+        let note = normalize_version_note(note);
+        if note.is_empty() {
+            return self.version_notes.remove(version).is_some();
+        }
+        if self.version_note(version) == Some(note.as_str()) {
+            return false;
+        }
+        self.version_notes.insert(version.to_string(), note);
+        true
+    }
+
+    /// Drop pins for versions that are no longer installed. Returns whether
+    /// anything was removed.
+    pub fn retain_installed_pins(&mut self, is_installed: impl Fn(&str) -> bool) -> bool {
+        let before = self.pinned_versions.len();
+        self.pinned_versions.retain(|pinned| is_installed(pinned));
+        self.pinned_versions.len() != before
+    }
+
+    /// Reset the preferences in `section`, leaving the fields in
+    /// `KEPT_ON_RESET`. Returns `false` for sections that hold paths or
+    /// user data rather than preferences and have no reset.
+    pub fn reset_section(&mut self, section: SettingsSection) -> bool {
+        if !section.is_resettable() {
+            return false;
+        }
+        match self.with_fields_from(&Self::default(), |field| {
+            section.fields().contains(&field) && !KEPT_ON_RESET.contains(&field)
+        }) {
+            Ok(reset) => *self = reset,
+            Err(error) => log::warn!("Could not reset {} settings: {error}", section.label()),
+        }
+        true
+    }
+
+    /// Whether "Reset" would leave `section` unchanged.
+    pub fn section_is_default(&self, section: SettingsSection) -> bool {
+        let mut reset = self.clone();
+        !reset.reset_section(section) || self.diff(&reset).is_empty()
+    }
+
+    /// Top-level settings whose values differ in `other`, keyed by their
+    /// name in the settings file.
+    pub fn diff(&self, other: &Self) -> Vec<SettingChange> {
+        let (Ok(Value::Object(current)), Ok(Value::Object(imported))) =
+            (serde_json::to_value(self), serde_json::to_value(other))
+        else {
+            return Vec::new();
+        };
+        let fields: BTreeSet<&String> = current.keys().chain(imported.keys()).collect();
+        fields
+            .into_iter()
+            .filter(|field| field.as_str() != "version")
+            .filter_map(|field| {
+                let before = current.get(field).unwrap_or(&Value::Null);
+                let after = imported.get(field).unwrap_or(&Value::Null);
+                (before != after).then(|| SettingChange {
+                    section: SettingsSection::of_field(field),
+                    field: field.clone(),
+                    current: display_setting_value(before),
+                    imported: display_setting_value(after),
+                })
+            })
+            .collect()
+    }
+
+    /// `self` with every field in `sections` taken from `imported`.
+    ///
+    /// # Errors
+    /// Returns an error if the merged fields don't form valid settings.
+    pub fn merge_sections(
+        &self,
+        imported: &Self,
+        sections: &BTreeSet<SettingsSection>,
+    ) -> Result<Self, serde_json::Error> {
+        self.with_fields_from(imported, |field| {
+            sections.contains(&SettingsSection::of_field(field))
+        })
+    }
+
+    /// `self` with the settings file fields that `include` accepts taken
+    /// from `other`.
+    fn with_fields_from(
+        &self,
+        other: &Self,
+        include: impl Fn(&str) -> bool,
+    ) -> Result<Self, serde_json::Error> {
+        let mut merged = settings_fields(self)?;
+        for (field, value) in settings_fields(other)? {
+            if field != "version" && include(&field) {
+                merged.insert(field, value);
+            }
+        }
+        serde_json::from_value(Value::Object(merged))
+    }
+
+    /// Parse a settings file, upgrading older schema versions first.
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        let mut value: Value = serde_json::from_str(content)?;
+        schema::migrate(&mut value, SETTINGS_MIGRATIONS);
+        serde_json::from_value(value)
+    }
+
     fn load_from_path(settings_path: &Path) -> Self {
         let mut settings: Self = if settings_path.exists() {
             match std::fs::read_to_string(settings_path) {
-                Ok(content) => match serde_json::from_str(&content) {
+                Ok(content) => match Self::from_json(&content) {
                     Ok(settings) => settings,
                     Err(error) => {
                         warn_settings_io(&format!(
@@ -286,14 +681,6 @@ impl AppSettings {
             Self::default()
         };
 
-        if let Some(legacy) = settings.shell_options.take()
-            && settings.backend_shell_options.is_empty()
-        {
-            settings
-                .backend_shell_options
-                .insert(BackendKind::Fnm, legacy);
-        }
-
         if settings.sanitize_in_place() {
             warn_settings_io(
                 "Loaded settings contained out-of-range values; defaults were applied where needed.",
@@ -303,8 +690,41 @@ impl AppSettings {
         settings
     }
 
+    /// Import an fnm-ui settings file into `settings_path`. The legacy file
+    /// is left alone; once the new file exists this never runs again.
+    fn import_legacy(legacy_path: &Path, settings_path: &Path) -> Option<Self> {
+        let content = match std::fs::read_to_string(legacy_path) {
+            Ok(content) => content,
+            Err(error) => {
+                warn_settings_io(&format!(
+                    "Failed to read legacy settings at {}: {error}",
+                    legacy_path.display()
+                ));
+                return None;
+            }
+        };
+        let mut settings = match Self::from_json(&content) {
+            Ok(settings) => settings,
+            Err(error) => {
+                warn_settings_io(&format!(
+                    "Skipping legacy settings at {}: {error}",
+                    legacy_path.display()
+                ));
+                return None;
+            }
+        };
+        settings.sanitize_in_place();
+
+        if let Err(error) = settings.save_to_path(settings_path) {
+            warn_settings_io(&format!("Failed to save imported legacy settings: {error}"));
+        }
+        log::info!("Imported legacy settings from {}", legacy_path.display());
+        Some(settings)
+    }
+
     fn save_to_path(&self, settings_path: &Path) -> Result<(), std::io::Error> {
         let mut settings = self.clone();
+        settings.version = current_version(SETTINGS_MIGRATIONS);
         if settings.sanitize_in_place() {
             warn_settings_io("Saving sanitized settings after clamping out-of-range values.");
         }
@@ -326,7 +746,7 @@ impl AppSettings {
             file.sync_all()?;
         }
 
-        if let Err(error) = replace_file(&temp_path, settings_path) {
+        if let Err(error) = crate::atomic_file::replace_file(&temp_path, settings_path) {
             let _ = std::fs::remove_file(&temp_path);
             return Err(error);
         }
@@ -339,7 +759,7 @@ impl AppSettings {
         Ok(())
     }
 
-    fn sanitize_in_place(&mut self) -> bool {
+    pub(crate) fn sanitize_in_place(&mut self) -> bool {
         let mut changed = false;
 
         changed |= clamp_u64(&mut self.cache_ttl_hours, &CACHE_TTL_HOURS_RANGE);
@@ -356,6 +776,7 @@ impl AppSettings {
         changed |= clamp_u64(&mut self.http_timeout_secs, &HTTP_TIMEOUT_SECS_RANGE);
         changed |= clamp_u64(&mut self.toast_timeout_secs, &TOAST_TIMEOUT_SECS_RANGE);
         changed |= clamp_usize(&mut self.max_visible_toasts, &MAX_VISIBLE_TOASTS_RANGE);
+        changed |= clamp_u64(&mut self.undo_uninstall_secs, &UNDO_UNINSTALL_SECS_RANGE);
         changed |= clamp_usize(&mut self.search_results_limit, &SEARCH_RESULTS_LIMIT_RANGE);
         changed |= clamp_usize(&mut self.modal_preview_limit, &MODAL_PREVIEW_LIMIT_RANGE);
         changed |= clamp_u64(&mut self.max_log_size_bytes, &MAX_LOG_SIZE_BYTES_RANGE);
@@ -371,10 +792,46 @@ impl AppSettings {
         }
         changed |= self.retry_delays_secs != original_retry_delays;
 
+        for note in self.version_notes.values_mut() {
+            let normalized = normalize_version_note(note);
+            if normalized != *note {
+                *note = normalized;
+                changed = true;
+            }
+        }
+        let notes_before = self.version_notes.len();
+        self.version_notes.retain(|_, note| !note.is_empty());
+        changed |= self.version_notes.len() != notes_before;
+
+        for (backend, options) in &mut self.backend_shell_options {
+            changed |= options.retain_supported(backend.shell_option_support());
+        }
+
+        if !versi_core::is_valid_update_repo(&self.update_repo) {
+            self.update_repo = default_update_repo();
+            changed = true;
+        }
+
+        if let Some(mirror) = self.node_dist_mirror.take() {
+            let normalized = normalize_dist_mirror(&mirror);
+            if normalized.is_none() && !mirror.trim().is_empty() {
+                warn_settings_io(&format!(
+                    "Ignoring node_dist_mirror {mirror:?}: not an absolute http(s) URL."
+                ));
+            }
+            changed |= normalized.as_deref() != Some(mirror.as_str());
+            self.node_dist_mirror = normalized;
+        }
+
         changed
     }
 }
 
+fn normalize_version_note(note: &str) -> String {
+    let capped: String = note.trim().chars().take(MAX_VERSION_NOTE_CHARS).collect();
+    capped.trim_end().to_string()
+}
+
 fn clamp_u64(value: &mut u64, range: &std::ops::RangeInclusive<u64>) -> bool {
     let clamped = (*value).clamp(*range.start(), *range.end());
     let changed = clamped != *value;
@@ -389,6 +846,14 @@ fn clamp_usize(value: &mut usize, range: &std::ops::RangeInclusive<usize>) -> bo
     changed
 }
 
+/// `mirror` trimmed of whitespace and trailing slashes, or `None` if it's
+/// blank or not an absolute http(s) URL.
+fn normalize_dist_mirror(mirror: &str) -> Option<String> {
+    let trimmed = mirror.trim().trim_end_matches('/');
+    let url = reqwest::Url::parse(trimmed).ok()?;
+    (matches!(url.scheme(), "http" | "https") && url.has_host()).then(|| trimmed.to_string())
+}
+
 fn warn_settings_io(message: &str) {
     eprintln!("Versi settings warning: {message}");
     log::warn!("{message}");
@@ -455,44 +920,353 @@ fn temp_settings_path(settings_path: &Path) -> PathBuf {
     ))
 }
 
-fn replace_file(src: &Path, dst: &Path) -> Result<(), std::io::Error> {
-    #[cfg(target_os = "windows")]
-    {
-        use std::os::windows::ffi::OsStrExt;
-        use windows_sys::Win32::Storage::FileSystem::{
-            MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH, MoveFileExW,
-        };
+/// Numeric settings editable from the Settings view, each with its allowed
+/// range in the unit the view shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericSetting {
+    InstallTimeout,
+    UninstallTimeout,
+    SetDefaultTimeout,
+    FetchTimeout,
+    HttpTimeout,
+    CacheTtl,
+    ToastTimeout,
+    MaxVisibleToasts,
+    SearchResultsLimit,
+    ModalPreviewLimit,
+    UndoUninstall,
+    MaxLogSize,
+}
 
-        let src_utf16: Vec<u16> = src
-            .as_os_str()
-            .encode_wide()
-            .chain(std::iter::once(0))
-            .collect();
-        let dst_utf16: Vec<u16> = dst
-            .as_os_str()
-            .encode_wide()
-            .chain(std::iter::once(0))
-            .collect();
+/// Why a typed numeric setting can't be applied.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NumericInputError {
+    #[error("Enter a whole number")]
+    NotANumber,
+    #[error("Enter {}–{} {unit}", range.start(), range.end())]
+    OutOfRange {
+        range: std::ops::RangeInclusive<u64>,
+        unit: &'static str,
+    },
+}
 
-        // SAFETY: both paths are NUL-terminated UTF-16 buffers that live for
-        // the duration of the FFI call.
-        let moved = unsafe {
-            MoveFileExW(
-                src_utf16.as_ptr(),
-                dst_utf16.as_ptr(),
-                MOVEFILE_REPLACE_EXISTING | MOVEFILE_WRITE_THROUGH,
-            )
+impl NumericSetting {
+    pub const ALL: [Self; 12] = [
+        Self::InstallTimeout,
+        Self::UninstallTimeout,
+        Self::SetDefaultTimeout,
+        Self::FetchTimeout,
+        Self::HttpTimeout,
+        Self::CacheTtl,
+        Self::ToastTimeout,
+        Self::MaxVisibleToasts,
+        Self::SearchResultsLimit,
+        Self::ModalPreviewLimit,
+        Self::UndoUninstall,
+        Self::MaxLogSize,
+    ];
+
+    /// The settings shown in `section`, in display order.
+    pub fn in_section(section: SettingsSection) -> impl Iterator<Item = Self> {
+        Self::ALL
+            .into_iter()
+            .filter(move |setting| setting.section() == section)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::InstallTimeout => "Install",
+            Self::UninstallTimeout => "Uninstall",
+            Self::SetDefaultTimeout => "Set default",
+            Self::FetchTimeout => "Fetch versions",
+            Self::HttpTimeout => "HTTP requests",
+            Self::CacheTtl => "Cache lifetime",
+            Self::ToastTimeout => "Toast duration",
+            Self::MaxVisibleToasts => "Visible toasts",
+            Self::SearchResultsLimit => "Search results",
+            Self::ModalPreviewLimit => "Preview list",
+            Self::UndoUninstall => "Undo window",
+            Self::MaxLogSize => "Max log size",
+        }
+    }
+
+    pub fn section(self) -> SettingsSection {
+        match self {
+            Self::InstallTimeout
+            | Self::UninstallTimeout
+            | Self::SetDefaultTimeout
+            | Self::FetchTimeout
+            | Self::HttpTimeout => SettingsSection::Timeouts,
+            Self::CacheTtl => SettingsSection::Updates,
+            Self::ToastTimeout
+            | Self::MaxVisibleToasts
+            | Self::SearchResultsLimit
+            | Self::ModalPreviewLimit => SettingsSection::Appearance,
+            Self::UndoUninstall => SettingsSection::Versions,
+            Self::MaxLogSize => SettingsSection::Advanced,
+        }
+    }
+
+    /// Unit of the value as typed, used in the range hint and errors.
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::InstallTimeout
+            | Self::UninstallTimeout
+            | Self::SetDefaultTimeout
+            | Self::FetchTimeout
+            | Self::HttpTimeout
+            | Self::ToastTimeout
+            | Self::UndoUninstall => "seconds",
+            Self::CacheTtl => "hours",
+            Self::MaxVisibleToasts => "toasts",
+            Self::SearchResultsLimit => "results",
+            Self::ModalPreviewLimit => "versions",
+            Self::MaxLogSize => "MB",
+        }
+    }
+
+    pub fn range(self) -> std::ops::RangeInclusive<u64> {
+        match self {
+            Self::InstallTimeout => INSTALL_TIMEOUT_SECS_RANGE,
+            Self::UninstallTimeout | Self::SetDefaultTimeout => OPERATION_TIMEOUT_SECS_RANGE,
+            Self::FetchTimeout => FETCH_TIMEOUT_SECS_RANGE,
+            Self::HttpTimeout => HTTP_TIMEOUT_SECS_RANGE,
+            Self::CacheTtl => CACHE_TTL_HOURS_RANGE,
+            Self::ToastTimeout => TOAST_TIMEOUT_SECS_RANGE,
+            Self::UndoUninstall => UNDO_UNINSTALL_SECS_RANGE,
+            Self::MaxVisibleToasts => usize_range(&MAX_VISIBLE_TOASTS_RANGE),
+            Self::SearchResultsLimit => usize_range(&SEARCH_RESULTS_LIMIT_RANGE),
+            Self::ModalPreviewLimit => usize_range(&MODAL_PREVIEW_LIMIT_RANGE),
+            Self::MaxLogSize => {
+                MAX_LOG_SIZE_BYTES_RANGE.start() / BYTES_PER_MB
+                    ..=MAX_LOG_SIZE_BYTES_RANGE.end() / BYTES_PER_MB
+            }
+        }
+    }
+
+    pub fn get(self, settings: &AppSettings) -> u64 {
+        match self {
+            Self::InstallTimeout => settings.install_timeout_secs,
+            Self::UninstallTimeout => settings.uninstall_timeout_secs,
+            Self::SetDefaultTimeout => settings.set_default_timeout_secs,
+            Self::FetchTimeout => settings.fetch_timeout_secs,
+            Self::HttpTimeout => settings.http_timeout_secs,
+            Self::CacheTtl => settings.cache_ttl_hours,
+            Self::ToastTimeout => settings.toast_timeout_secs,
+            Self::UndoUninstall => settings.undo_uninstall_secs,
+            Self::MaxVisibleToasts => usize_to_u64(settings.max_visible_toasts),
+            Self::SearchResultsLimit => usize_to_u64(settings.search_results_limit),
+            Self::ModalPreviewLimit => usize_to_u64(settings.modal_preview_limit),
+            Self::MaxLogSize => settings.max_log_size_bytes / BYTES_PER_MB,
+        }
+    }
+
+    pub fn set(self, settings: &mut AppSettings, value: u64) {
+        let field = match self {
+            Self::InstallTimeout => &mut settings.install_timeout_secs,
+            Self::UninstallTimeout => &mut settings.uninstall_timeout_secs,
+            Self::SetDefaultTimeout => &mut settings.set_default_timeout_secs,
+            Self::FetchTimeout => &mut settings.fetch_timeout_secs,
+            Self::HttpTimeout => &mut settings.http_timeout_secs,
+            Self::CacheTtl => &mut settings.cache_ttl_hours,
+            Self::ToastTimeout => &mut settings.toast_timeout_secs,
+            Self::UndoUninstall => &mut settings.undo_uninstall_secs,
+            Self::MaxVisibleToasts => {
+                settings.max_visible_toasts = u64_to_usize(value);
+                return;
+            }
+            Self::SearchResultsLimit => {
+                settings.search_results_limit = u64_to_usize(value);
+                return;
+            }
+            Self::ModalPreviewLimit => {
+                settings.modal_preview_limit = u64_to_usize(value);
+                return;
+            }
+            Self::MaxLogSize => {
+                settings.max_log_size_bytes = value.saturating_mul(BYTES_PER_MB);
+                return;
+            }
         };
-        if moved != 0 {
-            Ok(())
+        *field = value;
+    }
+
+    /// Parse `input` in this setting's unit, or explain why it isn't allowed.
+    pub fn validate(self, input: &str) -> Result<u64, NumericInputError> {
+        let value = input
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| NumericInputError::NotANumber)?;
+        let range = self.range();
+        if range.contains(&value) {
+            Ok(value)
         } else {
-            Err(std::io::Error::last_os_error())
+            Err(NumericInputError::OutOfRange {
+                range,
+                unit: self.unit(),
+            })
+        }
+    }
+}
+
+fn usize_to_u64(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+fn u64_to_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+fn usize_range(range: &std::ops::RangeInclusive<usize>) -> std::ops::RangeInclusive<u64> {
+    usize_to_u64(*range.start())..=usize_to_u64(*range.end())
+}
+
+/// `settings` as a map of settings file fields.
+fn settings_fields(settings: &AppSettings) -> Result<Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(settings)? {
+        Value::Object(fields) => Ok(fields),
+        _ => Err(<serde_json::Error as serde::de::Error>::custom(
+            "settings did not serialize to an object",
+        )),
+    }
+}
+
+/// Fields "Reset" leaves alone: they mirror OS or window state rather than
+/// a preference.
+const KEPT_ON_RESET: &[&str] = &["launch_at_login", "window_geometry"];
+
+/// Groups of settings that can be imported independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SettingsSection {
+    Appearance,
+    Tray,
+    Engine,
+    ShellOptions,
+    Versions,
+    Updates,
+    Timeouts,
+    Advanced,
+}
+
+impl SettingsSection {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Appearance => "Appearance",
+            Self::Tray => "Tray & launch",
+            Self::Engine => "Engine",
+            Self::ShellOptions => "Shell options",
+            Self::Versions => "Versions",
+            Self::Updates => "Updates",
+            Self::Timeouts => "Timeouts",
+            Self::Advanced => "Advanced",
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
-    {
-        std::fs::rename(src, dst)
+    pub const ALL: [Self; 8] = [
+        Self::Appearance,
+        Self::Tray,
+        Self::Engine,
+        Self::ShellOptions,
+        Self::Versions,
+        Self::Updates,
+        Self::Timeouts,
+        Self::Advanced,
+    ];
+
+    /// Settings file fields in this section. Import, reset and
+    /// [`Self::of_field`] all read this list.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Appearance => &[
+                "theme",
+                "reduced_motion",
+                "version_prefix",
+                "language",
+                "window_geometry",
+                "toast_timeout_secs",
+                "max_visible_toasts",
+                "search_results_limit",
+                "modal_preview_limit",
+            ],
+            Self::Tray => &[
+                "tray_behavior",
+                "start_minimized",
+                "launch_at_login",
+                "confirm_tray_environment_switch",
+            ],
+            Self::Engine => &[
+                "fnm_dir",
+                "node_dist_mirror",
+                "preferred_backend",
+                "last_environment",
+            ],
+            Self::ShellOptions => &["backend_shell_options"],
+            Self::Versions => &[
+                "set_default_on_install",
+                "confirm_uninstall",
+                "confirm_eol_default",
+                "verify_installs",
+                "fetch_version_metadata",
+                "hidden_metadata_fields",
+                "patches_to_keep_per_major",
+                "persisted_filters",
+                "pinned_versions",
+                "version_notes",
+                "dismissed_banners",
+                "undo_uninstall_secs",
+            ],
+            Self::Updates => &[
+                "app_update_behavior",
+                "update_repo",
+                "update_channel",
+                "update_download_dir",
+                "cache_ttl_hours",
+            ],
+            Self::Timeouts => &[
+                "install_timeout_secs",
+                "uninstall_timeout_secs",
+                "set_default_timeout_secs",
+                "fetch_timeout_secs",
+                "http_timeout_secs",
+                "retry_delays_secs",
+            ],
+            Self::Advanced => &["debug_logging", "max_log_size_bytes"],
+        }
+    }
+
+    /// Whether the section holds preferences that "Reset" restores, rather
+    /// than paths or user data.
+    pub fn is_resettable(self) -> bool {
+        matches!(
+            self,
+            Self::Appearance | Self::Tray | Self::Updates | Self::ShellOptions | Self::Timeouts
+        )
+    }
+
+    /// The section a settings file field belongs to. Fields not listed in
+    /// any section count as [`Self::Advanced`].
+    pub fn of_field(field: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|section| section.fields().contains(&field))
+            .unwrap_or(Self::Advanced)
+    }
+}
+
+/// One setting that an import would change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingChange {
+    pub section: SettingsSection,
+    pub field: String,
+    pub current: String,
+    pub imported: String,
+}
+
+fn display_setting_value(value: &Value) -> String {
+    match value {
+        Value::Null => "none".to_string(),
+        Value::String(value) => value.clone(),
+        other => other.to_string(),
     }
 }
 
@@ -543,9 +1317,65 @@ mod tests {
     use tempfile::tempdir;
 
     use super::{
-        AppSettings, AppUpdateBehavior, BackendKind, ShellOptions, ThemeSetting, WindowGeometry,
+        AppSettings, AppUpdateBehavior, BackendKind, ConfirmUninstall, DefaultOnInstall,
+        MetadataField, NumericInputError, NumericSetting, SearchFilter, SettingChange,
+        SettingsSection, ShellOptions, ThemeSetting, TrayBehavior, WindowGeometry,
     };
 
+    #[test]
+    fn default_on_install_policy_decides_by_existing_default() {
+        assert!(DefaultOnInstall::Auto.should_set_default(false));
+        assert!(!DefaultOnInstall::Auto.should_set_default(true));
+        assert!(DefaultOnInstall::Always.should_set_default(true));
+        assert!(DefaultOnInstall::Always.should_set_default(false));
+        assert!(!DefaultOnInstall::Never.should_set_default(false));
+        assert!(!DefaultOnInstall::Never.should_set_default(true));
+    }
+
+    #[test]
+    fn metadata_fields_can_be_hidden_and_shown_again() {
+        let mut settings = AppSettings::default();
+        assert!(settings.fetch_version_metadata);
+        assert!(settings.shows_metadata_field(MetadataField::V8));
+
+        settings.set_metadata_field_visible(MetadataField::V8, false);
+        settings.set_metadata_field_visible(MetadataField::V8, false);
+        assert!(!settings.shows_metadata_field(MetadataField::V8));
+        assert!(settings.shows_metadata_field(MetadataField::Npm));
+        assert_eq!(settings.hidden_metadata_fields, vec![MetadataField::V8]);
+
+        settings.set_metadata_field_visible(MetadataField::V8, true);
+        assert!(settings.hidden_metadata_fields.is_empty());
+    }
+
+    #[test]
+    fn dismissing_a_banner_replaces_older_dismissals_of_it() {
+        let mut settings = AppSettings::default();
+        let old = AppSettings::banner_key("updates", "v22.1.0");
+        let new = AppSettings::banner_key("updates", "v22.2.0");
+        let other = AppSettings::banner_key("eol", "16");
+
+        settings.dismiss_banner(old.clone());
+        settings.dismiss_banner(other.clone());
+        assert!(settings.is_banner_dismissed(&old));
+        assert!(!settings.is_banner_dismissed(&new));
+
+        settings.dismiss_banner(new.clone());
+        assert!(!settings.is_banner_dismissed(&old));
+        assert!(settings.is_banner_dismissed(&new));
+        assert!(settings.is_banner_dismissed(&other));
+    }
+
+    #[test]
+    fn confirm_uninstall_policy_always_confirms_the_default() {
+        assert!(ConfirmUninstall::Always.should_confirm(true));
+        assert!(ConfirmUninstall::Always.should_confirm(false));
+        assert!(ConfirmUninstall::DefaultOnly.should_confirm(true));
+        assert!(!ConfirmUninstall::DefaultOnly.should_confirm(false));
+        assert!(ConfirmUninstall::Never.should_confirm(true));
+        assert!(!ConfirmUninstall::Never.should_confirm(false));
+    }
+
     #[test]
     fn shell_options_default_enables_use_on_cd_only() {
         let options = ShellOptions::default();
@@ -603,6 +1433,404 @@ mod tests {
         );
     }
 
+    #[test]
+    fn v1_settings_migrate_to_current_schema_preserving_fields() {
+        let v1 = json!({
+            "theme": "Dark",
+            "cache_ttl_hours": 12,
+            "start_minimized": true,
+            "node_dist_mirror": "https://mirror.example.com/node",
+            "shell_options": { "use_on_cd": false, "resolve_engines": true, "corepack_enabled": true },
+            "retry_delays_secs": [0, 5]
+        });
+
+        let settings = AppSettings::from_json(&v1.to_string()).expect("v1 settings should migrate");
+
+        assert_eq!(
+            settings.version,
+            super::current_version(super::SETTINGS_MIGRATIONS)
+        );
+        assert!(matches!(settings.theme, ThemeSetting::Dark));
+        assert_eq!(settings.cache_ttl_hours, 12);
+        assert!(settings.start_minimized);
+        assert_eq!(
+            settings.node_dist_mirror.as_deref(),
+            Some("https://mirror.example.com/node")
+        );
+        assert_eq!(settings.retry_delays_secs, vec![0, 5]);
+        let fnm = settings.shell_options_for(BackendKind::Fnm);
+        assert!(!fnm.use_on_cd);
+        assert!(fnm.resolve_engines);
+        assert!(fnm.corepack_enabled);
+    }
+
+    #[test]
+    fn numeric_validation_reports_each_fields_range() {
+        let expected = [
+            (NumericSetting::InstallTimeout, "Enter 30–7200 seconds"),
+            (NumericSetting::UninstallTimeout, "Enter 5–900 seconds"),
+            (NumericSetting::SetDefaultTimeout, "Enter 5–900 seconds"),
+            (NumericSetting::FetchTimeout, "Enter 5–300 seconds"),
+            (NumericSetting::HttpTimeout, "Enter 3–120 seconds"),
+            (NumericSetting::CacheTtl, "Enter 1–168 hours"),
+            (NumericSetting::ToastTimeout, "Enter 1–60 seconds"),
+            (NumericSetting::MaxVisibleToasts, "Enter 1–10 toasts"),
+            (NumericSetting::SearchResultsLimit, "Enter 1–200 results"),
+            (NumericSetting::ModalPreviewLimit, "Enter 1–50 versions"),
+            (NumericSetting::UndoUninstall, "Enter 0–120 seconds"),
+            (NumericSetting::MaxLogSize, "Enter 1–100 MB"),
+        ];
+        for (setting, message) in expected {
+            let error = setting.validate("100000").unwrap_err();
+            assert!(matches!(error, NumericInputError::OutOfRange { .. }));
+            assert_eq!(error.to_string(), message);
+            assert_eq!(setting.validate("soon"), Err(NumericInputError::NotANumber));
+            let end = *setting.range().end();
+            assert_eq!(setting.validate(&format!(" {end} ")), Ok(end));
+        }
+        assert_eq!(
+            NumericSetting::HttpTimeout
+                .validate("0")
+                .unwrap_err()
+                .to_string(),
+            "Enter 3–120 seconds"
+        );
+    }
+
+    #[test]
+    fn numeric_settings_read_and_write_their_field() {
+        let mut settings = AppSettings::default();
+        for (value, setting) in (10..).zip(NumericSetting::ALL) {
+            setting.set(&mut settings, value);
+            assert_eq!(setting.get(&settings), value);
+        }
+        assert_eq!(settings.install_timeout_secs, 10);
+        assert_eq!(settings.http_timeout_secs, 14);
+        assert_eq!(settings.max_visible_toasts, 17);
+        assert_eq!(settings.max_log_size_bytes, 21 * 1_024 * 1_024);
+    }
+
+    #[test]
+    fn every_numeric_setting_belongs_to_its_fields_section() {
+        let defaults = AppSettings::default();
+        for setting in NumericSetting::ALL {
+            let mut changed = defaults.clone();
+            setting.set(&mut changed, setting.get(&defaults) + 1);
+            let changes = defaults.diff(&changed);
+            assert_eq!(changes.len(), 1, "{setting:?} should change one field");
+            assert_eq!(changes[0].section, setting.section());
+        }
+    }
+
+    #[test]
+    fn diff_lists_only_changed_fields() {
+        let current = AppSettings::default();
+        let imported = AppSettings {
+            theme: ThemeSetting::Dark,
+            cache_ttl_hours: 6,
+            node_dist_mirror: Some("https://mirror.example.com/node".to_string()),
+            ..AppSettings::default()
+        };
+
+        let changes = current.diff(&imported);
+
+        assert_eq!(
+            changes,
+            vec![
+                SettingChange {
+                    section: SettingsSection::Updates,
+                    field: "cache_ttl_hours".to_string(),
+                    current: "1".to_string(),
+                    imported: "6".to_string(),
+                },
+                SettingChange {
+                    section: SettingsSection::Engine,
+                    field: "node_dist_mirror".to_string(),
+                    current: "none".to_string(),
+                    imported: "https://mirror.example.com/node".to_string(),
+                },
+                SettingChange {
+                    section: SettingsSection::Appearance,
+                    field: "theme".to_string(),
+                    current: "System".to_string(),
+                    imported: "Dark".to_string(),
+                },
+            ]
+        );
+        assert!(current.diff(&current.clone()).is_empty());
+    }
+
+    fn customized_settings() -> AppSettings {
+        let mut settings = AppSettings {
+            theme: ThemeSetting::Dark,
+            reduced_motion: true,
+            version_prefix: false,
+            toast_timeout_secs: 8,
+            max_visible_toasts: 5,
+            search_results_limit: 50,
+            modal_preview_limit: 4,
+            tray_behavior: TrayBehavior::AlwaysRunning,
+            start_minimized: true,
+            confirm_tray_environment_switch: true,
+            app_update_behavior: AppUpdateBehavior::DoNotCheck,
+            update_repo: "someone/fork".to_string(),
+            update_download_dir: Some("/tmp/updates".into()),
+            install_timeout_secs: 1_200,
+            uninstall_timeout_secs: 10,
+            set_default_timeout_secs: 10,
+            fetch_timeout_secs: 10,
+            http_timeout_secs: 20,
+            retry_delays_secs: vec![1],
+            cache_ttl_hours: 12,
+            verify_installs: true,
+            ..AppSettings::default()
+        };
+        settings.shell_options_for_mut(BackendKind::Fnm).use_on_cd = false;
+        settings
+    }
+
+    fn reset_fields(section: SettingsSection) -> Vec<String> {
+        let before = customized_settings();
+        let mut after = before.clone();
+        assert!(after.reset_section(section));
+        before
+            .diff(&after)
+            .into_iter()
+            .map(|change| change.field)
+            .collect()
+    }
+
+    #[test]
+    fn each_reset_restores_exactly_its_fields() {
+        assert_eq!(
+            reset_fields(SettingsSection::Appearance),
+            [
+                "max_visible_toasts",
+                "modal_preview_limit",
+                "reduced_motion",
+                "search_results_limit",
+                "theme",
+                "toast_timeout_secs",
+                "version_prefix"
+            ]
+        );
+        assert_eq!(
+            reset_fields(SettingsSection::Tray),
+            [
+                "confirm_tray_environment_switch",
+                "start_minimized",
+                "tray_behavior"
+            ]
+        );
+        assert_eq!(
+            reset_fields(SettingsSection::Updates),
+            [
+                "app_update_behavior",
+                "cache_ttl_hours",
+                "update_download_dir",
+                "update_repo"
+            ]
+        );
+        assert_eq!(
+            reset_fields(SettingsSection::ShellOptions),
+            ["backend_shell_options"]
+        );
+        assert_eq!(
+            reset_fields(SettingsSection::Timeouts),
+            [
+                "fetch_timeout_secs",
+                "http_timeout_secs",
+                "install_timeout_secs",
+                "retry_delays_secs",
+                "set_default_timeout_secs",
+                "uninstall_timeout_secs"
+            ]
+        );
+    }
+
+    #[test]
+    fn every_settings_field_belongs_to_a_section() {
+        let serde_json::Value::Object(fields) =
+            serde_json::to_value(AppSettings::default()).expect("settings should serialize")
+        else {
+            panic!("settings should serialize to an object");
+        };
+        for field in fields.keys().filter(|field| field.as_str() != "version") {
+            assert!(
+                SettingsSection::ALL
+                    .iter()
+                    .any(|section| section.fields().contains(&field.as_str())),
+                "{field} is not listed in any SettingsSection"
+            );
+        }
+    }
+
+    #[test]
+    fn reset_and_import_use_the_same_fields() {
+        for section in SettingsSection::ALL {
+            for field in section.fields() {
+                assert_eq!(SettingsSection::of_field(field), section, "{field}");
+            }
+        }
+    }
+
+    #[test]
+    fn section_is_default_until_a_field_changes() {
+        let mut settings = AppSettings {
+            launch_at_login: true,
+            ..AppSettings::default()
+        };
+        assert!(settings.section_is_default(SettingsSection::Tray));
+
+        settings.start_minimized = true;
+        assert!(!settings.section_is_default(SettingsSection::Tray));
+        assert!(settings.section_is_default(SettingsSection::Versions));
+    }
+
+    #[test]
+    fn reset_fields_match_defaults() {
+        let mut settings = customized_settings();
+        for section in [
+            SettingsSection::Appearance,
+            SettingsSection::Tray,
+            SettingsSection::Updates,
+            SettingsSection::ShellOptions,
+            SettingsSection::Timeouts,
+        ] {
+            assert!(settings.reset_section(section));
+        }
+        assert!(!settings.reset_section(SettingsSection::Versions));
+
+        let remaining: Vec<String> = AppSettings::default()
+            .diff(&settings)
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        assert_eq!(remaining, ["verify_installs"]);
+    }
+
+    #[test]
+    fn merging_only_appearance_leaves_other_sections_untouched() {
+        let mut current = AppSettings::default();
+        current.shell_options_for_mut(BackendKind::Fnm).use_on_cd = false;
+        let mut imported = AppSettings {
+            theme: ThemeSetting::Dark,
+            reduced_motion: true,
+            cache_ttl_hours: 12,
+            install_timeout_secs: 1_200,
+            start_minimized: true,
+            ..AppSettings::default()
+        };
+        imported
+            .shell_options_for_mut(BackendKind::Fnm)
+            .resolve_engines = true;
+
+        let merged = current
+            .merge_sections(
+                &imported,
+                &std::collections::BTreeSet::from([SettingsSection::Appearance]),
+            )
+            .expect("merged settings should be valid");
+
+        assert!(matches!(merged.theme, ThemeSetting::Dark));
+        assert!(merged.reduced_motion);
+        assert_eq!(merged.cache_ttl_hours, current.cache_ttl_hours);
+        assert_eq!(merged.install_timeout_secs, current.install_timeout_secs);
+        assert_eq!(merged.start_minimized, current.start_minimized);
+        let fnm = merged.shell_options_for(BackendKind::Fnm);
+        assert!(!fnm.use_on_cd);
+        assert!(!fnm.resolve_engines);
+    }
+
+    #[test]
+    fn valid_dist_mirror_is_kept() {
+        let mut settings = AppSettings {
+            node_dist_mirror: Some("https://npmmirror.com/mirrors/node".to_string()),
+            ..AppSettings::default()
+        };
+
+        assert!(!settings.sanitize_in_place());
+        assert_eq!(
+            settings.node_dist_mirror.as_deref(),
+            Some("https://npmmirror.com/mirrors/node")
+        );
+    }
+
+    #[test]
+    fn malformed_dist_mirror_is_cleared() {
+        for mirror in [
+            "mirror.example.com/node",
+            "not a url",
+            "ftp://mirror.example.com",
+            "   ",
+        ] {
+            let mut settings = AppSettings {
+                node_dist_mirror: Some(mirror.to_string()),
+                ..AppSettings::default()
+            };
+
+            assert!(settings.sanitize_in_place(), "{mirror:?} should be cleared");
+            assert_eq!(settings.node_dist_mirror, None);
+        }
+    }
+
+    #[test]
+    fn dist_mirror_trailing_slashes_are_trimmed() {
+        let mut settings = AppSettings {
+            node_dist_mirror: Some(" https://mirror.example.com/node// ".to_string()),
+            ..AppSettings::default()
+        };
+
+        assert!(settings.sanitize_in_place());
+        assert_eq!(
+            settings.node_dist_mirror.as_deref(),
+            Some("https://mirror.example.com/node")
+        );
+    }
+
+    #[test]
+    fn v1_migration_keeps_existing_backend_shell_options() {
+        let v1 = json!({
+            "shell_options": { "use_on_cd": false },
+            "backend_shell_options": { "nvm": { "use_on_cd": true } }
+        });
+
+        let settings = AppSettings::from_json(&v1.to_string()).expect("v1 settings should migrate");
+
+        assert!(
+            !settings
+                .backend_shell_options
+                .contains_key(&BackendKind::Fnm)
+        );
+        assert!(
+            settings
+                .backend_shell_options
+                .contains_key(&BackendKind::Nvm)
+        );
+    }
+
+    #[test]
+    fn saved_settings_record_current_schema_version() {
+        let temp_dir = tempdir().expect("create temp dir");
+        let settings_path = temp_dir.path().join("settings.json");
+        let settings = AppSettings {
+            version: 1,
+            ..AppSettings::default()
+        };
+
+        settings
+            .save_to_path(&settings_path)
+            .expect("save settings");
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).expect("read saved settings"))
+                .expect("saved settings should be JSON");
+        assert_eq!(
+            saved["version"],
+            json!(super::current_version(super::SETTINGS_MIGRATIONS))
+        );
+    }
+
     #[test]
     fn backend_shell_options_serialization_uses_backend_names() {
         let mut settings = AppSettings::default();
@@ -639,6 +1867,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sanitize_clears_shell_options_the_backend_does_not_support() {
+        let mut settings = AppSettings::default();
+        for backend in [BackendKind::Fnm, BackendKind::Nvm] {
+            *settings.shell_options_for_mut(backend) = ShellOptions {
+                use_on_cd: true,
+                resolve_engines: true,
+                corepack_enabled: true,
+            };
+        }
+
+        assert!(settings.sanitize_in_place());
+
+        let nvm = settings.shell_options_for(BackendKind::Nvm);
+        assert!(!nvm.use_on_cd);
+        assert!(!nvm.resolve_engines);
+        assert!(!nvm.corepack_enabled);
+        let fnm = settings.shell_options_for(BackendKind::Fnm);
+        assert!(fnm.use_on_cd && fnm.resolve_engines && fnm.corepack_enabled);
+    }
+
     #[test]
     fn shell_options_for_mut_inserts_default_entry() {
         let mut settings = AppSettings::default();
@@ -655,6 +1904,95 @@ mod tests {
         assert!(stored.use_on_cd);
     }
 
+    #[test]
+    fn toggling_a_pin_round_trips_through_json() {
+        let mut settings = AppSettings::default();
+
+        settings.toggle_pinned_version("v20.11.0");
+        settings.toggle_pinned_version("v18.19.0");
+        settings.toggle_pinned_version("v18.19.0");
+
+        let json = serde_json::to_string(&settings).expect("settings should serialize");
+        let restored = AppSettings::from_json(&json).expect("settings should parse");
+        assert_eq!(restored.pinned_versions, vec!["v20.11.0".to_string()]);
+    }
+
+    #[test]
+    fn version_notes_round_trip_through_json() {
+        let mut settings = AppSettings::default();
+
+        assert!(settings.set_version_note("v20.11.0", "  used for project X  "));
+        assert!(!settings.set_version_note("v20.11.0", "used for project X"));
+        assert!(settings.set_version_note("v18.19.0", "legacy CI"));
+        assert!(settings.set_version_note("v18.19.0", "   "));
+        assert_eq!(
+            settings.version_note("v20.11.0"),
+            Some("used for project X")
+        );
+        assert_eq!(settings.version_note("v18.19.0"), None);
+
+        let json = serde_json::to_string(&settings).expect("settings should serialize");
+        let restored = AppSettings::from_json(&json).expect("settings should parse");
+        assert_eq!(
+            restored.version_note("v20.11.0"),
+            Some("used for project X")
+        );
+        assert_eq!(restored.version_notes.len(), 1);
+    }
+
+    #[test]
+    fn version_notes_are_capped_in_length() {
+        let mut settings = AppSettings::default();
+        settings.set_version_note("v20.11.0", &"x".repeat(500));
+
+        assert_eq!(
+            settings
+                .version_note("v20.11.0")
+                .map(|note| note.chars().count()),
+            Some(200)
+        );
+    }
+
+    #[test]
+    fn persisted_filters_round_trip_through_json() {
+        let settings = AppSettings {
+            persisted_filters: vec![SearchFilter::Lts, SearchFilter::NotInstalled],
+            ..AppSettings::default()
+        };
+
+        let json = serde_json::to_string(&settings).expect("settings should serialize");
+        let restored = AppSettings::from_json(&json).expect("settings should parse");
+        assert_eq!(
+            restored.persisted_filters,
+            vec![SearchFilter::Lts, SearchFilter::NotInstalled]
+        );
+    }
+
+    #[test]
+    fn unknown_persisted_filters_are_dropped_on_load() {
+        let mut value = serde_json::to_value(AppSettings::default()).expect("serialize");
+        value["persisted_filters"] = json!(["Lts", "Nightly", "Lts", "Eol"]);
+
+        let settings: AppSettings = serde_json::from_value(value).expect("settings should parse");
+
+        assert_eq!(
+            settings.persisted_filters,
+            vec![SearchFilter::Lts, SearchFilter::Eol]
+        );
+    }
+
+    #[test]
+    fn retain_installed_pins_drops_stale_entries() {
+        let mut settings = AppSettings {
+            pinned_versions: vec!["v20.11.0".to_string(), "v16.20.2".to_string()],
+            ..AppSettings::default()
+        };
+
+        assert!(settings.retain_installed_pins(|version| version == "v20.11.0"));
+        assert_eq!(settings.pinned_versions, vec!["v20.11.0".to_string()]);
+        assert!(!settings.retain_installed_pins(|version| version == "v20.11.0"));
+    }
+
     #[test]
     fn window_geometry_visibility_checks_bounds() {
         let visible = WindowGeometry {
@@ -697,6 +2035,7 @@ mod tests {
             modal_preview_limit: 0,
             max_log_size_bytes: 1,
             retry_delays_secs: vec![900, 800, 700],
+            update_repo: "not a repo".to_string(),
             ..AppSettings::default()
         };
 
@@ -715,6 +2054,7 @@ mod tests {
         assert_eq!(settings.modal_preview_limit, 1);
         assert_eq!(settings.max_log_size_bytes, 1_024 * 1_024);
         assert_eq!(settings.retry_delays_secs, vec![0, 2, 5, 15]);
+        assert_eq!(settings.update_repo, versi_core::DEFAULT_UPDATE_REPO);
     }
 
     #[test]
@@ -737,6 +2077,53 @@ mod tests {
         assert_eq!(quarantined_files.len(), 1);
     }
 
+    #[test]
+    fn import_legacy_migrates_fnm_ui_settings() {
+        let temp_dir = tempdir().expect("create temp dir");
+        let legacy_path = temp_dir.path().join("fnm-ui-settings.json");
+        let settings_path = temp_dir.path().join("settings.json");
+        fs::write(
+            &legacy_path,
+            json!({
+                "theme": "Dark",
+                "cache_ttl_hours": 6,
+                "shell_options": {
+                    "use_on_cd": false,
+                    "resolve_engines": true
+                },
+                "window_width": 1024
+            })
+            .to_string(),
+        )
+        .expect("write legacy settings");
+
+        let imported =
+            AppSettings::import_legacy(&legacy_path, &settings_path).expect("import legacy");
+
+        assert!(matches!(imported.theme, ThemeSetting::Dark));
+        assert_eq!(imported.cache_ttl_hours, 6);
+        let fnm_options = imported.shell_options_for(BackendKind::Fnm);
+        assert!(!fnm_options.use_on_cd);
+        assert!(fnm_options.resolve_engines);
+
+        let reloaded = AppSettings::load_from_path(&settings_path);
+        assert!(matches!(reloaded.theme, ThemeSetting::Dark));
+        assert_eq!(reloaded.cache_ttl_hours, 6);
+        assert!(legacy_path.exists());
+    }
+
+    #[test]
+    fn import_legacy_skips_unparseable_files() {
+        let temp_dir = tempdir().expect("create temp dir");
+        let legacy_path = temp_dir.path().join("fnm-ui-settings.json");
+        let settings_path = temp_dir.path().join("settings.json");
+        fs::write(&legacy_path, "not json").expect("write legacy settings");
+
+        assert!(AppSettings::import_legacy(&legacy_path, &settings_path).is_none());
+        assert!(!settings_path.exists());
+        assert!(legacy_path.exists());
+    }
+
     #[test]
     fn save_to_path_writes_replacement_file_without_temp_leftovers() {
         let temp_dir = tempdir().expect("create temp dir");
@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 
 use tokio_util::sync::CancellationToken;
-use versi_backend::{InstalledVersion, NodeVersion, VersionGroup};
+use versi_backend::{
+    BackendProvider, BackendUpdate, InstalledVersion, NodeVersion, VersionAlias, VersionGroup,
+};
 use versi_platform::EnvironmentId;
 
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
+use crate::state::VersionSortMode;
 
 #[derive(Debug)]
 pub struct EnvironmentState {
@@ -14,9 +17,19 @@ pub struct EnvironmentState {
     pub installed_versions: Vec<InstalledVersion>,
     pub installed_set: HashSet<NodeVersion>,
     pub version_groups: Vec<VersionGroup>,
+    /// Installed versions whose `node` binary failed to run.
+    pub broken_versions: HashSet<NodeVersion>,
+    /// Installed versions already checked for [`Self::broken_versions`], so
+    /// reloading only checks versions installed since.
+    pub validated_versions: HashSet<NodeVersion>,
     pub default_version: Option<NodeVersion>,
+    /// Version the backend reports as active, which can differ from the
+    /// default when a shell has switched versions.
+    pub active_version: Option<NodeVersion>,
+    pub aliases: Vec<VersionAlias>,
     pub backend_name: BackendKind,
     pub backend_version: Option<String>,
+    pub backend_update: Option<BackendUpdate>,
     pub loading: bool,
     pub error: Option<AppError>,
     pub load_request_seq: u64,
@@ -37,9 +50,14 @@ impl EnvironmentState {
             installed_versions: Vec::new(),
             installed_set: HashSet::new(),
             version_groups: Vec::new(),
+            broken_versions: HashSet::new(),
+            validated_versions: HashSet::new(),
             default_version: None,
+            active_version: None,
+            aliases: Vec::new(),
             backend_name,
             backend_version,
+            backend_update: None,
             loading: true,
             error: None,
             load_request_seq: 0,
@@ -56,9 +74,14 @@ impl EnvironmentState {
             installed_versions: Vec::new(),
             installed_set: HashSet::new(),
             version_groups: Vec::new(),
+            broken_versions: HashSet::new(),
+            validated_versions: HashSet::new(),
             default_version: None,
+            active_version: None,
+            aliases: Vec::new(),
             backend_name,
             backend_version: None,
+            backend_update: None,
             loading: false,
             error: Some(AppError::environment_unavailable(reason)),
             load_request_seq: 0,
@@ -67,28 +90,149 @@ impl EnvironmentState {
         }
     }
 
+    pub fn accent(&self) -> iced::Color {
+        crate::theme::environment_accent(&self.id)
+    }
+
+    /// Label for the active version when it differs from the default.
+    pub fn active_version_note(&self) -> Option<String> {
+        let active = self.active_version.as_ref()?;
+        if self.default_version.as_ref() == Some(active) {
+            return None;
+        }
+        Some(format!("active: {active}"))
+    }
+
+    /// Names of the aliases pointing at `version`.
+    pub fn aliases_for(&self, version: &NodeVersion) -> Vec<&str> {
+        self.aliases
+            .iter()
+            .filter(|alias| &alias.version == version)
+            .map(|alias| alias.name.as_str())
+            .collect()
+    }
+
+    /// Why this environment's versions can't be copied into `target` yet,
+    /// or `None` when there is something to copy.
+    pub fn clone_blocker(&self, target: &EnvironmentState) -> Option<String> {
+        if self.error.is_some() {
+            return Some(format!(
+                "Installed versions in {} failed to load",
+                self.name
+            ));
+        }
+        if self.loading && self.installed_versions.is_empty() {
+            return Some(format!(
+                "Open {} first so its installed versions are loaded",
+                self.name
+            ));
+        }
+        if target.error.is_some() || (target.loading && target.installed_versions.is_empty()) {
+            return Some(format!(
+                "Installed versions in {} haven't loaded yet",
+                target.name
+            ));
+        }
+        if self
+            .installed_versions
+            .iter()
+            .all(|installed| target.installed_set.contains(&installed.version))
+        {
+            return Some(format!(
+                "{} already has every version installed in {}",
+                target.name, self.name
+            ));
+        }
+        None
+    }
+
+    /// Shell commands that reproduce this environment's installed versions
+    /// and default with `provider`'s backend.
+    pub fn setup_snippet(&self, provider: &dyn BackendProvider) -> String {
+        let mut versions: Vec<&NodeVersion> =
+            self.installed_versions.iter().map(|v| &v.version).collect();
+        versions.sort();
+
+        let mut lines: Vec<String> = versions
+            .iter()
+            .map(|version| provider.install_command_hint(&version.to_string()))
+            .collect();
+        if let Some(default) = &self.default_version {
+            lines.push(provider.default_command_hint(&default.to_string()));
+        }
+        lines.join("\n")
+    }
+
     pub fn update_versions(&mut self, versions: Vec<InstalledVersion>) {
         self.default_version = versions
             .iter()
             .find(|v| v.is_default)
             .map(|v| v.version.clone());
         self.installed_set = versions.iter().map(|v| v.version.clone()).collect();
+        self.broken_versions
+            .retain(|version| self.installed_set.contains(version));
+        self.validated_versions
+            .retain(|version| self.installed_set.contains(version));
         self.version_groups = VersionGroup::from_versions(&versions);
         self.installed_versions = versions;
         self.loading = false;
         self.error = None;
     }
+
+    /// Reorder version groups and the versions inside them. Groups follow
+    /// their first version under `mode`; versions missing the sorted field
+    /// go last, with ties broken by version.
+    pub fn sort_groups(&mut self, mode: VersionSortMode) {
+        for group in &mut self.version_groups {
+            group
+                .versions
+                .sort_by(|a, b| sort_key_cmp(mode, a, b).then_with(|| b.version.cmp(&a.version)));
+        }
+        self.version_groups.sort_by(|a, b| match mode {
+            VersionSortMode::Version => b.major.cmp(&a.major),
+            VersionSortMode::InstallDate | VersionSortMode::DiskSize => {
+                match (a.versions.first(), b.versions.first()) {
+                    (Some(a_first), Some(b_first)) => sort_key_cmp(mode, a_first, b_first),
+                    _ => std::cmp::Ordering::Equal,
+                }
+                .then_with(|| b.major.cmp(&a.major))
+            }
+        });
+    }
+}
+
+/// Descending order by the field `mode` sorts on, with `None` last.
+fn sort_key_cmp(
+    mode: VersionSortMode,
+    a: &InstalledVersion,
+    b: &InstalledVersion,
+) -> std::cmp::Ordering {
+    fn descending_some_first<T: Ord>(a: Option<T>, b: Option<T>) -> std::cmp::Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+
+    match mode {
+        VersionSortMode::Version => b.version.cmp(&a.version),
+        VersionSortMode::InstallDate => descending_some_first(a.install_date, b.install_date),
+        VersionSortMode::DiskSize => descending_some_first(a.disk_size, b.disk_size),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
     use std::time::{Duration, Instant};
-    use versi_backend::NodeVersion;
+    use versi_backend::{NodeVersion, VersionAlias};
     use versi_platform::EnvironmentId;
 
     use super::EnvironmentState;
     use crate::backend_kind::BackendKind;
+    use crate::state::VersionSortMode;
 
     fn installed(version: &str, is_default: bool) -> versi_backend::InstalledVersion {
         versi_backend::InstalledVersion {
@@ -189,4 +333,126 @@ mod tests {
         );
         assert!(!state.version_groups.is_empty());
     }
+
+    #[test]
+    fn setup_snippet_installs_versions_and_sets_default() {
+        let mut state = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        state.update_versions(vec![
+            installed("v22.11.0", true),
+            installed("v18.20.4", false),
+            installed("v20.18.0", false),
+        ]);
+
+        assert_eq!(
+            state.setup_snippet(&versi_fnm::FnmProvider::new()),
+            "fnm install v18.20.4\n\
+             fnm install v20.18.0\n\
+             fnm install v22.11.0\n\
+             fnm default v22.11.0"
+        );
+        assert_eq!(
+            state.setup_snippet(&versi_nvm::NvmProvider::new()),
+            "nvm install v18.20.4\n\
+             nvm install v20.18.0\n\
+             nvm install v22.11.0\n\
+             nvm alias default v22.11.0"
+        );
+    }
+
+    #[test]
+    fn aliases_for_matches_only_the_given_version() {
+        let mut state = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        state.aliases = vec![
+            VersionAlias {
+                name: "default".to_string(),
+                version: NodeVersion::new(22, 11, 0),
+            },
+            VersionAlias {
+                name: "project".to_string(),
+                version: NodeVersion::new(20, 18, 0),
+            },
+        ];
+
+        assert_eq!(
+            state.aliases_for(&NodeVersion::new(20, 18, 0)),
+            vec!["project"]
+        );
+        assert!(state.aliases_for(&NodeVersion::new(18, 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn active_version_note_only_shows_when_it_differs_from_default() {
+        let mut state = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        state.update_versions(vec![
+            installed("v22.11.0", true),
+            installed("v20.18.0", false),
+        ]);
+
+        assert_eq!(state.active_version_note(), None);
+
+        state.active_version = Some(NodeVersion::new(22, 11, 0));
+        assert_eq!(state.active_version_note(), None);
+
+        state.active_version = Some(NodeVersion::new(20, 18, 0));
+        assert_eq!(
+            state.active_version_note().as_deref(),
+            Some("active: v20.18.0")
+        );
+    }
+
+    fn sorted_fixture() -> EnvironmentState {
+        let now = Utc::now();
+        let entry = |version: &str, days_ago: i64, size: Option<u64>| {
+            let mut v = installed(version, false);
+            v.install_date = Some(now - chrono::Duration::days(days_ago));
+            v.disk_size = size;
+            v
+        };
+        let mut state = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        state.update_versions(vec![
+            entry("v22.1.0", 30, Some(100)),
+            entry("v22.2.0", 10, Some(50)),
+            entry("v20.1.0", 1, Some(10)),
+            entry("v18.1.0", 60, None),
+        ]);
+        state
+    }
+
+    fn order(state: &EnvironmentState) -> Vec<String> {
+        state
+            .version_groups
+            .iter()
+            .flat_map(|group| group.versions.iter().map(|v| v.version.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn sort_by_version_keeps_newest_major_and_patch_first() {
+        let mut state = sorted_fixture();
+        state.sort_groups(VersionSortMode::Version);
+        assert_eq!(
+            order(&state),
+            vec!["v22.2.0", "v22.1.0", "v20.1.0", "v18.1.0"]
+        );
+    }
+
+    #[test]
+    fn sort_by_install_date_puts_recent_installs_first() {
+        let mut state = sorted_fixture();
+        state.sort_groups(VersionSortMode::InstallDate);
+        assert_eq!(
+            order(&state),
+            vec!["v20.1.0", "v22.2.0", "v22.1.0", "v18.1.0"]
+        );
+    }
+
+    #[test]
+    fn sort_by_disk_size_puts_largest_first_and_unknown_last() {
+        let mut state = sorted_fixture();
+        state.sort_groups(VersionSortMode::DiskSize);
+        assert_eq!(
+            order(&state),
+            vec!["v22.1.0", "v22.2.0", "v20.1.0", "v18.1.0"]
+        );
+    }
 }
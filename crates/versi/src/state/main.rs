@@ -3,19 +3,33 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
-use versi_backend::{BackendUpdate, NodeVersion, RemoteVersion, VersionManager};
+use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionManager};
 use versi_core::{AppUpdate, ReleaseSchedule, VersionMeta};
+use versi_platform::EnvironmentId;
 
+use crate::audit_log::{self, AuditSink};
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
-use crate::version_query::{RemoteVersionSearchIndex, search_available_versions_with_index};
+use crate::settings::AppSettings;
+use crate::tray::TrayAvailability;
+use crate::version_display::display_version;
+use crate::version_files::VersionFileSuggestion;
+use crate::version_query::{
+    AvailableVersionSearch, RemoteVersionSearchIndex, ResultFilters,
+    search_available_versions_with_index,
+};
 
 use super::{
-    ContextMenu, EnvironmentState, MainViewKind, Modal, OperationQueue, SettingsModalState, Toast,
+    ContextMenu, EnvironmentState, MainViewKind, Modal, ModalFocus, Operation, OperationQueue,
+    SettingsModalState, Toast, ToastAction,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Previous defaults kept for "Revert to …".
+pub const DEFAULT_HISTORY_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SearchFilter {
     Lts,
     Installed,
@@ -24,6 +38,69 @@ pub enum SearchFilter {
     Active,
 }
 
+impl SearchFilter {
+    /// Filters in chip order; the number keys 1–5 toggle them in this order.
+    pub const ALL: [Self; 5] = [
+        Self::Lts,
+        Self::Installed,
+        Self::NotInstalled,
+        Self::Eol,
+        Self::Active,
+    ];
+
+    /// Text on this filter's chip.
+    pub fn chip_label(self) -> &'static str {
+        match self {
+            Self::Lts => "LTS",
+            Self::Installed => "Installed",
+            Self::NotInstalled => "Not installed",
+            Self::Eol => "EOL",
+            Self::Active => "Active",
+        }
+    }
+
+    /// Filter that can never match together with this one, so turning this
+    /// one on turns the other off.
+    pub fn excludes(self) -> Option<Self> {
+        match self {
+            Self::Installed => Some(Self::NotInstalled),
+            Self::NotInstalled => Some(Self::Installed),
+            Self::Eol => Some(Self::Active),
+            Self::Active => Some(Self::Eol),
+            Self::Lts => None,
+        }
+    }
+
+    /// Filter toggled by pressing `key` outside the search box.
+    pub fn from_shortcut_key(key: &str) -> Option<Self> {
+        let index: usize = key.parse().ok()?;
+        Self::ALL.get(index.checked_sub(1)?).copied()
+    }
+}
+
+/// Order of installed versions within and across major groups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VersionSortMode {
+    #[default]
+    Version,
+    InstallDate,
+    DiskSize,
+}
+
+impl VersionSortMode {
+    pub const ALL: [Self; 3] = [Self::Version, Self::InstallDate, Self::DiskSize];
+}
+
+impl std::fmt::Display for VersionSortMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Version => "Sort: Version",
+            Self::InstallDate => "Sort: Install date",
+            Self::DiskSize => "Sort: Disk size",
+        })
+    }
+}
+
 pub struct MainState {
     pub environments: Vec<EnvironmentState>,
     pub active_environment_idx: usize,
@@ -31,13 +108,17 @@ pub struct MainState {
     pub operation_queue: OperationQueue,
     pub toasts: Vec<Toast>,
     pub modal: Option<Modal>,
+    pub modal_focus: ModalFocus,
     pub search_query: String,
     pub backend: Arc<dyn VersionManager>,
     pub app_update: Option<AppUpdate>,
     pub app_update_state: AppUpdateState,
     pub app_update_check_in_flight: bool,
     pub app_update_last_checked_at: Option<Instant>,
-    pub backend_update: Option<BackendUpdate>,
+    /// Cancels the app update download while it's in progress.
+    pub app_update_cancel_token: Option<CancellationToken>,
+    /// When the backend binaries' versions were last re-read.
+    pub backend_version_checked_at: Instant,
     pub view: MainViewKind,
     pub settings_state: SettingsModalState,
     pub hovered_version: Option<String>,
@@ -45,9 +126,43 @@ pub struct MainState {
     pub detected_backends: Vec<BackendKind>,
     pub refresh_rotation: f32,
     pub active_filters: HashSet<SearchFilter>,
+    pub sort_mode: VersionSortMode,
+    /// Majors whose search result group is collapsed.
+    pub collapsed_search_majors: HashSet<u32>,
+    /// Result limit raised by "Show more" for the current query.
+    pub search_limit_override: Option<usize>,
+    /// Whether version rows show selection checkboxes.
+    pub selection_mode: bool,
+    /// Whether the list only shows installed majors with a newer release.
+    pub updatable_only: bool,
+    pub selected_versions: HashSet<String>,
+    /// Defaults replaced in the active environment, most recent last.
+    pub default_history: Vec<String>,
+    /// Version being restored by a revert, which shouldn't be recorded again.
+    pub pending_default_revert: Option<String>,
+    /// When each version was last uninstalled, for the undo window.
+    pub recently_uninstalled: HashMap<String, (EnvironmentId, Instant)>,
+    /// Latest LTS install requested before remote versions had loaded.
+    pub install_latest_lts_when_loaded: bool,
+    /// Where finished operations are recorded for the audit log.
+    pub audit_sink: AuditSink,
+    /// Install timeouts raised by "retry with a longer timeout", by version.
+    pub install_timeouts: HashMap<String, u64>,
     pub banner_stats: BannerStats,
+    /// Wall-clock time as of the last tick. Date-dependent checks and views
+    /// read this instead of the clock, so one frame sees one date.
+    pub now: DateTime<Utc>,
     pub context_menu: Option<ContextMenu>,
     pub cursor_position: iced::Point,
+    /// Version pinned by a version file in the working directory.
+    pub project_version: Option<VersionFileSuggestion>,
+    /// Most recent failed operation, shown in the header until acknowledged.
+    pub last_error: Option<(Operation, AppError)>,
+    /// Versions last read from another engine for "Import versions", kept
+    /// so the button can tell when there's nothing left to migrate.
+    pub migration_source: Option<(BackendKind, Vec<InstalledVersion>)>,
+    /// Tray status as of the last init or host probe, for the settings view.
+    pub tray_availability: TrayAvailability,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -64,10 +179,39 @@ pub enum AppUpdateState {
     Failed(AppError),
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct BannerStats {
     pub updatable_major_count: usize,
     pub eol_installed_count: usize,
+    /// Installed versions outside the patch retention policy.
+    pub retention_candidates: Vec<String>,
+    /// Dismissal keys naming the versions each banner is about, so a
+    /// dismissed banner comes back once that set changes.
+    pub updates_key: Option<String>,
+    pub eol_key: Option<String>,
+    pub retention_key: Option<String>,
+    /// Set while this build is old enough to suggest updating.
+    pub build_age_key: Option<String>,
+}
+
+/// Builds older than this get a nudge to update.
+const BUILD_AGE_THRESHOLD_DAYS: i64 = 365;
+
+fn build_timestamp() -> Option<DateTime<Utc>> {
+    let secs = option_env!("VERSI_BUILD_TIMESTAMP")?.parse().ok()?;
+    DateTime::from_timestamp(secs, 0)
+}
+
+fn is_build_outdated(built_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now.signed_duration_since(built_at).num_days() > BUILD_AGE_THRESHOLD_DAYS
+}
+
+/// Dismissal key of the "build is old" banner, when it should show as of
+/// `now`.
+fn build_age_key(now: DateTime<Utc>) -> Option<String> {
+    let built_at = build_timestamp()?;
+    is_build_outdated(built_at, now)
+        .then(|| AppSettings::banner_key("build-age", &built_at.timestamp().to_string()))
 }
 
 impl std::fmt::Debug for MainState {
@@ -82,7 +226,6 @@ impl std::fmt::Debug for MainState {
             .field("search_query", &self.search_query)
             .field("backend", &self.backend.name())
             .field("app_update", &self.app_update)
-            .field("backend_update", &self.backend_update)
             .field("view", &self.view)
             .field("hovered_version", &self.hovered_version)
             .finish_non_exhaustive()
@@ -102,13 +245,15 @@ impl MainState {
             operation_queue: OperationQueue::new(),
             toasts: Vec::new(),
             modal: None,
+            modal_focus: ModalFocus::default(),
             search_query: String::new(),
             backend,
             app_update: None,
             app_update_state: AppUpdateState::default(),
             app_update_check_in_flight: false,
             app_update_last_checked_at: None,
-            backend_update: None,
+            app_update_cancel_token: None,
+            backend_version_checked_at: Instant::now(),
             view: MainViewKind::default(),
             settings_state: SettingsModalState::new(),
             hovered_version: None,
@@ -116,9 +261,26 @@ impl MainState {
             detected_backends: Vec::new(),
             refresh_rotation: 0.0,
             active_filters: HashSet::new(),
+            sort_mode: VersionSortMode::default(),
+            collapsed_search_majors: HashSet::new(),
+            search_limit_override: None,
+            selection_mode: false,
+            updatable_only: false,
+            selected_versions: HashSet::new(),
+            default_history: Vec::new(),
+            pending_default_revert: None,
+            recently_uninstalled: HashMap::new(),
+            install_latest_lts_when_loaded: false,
+            audit_sink: audit_log::log_writer(),
+            install_timeouts: HashMap::new(),
             banner_stats: BannerStats::default(),
+            now: Utc::now(),
             context_menu: None,
             cursor_position: iced::Point::ORIGIN,
+            project_version: None,
+            last_error: None,
+            migration_source: None,
+            tray_availability: TrayAvailability::Disabled,
         }
     }
 
@@ -126,46 +288,267 @@ impl MainState {
         &self.environments[self.active_environment_idx]
     }
 
+    /// Whether `source` was read and the active environment already has
+    /// every version it has.
+    pub fn nothing_to_migrate_from(&self, source: BackendKind) -> bool {
+        let installed_set = &self.active_environment().installed_set;
+        self.migration_source
+            .as_ref()
+            .filter(|(kind, _)| *kind == source)
+            .is_some_and(|(_, versions)| {
+                versions
+                    .iter()
+                    .all(|installed| installed_set.contains(&installed.version))
+            })
+    }
+
     pub fn active_environment_mut(&mut self) -> &mut EnvironmentState {
         &mut self.environments[self.active_environment_idx]
     }
 
+    /// Enter or leave selection mode. Leaving it clears the selection.
+    pub fn set_selection_mode(&mut self, enabled: bool) {
+        self.selection_mode = enabled;
+        if !enabled {
+            self.selected_versions.clear();
+        }
+    }
+
+    pub fn toggle_version_selected(&mut self, version: &str) {
+        if !self.selected_versions.remove(version) {
+            self.selected_versions.insert(version.to_string());
+        }
+    }
+
+    /// Selected versions that are installed in the active environment, in
+    /// list order.
+    pub fn selected_installed_versions(&self) -> Vec<&InstalledVersion> {
+        self.active_environment()
+            .installed_versions
+            .iter()
+            .filter(|installed| {
+                self.selected_versions
+                    .contains(&installed.version.to_string())
+            })
+            .collect()
+    }
+
+    /// Merge a fresh WSL scan into `environments`.
+    ///
+    /// Loaded environments whose distro and backend are unchanged are kept,
+    /// changed or newly stopped distros are replaced by the scanned state,
+    /// unregistered distros are dropped and new ones are appended. Returns
+    /// `true` when the active environment had to change.
+    pub fn reconcile_wsl_environments(&mut self, detected: Vec<EnvironmentState>) -> bool {
+        let active_id = self.active_environment().id.clone();
+        let mut detected = detected;
+        let mut reconciled = Vec::with_capacity(self.environments.len().max(detected.len() + 1));
+
+        for env in self.environments.drain(..) {
+            let Some(distro) = wsl_distro(&env.id) else {
+                reconciled.push(env);
+                continue;
+            };
+            let Some(position) = detected
+                .iter()
+                .position(|fresh| wsl_distro(&fresh.id) == Some(distro))
+            else {
+                if let Some(token) = &env.load_cancel_token {
+                    token.cancel();
+                }
+                continue;
+            };
+
+            let fresh = detected.remove(position);
+            if env.available && fresh.available && env.id == fresh.id {
+                reconciled.push(env);
+            } else {
+                if let Some(token) = &env.load_cancel_token {
+                    token.cancel();
+                }
+                reconciled.push(fresh);
+            }
+        }
+        reconciled.extend(detected);
+        self.environments = reconciled;
+
+        match self
+            .environments
+            .iter()
+            .position(|env| env.id == active_id && env.available)
+        {
+            Some(idx) => {
+                self.active_environment_idx = idx;
+                false
+            }
+            None => {
+                self.active_environment_idx = 0;
+                true
+            }
+        }
+    }
+
     pub fn add_toast(&mut self, toast: Toast) {
         self.toasts.push(toast);
     }
 
-    pub fn recompute_banner_stats(&mut self) {
-        let env = &self.environments[self.active_environment_idx];
-        let updatable_major_count = env
+    /// Refresh what the banners show, as of `self.now`. Call when versions,
+    /// the schedule, the active environment or the retention policy change.
+    pub fn recompute_banner_stats(&mut self, patches_to_keep_per_major: Option<usize>) {
+        let mut update_versions: Vec<String> = self
+            .available_update_versions()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+        update_versions.sort_unstable();
+
+        let mut eol_majors = self.eol_installed_majors_at(self.now);
+        eol_majors.sort_unstable();
+        let eol_installed_count = self
+            .active_environment()
+            .version_groups
+            .iter()
+            .filter(|group| eol_majors.contains(&group.major))
+            .map(|group| group.versions.len())
+            .sum();
+        let eol_majors: Vec<String> = eol_majors.iter().map(ToString::to_string).collect();
+
+        let retention_candidates = patches_to_keep_per_major
+            .map_or_else(Vec::new, |keep| self.retention_prune_candidates(keep));
+        let mut retained = retention_candidates.clone();
+        retained.sort_unstable();
+
+        let key = |kind: &str, items: &[String]| {
+            (!items.is_empty()).then(|| AppSettings::banner_key(kind, &items.join(",")))
+        };
+        self.banner_stats = BannerStats {
+            updatable_major_count: update_versions.len(),
+            eol_installed_count,
+            updates_key: key("updates", &update_versions),
+            eol_key: key("eol", &eol_majors),
+            retention_key: key("retention", &retained),
+            retention_candidates,
+            build_age_key: build_age_key(self.now),
+        };
+    }
+
+    /// The newest release of each installed major that has a newer release
+    /// than anything installed.
+    pub fn available_update_versions(&self) -> Vec<&NodeVersion> {
+        self.active_environment()
             .version_groups
             .iter()
-            .filter(|group| {
-                let installed_latest = group.versions.iter().map(|v| &v.version).max();
+            .filter_map(|group| {
+                let installed_latest = group.versions.iter().map(|v| &v.version).max()?;
                 self.available_versions
                     .latest_by_major
                     .get(&group.major)
-                    .is_some_and(|latest| {
-                        installed_latest.is_some_and(|installed| latest > installed)
-                    })
+                    .filter(|latest| *latest > installed_latest)
             })
-            .count();
-
-        let eol_installed_count = self
-            .available_versions
-            .schedule
-            .as_ref()
-            .map_or(0, |schedule| {
-                env.version_groups
-                    .iter()
-                    .filter(|group| !schedule.is_active(group.major))
-                    .map(|group| group.versions.len())
-                    .sum::<usize>()
-            });
+            .collect()
+    }
 
-        self.banner_stats = BannerStats {
-            updatable_major_count,
-            eol_installed_count,
+    /// Installed majors that are past end-of-life as of `as_of`.
+    pub fn eol_installed_majors_at(&self, as_of: DateTime<Utc>) -> Vec<u32> {
+        let Some(schedule) = self.available_versions.schedule.as_ref() else {
+            return Vec::new();
         };
+        self.active_environment()
+            .version_groups
+            .iter()
+            .filter(|group| !schedule.is_active_at(group.major, as_of))
+            .map(|group| group.major)
+            .collect()
+    }
+
+    /// The newest release of the newest supported LTS line, i.e. what to
+    /// install when unsure. Lines that are scheduled for LTS but haven't had
+    /// an LTS release yet are skipped.
+    pub fn recommended_version(&self) -> Option<&NodeVersion> {
+        let cache = &self.available_versions;
+        let schedule = cache.schedule.as_ref()?;
+        let mut lts_majors = schedule.active_lts_versions();
+        lts_majors.sort_unstable_by(|a, b| b.cmp(a));
+        lts_majors.into_iter().find_map(|major| {
+            let latest = cache.latest_by_major.get(&major)?;
+            cache
+                .versions
+                .iter()
+                .any(|remote| &remote.version == latest && remote.lts_codename.is_some())
+                .then_some(latest)
+        })
+    }
+
+    /// Installed versions in the active environment that fall outside the
+    /// newest `keep` patches of their major. The default version is never
+    /// included.
+    pub fn retention_prune_candidates(&self, keep: usize) -> Vec<String> {
+        let env = self.active_environment();
+        env.version_groups
+            .iter()
+            .flat_map(|group| {
+                let mut versions: Vec<&NodeVersion> =
+                    group.versions.iter().map(|v| &v.version).collect();
+                versions.sort_by(|a, b| b.cmp(a));
+                versions.into_iter().skip(keep)
+            })
+            .filter(|version| env.default_version.as_ref() != Some(*version))
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Remember `version` as the default that was just replaced. Only the
+    /// last [`DEFAULT_HISTORY_LIMIT`] defaults are kept.
+    pub fn push_previous_default(&mut self, version: String) {
+        if self.default_history.last() == Some(&version) {
+            return;
+        }
+        self.default_history.push(version);
+        if self.default_history.len() > DEFAULT_HISTORY_LIMIT {
+            self.default_history.remove(0);
+        }
+    }
+
+    pub fn pop_previous_default(&mut self) -> Option<String> {
+        self.default_history.pop()
+    }
+
+    pub fn record_uninstall(&mut self, environment: EnvironmentId, version: &str) {
+        self.recently_uninstalled
+            .insert(version.to_string(), (environment, Instant::now()));
+    }
+
+    /// Forget `version`'s uninstall, returning whether it happened in
+    /// `environment` within `window` and can still be undone.
+    pub fn take_undoable_uninstall(
+        &mut self,
+        environment: &EnvironmentId,
+        version: &str,
+        window: Duration,
+    ) -> bool {
+        self.recently_uninstalled
+            .remove(version)
+            .is_some_and(|(removed_from, at)| {
+                &removed_from == environment && at.elapsed() <= window
+            })
+    }
+
+    pub fn prune_recent_uninstalls(&mut self, window: Duration) {
+        self.recently_uninstalled
+            .retain(|_, (_, at)| at.elapsed() <= window);
+    }
+
+    /// Dismiss toasts whose action belongs to an environment that is no
+    /// longer active, so it can't be applied to the wrong one.
+    pub fn dismiss_other_environment_actions(&mut self) {
+        let active = self.active_environment().id.clone();
+        self.toasts.retain(|toast| {
+            toast
+                .action
+                .as_ref()
+                .and_then(ToastAction::environment)
+                .is_none_or(|environment| environment == &active)
+        });
     }
 
     pub fn remove_toast(&mut self, id: usize) {
@@ -176,40 +559,92 @@ impl MainState {
         self.toasts.iter().map(|t| t.id).max().unwrap_or(0) + 1
     }
 
+    /// The search result limit for the current query: the configured limit,
+    /// or a higher one after "Show more".
+    pub fn effective_search_limit(&self, search_results_limit: usize) -> usize {
+        self.search_limit_override
+            .map_or(search_results_limit, |limit| {
+                limit.max(search_results_limit)
+            })
+    }
+
     pub fn navigable_versions(&self, search_results_limit: usize) -> Vec<String> {
+        if !self.search_query.is_empty() {
+            let search = self.search_available(search_results_limit);
+            return search
+                .versions
+                .into_iter()
+                .filter(|v| {
+                    search.alias_resolved
+                        || !self.collapsed_search_majors.contains(&v.version.major)
+                })
+                .map(|v| v.version.to_string())
+                .collect();
+        }
+
         let env = self.active_environment();
         let mut result = Vec::new();
         let mut version_text = String::with_capacity(16);
-
-        if self.search_query.is_empty() {
-            for group in &env.version_groups {
-                if group.is_expanded {
-                    for v in &group.versions {
-                        v.version.write_prefixed_into(&mut version_text);
-                        result.push(version_text.clone());
-                    }
+        for group in &env.version_groups {
+            if group.is_expanded {
+                for v in &group.versions {
+                    v.version.write_prefixed_into(&mut version_text);
+                    result.push(version_text.clone());
                 }
             }
-        } else {
-            let search = search_available_versions_with_index(
-                &self.available_versions.versions,
-                Some(&self.available_versions.search_index),
-                &self.search_query,
-                search_results_limit,
-                &self.active_filters,
-                &env.installed_set,
-                self.available_versions.schedule.as_ref(),
-            );
-
-            for v in search.versions {
-                v.version.write_prefixed_into(&mut version_text);
-                result.push(version_text.clone());
-            }
         }
 
         result
     }
 
+    /// Versions matching the current search query and filters, in the order
+    /// the search results show them.
+    pub fn search_result_versions(&self, search_results_limit: usize) -> Vec<String> {
+        self.search_available(search_results_limit)
+            .versions
+            .into_iter()
+            .map(|v| v.version.to_string())
+            .collect()
+    }
+
+    fn search_available(&self, search_results_limit: usize) -> AvailableVersionSearch<'_> {
+        search_available_versions_with_index(
+            &self.available_versions.versions,
+            Some(&self.available_versions.search_index),
+            &self.search_query,
+            self.effective_search_limit(search_results_limit),
+            ResultFilters {
+                active: &self.active_filters,
+                installed_set: &self.active_environment().installed_set,
+                schedule: self.available_versions.schedule.as_ref(),
+                as_of: self.now,
+            },
+        )
+    }
+
+    /// Newline-separated installed versions of the active environment, for
+    /// copying to the clipboard.
+    pub fn installed_versions_text(&self, version_prefix: bool) -> String {
+        let versions: Vec<String> = self
+            .active_environment()
+            .version_groups
+            .iter()
+            .flat_map(|group| &group.versions)
+            .map(|v| display_version(&v.version.to_string(), version_prefix))
+            .collect();
+        versions.join("\n")
+    }
+
+    /// Newline-separated search results, respecting the active filters.
+    pub fn search_results_text(&self, search_results_limit: usize, version_prefix: bool) -> String {
+        let versions: Vec<String> = self
+            .search_result_versions(search_results_limit)
+            .iter()
+            .map(|version| display_version(version, version_prefix))
+            .collect();
+        versions.join("\n")
+    }
+
     pub fn is_version_installed(&self, version_str: &str) -> bool {
         version_str
             .parse()
@@ -217,6 +652,53 @@ impl MainState {
             .is_some_and(|version| self.active_environment().installed_set.contains(&version))
     }
 
+    /// Version pinned by the working directory that the native environment
+    /// doesn't have installed yet, unless it is already queued.
+    pub fn missing_project_version(&self) -> Option<&VersionFileSuggestion> {
+        let suggestion = self.project_version.as_ref()?;
+        let env = self.active_environment();
+        if env.id != EnvironmentId::Native || env.loading {
+            return None;
+        }
+        if suggestion.is_satisfied_by(&env.installed_set)? {
+            return None;
+        }
+        let queued = self.operation_queue.is_current_version(&suggestion.spec)
+            || self
+                .operation_queue
+                .has_pending_for_version(&suggestion.spec);
+        (!queued).then_some(suggestion)
+    }
+
+    /// Whether the active environment or any version data is still loading.
+    pub fn is_refreshing(&self) -> bool {
+        let cache = &self.available_versions;
+        self.active_environment().loading
+            || cache.remote.is_in_flight()
+            || cache.schedule_fetch.is_in_flight()
+            || cache.metadata_fetch.is_in_flight()
+    }
+
+    /// Describe which version data fetches are in flight, e.g.
+    /// `Fetching versions, schedule…`. Returns `None` when nothing is loading.
+    pub fn fetch_stage_summary(&self) -> Option<String> {
+        let cache = &self.available_versions;
+        let stages: Vec<&str> = [
+            (&cache.remote, "versions"),
+            (&cache.schedule_fetch, "schedule"),
+            (&cache.metadata_fetch, "metadata"),
+        ]
+        .into_iter()
+        .filter(|(fetch, _)| fetch.is_in_flight())
+        .map(|(_, stage)| stage)
+        .collect();
+
+        if stages.is_empty() {
+            return None;
+        }
+        Some(format!("Fetching {}…", stages.join(", ")))
+    }
+
     pub fn should_check_for_app_updates(&self, interval: Duration) -> bool {
         if self.app_update_check_in_flight {
             return false;
@@ -226,6 +708,10 @@ impl MainState {
                 Instant::now().saturating_duration_since(last_checked_at) >= interval
             })
     }
+
+    pub fn should_recheck_backend_version(&self, interval: Duration) -> bool {
+        Instant::now().saturating_duration_since(self.backend_version_checked_at) >= interval
+    }
 }
 
 /// Tracks the request lifecycle for a cancellable async fetch.
@@ -266,6 +752,10 @@ impl FetchState {
         self.cancel_token = None;
         true
     }
+
+    pub fn is_in_flight(&self) -> bool {
+        self.cancel_token.is_some()
+    }
 }
 
 #[derive(Debug)]
@@ -276,9 +766,13 @@ pub struct VersionCache {
     pub loading: bool,
     pub remote: FetchState,
     pub schedule: Option<ReleaseSchedule>,
+    /// `schedule` is the snapshot compiled into the app, not fetched data.
+    pub schedule_is_bundled: bool,
     pub schedule_fetch: FetchState,
     pub metadata: Option<HashMap<String, VersionMeta>>,
     pub metadata_fetch: FetchState,
+    /// Versions that already triggered a metadata refresh this session.
+    pub metadata_requested: HashSet<String>,
     pub loaded_from_disk: bool,
     pub disk_cached_at: Option<DateTime<Utc>>,
     pub search_index: RemoteVersionSearchIndex,
@@ -293,15 +787,41 @@ impl VersionCache {
             loading: false,
             remote: FetchState::new(),
             schedule: None,
+            schedule_is_bundled: false,
             schedule_fetch: FetchState::new(),
             metadata: None,
             metadata_fetch: FetchState::new(),
+            metadata_requested: HashSet::new(),
             loaded_from_disk: false,
             disk_cached_at: None,
             search_index: RemoteVersionSearchIndex::default(),
         }
     }
 
+    /// Drop all cached data and cancel in-flight fetches. Request sequence
+    /// numbers are kept so responses to cancelled requests are still ignored.
+    pub fn clear(&mut self) {
+        for fetch in [
+            &mut self.remote,
+            &mut self.schedule_fetch,
+            &mut self.metadata_fetch,
+        ] {
+            if let Some(token) = fetch.cancel_token.take() {
+                token.cancel();
+            }
+            fetch.error = None;
+        }
+        self.set_versions(Vec::new());
+        self.fetched_at = None;
+        self.loading = false;
+        self.schedule = None;
+        self.schedule_is_bundled = false;
+        self.metadata = None;
+        self.metadata_requested.clear();
+        self.loaded_from_disk = false;
+        self.disk_cached_at = None;
+    }
+
     pub fn set_versions(&mut self, versions: Vec<RemoteVersion>) {
         self.versions = versions;
         self.search_index = RemoteVersionSearchIndex::from_versions(&self.versions);
@@ -323,6 +843,25 @@ impl VersionCache {
         }
     }
 
+    /// Whether the data loaded from disk is older than `ttl` and should be
+    /// refreshed. A cache without versions or a timestamp is always stale.
+    pub fn is_stale(&self, ttl: Duration) -> bool {
+        self.is_stale_at(ttl, Utc::now())
+    }
+
+    fn is_stale_at(&self, ttl: Duration, now: DateTime<Utc>) -> bool {
+        if self.versions.is_empty() {
+            return true;
+        }
+        let Some(cached_at) = self.disk_cached_at else {
+            return true;
+        };
+        let Ok(ttl) = chrono::Duration::from_std(ttl) else {
+            return false;
+        };
+        now.signed_duration_since(cached_at) >= ttl
+    }
+
     pub fn network_status(&self) -> NetworkStatus {
         if self.loading {
             return NetworkStatus::Fetching;
@@ -344,13 +883,24 @@ pub enum NetworkStatus {
     Stale,
 }
 
+fn wsl_distro(id: &EnvironmentId) -> Option<&str> {
+    match id {
+        EnvironmentId::Native => None,
+        EnvironmentId::Wsl { distro, .. } => Some(distro),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
+    use std::time::Duration;
 
-    use super::{MainState, NetworkStatus, SearchFilter, VersionCache};
+    use super::{MainState, NetworkStatus, SearchFilter, VersionCache, is_build_outdated};
     use crate::backend_kind::BackendKind;
+    use crate::settings::AppSettings;
     use crate::state::EnvironmentState;
+    use crate::tray::TrayAvailability;
+    use crate::version_files::VersionFileSuggestion;
     use versi_backend::{NodeVersion, RemoteVersion};
     use versi_platform::EnvironmentId;
 
@@ -413,6 +963,65 @@ mod tests {
         assert!(matches!(cache.network_status(), NetworkStatus::Stale));
     }
 
+    fn cache_with_versions_cached_at(cached_at: chrono::DateTime<chrono::Utc>) -> VersionCache {
+        let mut cache = VersionCache::new();
+        cache.set_versions(vec![remote(NodeVersion::new(22, 11, 0), Some("Jod"))]);
+        cache.disk_cached_at = Some(cached_at);
+        cache
+    }
+
+    #[test]
+    fn clear_resets_data_and_keeps_request_sequence() {
+        let mut cache = cache_with_versions_cached_at(chrono::Utc::now());
+        cache.loaded_from_disk = true;
+        cache.schedule = Some(schedule_with_eol_major(18));
+        cache.metadata = Some(std::collections::HashMap::new());
+        let (token, seq) = cache.remote.start();
+
+        cache.clear();
+
+        assert!(cache.versions.is_empty());
+        assert!(cache.latest_by_major.is_empty());
+        assert!(cache.schedule.is_none());
+        assert!(cache.metadata.is_none());
+        assert!(!cache.loaded_from_disk);
+        assert!(cache.disk_cached_at.is_none());
+        assert!(token.is_cancelled());
+        assert!(!cache.remote.is_in_flight());
+        assert_eq!(cache.remote.request_seq, seq);
+        assert!(cache.is_stale(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn is_stale_compares_cache_age_against_ttl() {
+        let now = chrono::Utc::now();
+        let ttl = Duration::from_secs(3600);
+        let hour = chrono::Duration::hours(1);
+        let second = chrono::Duration::seconds(1);
+
+        assert!(!cache_with_versions_cached_at(now).is_stale_at(ttl, now));
+        assert!(!cache_with_versions_cached_at(now - hour + second).is_stale_at(ttl, now));
+        assert!(cache_with_versions_cached_at(now - hour).is_stale_at(ttl, now));
+        assert!(cache_with_versions_cached_at(now - hour - second).is_stale_at(ttl, now));
+    }
+
+    #[test]
+    fn is_stale_when_cache_is_empty_or_untimestamped() {
+        let ttl = Duration::from_secs(3600);
+        assert!(VersionCache::new().is_stale(ttl));
+
+        let mut cache = cache_with_versions_cached_at(chrono::Utc::now());
+        cache.disk_cached_at = None;
+        assert!(cache.is_stale(ttl));
+    }
+
+    #[test]
+    fn is_stale_treats_future_timestamps_as_fresh() {
+        let now = chrono::Utc::now();
+        let cache = cache_with_versions_cached_at(now + chrono::Duration::minutes(5));
+        assert!(!cache.is_stale_at(Duration::from_secs(3600), now));
+    }
+
     fn main_state_with_native_env() -> MainState {
         let provider: std::sync::Arc<dyn versi_backend::BackendProvider> =
             std::sync::Arc::new(versi_fnm::FnmProvider::new());
@@ -428,6 +1037,173 @@ mod tests {
         MainState::new_with_environments(backend, vec![env], BackendKind::Fnm)
     }
 
+    #[test]
+    fn missing_project_version_requires_an_uninstalled_pinned_version() {
+        let mut state = main_state_with_native_env();
+        state
+            .active_environment_mut()
+            .update_versions(vec![installed(NodeVersion::new(20, 11, 0), true)]);
+        assert!(state.missing_project_version().is_none());
+
+        state.project_version = Some(VersionFileSuggestion {
+            path: PathBuf::from("/work/app/.nvmrc"),
+            spec: "20".to_string(),
+            version: None,
+        });
+        assert!(state.missing_project_version().is_none());
+
+        state.project_version = Some(VersionFileSuggestion {
+            path: PathBuf::from("/work/app/.tool-versions"),
+            spec: "18.19.1".to_string(),
+            version: Some(NodeVersion::new(18, 19, 1)),
+        });
+        assert_eq!(
+            state.missing_project_version().map(|s| s.spec.as_str()),
+            Some("18.19.1")
+        );
+
+        state.operation_queue.start_install("18.19.1".to_string());
+        assert!(state.missing_project_version().is_none());
+    }
+
+    #[test]
+    fn default_history_pushes_and_pops_most_recent_first() {
+        let mut state = main_state_with_native_env();
+
+        state.push_previous_default("v18.19.1".to_string());
+        state.push_previous_default("v20.11.0".to_string());
+        state.push_previous_default("v20.11.0".to_string());
+
+        assert_eq!(state.pop_previous_default().as_deref(), Some("v20.11.0"));
+        assert_eq!(state.pop_previous_default().as_deref(), Some("v18.19.1"));
+        assert_eq!(state.pop_previous_default(), None);
+    }
+
+    #[test]
+    fn default_history_keeps_only_the_most_recent_entries() {
+        let mut state = main_state_with_native_env();
+
+        for minor in 0..8 {
+            state.push_previous_default(format!("v20.{minor}.0"));
+        }
+
+        assert_eq!(state.default_history.len(), super::DEFAULT_HISTORY_LIMIT);
+        assert_eq!(
+            state.default_history.first().map(String::as_str),
+            Some("v20.3.0")
+        );
+        assert_eq!(state.pop_previous_default().as_deref(), Some("v20.7.0"));
+    }
+
+    #[test]
+    fn number_keys_map_to_filters_in_chip_order() {
+        let cases = [
+            ("1", SearchFilter::Lts),
+            ("2", SearchFilter::Installed),
+            ("3", SearchFilter::NotInstalled),
+            ("4", SearchFilter::Eol),
+            ("5", SearchFilter::Active),
+        ];
+        for (key, filter) in cases {
+            assert_eq!(SearchFilter::from_shortcut_key(key), Some(filter), "{key}");
+        }
+        assert_eq!(SearchFilter::from_shortcut_key("0"), None);
+        assert_eq!(SearchFilter::from_shortcut_key("6"), None);
+        assert_eq!(SearchFilter::from_shortcut_key("a"), None);
+    }
+
+    #[test]
+    fn copied_search_results_respect_active_filters() {
+        let mut state = main_state_with_native_env();
+        state.available_versions.set_versions(vec![
+            remote(NodeVersion::new(22, 1, 0), None),
+            remote(NodeVersion::new(20, 11, 0), Some("Iron")),
+            remote(NodeVersion::new(20, 10, 0), Some("Iron")),
+            remote(NodeVersion::new(18, 19, 1), Some("Hydrogen")),
+        ]);
+        state.active_environment_mut().update_versions(vec![
+            installed(NodeVersion::new(20, 11, 0), true),
+            installed(NodeVersion::new(18, 19, 1), false),
+        ]);
+        state.search_query = "20".to_string();
+
+        assert_eq!(state.search_results_text(20, true).lines().count(), 2);
+
+        state.active_filters.insert(SearchFilter::Installed);
+        assert_eq!(state.search_results_text(20, true), "v20.11.0");
+        assert_eq!(state.search_results_text(20, false), "20.11.0");
+    }
+
+    #[test]
+    fn copied_installed_versions_are_newline_separated() {
+        let mut state = main_state_with_native_env();
+        state.active_environment_mut().update_versions(vec![
+            installed(NodeVersion::new(20, 11, 0), true),
+            installed(NodeVersion::new(18, 19, 1), false),
+        ]);
+
+        let text = state.installed_versions_text(true);
+
+        let mut lines: Vec<&str> = text.lines().collect();
+        lines.sort_unstable();
+        assert_eq!(lines, vec!["v18.19.1", "v20.11.0"]);
+    }
+
+    fn wsl_env(distro: &str, backend_path: &str) -> EnvironmentState {
+        let id = EnvironmentId::Wsl {
+            distro: distro.to_string(),
+            backend_path: backend_path.to_string(),
+        };
+        if backend_path.is_empty() {
+            EnvironmentState::unavailable(id, BackendKind::Fnm, "Not running")
+        } else {
+            EnvironmentState::new(id, BackendKind::Fnm, None)
+        }
+    }
+
+    #[test]
+    fn reconcile_wsl_environments_adds_new_and_marks_stopped_distros() {
+        let mut state = main_state_with_native_env();
+        let mut ubuntu = wsl_env("Ubuntu", "/usr/bin/fnm");
+        ubuntu.update_versions(vec![installed(NodeVersion::new(22, 1, 0), true)]);
+        state.environments.push(ubuntu);
+        state.environments.push(wsl_env("Debian", "/usr/bin/fnm"));
+        state.environments.push(wsl_env("Removed", "/usr/bin/fnm"));
+        state.active_environment_idx = 1;
+
+        let active_changed = state.reconcile_wsl_environments(vec![
+            wsl_env("Ubuntu", "/usr/bin/fnm"),
+            wsl_env("Debian", ""),
+            wsl_env("Alpine", "/usr/local/bin/fnm"),
+        ]);
+
+        let names: Vec<_> = state
+            .environments
+            .iter()
+            .map(|env| env.name.as_str())
+            .collect();
+        assert_eq!(names[1..], ["WSL: Ubuntu", "WSL: Debian", "WSL: Alpine"]);
+        assert!(!active_changed);
+        assert_eq!(state.active_environment_idx, 1);
+        assert_eq!(state.active_environment().installed_versions.len(), 1);
+        assert!(!state.environments[2].available);
+        assert!(state.environments[3].available);
+        assert!(state.environments[3].loading);
+    }
+
+    #[test]
+    fn reconcile_wsl_environments_falls_back_when_active_distro_stops() {
+        let mut state = main_state_with_native_env();
+        state.environments.push(wsl_env("Ubuntu", "/usr/bin/fnm"));
+        state.active_environment_idx = 1;
+
+        let active_changed = state.reconcile_wsl_environments(vec![wsl_env("Ubuntu", "")]);
+
+        assert!(active_changed);
+        assert_eq!(state.active_environment_idx, 0);
+        assert!(!state.environments[1].available);
+    }
+
     fn remote(version: NodeVersion, lts: Option<&str>) -> RemoteVersion {
         RemoteVersion {
             version,
@@ -465,6 +1241,36 @@ mod tests {
         .expect("schedule fixture should deserialize")
     }
 
+    #[test]
+    fn fetch_stage_summary_lists_in_flight_fetches() {
+        let mut state = main_state_with_native_env();
+        assert_eq!(state.fetch_stage_summary(), None);
+
+        let _ = state.available_versions.remote.start();
+        assert_eq!(
+            state.fetch_stage_summary().as_deref(),
+            Some("Fetching versions…")
+        );
+
+        let (_, schedule_seq) = state.available_versions.schedule_fetch.start();
+        let (_, metadata_seq) = state.available_versions.metadata_fetch.start();
+        assert_eq!(
+            state.fetch_stage_summary().as_deref(),
+            Some("Fetching versions, schedule, metadata…")
+        );
+
+        let remote_seq = state.available_versions.remote.request_seq;
+        assert!(state.available_versions.remote.accept(remote_seq));
+        assert!(state.available_versions.metadata_fetch.accept(metadata_seq));
+        assert_eq!(
+            state.fetch_stage_summary().as_deref(),
+            Some("Fetching schedule…")
+        );
+
+        assert!(state.available_versions.schedule_fetch.accept(schedule_seq));
+        assert_eq!(state.fetch_stage_summary(), None);
+    }
+
     #[test]
     fn navigable_versions_uses_expanded_groups_without_search() {
         let mut state = main_state_with_native_env();
@@ -499,6 +1305,23 @@ mod tests {
         assert_eq!(state.navigable_versions(10), vec!["v20.12.0".to_string()]);
     }
 
+    #[test]
+    fn navigable_versions_skip_collapsed_search_groups() {
+        let mut state = main_state_with_native_env();
+        state.available_versions.set_versions(vec![
+            remote(NodeVersion::new(22, 11, 0), Some("Jod")),
+            remote(NodeVersion::new(20, 12, 0), Some("Iron")),
+        ]);
+        state.search_query = "v2".to_string();
+        state.collapsed_search_majors.insert(22);
+
+        assert_eq!(state.navigable_versions(10), vec!["v20.12.0".to_string()]);
+        assert_eq!(
+            state.search_result_versions(10),
+            vec!["v22.11.0".to_string(), "v20.12.0".to_string()]
+        );
+    }
+
     #[test]
     fn is_version_installed_checks_active_environment_versions() {
         let mut state = main_state_with_native_env();
@@ -533,9 +1356,134 @@ mod tests {
         ]);
         state.available_versions.schedule = Some(schedule_with_eol_major(20));
 
-        state.recompute_banner_stats();
+        state.now =
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2026, 10, 16, 12, 0, 0).unwrap();
+        state.recompute_banner_stats(Some(1));
 
         assert_eq!(state.banner_stats.updatable_major_count, 1);
         assert_eq!(state.banner_stats.eol_installed_count, 2);
+        assert_eq!(state.banner_stats.retention_candidates, vec!["v20.10.0"]);
+        assert_eq!(
+            state.banner_stats.eol_key,
+            Some(AppSettings::banner_key("eol", "20"))
+        );
+        assert_eq!(
+            state.banner_stats.retention_key,
+            Some(AppSettings::banner_key("retention", "v20.10.0"))
+        );
+    }
+
+    #[test]
+    fn build_is_outdated_only_after_a_year() {
+        use chrono::{TimeZone, Utc};
+
+        let built_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(!is_build_outdated(
+            built_at,
+            Utc.with_ymd_and_hms(2025, 12, 31, 0, 0, 0).unwrap()
+        ));
+        assert!(!is_build_outdated(
+            built_at,
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+        ));
+        assert!(is_build_outdated(
+            built_at,
+            Utc.with_ymd_and_hms(2026, 1, 3, 0, 0, 0).unwrap()
+        ));
+    }
+
+    #[test]
+    fn recommended_version_is_latest_release_of_newest_lts_line() {
+        let mut state = main_state_with_native_env();
+        let remote = |version: NodeVersion, lts: Option<&str>| RemoteVersion {
+            version,
+            lts_codename: lts.map(str::to_string),
+            is_latest: false,
+        };
+        state.available_versions.set_versions(vec![
+            remote(NodeVersion::new(18, 20, 8), Some("Hydrogen")),
+            remote(NodeVersion::new(20, 18, 0), Some("Iron")),
+            remote(NodeVersion::new(22, 10, 0), Some("Jod")),
+            remote(NodeVersion::new(22, 11, 0), Some("Jod")),
+            remote(NodeVersion::new(24, 1, 0), None),
+        ]);
+        state.available_versions.schedule = Some(
+            serde_json::from_value(serde_json::json!({
+                "versions": {
+                    "18": {
+                        "start": "2022-04-19",
+                        "lts": "2022-10-25",
+                        "end": "2025-04-30",
+                        "codename": "Hydrogen"
+                    },
+                    "20": {
+                        "start": "2023-04-18",
+                        "lts": "2023-10-24",
+                        "end": "2099-04-30",
+                        "codename": "Iron"
+                    },
+                    "22": {
+                        "start": "2024-04-24",
+                        "lts": "2024-10-29",
+                        "end": "2099-04-30",
+                        "codename": "Jod"
+                    },
+                    "24": {
+                        "start": "2025-05-06",
+                        "lts": "2099-10-28",
+                        "end": "2099-04-30",
+                        "codename": "Krypton"
+                    }
+                }
+            }))
+            .expect("schedule fixture should deserialize"),
+        );
+
+        assert_eq!(
+            state.recommended_version(),
+            Some(&NodeVersion::new(22, 11, 0))
+        );
+
+        state.available_versions.schedule = None;
+        assert_eq!(state.recommended_version(), None);
+    }
+
+    #[test]
+    fn retention_prune_candidates_keeps_newest_patches_per_major() {
+        let mut state = main_state_with_native_env();
+        state.active_environment_mut().update_versions(vec![
+            installed(NodeVersion::new(22, 1, 0), false),
+            installed(NodeVersion::new(22, 3, 0), false),
+            installed(NodeVersion::new(22, 2, 0), false),
+            installed(NodeVersion::new(20, 9, 0), true),
+            installed(NodeVersion::new(20, 11, 0), false),
+            installed(NodeVersion::new(20, 10, 0), false),
+            installed(NodeVersion::new(20, 8, 0), false),
+            installed(NodeVersion::new(18, 19, 0), false),
+        ]);
+
+        let mut candidates = state.retention_prune_candidates(2);
+        candidates.sort();
+
+        assert_eq!(
+            candidates,
+            vec!["v20.8.0".to_string(), "v22.1.0".to_string()]
+        );
+    }
+
+    #[test]
+    fn retention_prune_candidates_is_empty_within_policy() {
+        let mut state = main_state_with_native_env();
+        state.active_environment_mut().update_versions(vec![
+            installed(NodeVersion::new(22, 3, 0), true),
+            installed(NodeVersion::new(22, 2, 0), false),
+        ]);
+
+        assert!(state.retention_prune_candidates(2).is_empty());
+        assert_eq!(
+            state.retention_prune_candidates(0),
+            vec!["v22.2.0".to_string()]
+        );
     }
 }
@@ -1,9 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use versi_shell::ShellType;
+use versi_shell::{ShellType, VerificationResult};
 
 use crate::backend_kind::BackendKind;
 use crate::error::AppError;
+use crate::version_files::VersionFileSuggestion;
 
 #[derive(Debug)]
 pub struct OnboardingState {
@@ -13,6 +14,10 @@ pub struct OnboardingState {
     pub detected_shells: Vec<ShellConfigStatus>,
     pub available_backends: Vec<BackendOption>,
     pub selected_backend: Option<BackendKind>,
+    pub suggested_version: Option<VersionFileSuggestion>,
+    pub install_suggested_version: bool,
+    pub install_latest_lts: bool,
+    pub shell_reload_hint: Option<ShellReloadHint>,
 }
 
 impl OnboardingState {
@@ -24,13 +29,19 @@ impl OnboardingState {
             detected_shells: Vec::new(),
             available_backends: Vec::new(),
             selected_backend: None,
+            suggested_version: None,
+            install_suggested_version: false,
+            install_latest_lts: false,
+            shell_reload_hint: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{OnboardingState, OnboardingStep};
+    use versi_shell::{ShellType, VerificationResult};
+
+    use super::{OnboardingState, OnboardingStep, ShellReloadHint};
 
     #[test]
     fn onboarding_state_new_has_expected_defaults() {
@@ -42,6 +53,62 @@ mod tests {
         assert!(state.detected_shells.is_empty());
         assert!(state.available_backends.is_empty());
         assert!(state.selected_backend.is_none());
+        assert!(state.suggested_version.is_none());
+        assert!(!state.install_suggested_version);
+        assert!(!state.install_latest_lts);
+        assert!(state.shell_reload_hint.is_none());
+    }
+
+    #[test]
+    fn reload_hint_is_shown_when_config_is_not_sourced_yet() {
+        let config_path = dirs::home_dir()
+            .expect("home directory should be known")
+            .join(".zshrc");
+
+        let hint = ShellReloadHint::select(
+            &ShellType::Zsh,
+            Some(&config_path),
+            &VerificationResult::Configured(None),
+            false,
+        )
+        .expect("configured but unsourced shell should get a reminder");
+
+        assert_eq!(hint.command.as_deref(), Some("source ~/.zshrc"));
+        assert_eq!(
+            hint.message(),
+            "Restart your terminal or run `source ~/.zshrc` so Zsh picks up the new configuration."
+        );
+    }
+
+    #[test]
+    fn reload_hint_is_skipped_when_session_already_has_backend_or_config_is_missing() {
+        assert!(
+            ShellReloadHint::select(
+                &ShellType::Bash,
+                None,
+                &VerificationResult::Configured(None),
+                true,
+            )
+            .is_none()
+        );
+        assert!(
+            ShellReloadHint::select(
+                &ShellType::Bash,
+                None,
+                &VerificationResult::NotConfigured,
+                false
+            )
+            .is_none()
+        );
+
+        let hint = ShellReloadHint::select(
+            &ShellType::Cmd,
+            None,
+            &VerificationResult::FunctionalButNotInConfig,
+            false,
+        )
+        .expect("functional shell should still get a restart reminder");
+        assert_eq!(hint.command, None);
     }
 }
 
@@ -63,6 +130,71 @@ pub struct ShellConfigStatus {
     pub error: Option<AppError>,
 }
 
+/// Reminder that terminals opened before setup won't see the backend until
+/// they reload their shell config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellReloadHint {
+    pub shell_name: String,
+    /// Command that reloads the config in place, when the shell has one.
+    pub command: Option<String>,
+}
+
+impl ShellReloadHint {
+    pub fn select(
+        shell_type: &ShellType,
+        config_path: Option<&Path>,
+        verification: &VerificationResult,
+        in_current_session: bool,
+    ) -> Option<Self> {
+        if in_current_session {
+            return None;
+        }
+        match verification {
+            VerificationResult::Configured(_) | VerificationResult::FunctionalButNotInConfig => {
+                Some(Self {
+                    shell_name: shell_type.name().to_string(),
+                    command: reload_command(shell_type, config_path),
+                })
+            }
+            VerificationResult::NotConfigured
+            | VerificationResult::ConfigFileNotFound
+            | VerificationResult::Error(_) => None,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match &self.command {
+            Some(command) => format!(
+                "Restart your terminal or run `{command}` so {} picks up the new configuration.",
+                self.shell_name
+            ),
+            None => format!(
+                "Restart your terminal so {} picks up the new configuration.",
+                self.shell_name
+            ),
+        }
+    }
+}
+
+fn reload_command(shell_type: &ShellType, config_path: Option<&Path>) -> Option<String> {
+    match shell_type {
+        ShellType::Bash | ShellType::Zsh | ShellType::Fish => {
+            config_path.map(|path| format!("source {}", home_relative(path)))
+        }
+        ShellType::PowerShell => Some(". $PROFILE".to_string()),
+        ShellType::Cmd => None,
+    }
+}
+
+fn home_relative(path: &Path) -> String {
+    dirs::home_dir()
+        .and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf))
+        .map_or_else(
+            || path.display().to_string(),
+            |relative| format!("~/{}", relative.display()),
+        )
+}
+
 #[derive(Debug, Clone)]
 pub struct BackendOption {
     pub kind: BackendKind,
@@ -1,18 +1,55 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use versi_platform::EnvironmentId;
 
 #[derive(Debug, Clone)]
 pub enum Operation {
-    Install { version: String },
-    Uninstall { version: String },
-    SetDefault { version: String },
+    Install {
+        version: String,
+    },
+    Uninstall {
+        version: String,
+    },
+    SetDefault {
+        version: String,
+    },
+    InstallArchive {
+        version: String,
+        path: PathBuf,
+    },
+    SetAlias {
+        environment: EnvironmentId,
+        name: String,
+        version: String,
+    },
+    RemoveAlias {
+        environment: EnvironmentId,
+        name: String,
+        version: String,
+    },
 }
 
 impl Operation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Install { .. } | Self::InstallArchive { .. } => "Install",
+            Self::Uninstall { .. } => "Uninstall",
+            Self::SetDefault { .. } => "Set default",
+            Self::SetAlias { .. } => "Create alias",
+            Self::RemoveAlias { .. } => "Remove alias",
+        }
+    }
+
     pub fn version(&self) -> &str {
         match self {
             Self::Install { version }
             | Self::Uninstall { version }
-            | Self::SetDefault { version } => version,
+            | Self::SetDefault { version }
+            | Self::InstallArchive { version, .. }
+            | Self::SetAlias { version, .. }
+            | Self::RemoveAlias { version, .. } => version,
         }
     }
 }
@@ -22,6 +59,11 @@ pub struct OperationQueue {
     pub active_installs: Vec<Operation>,
     pub exclusive_op: Option<Operation>,
     pub pending: VecDeque<Operation>,
+    /// When each active install started, for the elapsed-time hint.
+    pub install_started_at: HashMap<String, Instant>,
+    /// Environment each running operation was started in, by version, so it
+    /// is audited there even if another environment is active when it ends.
+    pub started_in: HashMap<String, EnvironmentId>,
 }
 
 impl std::fmt::Debug for OperationQueue {
@@ -46,6 +88,8 @@ impl OperationQueue {
             active_installs: Vec::new(),
             exclusive_op: None,
             pending: VecDeque::new(),
+            install_started_at: HashMap::new(),
+            started_in: HashMap::new(),
         }
     }
 
@@ -88,24 +132,55 @@ impl OperationQueue {
             .any(|op| matches!(op, Operation::Install { .. }) && op.version() == version)
     }
 
+    pub fn has_pending_set_default(&self, version: &str) -> bool {
+        self.pending
+            .iter()
+            .any(|op| matches!(op, Operation::SetDefault { .. }) && op.version() == version)
+    }
+
+    pub fn cancel_pending_set_default(&mut self, version: &str) {
+        self.pending
+            .retain(|op| !(matches!(op, Operation::SetDefault { .. }) && op.version() == version));
+    }
+
     pub fn enqueue(&mut self, op: Operation) {
         self.pending.push_back(op);
     }
 
     pub fn start_install(&mut self, version: String) {
+        self.install_started_at
+            .insert(version.clone(), Instant::now());
         self.active_installs.push(Operation::Install { version });
     }
 
+    /// How long the active install of `version` has been running.
+    pub fn install_elapsed(&self, version: &str) -> Option<Duration> {
+        self.install_started_at.get(version).map(Instant::elapsed)
+    }
+
     pub fn start_exclusive(&mut self, op: Operation) {
         self.exclusive_op = Some(op);
     }
 
     pub fn complete_exclusive(&mut self) {
-        self.exclusive_op = None;
+        if let Some(op) = self.exclusive_op.take() {
+            self.started_in.remove(op.version());
+        }
     }
 
     pub fn remove_completed_install(&mut self, version: &str) {
         self.active_installs.retain(|op| op.version() != version);
+        self.install_started_at.remove(version);
+        self.started_in.remove(version);
+    }
+
+    pub fn set_started_in(&mut self, version: &str, environment: EnvironmentId) {
+        self.started_in.insert(version.to_string(), environment);
+    }
+
+    /// Environment the running operation on `version` was started in.
+    pub fn take_started_in(&mut self, version: &str) -> Option<EnvironmentId> {
+        self.started_in.remove(version)
     }
 
     pub fn drain_next(&mut self) -> (Vec<String>, Option<Operation>) {
@@ -138,6 +213,24 @@ impl OperationQueue {
     }
 }
 
+/// Which modal button Enter activates; Tab moves it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModalFocus {
+    Cancel,
+    #[default]
+    Primary,
+}
+
+impl ModalFocus {
+    #[must_use]
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Cancel => Self::Primary,
+            Self::Primary => Self::Cancel,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Modal {
     ConfirmBulkUpdateMajors {
@@ -155,15 +248,101 @@ pub enum Modal {
         versions: Vec<String>,
         keeping: String,
     },
+    ConfirmApplyRetentionPolicy {
+        keep: usize,
+        versions: Vec<String>,
+    },
+    /// Uninstall the versions checked in selection mode. A selected default
+    /// version is held back for [`Modal::ConfirmUninstallDefault`].
+    ConfirmBulkUninstallSelected {
+        versions: Vec<String>,
+        default: Option<String>,
+    },
+    ConfirmUninstall {
+        version: String,
+    },
     ConfirmUninstallDefault {
         version: String,
     },
+    /// Uninstall then install `version` again, confirmed under the same
+    /// policy as a plain uninstall.
+    ConfirmReinstall {
+        version: String,
+        is_default: bool,
+    },
+    /// Make an end-of-life version the default.
+    ConfirmEolDefault {
+        version: String,
+    },
+    ConfirmClearVersionCache,
+    ConfirmTraySetDefault {
+        env_id: versi_platform::EnvironmentId,
+        env_name: String,
+        version: String,
+    },
+    CreateAlias {
+        version: String,
+        name: String,
+    },
+    ConfirmRemoveAlias {
+        name: String,
+        version: String,
+    },
     KeyboardShortcuts,
+    /// Details of [`MainState::last_error`](super::MainState::last_error).
+    LastOperationError,
+    /// Imported settings waiting on confirmation, with what they'd change.
+    /// Only fields in `sections` are applied.
+    ConfirmSettingsImport {
+        imported: Box<crate::settings::AppSettings>,
+        changes: Vec<crate::settings::SettingChange>,
+        sections: std::collections::BTreeSet<crate::settings::SettingsSection>,
+    },
     VersionDetail {
         version: String,
+        /// Note being edited, saved with `SaveVersionNote`.
+        note: String,
+    },
+    /// Install the versions another environment has into the active one.
+    ConfirmCloneEnvironment {
+        source: String,
+        target: String,
+        versions: Vec<String>,
+    },
+    /// Copy installed versions from another engine into the active one.
+    /// `started` switches the modal from confirmation to progress.
+    Migration {
+        source: crate::backend_kind::BackendKind,
+        plan: MigrationPlan,
+        set_default: bool,
+        started: bool,
+        progress: MigrationProgress,
     },
 }
 
+/// What a migration from another engine will do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationPlan {
+    /// Versions installed in the source engine but not the target, oldest
+    /// first.
+    pub install: Vec<String>,
+    /// The source engine's default version.
+    pub default: Option<String>,
+}
+
+/// Installs of a started migration that have finished, either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MigrationProgress {
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+impl MigrationProgress {
+    pub fn completed(self) -> usize {
+        self.succeeded + self.failed
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -235,6 +414,23 @@ mod tests {
         assert!(q.is_busy_for_install());
     }
 
+    #[test]
+    fn completing_an_operation_forgets_where_it_started() {
+        let mut q = OperationQueue::new();
+        q.start_exclusive(Operation::Uninstall {
+            version: "20.0.0".into(),
+        });
+        q.set_started_in("20.0.0", EnvironmentId::Native);
+        q.complete_exclusive();
+        assert!(q.started_in.is_empty());
+
+        q.start_install("22.0.0".into());
+        q.set_started_in("22.0.0", EnvironmentId::Native);
+        assert_eq!(q.take_started_in("22.0.0"), Some(EnvironmentId::Native));
+        q.remove_completed_install("22.0.0");
+        assert!(q.started_in.is_empty());
+    }
+
     #[test]
     fn is_busy_for_exclusive_when_empty() {
         let q = OperationQueue::new();
@@ -686,4 +882,16 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn install_elapsed_is_tracked_until_completion() {
+        let mut queue = OperationQueue::new();
+        assert!(queue.install_elapsed("v22.0.0").is_none());
+
+        queue.start_install("v22.0.0".to_string());
+        assert!(queue.install_elapsed("v22.0.0").is_some());
+
+        queue.remove_completed_install("v22.0.0");
+        assert!(queue.install_elapsed("v22.0.0").is_none());
+    }
 }
@@ -5,27 +5,96 @@ pub struct ContextMenu {
     pub version: String,
     pub is_installed: bool,
     pub is_default: bool,
+    pub can_open_terminal: bool,
+    pub can_create_alias: bool,
     pub position: iced::Point,
 }
 
+/// Follow-up offered by a button on a toast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToastAction {
+    UndoUninstall {
+        environment: versi_platform::EnvironmentId,
+        version: String,
+    },
+    RetryInstall {
+        version: String,
+        timeout_secs: u64,
+    },
+    RevertDefault {
+        environment: versi_platform::EnvironmentId,
+        version: String,
+    },
+}
+
+impl ToastAction {
+    pub fn label(&self) -> String {
+        match self {
+            Self::UndoUninstall { .. } => "Undo".to_string(),
+            Self::RetryInstall { .. } => "Retry with longer timeout".to_string(),
+            Self::RevertDefault { version } => format!("Revert to {version}"),
+        }
+    }
+
+    /// Environment the action applies to, if it's tied to one.
+    pub fn environment(&self) -> Option<&versi_platform::EnvironmentId> {
+        match self {
+            Self::UndoUninstall { environment, .. } | Self::RevertDefault { environment, .. } => {
+                Some(environment)
+            }
+            Self::RetryInstall { .. } => None,
+        }
+    }
+}
+
+/// Errors are drawn in red; info toasts only carry a follow-up action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Error,
+    Info,
+}
+
 #[derive(Debug, Clone)]
 pub struct Toast {
     pub id: usize,
+    pub kind: ToastKind,
     pub message: String,
     pub created_at: Instant,
+    pub action: Option<ToastAction>,
+    /// Overrides the configured toast timeout, so an action stays
+    /// reachable for as long as it is valid.
+    pub lifetime_secs: Option<u64>,
 }
 
 impl Toast {
-    pub fn error(id: usize, message: String) -> Self {
+    fn new(id: usize, kind: ToastKind, message: String) -> Self {
         Self {
             id,
+            kind,
             message,
             created_at: Instant::now(),
+            action: None,
+            lifetime_secs: None,
         }
     }
 
+    #[must_use]
+    pub fn with_action(mut self, action: ToastAction, lifetime_secs: u64) -> Self {
+        self.action = Some(action);
+        self.lifetime_secs = Some(lifetime_secs);
+        self
+    }
+
+    pub fn error(id: usize, message: String) -> Self {
+        Self::new(id, ToastKind::Error, message)
+    }
+
+    pub fn info(id: usize, message: String) -> Self {
+        Self::new(id, ToastKind::Info, message)
+    }
+
     pub fn is_expired(&self, timeout_secs: u64) -> bool {
-        self.created_at.elapsed().as_secs() > timeout_secs
+        self.created_at.elapsed().as_secs() > self.lifetime_secs.unwrap_or(timeout_secs)
     }
 }
 
@@ -34,6 +103,24 @@ pub struct SettingsModalState {
     pub shell_statuses: Vec<ShellSetupStatus>,
     pub checking_shells: bool,
     pub log_file_size: Option<u64>,
+    pub npm_registry: Option<versi_core::RegistryStatus>,
+    /// Registry the last check went to, from the user's npm config.
+    pub npm_registry_url: Option<String>,
+    pub checking_npm_registry: bool,
+    /// A system `node` found ahead of the managed one on PATH.
+    pub node_path_conflict: Option<versi_shell::NodePathConflict>,
+    /// Update repo being typed, before it's submitted and saved.
+    pub update_repo_input: Option<String>,
+    /// Numeric settings being typed; only saved once they're in range.
+    pub numeric_inputs: std::collections::HashMap<crate::settings::NumericSetting, String>,
+    /// Settings as they were when the view was opened, for "Discard changes".
+    pub baseline: Option<crate::settings::AppSettings>,
+    /// Whether settings differ from `baseline`; kept up to date in update.
+    pub has_changes: bool,
+    /// Recent audit log records, loaded while the viewer is open.
+    pub audit_log: Option<Vec<crate::audit_log::AuditRecord>>,
+    /// Set once "Clear Version Cache" succeeds, shown next to the button.
+    pub version_cache_cleared: bool,
 }
 
 impl SettingsModalState {
@@ -42,6 +129,16 @@ impl SettingsModalState {
             shell_statuses: Vec::new(),
             checking_shells: false,
             log_file_size: None,
+            npm_registry: None,
+            npm_registry_url: None,
+            checking_npm_registry: false,
+            node_path_conflict: None,
+            update_repo_input: None,
+            numeric_inputs: std::collections::HashMap::new(),
+            baseline: None,
+            has_changes: false,
+            audit_log: None,
+            version_cache_cleared: false,
         }
     }
 }
@@ -52,6 +149,25 @@ pub struct ShellSetupStatus {
     pub shell_name: String,
     pub status: ShellVerificationStatus,
     pub configuring: bool,
+    pub testing: bool,
+    pub repairing: bool,
+    /// Result of the last Repair, shown under the shell's row.
+    pub repair_outcome: Option<ShellRepairOutcome>,
+    /// Result of the last Test, shown under the shell's row.
+    pub test_outcome: Option<ShellTestOutcome>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ShellRepairOutcome {
+    NothingToRepair,
+    Repaired(Vec<String>),
+    Failed(crate::error::AppError),
+}
+
+#[derive(Debug, Clone)]
+pub enum ShellTestOutcome {
+    Resolved(std::path::PathBuf),
+    Failed(crate::error::AppError),
 }
 
 #[derive(Debug, Clone)]
@@ -67,31 +183,45 @@ pub enum ShellVerificationStatus {
 mod tests {
     use std::time::{Duration, Instant};
 
-    use super::{SettingsModalState, Toast};
+    use super::{SettingsModalState, Toast, ToastAction, ToastKind};
 
     #[test]
     fn toast_error_sets_id_and_message() {
         let toast = Toast::error(7, "operation failed".to_string());
 
         assert_eq!(toast.id, 7);
+        assert_eq!(toast.kind, ToastKind::Error);
         assert_eq!(toast.message, "operation failed");
     }
 
+    #[test]
+    fn toast_info_is_not_drawn_as_an_error() {
+        let toast = Toast::info(8, "Uninstalled Node v20.11.0".to_string());
+
+        assert_eq!(toast.kind, ToastKind::Info);
+    }
+
     #[test]
     fn toast_expiration_respects_timeout_boundary() {
         let fresh = Toast {
             id: 1,
+            kind: ToastKind::Error,
             message: "fresh".to_string(),
             created_at: Instant::now(),
+            action: None,
+            lifetime_secs: None,
         };
         assert!(!fresh.is_expired(0));
 
         let stale = Toast {
             id: 2,
+            kind: ToastKind::Error,
             message: "stale".to_string(),
             created_at: Instant::now()
                 .checked_sub(Duration::from_secs(2))
                 .expect("constructing stale toast timestamp should not underflow"),
+            action: None,
+            lifetime_secs: None,
         };
         assert!(stale.is_expired(1));
     }
@@ -104,4 +234,20 @@ mod tests {
         assert!(!state.checking_shells);
         assert!(state.log_file_size.is_none());
     }
+
+    #[test]
+    fn toast_with_action_outlives_default_timeout() {
+        let mut toast = Toast::info(3, "Uninstalled Node v20.11.0".to_string()).with_action(
+            ToastAction::UndoUninstall {
+                environment: versi_platform::EnvironmentId::Native,
+                version: "v20.11.0".to_string(),
+            },
+            10,
+        );
+        toast.created_at = Instant::now()
+            .checked_sub(Duration::from_secs(7))
+            .expect("constructing toast timestamp should not underflow");
+
+        assert!(!toast.is_expired(5));
+    }
 }
@@ -0,0 +1,239 @@
+//! Keyed lookup for user-facing strings.
+//!
+//! Every key has an English entry; other languages may translate a subset
+//! and fall back to English for the rest. Views receive the language to use
+//! from the app; nothing here holds it globally.
+//!
+//! The table also holds accessible names for icon-only and terse widgets.
+//! iced does not expose an accessibility tree yet, so these are shown as
+//! tooltips:
+//! - Header: refresh, home, settings and about buttons
+//! - Environment tabs: WSL rescan button
+//! - Search bar: clear button and each filter chip
+//!
+//! Version row actions already carry their name as button text.
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::SearchFilter;
+
+type Table = &'static [(&'static str, &'static str)];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Portuguese,
+}
+
+impl Language {
+    pub const ALL: [Self; 2] = [Self::English, Self::Portuguese];
+
+    /// Name of the language in that language.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Portuguese => "Português",
+        }
+    }
+
+    /// Match a BCP 47 or POSIX locale such as `pt-PT` or `pt_BR.UTF-8`.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "en" => Some(Self::English),
+            "pt" => Some(Self::Portuguese),
+            _ => None,
+        }
+    }
+
+    /// The operating system's language, or English when it is unsupported.
+    pub fn system() -> Self {
+        sys_locale::get_locale()
+            .and_then(|locale| Self::from_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    fn table(self) -> Table {
+        match self {
+            Self::English => ENGLISH,
+            Self::Portuguese => PORTUGUESE,
+        }
+    }
+}
+
+/// Look up `key` in `language`.
+pub fn t(language: Language, key: &'static str) -> &'static str {
+    lookup_in(language.table(), key)
+}
+
+/// Key of the accessible name for `filter`'s chip.
+pub fn filter_chip_key(filter: SearchFilter) -> &'static str {
+    match filter {
+        SearchFilter::Lts => "filter.lts",
+        SearchFilter::Installed => "filter.installed",
+        SearchFilter::NotInstalled => "filter.not_installed",
+        SearchFilter::Eol => "filter.eol",
+        SearchFilter::Active => "filter.active",
+    }
+}
+
+fn lookup_in(table: Table, key: &'static str) -> &'static str {
+    find(table, key)
+        .or_else(|| find(ENGLISH, key))
+        .unwrap_or(key)
+}
+
+fn find(table: Table, key: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, value)| *value)
+}
+
+const ENGLISH: Table = &[
+    (
+        "search.placeholder",
+        "Search versions (e.g., '22', 'lts', 'lts/iron', 'latest')...",
+    ),
+    ("header.install_from_file", "Install from file…"),
+    ("versions.loading", "Loading versions..."),
+    ("versions.load_error", "Error loading versions"),
+    ("versions.retry", "Retry"),
+    ("versions.empty", "No versions found"),
+    (
+        "versions.empty_hint",
+        "Install your first Node.js version by searching above, or:",
+    ),
+    (
+        "versions.waiting_for_remote",
+        "Waiting for the list of available versions...",
+    ),
+    ("settings.cache_cleared", "Version cache cleared"),
+    ("nav.refresh", "Refresh"),
+    ("nav.refreshing", "Refreshing…"),
+    ("nav.home", "Home"),
+    ("nav.settings", "Settings"),
+    ("nav.about", "About"),
+    ("tabs.rescan_wsl", "Rescan WSL distros"),
+    ("search.clear", "Clear search"),
+    ("filter.lts", "Show only LTS releases"),
+    ("filter.installed", "Show only installed versions"),
+    (
+        "filter.not_installed",
+        "Show only versions that are not installed",
+    ),
+    ("filter.eol", "Show only end-of-life versions"),
+    ("filter.active", "Show only actively supported versions"),
+    ("installed.today", "installed today"),
+    ("installed.yesterday", "installed yesterday"),
+    ("installed.days_ago", "installed {n} days ago"),
+    ("installed.a_month_ago", "installed a month ago"),
+    ("installed.months_ago", "installed {n} months ago"),
+    ("installed.a_year_ago", "installed a year ago"),
+    ("installed.years_ago", "installed {n} years ago"),
+    ("installed.unknown", "Install date unknown"),
+];
+
+const PORTUGUESE: Table = &[
+    (
+        "search.placeholder",
+        "Procurar versões (ex.: '22', 'lts', 'lts/iron', 'latest')...",
+    ),
+    ("header.install_from_file", "Instalar a partir de ficheiro…"),
+    ("versions.loading", "A carregar versões..."),
+    ("versions.load_error", "Erro ao carregar versões"),
+    ("versions.retry", "Tentar novamente"),
+    ("versions.empty", "Nenhuma versão encontrada"),
+    (
+        "versions.empty_hint",
+        "Instale a sua primeira versão do Node.js pesquisando acima, ou:",
+    ),
+    (
+        "versions.waiting_for_remote",
+        "A aguardar a lista de versões disponíveis...",
+    ),
+    ("settings.cache_cleared", "Cache de versões limpa"),
+    ("nav.refresh", "Atualizar"),
+    ("nav.refreshing", "A atualizar…"),
+    ("nav.home", "Início"),
+    ("nav.settings", "Definições"),
+    ("nav.about", "Acerca"),
+    ("tabs.rescan_wsl", "Procurar distribuições WSL"),
+    ("search.clear", "Limpar pesquisa"),
+    ("filter.lts", "Mostrar apenas versões LTS"),
+    ("filter.installed", "Mostrar apenas versões instaladas"),
+    (
+        "filter.not_installed",
+        "Mostrar apenas versões não instaladas",
+    ),
+    ("filter.eol", "Mostrar apenas versões em fim de vida"),
+    ("filter.active", "Mostrar apenas versões com suporte ativo"),
+    ("installed.today", "instalada hoje"),
+    ("installed.yesterday", "instalada ontem"),
+    ("installed.days_ago", "instalada há {n} dias"),
+    ("installed.a_month_ago", "instalada há um mês"),
+    ("installed.months_ago", "instalada há {n} meses"),
+    ("installed.a_year_ago", "instalada há um ano"),
+    ("installed.years_ago", "instalada há {n} anos"),
+    ("installed.unknown", "Data de instalação desconhecida"),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{ENGLISH, Language, PORTUGUESE, filter_chip_key, lookup_in, t};
+    use crate::state::SearchFilter;
+
+    #[test]
+    fn missing_keys_fall_back_to_english() {
+        assert_eq!(lookup_in(&[], "versions.retry"), "Retry");
+        assert_eq!(t(Language::Portuguese, "no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn switching_language_changes_strings() {
+        assert_eq!(t(Language::English, "versions.empty"), "No versions found");
+        assert_eq!(
+            t(Language::Portuguese, "versions.empty"),
+            "Nenhuma versão encontrada"
+        );
+    }
+
+    #[test]
+    fn filter_chip_labels_are_distinct() {
+        for language in Language::ALL {
+            let labels: HashSet<&str> = SearchFilter::ALL
+                .into_iter()
+                .map(|filter| t(language, filter_chip_key(filter)))
+                .collect();
+            assert_eq!(labels.len(), SearchFilter::ALL.len());
+        }
+    }
+
+    #[test]
+    fn translations_only_use_english_keys() {
+        for (key, _) in PORTUGUESE {
+            assert!(
+                ENGLISH.iter().any(|(english, _)| english == key),
+                "{key} has no English entry"
+            );
+        }
+    }
+
+    #[test]
+    fn locales_map_to_languages() {
+        assert_eq!(Language::from_locale("pt-PT"), Some(Language::Portuguese));
+        assert_eq!(
+            Language::from_locale("pt_BR.UTF-8"),
+            Some(Language::Portuguese)
+        );
+        assert_eq!(Language::from_locale("en-US"), Some(Language::English));
+        assert_eq!(Language::from_locale("de-DE"), None);
+    }
+}
@@ -2,6 +2,7 @@ pub mod styles;
 
 use iced::theme::Palette;
 use iced::{Theme, color};
+use versi_platform::EnvironmentId;
 
 pub mod tokens {
     pub const RADIUS_XS: f32 = 6.0;
@@ -31,6 +32,18 @@ pub mod tokens {
     pub const ONBOARDING_PADDING: f32 = 48.0;
     pub const GROUP_INDENT: f32 = 24.0;
 
+    /// Muted hues that read on both the light and dark backgrounds.
+    pub const ENVIRONMENT_ACCENTS: [iced::Color; 8] = [
+        iced::Color::from_rgb(0.35, 0.55, 0.85),
+        iced::Color::from_rgb(0.30, 0.68, 0.55),
+        iced::Color::from_rgb(0.85, 0.55, 0.30),
+        iced::Color::from_rgb(0.68, 0.45, 0.82),
+        iced::Color::from_rgb(0.82, 0.42, 0.52),
+        iced::Color::from_rgb(0.30, 0.65, 0.75),
+        iced::Color::from_rgb(0.72, 0.65, 0.30),
+        iced::Color::from_rgb(0.52, 0.58, 0.68),
+    ];
+
     pub fn card_bg(is_dark: bool) -> iced::Color {
         if is_dark {
             iced::Color::from_rgba8(44, 44, 46, 0.72)
@@ -40,6 +53,26 @@ pub mod tokens {
     }
 }
 
+/// Stable accent color for an environment, derived from its distro name so
+/// the same WSL distro keeps its color across restarts.
+pub fn environment_accent(id: &EnvironmentId) -> iced::Color {
+    let index = match id {
+        EnvironmentId::Native => 0,
+        EnvironmentId::Wsl { distro, .. } => {
+            // FNV-1a: unlike `DefaultHasher`, its output is fixed across releases.
+            let hash = distro
+                .bytes()
+                .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                    (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+                });
+            let others = tokens::ENVIRONMENT_ACCENTS.len() - 1;
+            let offset = u64::try_from(others).map_or(0, |others| hash % others);
+            1 + usize::try_from(offset).unwrap_or_default()
+        }
+    };
+    tokens::ENVIRONMENT_ACCENTS[index]
+}
+
 pub fn light_theme() -> Theme {
     Theme::custom(
         "Versi Light".to_string(),
@@ -70,7 +103,9 @@ pub fn dark_theme() -> Theme {
 
 #[cfg(test)]
 mod tests {
-    use super::tokens;
+    use versi_platform::EnvironmentId;
+
+    use super::{environment_accent, tokens};
 
     fn assert_close(actual: f32, expected: f32) {
         assert!(
@@ -102,6 +137,27 @@ mod tests {
         assert_close(tokens::EOL_ORANGE.b, 0.0);
     }
 
+    #[test]
+    fn environment_accent_is_stable_for_the_same_id() {
+        let wsl = |distro: &str, backend_path: &str| EnvironmentId::Wsl {
+            distro: distro.to_string(),
+            backend_path: backend_path.to_string(),
+        };
+
+        assert_eq!(
+            environment_accent(&wsl("Ubuntu", "/usr/bin/fnm")),
+            environment_accent(&wsl("Ubuntu", "/home/me/.local/bin/fnm"))
+        );
+        assert_eq!(
+            environment_accent(&EnvironmentId::Native),
+            tokens::ENVIRONMENT_ACCENTS[0]
+        );
+        assert_ne!(
+            environment_accent(&wsl("Ubuntu", "fnm")),
+            tokens::ENVIRONMENT_ACCENTS[0]
+        );
+    }
+
     #[test]
     fn tokens_card_background_uses_expected_light_color() {
         let color = tokens::card_bg(false);
@@ -37,6 +37,24 @@ pub fn badge_lts(theme: &Theme) -> container::Style {
     }
 }
 
+pub fn badge_recommended(theme: &Theme) -> container::Style {
+    let palette = theme.palette();
+
+    container::Style {
+        background: None,
+        text_color: Some(palette.success),
+        border: Border {
+            radius: crate::theme::tokens::RADIUS_SM.into(),
+            width: 1.0,
+            color: Color {
+                a: 0.4,
+                ..palette.success
+            },
+        },
+        ..Default::default()
+    }
+}
+
 pub fn badge_eol(_theme: &Theme) -> container::Style {
     let eol_color = crate::theme::tokens::EOL_ORANGE;
 
@@ -72,3 +90,7 @@ pub fn badge_security(theme: &Theme) -> container::Style {
         ..Default::default()
     }
 }
+
+pub fn badge_broken(theme: &Theme) -> container::Style {
+    badge_security(theme)
+}
@@ -147,6 +147,18 @@ pub fn kbd_container(theme: &Theme) -> container::Style {
     }
 }
 
+/// Outline drawn around the modal button that Enter activates.
+pub fn focus_ring(theme: &Theme) -> container::Style {
+    container::Style {
+        border: Border {
+            radius: (crate::theme::tokens::RADIUS_MD + 2.0).into(),
+            width: 2.0,
+            color: theme.palette().primary,
+        },
+        ..Default::default()
+    }
+}
+
 pub fn context_menu_container(theme: &Theme) -> container::Style {
     tooltip_container(theme)
 }
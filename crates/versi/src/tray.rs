@@ -1,7 +1,9 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
+#[cfg(target_os = "linux")]
+use std::time::Instant;
 
 use iced::Subscription;
 use iced::futures::SinkExt;
@@ -16,9 +18,59 @@ use crate::state::EnvironmentState;
 
 thread_local! {
     static TRAY_ICON: RefCell<Option<TrayIcon>> = const { RefCell::new(None) };
+    static TRAY_AVAILABILITY: Cell<TrayAvailability> =
+        const { Cell::new(TrayAvailability::Disabled) };
+    #[cfg(target_os = "linux")]
+    static LAST_HOST_PROBE: Cell<Option<Instant>> = const { Cell::new(None) };
 }
 
 const TRAY_EVENT_RECV_TIMEOUT: Duration = Duration::from_millis(250);
+#[cfg(target_os = "linux")]
+const TRAY_HOST_PROBE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Whether a tray icon can currently be shown.
+///
+/// `Unavailable` means no tray host has been seen since startup, while
+/// `TemporarilyDown` means the host went away after the tray was shown (for
+/// example when the panel restarts) and the icon will come back on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAvailability {
+    Disabled,
+    Active,
+    Unavailable,
+    TemporarilyDown,
+}
+
+impl TrayAvailability {
+    #[must_use]
+    pub fn next(self, behavior: TrayBehavior, host_present: bool) -> Self {
+        if behavior == TrayBehavior::Disabled {
+            return Self::Disabled;
+        }
+
+        if host_present {
+            return Self::Active;
+        }
+
+        match self {
+            Self::Active | Self::TemporarilyDown => Self::TemporarilyDown,
+            Self::Disabled | Self::Unavailable => Self::Unavailable,
+        }
+    }
+
+    #[must_use]
+    pub fn description(self) -> Option<&'static str> {
+        match self {
+            Self::Disabled | Self::Active => None,
+            Self::Unavailable => Some(
+                "No system tray was found. Tray options take effect once a tray host (StatusNotifier/AppIndicator) is running.",
+            ),
+            Self::TemporarilyDown => {
+                Some("The system tray went away. Versi will restore the icon when it comes back.")
+            }
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum TrayError {
@@ -138,15 +190,22 @@ impl TrayMenuData {
 }
 
 pub fn init_tray(behavior: TrayBehavior) -> Result<(), TrayError> {
+    let availability = tray_availability().next(behavior, has_tray_host());
+    set_tray_availability(availability);
+
     if behavior == TrayBehavior::Disabled {
         return Ok(());
     }
 
     #[cfg(target_os = "linux")]
-    if !has_tray_host() {
+    if availability != TrayAvailability::Active {
         return Err(TrayError::NoTrayHost);
     }
 
+    build_tray_icon()
+}
+
+fn build_tray_icon() -> Result<(), TrayError> {
     let icon = load_icon()?;
     let menu = build_menu(&TrayMenuData {
         environments: vec![],
@@ -183,7 +242,69 @@ fn has_tray_host() -> bool {
         .unwrap_or(false)
 }
 
+#[cfg(not(target_os = "linux"))]
+fn has_tray_host() -> bool {
+    true
+}
+
+/// Re-probe the tray host and bring the icon up or down to match.
+///
+/// Probing is throttled, so this is cheap to call on every tick. Returns
+/// `true` when the tray icon was (re)created and the menu needs refreshing.
+#[cfg(target_os = "linux")]
+pub fn refresh_tray_availability(behavior: TrayBehavior) -> bool {
+    let current = tray_availability();
+    if behavior == TrayBehavior::Disabled {
+        set_tray_availability(TrayAvailability::Disabled);
+        return false;
+    }
+
+    let now = Instant::now();
+    let due = LAST_HOST_PROBE
+        .get()
+        .is_none_or(|last| now.duration_since(last) >= TRAY_HOST_PROBE_INTERVAL);
+    if !due {
+        return false;
+    }
+    LAST_HOST_PROBE.set(Some(now));
+
+    let next = current.next(behavior, has_tray_host());
+    set_tray_availability(next);
+
+    match next {
+        TrayAvailability::Active if !is_tray_active() => match build_tray_icon() {
+            Ok(()) => {
+                log::info!("Tray host detected; tray icon initialized");
+                true
+            }
+            Err(e) => {
+                log::warn!("Failed to initialize tray icon after host appeared: {e}");
+                false
+            }
+        },
+        TrayAvailability::TemporarilyDown if is_tray_active() => {
+            log::info!("Tray host went away; hiding tray icon until it returns");
+            destroy_tray_icon();
+            false
+        }
+        _ => false,
+    }
+}
+
+pub fn tray_availability() -> TrayAvailability {
+    TRAY_AVAILABILITY.get()
+}
+
+fn set_tray_availability(availability: TrayAvailability) {
+    TRAY_AVAILABILITY.set(availability);
+}
+
 pub fn destroy_tray() {
+    set_tray_availability(TrayAvailability::Disabled);
+    destroy_tray_icon();
+}
+
+fn destroy_tray_icon() {
     TRAY_ICON.with(|cell| {
         *cell.borrow_mut() = None;
     });
@@ -377,8 +498,11 @@ mod tests {
     use chrono::Utc;
     use versi_platform::EnvironmentId;
 
-    use super::{TrayMenuData, TrayMessage, encode_environment_id, parse_menu_event};
+    use super::{
+        TrayAvailability, TrayMenuData, TrayMessage, encode_environment_id, parse_menu_event,
+    };
     use crate::backend_kind::BackendKind;
+    use crate::settings::TrayBehavior;
     use crate::state::EnvironmentState;
 
     fn installed(version: &str, is_default: bool) -> versi_backend::InstalledVersion {
@@ -455,4 +579,49 @@ mod tests {
         assert!(parse_menu_event("set:nothex:v20.11.0").is_none());
         assert!(parse_menu_event("unknown").is_none());
     }
+
+    #[test]
+    fn tray_availability_tracks_host_appearing_and_disappearing() {
+        let behavior = TrayBehavior::WhenWindowOpen;
+
+        let state = TrayAvailability::Disabled.next(behavior, false);
+        assert_eq!(state, TrayAvailability::Unavailable);
+
+        let state = state.next(behavior, false);
+        assert_eq!(state, TrayAvailability::Unavailable);
+
+        let state = state.next(behavior, true);
+        assert_eq!(state, TrayAvailability::Active);
+
+        let state = state.next(behavior, false);
+        assert_eq!(state, TrayAvailability::TemporarilyDown);
+
+        let state = state.next(behavior, false);
+        assert_eq!(state, TrayAvailability::TemporarilyDown);
+
+        let state = state.next(behavior, true);
+        assert_eq!(state, TrayAvailability::Active);
+    }
+
+    #[test]
+    fn tray_availability_is_disabled_regardless_of_host() {
+        for current in [
+            TrayAvailability::Active,
+            TrayAvailability::Unavailable,
+            TrayAvailability::TemporarilyDown,
+        ] {
+            assert_eq!(
+                current.next(TrayBehavior::Disabled, true),
+                TrayAvailability::Disabled
+            );
+        }
+    }
+
+    #[test]
+    fn tray_availability_description_only_for_missing_host() {
+        assert!(TrayAvailability::Active.description().is_none());
+        assert!(TrayAvailability::Disabled.description().is_none());
+        assert!(TrayAvailability::Unavailable.description().is_some());
+        assert!(TrayAvailability::TemporarilyDown.description().is_some());
+    }
 }
@@ -0,0 +1,50 @@
+//! How version numbers are shown to the user.
+//!
+//! Versions are stored and passed around `v`-prefixed (`v20.11.0`); the
+//! `version_prefix` setting decides whether the list, the detail view and
+//! copied text keep the prefix.
+
+fn starts_with_digit(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Render `version` with or without its `v` prefix. Anything that isn't a
+/// version number, such as `lts/iron`, is returned unchanged.
+pub fn display_version(version: &str, prefix: bool) -> String {
+    let bare = version
+        .strip_prefix('v')
+        .filter(|rest| starts_with_digit(rest))
+        .unwrap_or(version);
+    if !starts_with_digit(bare) {
+        return version.to_string();
+    }
+    if prefix {
+        format!("v{bare}")
+    } else {
+        bare.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::display_version;
+    use crate::settings::AppSettings;
+
+    #[test]
+    fn display_version_honors_the_prefix_setting() {
+        let mut settings = AppSettings::default();
+        assert_eq!(settings.display_version("v20.11.0"), "v20.11.0");
+        assert_eq!(settings.display_version("20.11.0"), "v20.11.0");
+
+        settings.version_prefix = false;
+        assert_eq!(settings.display_version("v20.11.0"), "20.11.0");
+        assert_eq!(settings.display_version("20.11.0"), "20.11.0");
+    }
+
+    #[test]
+    fn display_version_leaves_non_versions_alone() {
+        assert_eq!(display_version("lts/iron", true), "lts/iron");
+        assert_eq!(display_version("lts/iron", false), "lts/iron");
+        assert_eq!(display_version("", false), "");
+    }
+}
@@ -0,0 +1,319 @@
+//! Discovery of project Node.js version files (`.nvmrc`, `.node-version`,
+//! `.tool-versions`).
+//!
+//! Used during onboarding to suggest the version the user's projects already
+//! ask for. The scan only looks at the home directory and one level into a
+//! few conventional project folders, and stops at a deadline. The main view
+//! also checks the working directory for a version the project pins.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use versi_backend::NodeVersion;
+
+pub const VERSION_FILE_NAMES: &[&str] = &[".nvmrc", ".node-version", ".tool-versions"];
+
+const TOOL_VERSIONS_FILE_NAME: &str = ".tool-versions";
+const TOOL_VERSIONS_NODE_NAMES: &[&str] = &["nodejs", "node"];
+
+const PROJECT_ROOT_NAMES: &[&str] = &[
+    "code",
+    "Code",
+    "dev",
+    "Developer",
+    "git",
+    "projects",
+    "Projects",
+    "repos",
+    "src",
+    "workspace",
+];
+const MAX_SCANNED_DIRS: usize = 256;
+pub const SCAN_TIME_BUDGET: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionFileSuggestion {
+    pub path: PathBuf,
+    /// Version spec as written in the file, e.g. `20`, `v20.11.0` or `lts/iron`.
+    pub spec: String,
+    /// Exact version, when the file pins one.
+    pub version: Option<NodeVersion>,
+}
+
+impl VersionFileSuggestion {
+    pub fn label(&self) -> String {
+        self.version
+            .as_ref()
+            .map_or_else(|| self.spec.clone(), ToString::to_string)
+    }
+
+    /// Whether an installed version satisfies the spec. `None` for specs that
+    /// can't be checked locally, such as `lts/iron`.
+    pub fn is_satisfied_by(&self, installed: &HashSet<NodeVersion>) -> Option<bool> {
+        if let Some(version) = &self.version {
+            return Some(installed.contains(version));
+        }
+
+        let spec = self.spec.strip_prefix(['v', 'V']).unwrap_or(&self.spec);
+        let parts: Vec<u32> = spec
+            .split('.')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .ok()?;
+        let (major, minor) = match parts.as_slice() {
+            [major] => (*major, None),
+            [major, minor] => (*major, Some(*minor)),
+            _ => return None,
+        };
+        Some(installed.iter().any(|version| {
+            version.major == major && minor.is_none_or(|minor| version.minor == minor)
+        }))
+    }
+}
+
+/// Extract an installable version spec from a version file's contents.
+///
+/// Returns `None` for files that only name `system`/`node` or are empty.
+pub fn parse_version_spec(content: &str) -> Option<String> {
+    let line = content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .find(|line| !line.is_empty())?;
+
+    let lower = line.to_ascii_lowercase();
+    let numeric = lower.strip_prefix('v').unwrap_or(&lower);
+    if numeric.starts_with(|c: char| c.is_ascii_digit()) || lower.starts_with("lts/") {
+        Some(line.to_string())
+    } else {
+        None
+    }
+}
+
+/// Extract the Node.js version spec from an asdf `.tool-versions` file.
+///
+/// Each line names a tool followed by one or more versions; the first
+/// version listed for `nodejs` (or `node`) wins.
+pub fn parse_tool_versions(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split_whitespace();
+        let tool = fields.next()?;
+        if !TOOL_VERSIONS_NODE_NAMES.contains(&tool) {
+            return None;
+        }
+        fields.find_map(parse_version_spec)
+    })
+}
+
+pub fn read_suggestion(path: &Path) -> Option<VersionFileSuggestion> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let is_tool_versions = path
+        .file_name()
+        .is_some_and(|name| name == TOOL_VERSIONS_FILE_NAME);
+    let spec = if is_tool_versions {
+        parse_tool_versions(&content)?
+    } else {
+        parse_version_spec(&content)?
+    };
+    let version = spec.parse::<NodeVersion>().ok();
+    Some(VersionFileSuggestion {
+        path: path.to_path_buf(),
+        spec,
+        version,
+    })
+}
+
+/// Read the version file `dir` itself pins, preferring `.nvmrc`, then
+/// `.node-version`, then `.tool-versions`.
+pub fn find_in_dir(dir: &Path) -> Option<VersionFileSuggestion> {
+    VERSION_FILE_NAMES
+        .iter()
+        .find_map(|name| read_suggestion(&dir.join(name)))
+}
+
+/// Find the most recently modified version file near `home`.
+pub fn scan_for_suggestion(home: &Path, deadline: Instant) -> Option<VersionFileSuggestion> {
+    let mut candidates = vec![home.to_path_buf()];
+    for root in PROJECT_ROOT_NAMES {
+        let root = home.join(root);
+        if !root.is_dir() {
+            continue;
+        }
+        candidates.push(root.clone());
+        let Ok(entries) = std::fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if candidates.len() >= MAX_SCANNED_DIRS || Instant::now() >= deadline {
+                break;
+            }
+            if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+                candidates.push(entry.path());
+            }
+        }
+    }
+
+    let mut best: Option<(SystemTime, VersionFileSuggestion)> = None;
+    for dir in candidates {
+        if Instant::now() >= deadline {
+            log::debug!("Version file scan hit its time budget");
+            break;
+        }
+        for name in VERSION_FILE_NAMES {
+            let path = dir.join(name);
+            let Ok(modified) = std::fs::metadata(&path).and_then(|meta| meta.modified()) else {
+                continue;
+            };
+            if best.as_ref().is_some_and(|(time, _)| *time >= modified) {
+                continue;
+            }
+            if let Some(suggestion) = read_suggestion(&path) {
+                best = Some((modified, suggestion));
+            }
+        }
+    }
+
+    best.map(|(_, suggestion)| suggestion)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::time::{Duration, Instant};
+
+    use tempfile::tempdir;
+    use versi_backend::NodeVersion;
+
+    use super::{
+        VersionFileSuggestion, find_in_dir, parse_tool_versions, parse_version_spec,
+        read_suggestion, scan_for_suggestion,
+    };
+
+    fn suggestion(spec: &str) -> VersionFileSuggestion {
+        VersionFileSuggestion {
+            path: ".nvmrc".into(),
+            spec: spec.to_string(),
+            version: spec.parse().ok(),
+        }
+    }
+
+    #[test]
+    fn parse_version_spec_accepts_versions_and_lts_aliases() {
+        assert_eq!(
+            parse_version_spec("v20.11.0\n"),
+            Some("v20.11.0".to_string())
+        );
+        assert_eq!(parse_version_spec("22"), Some("22".to_string()));
+        assert_eq!(
+            parse_version_spec("# pinned\nlts/iron\n"),
+            Some("lts/iron".to_string())
+        );
+        assert_eq!(parse_version_spec("system"), None);
+        assert_eq!(parse_version_spec("\n\n"), None);
+    }
+
+    #[test]
+    fn nvmrc_fixture_parses_into_suggested_version() {
+        let dir = tempdir().expect("create temp dir");
+        let path = dir.path().join(".nvmrc");
+        std::fs::write(&path, "v20.11.0\n").expect("write .nvmrc fixture");
+
+        let suggestion = read_suggestion(&path).expect("fixture should produce a suggestion");
+
+        assert_eq!(suggestion.version, Some(NodeVersion::new(20, 11, 0)));
+        assert_eq!(suggestion.spec, "v20.11.0");
+        assert_eq!(suggestion.label(), "v20.11.0");
+    }
+
+    #[test]
+    fn parse_tool_versions_reads_the_node_entry() {
+        assert_eq!(
+            parse_tool_versions("python 3.12.1\nnodejs 18.19.1 20.11.0 # pinned\n"),
+            Some("18.19.1".to_string())
+        );
+        assert_eq!(
+            parse_tool_versions("node lts/iron"),
+            Some("lts/iron".to_string())
+        );
+        assert_eq!(parse_tool_versions("# nodejs 18.19.1\nruby 3.3.0\n"), None);
+        assert_eq!(parse_tool_versions("nodejs system"), None);
+    }
+
+    #[test]
+    fn find_in_dir_parses_each_version_file_format() {
+        let cases = [
+            (".nvmrc", "v18.19.1\n"),
+            (".node-version", "18.19.1\n"),
+            (".tool-versions", "python 3.12.1\nnodejs 18.19.1\n"),
+        ];
+        for (name, content) in cases {
+            let dir = tempdir().expect("create temp dir");
+            std::fs::write(dir.path().join(name), content).expect("write version file");
+
+            let suggestion = find_in_dir(dir.path()).expect("version file should be found");
+
+            assert_eq!(
+                suggestion.version,
+                Some(NodeVersion::new(18, 19, 1)),
+                "{name}"
+            );
+            assert_eq!(suggestion.path, dir.path().join(name));
+        }
+    }
+
+    #[test]
+    fn find_in_dir_prefers_nvmrc() {
+        let dir = tempdir().expect("create temp dir");
+        std::fs::write(dir.path().join(".tool-versions"), "nodejs 18.19.1\n")
+            .expect("write .tool-versions");
+        std::fs::write(dir.path().join(".nvmrc"), "20\n").expect("write .nvmrc");
+
+        let suggestion = find_in_dir(dir.path()).expect("version file should be found");
+
+        assert_eq!(suggestion.spec, "20");
+    }
+
+    #[test]
+    fn is_satisfied_by_matches_exact_and_partial_specs() {
+        let installed: HashSet<NodeVersion> = [NodeVersion::new(20, 11, 0)].into_iter().collect();
+
+        assert_eq!(
+            suggestion("v20.11.0").is_satisfied_by(&installed),
+            Some(true)
+        );
+        assert_eq!(
+            suggestion("18.19.1").is_satisfied_by(&installed),
+            Some(false)
+        );
+        assert_eq!(suggestion("20").is_satisfied_by(&installed), Some(true));
+        assert_eq!(
+            suggestion("v20.12").is_satisfied_by(&installed),
+            Some(false)
+        );
+        assert_eq!(suggestion("lts/iron").is_satisfied_by(&installed), None);
+    }
+
+    #[test]
+    fn scan_finds_version_file_in_project_folder() {
+        let home = tempdir().expect("create temp home");
+        let project = home.path().join("projects").join("app");
+        std::fs::create_dir_all(&project).expect("create project dir");
+        std::fs::write(project.join(".node-version"), "22\n").expect("write .node-version");
+
+        let suggestion = scan_for_suggestion(home.path(), Instant::now() + Duration::from_secs(5))
+            .expect("scan should find the project file");
+
+        assert_eq!(suggestion.spec, "22");
+        assert_eq!(suggestion.version, None);
+        assert_eq!(suggestion.label(), "22");
+    }
+
+    #[test]
+    fn scan_respects_expired_deadline() {
+        let home = tempdir().expect("create temp home");
+        std::fs::write(home.path().join(".nvmrc"), "20\n").expect("write .nvmrc");
+
+        assert!(scan_for_suggestion(home.path(), Instant::now()).is_none());
+    }
+}
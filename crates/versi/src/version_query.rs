@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
+use chrono::{DateTime, Utc};
 use versi_backend::{NodeVersion, RemoteVersion};
 use versi_core::ReleaseSchedule;
 
@@ -8,6 +10,8 @@ use crate::state::SearchFilter;
 pub(crate) struct AvailableVersionSearch<'a> {
     pub(crate) versions: Vec<&'a RemoteVersion>,
     pub(crate) alias_resolved: bool,
+    /// How many versions matched before the limit was applied.
+    pub(crate) total: usize,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -21,6 +25,16 @@ struct RemoteVersionSearchEntry {
     lts_codename_lower: Option<String>,
 }
 
+/// What search results are narrowed by. Release phases are judged as of
+/// `as_of`, so every result in a frame agrees on which lines are end-of-life.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ResultFilters<'a> {
+    pub(crate) active: &'a HashSet<SearchFilter>,
+    pub(crate) installed_set: &'a HashSet<NodeVersion>,
+    pub(crate) schedule: Option<&'a ReleaseSchedule>,
+    pub(crate) as_of: DateTime<Utc>,
+}
+
 const LATEST_BY_MAJOR_CAPACITY: usize = 32;
 const LATEST_BY_MINOR_CAPACITY: usize = 192;
 
@@ -46,18 +60,37 @@ impl RemoteVersionSearchIndex {
     }
 }
 
+/// Where a query matched: byte ranges into the version text and into the
+/// lowercased LTS codename.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct QueryMatch {
+    pub(crate) version: Option<Range<usize>>,
+    pub(crate) codename: Option<Range<usize>>,
+}
+
+/// Match `query` against a version and its codename, returning the matched
+/// ranges, or `None` if neither matched. `lts` matches the whole codename.
 pub(crate) fn matches_version_query(
     version_text: &str,
     lts_codename_lower: Option<&str>,
     query: &str,
     query_lower: &str,
-) -> bool {
+) -> Option<QueryMatch> {
     if query_lower == "lts" {
-        return lts_codename_lower.is_some();
+        return lts_codename_lower.map(|codename| QueryMatch {
+            version: None,
+            codename: Some(0..codename.len()),
+        });
     }
 
-    version_text.contains(query)
-        || lts_codename_lower.is_some_and(|codename| codename.contains(query_lower))
+    let version = version_text
+        .find(query)
+        .map(|start| start..start + query.len());
+    let codename = lts_codename_lower
+        .and_then(|codename| codename.find(query_lower))
+        .map(|start| start..start + query_lower.len());
+
+    (version.is_some() || codename.is_some()).then_some(QueryMatch { version, codename })
 }
 
 pub(crate) fn matches_version_query_case_insensitive(
@@ -74,20 +107,27 @@ pub(crate) fn matches_version_query_case_insensitive(
         || lts_codename.is_some_and(|codename| contains_case_insensitive(codename, query_lower))
 }
 
+/// Apply the EOL/Active filters to `major`. A major newer than anything in
+/// the schedule is treated as active rather than guessed at.
 pub(crate) fn passes_release_filters(
     major: u32,
     active_filters: &HashSet<SearchFilter>,
     schedule: Option<&ReleaseSchedule>,
+    as_of: DateTime<Utc>,
 ) -> bool {
+    if schedule.is_some_and(|s| s.is_pending(major)) {
+        return !active_filters.contains(&SearchFilter::Eol);
+    }
+
     if active_filters.contains(&SearchFilter::Eol) {
-        let is_eol = schedule.is_some_and(|s| !s.is_active(major));
+        let is_eol = schedule.is_some_and(|s| !s.is_active_at(major, as_of));
         if !is_eol {
             return false;
         }
     }
 
     if active_filters.contains(&SearchFilter::Active) {
-        let is_active = schedule.is_none_or(|s| s.is_active(major));
+        let is_active = schedule.is_none_or(|s| s.is_active_at(major, as_of));
         if !is_active {
             return false;
         }
@@ -144,21 +184,19 @@ pub(crate) fn search_available_versions_with_index<'a>(
     search_index: Option<&RemoteVersionSearchIndex>,
     query: &str,
     limit: usize,
-    active_filters: &HashSet<SearchFilter>,
-    installed_set: &HashSet<NodeVersion>,
-    schedule: Option<&ReleaseSchedule>,
+    filters: ResultFilters<'_>,
 ) -> AvailableVersionSearch<'a> {
     let query_lower = query.to_lowercase();
     let index_entries = search_index.and_then(|index| index.as_complete_entries_for(versions));
 
     if let Some(resolved) = resolve_alias_with_index(versions, search_index, query) {
-        let filtered = if matches_active_filters(resolved, active_filters, installed_set, schedule)
-        {
+        let filtered = if matches_active_filters(resolved, filters) {
             vec![resolved]
         } else {
             Vec::new()
         };
         return AvailableVersionSearch {
+            total: filtered.len(),
             versions: filtered,
             alias_resolved: true,
         };
@@ -178,6 +216,7 @@ pub(crate) fn search_available_versions_with_index<'a>(
                         query,
                         &query_lower,
                     )
+                    .is_some()
                 })
                 .map(|(version, _)| version),
         )
@@ -197,13 +236,32 @@ pub(crate) fn search_available_versions_with_index<'a>(
         }
         latest_by_minor(filtered.into_iter())
     };
-    apply_active_filters(&mut result, active_filters, installed_set, schedule);
+    apply_active_filters(&mut result, filters);
+    let total = result.len();
     result.truncate(limit);
 
     AvailableVersionSearch {
         versions: result,
         alias_resolved: false,
+        total,
+    }
+}
+
+/// Split search results into per-major buckets, newest major first. The
+/// input keeps its order within each bucket.
+pub(crate) fn group_results_by_major<'a>(
+    versions: &[&'a RemoteVersion],
+) -> Vec<(u32, Vec<&'a RemoteVersion>)> {
+    let mut groups: Vec<(u32, Vec<&RemoteVersion>)> = Vec::new();
+    for &version in versions {
+        let major = version.version.major;
+        match groups.iter_mut().find(|(existing, _)| *existing == major) {
+            Some((_, bucket)) => bucket.push(version),
+            None => groups.push((major, vec![version])),
+        }
     }
+    groups.sort_by(|a, b| b.0.cmp(&a.0));
+    groups
 }
 
 fn latest_by_major<'a>(
@@ -251,43 +309,109 @@ fn latest_by_minor<'a>(
     result
 }
 
-fn apply_active_filters(
-    versions: &mut Vec<&RemoteVersion>,
-    active_filters: &HashSet<SearchFilter>,
-    installed_set: &HashSet<NodeVersion>,
-    schedule: Option<&ReleaseSchedule>,
-) {
-    if active_filters.is_empty() {
+fn apply_active_filters(versions: &mut Vec<&RemoteVersion>, filters: ResultFilters<'_>) {
+    if filters.active.is_empty() {
         return;
     }
 
-    versions
-        .retain(|version| matches_active_filters(version, active_filters, installed_set, schedule));
+    versions.retain(|version| matches_active_filters(version, filters));
 }
 
-fn matches_active_filters(
-    version: &RemoteVersion,
-    active_filters: &HashSet<SearchFilter>,
-    installed_set: &HashSet<NodeVersion>,
-    schedule: Option<&ReleaseSchedule>,
-) -> bool {
-    if active_filters.contains(&SearchFilter::Lts) && version.lts_codename.is_none() {
+fn matches_active_filters(version: &RemoteVersion, filters: ResultFilters<'_>) -> bool {
+    if filters.active.contains(&SearchFilter::Lts) && version.lts_codename.is_none() {
         return false;
     }
 
-    if active_filters.contains(&SearchFilter::Installed)
-        && !installed_set.contains(&version.version)
+    if filters.active.contains(&SearchFilter::Installed)
+        && !filters.installed_set.contains(&version.version)
     {
         return false;
     }
 
-    if active_filters.contains(&SearchFilter::NotInstalled)
-        && installed_set.contains(&version.version)
+    if filters.active.contains(&SearchFilter::NotInstalled)
+        && filters.installed_set.contains(&version.version)
     {
         return false;
     }
 
-    passes_release_filters(version.version.major, active_filters, schedule)
+    passes_release_filters(
+        version.version.major,
+        filters.active,
+        filters.schedule,
+        filters.as_of,
+    )
+}
+
+/// A nudge shown when a search finds nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum QuerySuggestion {
+    /// The query named a major with no release line; this is the closest.
+    NearestMajor { requested: u32, nearest: u32 },
+    /// The query looks like a misspelling of this codename.
+    Codename(String),
+}
+
+const MAX_CODENAME_TYPOS: usize = 2;
+
+/// Suggest a better query for one that matched nothing: the nearest existing
+/// major for a major that doesn't exist, or the closest LTS codename for a
+/// likely typo.
+pub(crate) fn suggest_for_query(
+    versions: &[RemoteVersion],
+    query: &str,
+) -> Option<QuerySuggestion> {
+    let query = query.trim();
+    let bare = query.strip_prefix(['v', 'V']).unwrap_or(query);
+    let bare = bare.strip_suffix(".x").unwrap_or(bare);
+    if let Ok(requested) = bare.parse::<u32>() {
+        if versions.iter().any(|v| v.version.major == requested) {
+            return None;
+        }
+        return versions
+            .iter()
+            .map(|v| v.version.major)
+            .min_by_key(|&major| (major.abs_diff(requested), std::cmp::Reverse(major)))
+            .map(|nearest| QuerySuggestion::NearestMajor { requested, nearest });
+    }
+
+    let query_lower = query.to_lowercase();
+    let query_lower = query_lower.strip_prefix("lts/").unwrap_or(&query_lower);
+    if query_lower.is_empty() || !query_lower.chars().all(char::is_alphabetic) {
+        return None;
+    }
+
+    versions
+        .iter()
+        .filter_map(|v| v.lts_codename.as_deref())
+        .map(|codename| {
+            (
+                edit_distance(query_lower, &codename.to_lowercase()),
+                codename,
+            )
+        })
+        .filter(|&(distance, codename)| {
+            distance > 0 && distance <= MAX_CODENAME_TYPOS && distance < codename.len()
+        })
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, codename)| QuerySuggestion::Codename(codename.to_string()))
+}
+
+/// Levenshtein distance between two strings, by character.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
 }
 
 fn contains_case_insensitive(haystack: &str, needle_lower: &str) -> bool {
@@ -315,10 +439,27 @@ mod tests {
     use std::time::{Duration, Instant};
 
     use super::{
-        RemoteVersionSearchIndex, resolve_alias_with_index, search_available_versions_with_index,
+        QueryMatch, QuerySuggestion, RemoteVersionSearchIndex, ResultFilters, edit_distance,
+        group_results_by_major, matches_version_query, resolve_alias_with_index,
+        search_available_versions_with_index, suggest_for_query,
     };
     use crate::state::SearchFilter;
 
+    fn filters<'a>(
+        active: &'a HashSet<SearchFilter>,
+        installed_set: &'a HashSet<versi_backend::NodeVersion>,
+        schedule: Option<&'a versi_core::ReleaseSchedule>,
+    ) -> ResultFilters<'a> {
+        ResultFilters {
+            active,
+            installed_set,
+            schedule,
+            as_of: chrono::DateTime::parse_from_rfc3339("2026-01-15T12:00:00Z")
+                .expect("fixture date should parse")
+                .to_utc(),
+        }
+    }
+
     fn remote(version: &str, lts_codename: Option<&str>) -> versi_backend::RemoteVersion {
         versi_backend::RemoteVersion {
             version: version.parse().expect("test version should parse"),
@@ -382,9 +523,7 @@ mod tests {
             None,
             "stable",
             20,
-            &HashSet::new(),
-            &HashSet::new(),
-            None,
+            filters(&HashSet::new(), &HashSet::new(), None),
         );
 
         assert!(search.alias_resolved);
@@ -407,9 +546,7 @@ mod tests {
             None,
             "v",
             20,
-            &filters,
-            &installed,
-            Some(&schedule),
+            filters(&filters, &installed, Some(&schedule)),
         );
         assert_eq!(search.versions.len(), 1);
         assert_eq!(search.versions[0].version.to_string(), "v20.11.0");
@@ -427,11 +564,37 @@ mod tests {
         let filters = HashSet::from([SearchFilter::Installed]);
 
         let search = search_available_versions_with_index(
-            &versions, None, "v22", 1, &filters, &installed, None,
+            &versions,
+            None,
+            "v22",
+            1,
+            filters(&filters, &installed, None),
         );
 
         assert_eq!(search.versions.len(), 1);
         assert_eq!(search.versions[0].version.to_string(), "v22.2.0");
+        assert_eq!(search.total, 1);
+    }
+
+    #[test]
+    fn total_counts_matches_before_the_limit() {
+        let versions = vec![
+            remote("v22.3.0", None),
+            remote("v22.2.0", None),
+            remote("v22.1.0", None),
+            remote("v20.1.0", None),
+        ];
+
+        let search = search_available_versions_with_index(
+            &versions,
+            None,
+            "v2",
+            2,
+            filters(&HashSet::new(), &HashSet::new(), None),
+        );
+
+        assert_eq!(search.versions.len(), 2);
+        assert_eq!(search.total, 4);
     }
 
     #[test]
@@ -441,7 +604,11 @@ mod tests {
         let filters = HashSet::from([SearchFilter::NotInstalled]);
 
         let search = search_available_versions_with_index(
-            &versions, None, "stable", 10, &filters, &installed, None,
+            &versions,
+            None,
+            "stable",
+            10,
+            filters(&filters, &installed, None),
         );
 
         assert!(search.alias_resolved);
@@ -466,18 +633,14 @@ mod tests {
             None,
             "v22",
             10,
-            &filters,
-            &installed,
-            Some(&schedule),
+            filters(&filters, &installed, Some(&schedule)),
         );
         let indexed = search_available_versions_with_index(
             &versions,
             Some(&search_index),
             "v22",
             10,
-            &filters,
-            &installed,
-            Some(&schedule),
+            filters(&filters, &installed, Some(&schedule)),
         );
 
         let unindexed_versions: Vec<String> = unindexed
@@ -495,6 +658,125 @@ mod tests {
         assert_eq!(indexed_versions, unindexed_versions);
     }
 
+    #[test]
+    fn query_match_reports_matched_ranges() {
+        assert_eq!(
+            matches_version_query("v20.11.1", Some("iron"), "11", "11"),
+            Some(QueryMatch {
+                version: Some(4..6),
+                codename: None,
+            })
+        );
+        assert_eq!(
+            matches_version_query("v20.11.1", Some("iron"), "Ro", "ro"),
+            Some(QueryMatch {
+                version: None,
+                codename: Some(1..3),
+            })
+        );
+        assert_eq!(
+            matches_version_query("v20.11.1", Some("iron"), "lts", "lts"),
+            Some(QueryMatch {
+                version: None,
+                codename: Some(0..4),
+            })
+        );
+        assert_eq!(
+            matches_version_query("v20.11.1", Some("iron"), "18", "18"),
+            None
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("iron", "iron"), 0);
+        assert_eq!(edit_distance("irn", "iron"), 1);
+        assert_eq!(edit_distance("hydorgen", "hydrogen"), 2);
+        assert_eq!(edit_distance("", "jod"), 3);
+    }
+
+    #[test]
+    fn suggestion_corrects_a_misspelled_codename() {
+        let versions = vec![
+            remote("v22.11.0", Some("Jod")),
+            remote("v20.18.0", Some("Iron")),
+            remote("v18.20.4", Some("Hydrogen")),
+        ];
+
+        assert_eq!(
+            suggest_for_query(&versions, "hydorgen"),
+            Some(QuerySuggestion::Codename("Hydrogen".to_string()))
+        );
+        assert_eq!(
+            suggest_for_query(&versions, "lts/irn"),
+            Some(QuerySuggestion::Codename("Iron".to_string()))
+        );
+        assert_eq!(suggest_for_query(&versions, "banana"), None);
+    }
+
+    #[test]
+    fn suggestion_points_a_missing_major_at_the_nearest_line() {
+        let versions = vec![
+            remote("v22.11.0", None),
+            remote("v20.18.0", None),
+            remote("v18.20.4", None),
+        ];
+
+        assert_eq!(
+            suggest_for_query(&versions, "v21"),
+            Some(QuerySuggestion::NearestMajor {
+                requested: 21,
+                nearest: 22
+            })
+        );
+        assert_eq!(
+            suggest_for_query(&versions, "16.x"),
+            Some(QuerySuggestion::NearestMajor {
+                requested: 16,
+                nearest: 18
+            })
+        );
+        assert_eq!(suggest_for_query(&versions, "20"), None);
+    }
+
+    #[test]
+    fn search_results_group_into_major_buckets() {
+        let versions = vec![
+            remote("v22.3.0", Some("Jod")),
+            remote("v22.2.1", Some("Jod")),
+            remote("v20.12.0", Some("Iron")),
+            remote("v12.22.12", Some("Erbium")),
+            remote("v2.5.0", None),
+        ];
+        let search = search_available_versions_with_index(
+            &versions,
+            None,
+            "2",
+            20,
+            filters(&HashSet::new(), &HashSet::new(), None),
+        );
+
+        let groups: Vec<(u32, Vec<String>)> = group_results_by_major(&search.versions)
+            .into_iter()
+            .map(|(major, bucket)| {
+                (
+                    major,
+                    bucket.iter().map(|v| v.version.to_string()).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                (22, vec!["v22.3.0".to_string(), "v22.2.1".to_string()]),
+                (20, vec!["v20.12.0".to_string()]),
+                (12, vec!["v12.22.12".to_string()]),
+                (2, vec!["v2.5.0".to_string()]),
+            ]
+        );
+    }
+
     #[test]
     fn indexed_search_falls_back_when_index_is_stale() {
         let versions = vec![remote("v22.3.0", None), remote("v22.2.0", None)];
@@ -505,9 +787,7 @@ mod tests {
             Some(&stale_index),
             "v22",
             10,
-            &HashSet::new(),
-            &HashSet::new(),
-            None,
+            filters(&HashSet::new(), &HashSet::new(), None),
         );
 
         assert_eq!(result.versions.len(), 2);
@@ -550,9 +830,7 @@ mod tests {
                 Some(&search_index),
                 "v2",
                 30,
-                &filters,
-                &installed,
-                Some(&schedule),
+                filters(&filters, &installed, Some(&schedule)),
             );
             std::hint::black_box(result.versions.len());
         }
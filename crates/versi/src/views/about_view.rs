@@ -4,14 +4,20 @@ use iced::{Alignment, Element, Length};
 use crate::icon;
 use crate::message::Message;
 use crate::state::MainState;
+use crate::strings::Language;
 use crate::theme::styles;
 use crate::widgets::helpers::nav_icons;
 
-pub fn view(state: &MainState, has_tabs: bool) -> Element<'_, Message> {
+pub fn view(
+    state: &MainState,
+    language: Language,
+    has_tabs: bool,
+    reduced_motion: bool,
+) -> Element<'_, Message> {
     let header = row![
         text("About").size(14),
         Space::new().width(Length::Fill),
-        nav_icons(&state.view, state.refresh_rotation),
+        nav_icons(state, language, reduced_motion),
     ]
     .spacing(8)
     .align_y(Alignment::Center);
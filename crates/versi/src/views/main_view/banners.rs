@@ -3,19 +3,40 @@ use iced::widget::{Space, button, column, row, text};
 use iced::{Alignment, Element, Length};
 
 use crate::message::Message;
+use crate::settings::AppSettings;
 use crate::state::{MainState, NetworkStatus};
 use crate::theme::styles;
 
-pub(super) fn contextual_banners(state: &MainState) -> Option<Element<'_, Message>> {
+pub(super) fn contextual_banners<'a>(
+    state: &'a MainState,
+    settings: &AppSettings,
+) -> Option<Element<'a, Message>> {
     let schedule = state.available_versions.schedule.as_ref();
 
     let mut banners: Vec<Element<Message>> = Vec::new();
 
+    if let Some(build_banner) = build_age_banner(state, settings) {
+        banners.push(build_banner);
+    }
+
     if let Some(network_banner) = network_status_banner(state) {
         banners.push(network_banner);
     }
 
-    if let Some(schedule_banner) = release_schedule_banner(state, schedule.is_some()) {
+    if let Some(key) = project_version_banner_key(state)
+        && let Some(project_banner) = dismissible(project_version_banner(state), key, settings)
+    {
+        banners.push(project_banner);
+    }
+
+    let schedule_banner = release_schedule_banner(state, schedule.is_some());
+    let schedule_banner = if state.available_versions.schedule_is_bundled {
+        let key = AppSettings::banner_key("bundled-schedule", versi_core::BUNDLED_SCHEDULE_DATE);
+        dismissible(schedule_banner, key, settings)
+    } else {
+        schedule_banner
+    };
+    if let Some(schedule_banner) = schedule_banner {
         banners.push(schedule_banner);
     }
 
@@ -25,14 +46,27 @@ pub(super) fn contextual_banners(state: &MainState) -> Option<Element<'_, Messag
         banners.push(metadata_banner);
     }
 
-    if let Some(update_banner) = available_updates_banner(state) {
+    let stats = &state.banner_stats;
+    if !state.updatable_only
+        && let Some(key) = &stats.updates_key
+        && let Some(update_banner) =
+            dismissible(available_updates_banner(state), key.clone(), settings)
+    {
         banners.push(update_banner);
     }
 
-    if let Some(eol_banner) = eol_cleanup_banner(state) {
+    if let Some(key) = &stats.eol_key
+        && let Some(eol_banner) = dismissible(eol_cleanup_banner(state), key.clone(), settings)
+    {
         banners.push(eol_banner);
     }
 
+    if let Some(key) = &stats.retention_key
+        && let Some(retention_banner) = dismissible(retention_banner(state), key.clone(), settings)
+    {
+        banners.push(retention_banner);
+    }
+
     if banners.is_empty() {
         None
     } else {
@@ -40,6 +74,62 @@ pub(super) fn contextual_banners(state: &MainState) -> Option<Element<'_, Messag
     }
 }
 
+/// Add a close button to `banner`, or hide it when `key` was dismissed.
+fn dismissible<'a>(
+    banner: Option<Element<'a, Message>>,
+    key: String,
+    settings: &AppSettings,
+) -> Option<Element<'a, Message>> {
+    let banner = banner?;
+    if settings.is_banner_dismissed(&key) {
+        return None;
+    }
+    Some(
+        row![
+            banner,
+            button(text("\u{00d7}").size(13))
+                .on_press(Message::DismissBanner(key))
+                .style(styles::ghost_button)
+                .padding([12, 12]),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center)
+        .into(),
+    )
+}
+
+fn project_version_banner_key(state: &MainState) -> Option<String> {
+    let suggestion = state.missing_project_version()?;
+    let target = format!("{}:{}", suggestion.path.display(), suggestion.spec);
+    Some(AppSettings::banner_key("project-version", &target))
+}
+
+fn build_age_banner(
+    state: &MainState,
+    settings: &AppSettings,
+) -> Option<Element<'static, Message>> {
+    let key = state.banner_stats.build_age_key.clone()?;
+    if settings.is_banner_dismissed(&key) {
+        return None;
+    }
+
+    Some(
+        button(
+            row![
+                text("This build is over a year old; consider updating.").size(13),
+                Space::new().width(Length::Fill),
+                text("Dismiss").size(13),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::DismissBanner(key))
+        .style(styles::banner_button_info)
+        .padding([12, 16])
+        .width(Length::Fill)
+        .into(),
+    )
+}
+
 fn network_status_banner(state: &MainState) -> Option<Element<'_, Message>> {
     match state.available_versions.network_status() {
         NetworkStatus::Offline => Some(simple_retry_banner(
@@ -51,7 +141,9 @@ fn network_status_banner(state: &MainState) -> Option<Element<'_, Message>> {
             let age_text = state
                 .available_versions
                 .disk_cached_at
-                .map(|timestamp| format!(" (cached {})", format_relative_time(timestamp)))
+                .map(|timestamp| {
+                    format!(" (cached {})", format_relative_time(timestamp, state.now))
+                })
                 .unwrap_or_default();
             Some(simple_retry_banner(
                 format!("Using cached data{age_text} \u{2014} could not refresh from network"),
@@ -62,7 +154,15 @@ fn network_status_banner(state: &MainState) -> Option<Element<'_, Message>> {
 }
 
 fn release_schedule_banner(state: &MainState, has_schedule: bool) -> Option<Element<'_, Message>> {
-    if state.available_versions.schedule_fetch.error.is_some() && !has_schedule {
+    if state.available_versions.schedule_is_bundled {
+        Some(simple_retry_banner(
+            format!(
+                "Using the built-in release schedule from {} \u{2014} EOL dates may be outdated",
+                versi_core::BUNDLED_SCHEDULE_DATE
+            ),
+            Message::FetchReleaseSchedule,
+        ))
+    } else if state.available_versions.schedule_fetch.error.is_some() && !has_schedule {
         Some(simple_retry_banner(
             "Release schedule unavailable \u{2014} EOL detection may be inaccurate".to_string(),
             Message::FetchReleaseSchedule,
@@ -109,21 +209,42 @@ fn available_updates_banner(state: &MainState) -> Option<Element<'_, Message>> {
             text(if has_active_ops {
                 "Updating..."
             } else {
-                "Update All"
+                "Review"
             })
             .size(13),
         ]
         .align_y(Alignment::Center),
     )
+    .on_press(Message::UpdatableViewToggled(true))
     .style(styles::banner_button_info)
     .padding([12, 16])
     .width(Length::Fill);
 
-    Some(if has_active_ops {
-        button.into()
-    } else {
-        button.on_press(Message::RequestBulkUpdateMajors).into()
-    })
+    Some(button.into())
+}
+
+fn project_version_banner(state: &MainState) -> Option<Element<'_, Message>> {
+    let suggestion = state.missing_project_version()?;
+
+    Some(
+        button(
+            row![
+                text(format!(
+                    "This project needs Node {} \u{2014} install it?",
+                    suggestion.label()
+                ))
+                .size(13),
+                Space::new().width(Length::Fill),
+                text("Install").size(13),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::StartInstall(suggestion.spec.clone()))
+        .style(styles::banner_button_info)
+        .padding([12, 16])
+        .width(Length::Fill)
+        .into(),
+    )
 }
 
 fn eol_cleanup_banner(state: &MainState) -> Option<Element<'_, Message>> {
@@ -159,6 +280,38 @@ fn eol_cleanup_banner(state: &MainState) -> Option<Element<'_, Message>> {
     )
 }
 
+fn retention_banner(state: &MainState) -> Option<Element<'_, Message>> {
+    let prune_count = state.banner_stats.retention_candidates.len();
+    if prune_count == 0 {
+        return None;
+    }
+
+    Some(
+        button(
+            row![
+                text(format!(
+                    "{} {} beyond your retention policy",
+                    prune_count,
+                    if prune_count == 1 {
+                        "version"
+                    } else {
+                        "versions"
+                    }
+                ))
+                .size(13),
+                Space::new().width(Length::Fill),
+                text("Prune").size(13),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .on_press(Message::RequestApplyRetentionPolicy)
+        .style(styles::banner_button_info)
+        .padding([12, 16])
+        .width(Length::Fill)
+        .into(),
+    )
+}
+
 fn simple_retry_banner(label: String, retry_message: Message) -> Element<'static, Message> {
     button(
         row![
@@ -175,8 +328,8 @@ fn simple_retry_banner(label: String, retry_message: Message) -> Element<'static
     .into()
 }
 
-fn format_relative_time(timestamp: DateTime<Utc>) -> String {
-    let delta = Utc::now().signed_duration_since(timestamp);
+fn format_relative_time(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now.signed_duration_since(timestamp);
     let minutes = delta.num_minutes();
     if minutes < 1 {
         "just now".to_string()
@@ -201,9 +354,12 @@ mod tests {
     use versi_backend::{BackendDetection, BackendProvider};
     use versi_platform::EnvironmentId;
 
-    use super::{contextual_banners, metadata_banner};
+    use chrono::{TimeZone, Utc};
+
+    use super::{build_age_banner, contextual_banners, dismissible, metadata_banner};
     use crate::backend_kind::BackendKind;
     use crate::error::AppError;
+    use crate::settings::AppSettings;
     use crate::state::{EnvironmentState, MainState};
 
     fn main_state_for_banners() -> MainState {
@@ -231,7 +387,7 @@ mod tests {
         ));
 
         assert!(metadata_banner(&state, false).is_some());
-        assert!(contextual_banners(&state).is_some());
+        assert!(contextual_banners(&state, &AppSettings::default()).is_some());
     }
 
     #[test]
@@ -251,4 +407,66 @@ mod tests {
         let state = main_state_for_banners();
         assert!(metadata_banner(&state, false).is_none());
     }
+
+    #[test]
+    fn dismissed_build_age_banner_stays_hidden() {
+        let mut state = main_state_for_banners();
+        let mut settings = AppSettings::default();
+        state.now = Utc.with_ymd_and_hms(2999, 1, 1, 0, 0, 0).unwrap();
+        state.recompute_banner_stats(None);
+        assert!(build_age_banner(&state, &settings).is_some());
+
+        let key = state
+            .banner_stats
+            .build_age_key
+            .clone()
+            .expect("an old build should have a banner key");
+        settings.dismiss_banner(key);
+
+        assert!(build_age_banner(&state, &settings).is_none());
+    }
+
+    #[test]
+    fn dismissed_update_banner_returns_for_a_newer_release() {
+        let mut state = main_state_for_banners();
+        state.environments[0].update_versions(vec![versi_backend::InstalledVersion {
+            version: "v22.1.0".parse().expect("test version should parse"),
+            is_default: true,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+        }]);
+        let remote = |version: &str| versi_backend::RemoteVersion {
+            version: version.parse().expect("test version should parse"),
+            lts_codename: None,
+            is_latest: false,
+        };
+        state
+            .available_versions
+            .set_versions(vec![remote("v22.1.0"), remote("v22.2.0")]);
+
+        let mut settings = AppSettings::default();
+        state.recompute_banner_stats(None);
+        let key = state
+            .banner_stats
+            .updates_key
+            .clone()
+            .expect("an update should be available");
+        settings.dismiss_banner(key.clone());
+        assert!(dismissible(Some(iced::widget::text("").into()), key, &settings).is_none());
+
+        state.available_versions.set_versions(vec![
+            remote("v22.1.0"),
+            remote("v22.2.0"),
+            remote("v22.3.0"),
+        ]);
+        state.recompute_banner_stats(None);
+        let key = state
+            .banner_stats
+            .updates_key
+            .clone()
+            .expect("an update should be available");
+        assert!(!settings.is_banner_dismissed(&key));
+        assert!(dismissible(Some(iced::widget::text("").into()), key, &settings).is_some());
+    }
 }
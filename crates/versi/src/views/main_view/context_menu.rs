@@ -2,13 +2,16 @@ use iced::widget::{Space, button, column, container, mouse_area, row, text};
 use iced::{Element, Length};
 
 use crate::message::Message;
+use crate::settings::AppSettings;
 use crate::state::ContextMenu;
 use crate::theme::styles;
 
 pub(super) fn context_menu_overlay<'a>(
     content: Element<'a, Message>,
     menu: &ContextMenu,
+    settings: &AppSettings,
 ) -> Element<'a, Message> {
+    let is_pinned = settings.is_pinned(&menu.version);
     let backdrop = mouse_area(
         container(Space::new().width(Length::Fill).height(Length::Fill))
             .width(Length::Fill)
@@ -30,6 +33,34 @@ pub(super) fn context_menu_overlay<'a>(
                     .into(),
             );
         }
+        items.push(
+            button(text(if is_pinned { "Unpin" } else { "Pin to Top" }).size(13))
+                .on_press(Message::TogglePinnedVersion(menu.version.clone()))
+                .style(styles::context_menu_item)
+                .padding([6, 12])
+                .width(Length::Fill)
+                .into(),
+        );
+        if menu.can_create_alias {
+            items.push(
+                button(text("Create Alias\u{2026}").size(13))
+                    .on_press(Message::RequestCreateAlias(menu.version.clone()))
+                    .style(styles::context_menu_item)
+                    .padding([6, 12])
+                    .width(Length::Fill)
+                    .into(),
+            );
+        }
+        if menu.can_open_terminal {
+            items.push(
+                button(text(format!("Open Terminal with {}", menu.version)).size(13))
+                    .on_press(Message::OpenTerminalWithVersion(menu.version.clone()))
+                    .style(styles::context_menu_item)
+                    .padding([6, 12])
+                    .width(Length::Fill)
+                    .into(),
+            );
+        }
         items.push(
             button(text("Uninstall").size(13))
                 .on_press(Message::RequestUninstall(menu.version.clone()))
@@ -58,7 +89,9 @@ pub(super) fn context_menu_overlay<'a>(
 
     items.push(
         button(text("Copy Version Number").size(13))
-            .on_press(Message::CopyToClipboard(menu.version.clone()))
+            .on_press(Message::CopyToClipboard(
+                settings.display_version(&menu.version),
+            ))
             .style(styles::context_menu_item)
             .padding([6, 12])
             .width(Length::Fill)
@@ -1,13 +1,23 @@
-use iced::widget::{Space, button, container, row, text, tooltip};
+use iced::widget::{Space, button, column, container, pick_list, row, text, tooltip};
 use iced::{Alignment, Element, Length};
 
+use versi_backend::NodeVersion;
+use versi_core::ReleasePhase;
+
 use crate::icon;
 use crate::message::Message;
-use crate::state::{AppUpdateState, MainState};
+use crate::state::{AppUpdateState, MainState, VersionSortMode};
+use crate::strings::{Language, t};
 use crate::theme::styles;
-use crate::widgets::helpers::nav_icons;
+use crate::version_display::display_version;
+use crate::widgets::helpers::{nav_icons, styled_tooltip};
 
-pub(super) fn header_view(state: &MainState) -> Element<'_, Message> {
+pub(super) fn header_view(
+    state: &MainState,
+    language: Language,
+    reduced_motion: bool,
+    version_prefix: bool,
+) -> Element<'_, Message> {
     let env = state.active_environment();
 
     let subtitle = match &env.backend_version {
@@ -15,15 +25,28 @@ pub(super) fn header_view(state: &MainState) -> Element<'_, Message> {
         None => state.backend_name.to_string(),
     };
 
-    let mut left = row![text(subtitle).size(14),]
-        .spacing(8)
-        .align_y(Alignment::Center);
+    let mut left = row![
+        text(subtitle).size(14),
+        text(default_version_line(state, version_prefix))
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    if let Some(note) = env.active_version_note() {
+        left = left.push(styled_tooltip(
+            text(note).size(11).color(crate::theme::tokens::TEXT_MUTED),
+            "Differs from the default, e.g. because a project pinned another version",
+            tooltip::Position::Bottom,
+        ));
+    }
 
     if let Some(update) = &state.app_update {
         left = left.push(app_update_badge(update, &state.app_update_state));
     }
 
-    if let Some(update) = &state.backend_update {
+    if let Some(update) = &env.backend_update {
         left = left.push(
             button(
                 container(
@@ -46,15 +69,157 @@ pub(super) fn header_view(state: &MainState) -> Element<'_, Message> {
         );
     }
 
-    row![
+    if let Some((operation, _)) = &state.last_error {
+        left = left.push(styled_tooltip(
+            button(container(text("Last operation failed").size(11)).padding([2, 8]))
+                .on_press(Message::ShowLastError)
+                .style(styles::update_badge_button)
+                .padding(0),
+            format!(
+                "{} {} failed — click for details",
+                operation.label(),
+                display_version(operation.version(), version_prefix)
+            ),
+            tooltip::Position::Bottom,
+        ));
+    }
+
+    let mut right = row![].spacing(4).align_y(Alignment::Center);
+    if let Some(summary) = state.fetch_stage_summary() {
+        right = right.push(
+            text(summary)
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    }
+
+    let header = row![
         left,
         Space::new().width(Length::Fill),
-        nav_icons(&state.view, state.refresh_rotation),
+        sort_mode_picker(state),
+        select_button(state),
+        copy_installed_button(state),
+        install_from_file_button(state, language),
+        right.push(nav_icons(state, language, reduced_motion)),
     ]
-    .align_y(Alignment::Center)
+    .spacing(4)
+    .align_y(Alignment::Center);
+
+    if state.environments.len() <= 1 {
+        return header.into();
+    }
+
+    column![environment_stripe(env.accent()), header]
+        .spacing(6)
+        .into()
+}
+
+fn default_version_line(state: &MainState, version_prefix: bool) -> String {
+    let env = state.active_environment();
+    let Some(default) = env.default_version.as_ref() else {
+        return default_version_summary(None, None, None, version_prefix);
+    };
+    let schedule = state.available_versions.schedule.as_ref();
+    let codename = env
+        .installed_versions
+        .iter()
+        .find(|installed| &installed.version == default)
+        .and_then(|installed| installed.lts_codename.as_deref())
+        .or_else(|| schedule.and_then(|s| s.codename(default.major)));
+    let phase = schedule.and_then(|s| s.release_phase_at(default.major, state.now));
+    default_version_summary(Some(default), codename, phase, version_prefix)
+}
+
+/// One-line summary such as "Default: v20.11.0 (Iron, Active LTS)".
+fn default_version_summary(
+    default: Option<&NodeVersion>,
+    codename: Option<&str>,
+    phase: Option<ReleasePhase>,
+    version_prefix: bool,
+) -> String {
+    let Some(default) = default else {
+        return "No default version".to_string();
+    };
+    let version = display_version(&default.to_string(), version_prefix);
+    let details: Vec<&str> = codename
+        .into_iter()
+        .chain(phase.map(ReleasePhase::label))
+        .collect();
+    if details.is_empty() {
+        format!("Default: {version}")
+    } else {
+        format!("Default: {version} ({})", details.join(", "))
+    }
+}
+
+fn environment_stripe(accent: iced::Color) -> Element<'static, Message> {
+    let tint = iced::Color { a: 0.6, ..accent };
+    container(Space::new().width(Length::Fill).height(2))
+        .style(move |_theme| container::Style {
+            background: Some(iced::Background::Color(tint)),
+            border: iced::Border {
+                radius: 1.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+fn sort_mode_picker(state: &MainState) -> Element<'_, Message> {
+    pick_list(
+        VersionSortMode::ALL,
+        Some(state.sort_mode),
+        Message::SortModeChanged,
+    )
+    .text_size(12)
+    .padding([4, 8])
     .into()
 }
 
+fn select_button(state: &MainState) -> Element<'_, Message> {
+    let label = if state.selection_mode {
+        "Done"
+    } else {
+        "Select"
+    };
+    button(text(label).size(12))
+        .on_press(Message::SelectionModeToggled(!state.selection_mode))
+        .style(styles::ghost_button)
+        .padding([4, 8])
+        .into()
+}
+
+fn copy_installed_button(state: &MainState) -> Element<'_, Message> {
+    let has_versions = !state.active_environment().installed_versions.is_empty();
+    styled_tooltip(
+        button(text("Copy").size(12))
+            .on_press_maybe(has_versions.then_some(Message::CopyInstalledVersions))
+            .style(styles::ghost_button)
+            .padding([4, 8]),
+        "Copy installed versions",
+        tooltip::Position::Bottom,
+    )
+}
+
+fn install_from_file_button(state: &MainState, language: Language) -> Element<'_, Message> {
+    let supported = state.backend.capabilities().supports_archive_install;
+    let label = if supported {
+        "Install a downloaded Node.js archive"
+    } else {
+        "Installing from a file is not supported by this backend"
+    };
+
+    styled_tooltip(
+        button(text(t(language, "header.install_from_file")).size(12))
+            .on_press_maybe(supported.then_some(Message::RequestInstallFromArchive))
+            .style(styles::ghost_button)
+            .padding([4, 8]),
+        label,
+        tooltip::Position::Bottom,
+    )
+}
+
 fn badge_btn(label: &str) -> iced::widget::Button<'static, Message> {
     button(container(text(label.to_string()).size(11)).padding([2, 8]))
         .style(styles::app_update_button)
@@ -96,6 +261,7 @@ fn app_update_badge<'a>(
                 "Updating...".to_string()
             };
             badge_row = badge_row.push(badge_btn(&label));
+            badge_row = badge_row.push(badge_btn("Cancel").on_press(Message::CancelAppUpdate));
         }
         AppUpdateState::Extracting => {
             badge_row = badge_row.push(badge_btn("Extracting..."));
@@ -125,3 +291,42 @@ fn app_update_badge<'a>(
 
     badge_row.into()
 }
+
+#[cfg(test)]
+mod tests {
+    use versi_backend::NodeVersion;
+    use versi_core::ReleasePhase;
+
+    use super::default_version_summary;
+
+    #[test]
+    fn default_version_summary_combines_codename_and_phase() {
+        let version = NodeVersion::new(20, 11, 0);
+
+        assert_eq!(
+            default_version_summary(
+                Some(&version),
+                Some("Iron"),
+                Some(ReleasePhase::ActiveLts),
+                true
+            ),
+            "Default: v20.11.0 (Iron, Active LTS)"
+        );
+        assert_eq!(
+            default_version_summary(Some(&version), None, Some(ReleasePhase::Current), false),
+            "Default: 20.11.0 (Current)"
+        );
+        assert_eq!(
+            default_version_summary(Some(&version), None, None, true),
+            "Default: v20.11.0"
+        );
+    }
+
+    #[test]
+    fn default_version_summary_handles_no_default() {
+        assert_eq!(
+            default_version_summary(None, None, None, true),
+            "No default version"
+        );
+    }
+}
@@ -2,24 +2,30 @@ mod banners;
 mod context_menu;
 mod header;
 mod modals;
+
+pub(crate) use modals::modal_overlay;
 pub mod search;
 pub mod tabs;
 
-use iced::Element;
-use iced::widget::{column, container, mouse_area};
+use iced::widget::{Space, button, column, container, mouse_area, row, text};
+use iced::{Alignment, Element, Length};
 
 use crate::message::Message;
 use crate::settings::AppSettings;
 use crate::state::MainState;
+use crate::strings::Language;
+use crate::theme::styles;
 use crate::widgets::{toast_container, version_list};
 
 pub fn view<'a>(
     state: &'a MainState,
     settings: &'a AppSettings,
+    language: Language,
     has_tabs: bool,
+    reduced_motion: bool,
 ) -> Element<'a, Message> {
-    let header = header::header_view(state);
-    let search_bar = search::search_bar_view(state);
+    let header = header::header_view(state, language, reduced_motion, settings.version_prefix);
+    let search_bar = search::search_bar_view(state, language);
     let hovered = if state.modal.is_some() {
         &None
     } else {
@@ -31,15 +37,30 @@ pub fn view<'a>(
         search_index: Some(&state.available_versions.search_index),
         operation_queue: &state.operation_queue,
         hovered_version: hovered,
-        metadata: state.available_versions.metadata.as_ref(),
+        metadata: state
+            .available_versions
+            .metadata
+            .as_ref()
+            .filter(|_| settings.fetch_version_metadata),
         installed_set: &env.installed_set,
+        broken_versions: &env.broken_versions,
+        pinned_versions: &settings.pinned_versions,
+        selection: state.selection_mode.then_some(&state.selected_versions),
+        recommended: state.recommended_version(),
+        version_prefix: settings.version_prefix,
+        updatable_only: state.updatable_only && state.search_query.is_empty(),
+        collapsed_search_majors: &state.collapsed_search_majors,
+        version_notes: &settings.version_notes,
+        language,
+        as_of: state.now,
+        fetching_remote: state.available_versions.loading,
     };
     let version_list = version_list::view(
         env,
         &state.search_query,
         &state.available_versions.versions,
         &state.available_versions.latest_by_major,
-        settings.search_results_limit,
+        state.effective_search_limit(settings.search_results_limit),
         &state.active_filters,
         &ctx,
     );
@@ -52,16 +73,31 @@ pub fn view<'a>(
     .spacing(12);
 
     if !state.search_query.is_empty() {
-        let chips = search::filter_chips_view(&state.active_filters);
+        let chips = row![
+            search::filter_chips_view(&state.active_filters, language),
+            Space::new().width(Length::Fill),
+            search::copy_results_button(),
+        ]
+        .align_y(Alignment::Center);
         content_column = content_column.push(container(chips).padding(right_inset));
     }
 
     if state.search_query.is_empty()
-        && let Some(banner_content) = banners::contextual_banners(state)
+        && let Some(banner_content) = banners::contextual_banners(state, settings)
     {
         content_column = content_column.push(container(banner_content).padding(right_inset));
     }
 
+    if ctx.updatable_only {
+        content_column =
+            content_column.push(container(updatable_view_bar(state)).padding(right_inset));
+    }
+
+    if state.selection_mode {
+        content_column =
+            content_column.push(container(selection_action_bar(state)).padding(right_inset));
+    }
+
     content_column = content_column.push(version_list);
 
     let main_content = content_column.padding(crate::views::content_padding(has_tabs));
@@ -73,16 +109,64 @@ pub fn view<'a>(
         .into();
 
     let with_context_menu: Element<Message> = if let Some(menu) = &state.context_menu {
-        context_menu::context_menu_overlay(with_cursor_tracking, menu)
+        context_menu::context_menu_overlay(with_cursor_tracking, menu, settings)
     } else {
         with_cursor_tracking
     };
 
     let with_modal: Element<Message> = if let Some(modal) = &state.modal {
-        modals::modal_overlay(with_context_menu, modal, state, settings)
+        modals::modal_overlay(with_context_menu, modal, state, settings, language)
     } else {
         with_context_menu
     };
 
     toast_container::view(with_modal, &state.toasts, settings.max_visible_toasts)
 }
+
+fn updatable_view_bar(state: &MainState) -> Element<'_, Message> {
+    let count = version_list::updatable_groups(
+        &state.active_environment().version_groups,
+        &state.available_versions.latest_by_major,
+    )
+    .len();
+    let label = format!(
+        "{count} major {} with updates available",
+        if count == 1 { "version" } else { "versions" }
+    );
+    row![
+        text(label).size(13).color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().width(Length::Fill),
+        button(text("Show all").size(12))
+            .on_press(Message::UpdatableViewToggled(false))
+            .style(styles::secondary_button)
+            .padding([6, 12]),
+        button(text("Update All").size(12))
+            .on_press_maybe((count > 0).then_some(Message::RequestBulkUpdateMajors))
+            .style(styles::primary_button)
+            .padding([6, 12]),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn selection_action_bar(state: &MainState) -> Element<'_, Message> {
+    let count = state.selected_installed_versions().len();
+    row![
+        text(format!("{count} selected"))
+            .size(13)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().width(Length::Fill),
+        button(text("Cancel").size(12))
+            .on_press(Message::SelectionModeToggled(false))
+            .style(styles::secondary_button)
+            .padding([6, 12]),
+        button(text("Uninstall selected").size(12))
+            .on_press_maybe((count > 0).then_some(Message::RequestBulkUninstallSelected))
+            .style(styles::danger_button)
+            .padding([6, 12]),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
@@ -1,14 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use iced::widget::{Space, button, column, container, mouse_area, row, text};
+use iced::widget::{
+    Button, Space, button, checkbox, column, container, mouse_area, row, scrollable, text,
+    text_input, toggler,
+};
 use iced::{Element, Length};
 
-use versi_core::VersionMeta;
+use versi_core::{ReleasePhase, VersionMeta};
 
+use crate::backend_kind::BackendKind;
 use crate::message::Message;
-use crate::settings::AppSettings;
-use crate::state::{MainState, Modal};
+use crate::settings::{AppSettings, MetadataField, SettingChange, SettingsSection};
+use crate::state::{MainState, MigrationPlan, MigrationProgress, Modal, ModalFocus};
+use crate::strings::{Language, t};
 use crate::theme::styles;
+use crate::widgets::helpers::EolCountdown;
 
 fn version_preview_list(labels: Vec<String>, preview_limit: usize) -> Element<'static, Message> {
     let muted = crate::theme::tokens::TEXT_MUTED;
@@ -27,22 +33,24 @@ fn version_preview_list(labels: Vec<String>, preview_limit: usize) -> Element<'s
     list.into()
 }
 
-pub(super) fn modal_overlay<'a>(
+pub(crate) fn modal_overlay<'a>(
     content: Element<'a, Message>,
     modal: &'a Modal,
     state: &'a MainState,
     settings: &'a AppSettings,
+    language: Language,
 ) -> Element<'a, Message> {
     let preview_limit = settings.modal_preview_limit;
+    let focus = state.modal_focus;
     let modal_content: Element<Message> = match modal {
         Modal::ConfirmBulkUpdateMajors { versions } => {
-            confirm_bulk_update_view(versions, preview_limit)
+            confirm_bulk_update_view(versions, preview_limit, focus)
         }
         Modal::ConfirmBulkUninstallEOL { versions } => {
-            confirm_bulk_uninstall_eol_view(versions, preview_limit)
+            confirm_bulk_uninstall_eol_view(versions, preview_limit, focus)
         }
         Modal::ConfirmBulkUninstallMajor { major, versions } => {
-            confirm_bulk_uninstall_major_view(*major, versions, preview_limit)
+            confirm_bulk_uninstall_major_view(*major, versions, preview_limit, focus)
         }
         Modal::ConfirmBulkUninstallMajorExceptLatest {
             major,
@@ -53,12 +61,71 @@ pub(super) fn modal_overlay<'a>(
             versions,
             keeping,
             preview_limit,
+            focus,
         ),
-        Modal::ConfirmUninstallDefault { version } => confirm_uninstall_default_view(version),
-        Modal::KeyboardShortcuts => keyboard_shortcuts_view(),
-        Modal::VersionDetail { version } => {
-            version_detail_view(version, state.available_versions.metadata.as_ref(), state)
+        Modal::ConfirmApplyRetentionPolicy { keep, versions } => {
+            confirm_apply_retention_policy_view(*keep, versions, preview_limit, focus)
+        }
+        Modal::ConfirmBulkUninstallSelected { versions, default } => {
+            confirm_bulk_uninstall_selected_view(versions, default.as_deref(), preview_limit, focus)
+        }
+        Modal::ConfirmUninstall { version } => confirm_uninstall_view(version, focus),
+        Modal::ConfirmUninstallDefault { version } => {
+            confirm_uninstall_default_view(version, focus)
+        }
+        Modal::ConfirmReinstall {
+            version,
+            is_default,
+        } => confirm_reinstall_view(version, *is_default, focus),
+        Modal::ConfirmEolDefault { version } => confirm_eol_default_view(version, focus),
+        Modal::ConfirmClearVersionCache => confirm_clear_version_cache_view(focus),
+        Modal::ConfirmTraySetDefault {
+            env_id,
+            env_name,
+            version,
+        } => confirm_tray_set_default_view(env_id, env_name, version, focus),
+        Modal::CreateAlias { version, name } => create_alias_view(version, name, focus),
+        Modal::ConfirmRemoveAlias { name, version } => {
+            confirm_remove_alias_view(name, version, focus)
         }
+        Modal::KeyboardShortcuts => keyboard_shortcuts_view(),
+        Modal::LastOperationError => last_operation_error_view(state, settings, focus),
+        Modal::ConfirmSettingsImport {
+            changes, sections, ..
+        } => confirm_settings_import_view(changes, sections, focus),
+        Modal::VersionDetail { version, note } => version_detail_view(
+            version,
+            note,
+            state
+                .available_versions
+                .metadata
+                .as_ref()
+                .filter(|_| settings.fetch_version_metadata),
+            state,
+            settings,
+            language,
+            focus,
+        ),
+        Modal::ConfirmCloneEnvironment {
+            source,
+            target,
+            versions,
+        } => confirm_clone_environment_view(source, target, versions, preview_limit, focus),
+        Modal::Migration {
+            source,
+            plan,
+            set_default,
+            started,
+            progress,
+        } => migration_view(
+            *source,
+            plan,
+            *set_default,
+            started.then_some(*progress),
+            state,
+            preview_limit,
+            focus,
+        ),
     };
 
     let backdrop = mouse_area(
@@ -94,9 +161,35 @@ pub(super) fn modal_overlay<'a>(
     iced::widget::stack![content, backdrop, modal_layer].into()
 }
 
+/// Cancel and primary buttons of a modal, with a focus ring around the one
+/// Enter would activate.
+fn modal_actions<'a>(
+    cancel: Button<'a, Message>,
+    primary: Button<'a, Message>,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    row![
+        focus_ring(cancel, focus == ModalFocus::Cancel),
+        Space::new().width(Length::Fill),
+        focus_ring(primary, focus == ModalFocus::Primary),
+    ]
+    .spacing(16)
+    .into()
+}
+
+fn focus_ring(button: Button<'_, Message>, focused: bool) -> Element<'_, Message> {
+    let ring = container(button).padding(2);
+    if focused {
+        ring.style(styles::focus_ring).into()
+    } else {
+        ring.into()
+    }
+}
+
 fn confirm_bulk_update_view(
     versions: &[(String, String)],
     preview_limit: usize,
+    focus: ModalFocus,
 ) -> Element<'_, Message> {
     let labels: Vec<String> = versions
         .iter()
@@ -114,27 +207,155 @@ fn confirm_bulk_update_view(
         Space::new().height(8),
         version_preview_list(labels, preview_limit),
         Space::new().height(24),
-        row![
+        modal_actions(
             button(text("Cancel").size(13))
                 .on_press(Message::CancelBulkOperation)
                 .style(styles::secondary_button)
                 .padding([10, 20]),
-            Space::new().width(Length::Fill),
             button(text("Update All").size(13))
                 .on_press(Message::ConfirmBulkUpdateMajors)
                 .style(styles::primary_button)
                 .padding([10, 20]),
-        ]
-        .spacing(16),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_clone_environment_view<'a>(
+    source: &'a str,
+    target: &'a str,
+    versions: &'a [String],
+    preview_limit: usize,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
+
+    column![
+        text(format!("Copy Versions to {target}?")).size(20),
+        Space::new().height(12),
+        text(format!(
+            "{} version(s) installed in {source} are missing from {target}:",
+            versions.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        version_preview_list(labels, preview_limit),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Install All").size(13))
+                .on_press(Message::ConfirmCloneEnvironment)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+            focus,
+        ),
     ]
     .spacing(4)
     .width(Length::Fill)
     .into()
 }
 
+fn migration_view<'a>(
+    source: BackendKind,
+    plan: &'a MigrationPlan,
+    set_default: bool,
+    progress: Option<MigrationProgress>,
+    state: &'a MainState,
+    preview_limit: usize,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let target = state.backend_name;
+    let mut content = column![
+        text(format!("Migrate from {source} to {target}")).size(20),
+        Space::new().height(12),
+    ]
+    .spacing(4);
+
+    if let Some(progress) = progress {
+        let done = progress.completed();
+        let total = plan.install.len();
+        let status = match (done >= total, progress.failed) {
+            (true, 0) => "Migration complete".to_string(),
+            (true, failed) => format!("Migration finished: {failed} of {total} failed"),
+            (false, 0) => format!("Installing with {target}: {done} of {total} done"),
+            (false, failed) => {
+                format!("Installing with {target}: {done} of {total} done, {failed} failed")
+            }
+        };
+        return content
+            .push(text(status).size(14))
+            .push(
+                text("Installs continue in the background if you close this window.")
+                    .size(12)
+                    .color(crate::theme::tokens::TEXT_MUTED),
+            )
+            .push(Space::new().height(24))
+            .push(row![
+                Space::new().width(Length::Fill),
+                focus_ring(
+                    button(text("Close").size(13))
+                        .on_press(Message::CloseModal)
+                        .style(styles::primary_button)
+                        .padding([10, 20]),
+                    focus == ModalFocus::Primary,
+                ),
+            ])
+            .width(Length::Fill)
+            .into();
+    }
+
+    let labels: Vec<String> = plan.install.iter().map(|v| format!("Node {v}")).collect();
+    content = content
+        .push(
+            text(format!(
+                "{} version(s) installed with {source} will be installed with {target}:",
+                plan.install.len()
+            ))
+            .size(14),
+        )
+        .push(Space::new().height(8))
+        .push(version_preview_list(labels, preview_limit));
+
+    if let Some(default) = &plan.default {
+        content = content.push(Space::new().height(12)).push(
+            row![
+                toggler(set_default)
+                    .on_toggle(Message::MigrationSetDefaultToggled)
+                    .size(18),
+                text(format!("Make {default} the default, as in {source}")).size(12),
+            ]
+            .spacing(8)
+            .align_y(iced::Alignment::Center),
+        );
+    }
+
+    content
+        .push(Space::new().height(24))
+        .push(modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Migrate").size(13))
+                .on_press(Message::ConfirmMigration)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+            focus,
+        ))
+        .width(Length::Fill)
+        .into()
+}
+
 fn confirm_bulk_uninstall_eol_view(
     versions: &[String],
     preview_limit: usize,
+    focus: ModalFocus,
 ) -> Element<'_, Message> {
     let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
 
@@ -153,28 +374,76 @@ fn confirm_bulk_uninstall_eol_view(
             .size(12)
             .color(crate::theme::tokens::EOL_ORANGE),
         Space::new().height(24),
-        row![
+        modal_actions(
             button(text("Cancel").size(13))
                 .on_press(Message::CancelBulkOperation)
                 .style(styles::secondary_button)
                 .padding([10, 20]),
-            Space::new().width(Length::Fill),
             button(text("Remove All").size(13))
                 .on_press(Message::ConfirmBulkUninstallEOL)
                 .style(styles::danger_button)
                 .padding([10, 20]),
-        ]
-        .spacing(16),
+            focus,
+        ),
     ]
     .spacing(4)
     .width(Length::Fill)
     .into()
 }
 
+fn confirm_bulk_uninstall_selected_view<'a>(
+    versions: &'a [String],
+    default: Option<&'a str>,
+    preview_limit: usize,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
+
+    let mut content = column![
+        text("Remove Selected Versions?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "This will uninstall {} version(s):",
+            versions.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        version_preview_list(labels, preview_limit),
+    ]
+    .spacing(4)
+    .width(Length::Fill);
+
+    if let Some(default) = default {
+        content = content.push(Space::new().height(8)).push(
+            text(format!(
+                "Node {default} is your default version and will be confirmed separately."
+            ))
+            .size(12)
+            .color(crate::theme::tokens::EOL_ORANGE),
+        );
+    }
+
+    content
+        .push(Space::new().height(24))
+        .push(modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Remove Selected").size(13))
+                .on_press(Message::ConfirmBulkUninstallSelected)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ))
+        .into()
+}
+
 fn confirm_bulk_uninstall_major_view(
     major: u32,
     versions: &[String],
     preview_limit: usize,
+    focus: ModalFocus,
 ) -> Element<'_, Message> {
     let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
 
@@ -189,18 +458,17 @@ fn confirm_bulk_uninstall_major_view(
         Space::new().height(8),
         version_preview_list(labels, preview_limit),
         Space::new().height(24),
-        row![
+        modal_actions(
             button(text("Cancel").size(13))
                 .on_press(Message::CancelBulkOperation)
                 .style(styles::secondary_button)
                 .padding([10, 20]),
-            Space::new().width(Length::Fill),
             button(text("Remove All").size(13))
                 .on_press(Message::ConfirmBulkUninstallMajor { major })
                 .style(styles::danger_button)
                 .padding([10, 20]),
-        ]
-        .spacing(16),
+            focus,
+        ),
     ]
     .spacing(4)
     .width(Length::Fill)
@@ -212,6 +480,7 @@ fn confirm_bulk_uninstall_major_except_latest_view<'a>(
     versions: &'a [String],
     keeping: &'a str,
     preview_limit: usize,
+    focus: ModalFocus,
 ) -> Element<'a, Message> {
     let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
 
@@ -230,25 +499,90 @@ fn confirm_bulk_uninstall_major_except_latest_view<'a>(
             .size(12)
             .color(iced::Color::from_rgb8(52, 199, 89)),
         Space::new().height(24),
-        row![
+        modal_actions(
             button(text("Cancel").size(13))
                 .on_press(Message::CancelBulkOperation)
                 .style(styles::secondary_button)
                 .padding([10, 20]),
-            Space::new().width(Length::Fill),
             button(text("Remove Older").size(13))
                 .on_press(Message::ConfirmBulkUninstallMajorExceptLatest { major })
                 .style(styles::danger_button)
                 .padding([10, 20]),
-        ]
-        .spacing(16),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_apply_retention_policy_view(
+    keep: usize,
+    versions: &[String],
+    preview_limit: usize,
+    focus: ModalFocus,
+) -> Element<'_, Message> {
+    let labels: Vec<String> = versions.iter().map(|v| format!("Node {v}")).collect();
+
+    column![
+        text("Apply Retention Policy?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "This will uninstall {} version(s) beyond the newest {keep} per major:",
+            versions.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        version_preview_list(labels, preview_limit),
+        Space::new().height(8),
+        text("The default version is always kept.")
+            .size(12)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Prune").size(13))
+                .on_press(Message::ConfirmApplyRetentionPolicy)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
     ]
     .spacing(4)
     .width(Length::Fill)
     .into()
 }
 
-fn confirm_uninstall_default_view(version: &str) -> Element<'_, Message> {
+fn confirm_uninstall_view(version: &str, focus: ModalFocus) -> Element<'_, Message> {
+    column![
+        text("Uninstall Version?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "Node {version} will be removed from this environment."
+        ))
+        .size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Uninstall").size(13))
+                .on_press(Message::ConfirmUninstall(version.to_string()))
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_uninstall_default_view(version: &str, focus: ModalFocus) -> Element<'_, Message> {
     column![
         text("Uninstall Default Version?").size(20),
         Space::new().height(12),
@@ -257,18 +591,333 @@ fn confirm_uninstall_default_view(version: &str) -> Element<'_, Message> {
         ))
         .size(14),
         Space::new().height(24),
-        row![
+        modal_actions(
             button(text("Cancel").size(13))
                 .on_press(Message::CloseModal)
                 .style(styles::secondary_button)
                 .padding([10, 20]),
-            Space::new().width(Length::Fill),
             button(text("Uninstall").size(13))
                 .on_press(Message::ConfirmUninstallDefault(version.to_string()))
                 .style(styles::danger_button)
                 .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_reinstall_view(
+    version: &str,
+    is_default: bool,
+    focus: ModalFocus,
+) -> Element<'_, Message> {
+    let body = if is_default {
+        format!(
+            "Node {version} is your current default version. It will be uninstalled and installed again, leaving no default set in between."
+        )
+    } else {
+        format!("Node {version} will be uninstalled and installed again.")
+    };
+    column![
+        text("Reinstall Version?").size(20),
+        Space::new().height(12),
+        text(body).size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Reinstall").size(13))
+                .on_press(Message::ConfirmReinstall(version.to_string()))
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_remove_alias_view<'a>(
+    name: &'a str,
+    version: &'a str,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let body = if name == "default" {
+        format!(
+            "The default alias points at Node {version}. Removing it leaves new shells with no default version."
+        )
+    } else {
+        format!("The alias \"{name}\" will no longer point at Node {version}.")
+    };
+    column![
+        text(format!("Remove Alias \"{name}\"?")).size(20),
+        Space::new().height(12),
+        text(body).size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Remove").size(13))
+                .on_press(Message::ConfirmRemoveAlias(name.to_string()))
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_eol_default_view(version: &str, focus: ModalFocus) -> Element<'_, Message> {
+    let line = version.parse::<versi_backend::NodeVersion>().map_or_else(
+        |_| version.to_string(),
+        |parsed| format!("v{}", parsed.major),
+    );
+    column![
+        text("Set End-of-Life Default?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "{line} is end-of-life and no longer gets security fixes. Set Node {version} as default anyway?"
+        ))
+        .size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Set as Default").size(13))
+                .on_press(Message::ConfirmEolDefault(version.to_string()))
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn create_alias_view<'a>(
+    version: &'a str,
+    name: &'a str,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let confirm = button(text("Create Alias").size(13))
+        .style(styles::primary_button)
+        .padding([10, 20]);
+    let confirm = if name.trim().is_empty() {
+        confirm
+    } else {
+        confirm.on_press(Message::ConfirmCreateAlias)
+    };
+
+    column![
+        text("Create Alias").size(20),
+        Space::new().height(12),
+        text(format!(
+            "Give Node {version} a name you can use instead of its version."
+        ))
+        .size(14),
+        Space::new().height(12),
+        text_input("Alias name", name)
+            .on_input(Message::AliasNameChanged)
+            .on_submit(Message::ConfirmCreateAlias)
+            .padding(8)
+            .size(13),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            confirm,
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_clear_version_cache_view(focus: ModalFocus) -> Element<'static, Message> {
+    column![
+        text("Clear Version Cache?").size(20),
+        Space::new().height(12),
+        text("Cached release lists, schedule and metadata will be deleted and downloaded again.")
+            .size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Clear Cache").size(13))
+                .on_press(Message::ConfirmClearVersionCache)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_settings_import_view<'a>(
+    changes: &'a [SettingChange],
+    sections: &BTreeSet<SettingsSection>,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let muted = crate::theme::tokens::TEXT_MUTED;
+    let mut by_section: BTreeMap<SettingsSection, Vec<&SettingChange>> = BTreeMap::new();
+    for change in changes {
+        by_section.entry(change.section).or_default().push(change);
+    }
+
+    let mut list = column![].spacing(10);
+    for (section, section_changes) in by_section {
+        let selected = sections.contains(&section);
+        let mut group = column![
+            row![
+                checkbox(selected)
+                    .on_toggle(move |value| Message::SettingsImportSectionToggled(section, value))
+                    .size(14),
+                text(section.label()).size(13),
+            ]
+            .spacing(8)
+            .align_y(iced::Alignment::Center),
         ]
-        .spacing(16),
+        .spacing(4);
+        for change in section_changes {
+            group = group.push(
+                column![
+                    text(&change.field).size(12),
+                    text(format!("{} → {}", change.current, change.imported))
+                        .size(11)
+                        .color(muted),
+                ]
+                .spacing(2)
+                .padding(iced::Padding::new(0.0).left(22.0)),
+            );
+        }
+        list = list.push(group);
+    }
+
+    let selected_count = changes
+        .iter()
+        .filter(|change| sections.contains(&change.section))
+        .count();
+    let confirm = button(text("Import").size(13))
+        .on_press_maybe((!sections.is_empty()).then_some(Message::ConfirmSettingsImport))
+        .style(styles::primary_button)
+        .padding([10, 20]);
+
+    column![
+        text("Import Settings").size(20),
+        Space::new().height(12),
+        text(format!(
+            "{selected_count} of {} changed settings selected:",
+            changes.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        container(scrollable(list)).max_height(280),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            confirm,
+            focus,
+        ),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn last_operation_error_view<'a>(
+    state: &'a MainState,
+    settings: &AppSettings,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    let mut content = column![
+        text("Last Operation Failed").size(20),
+        Space::new().height(12)
+    ];
+    if let Some((operation, error)) = &state.last_error {
+        content = content
+            .push(
+                text(format!(
+                    "{} Node {}",
+                    operation.label(),
+                    settings.display_version(operation.version())
+                ))
+                .size(14),
+            )
+            .push(
+                text(error.to_string())
+                    .size(13)
+                    .color(crate::theme::tokens::TEXT_MUTED),
+            );
+    }
+
+    content
+        .push(Space::new().height(24))
+        .push(modal_actions(
+            button(text("Close").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Acknowledge").size(13))
+                .on_press(Message::AcknowledgeLastError)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+            focus,
+        ))
+        .spacing(4)
+        .width(Length::Fill)
+        .into()
+}
+
+fn confirm_tray_set_default_view<'a>(
+    env_id: &versi_platform::EnvironmentId,
+    env_name: &'a str,
+    version: &'a str,
+    focus: ModalFocus,
+) -> Element<'a, Message> {
+    column![
+        text(format!("Switch to {env_name}?")).size(20),
+        Space::new().height(12),
+        text(format!(
+            "Setting {version} as default from the tray will switch the active environment to {env_name}."
+        ))
+        .size(14),
+        Space::new().height(24),
+        modal_actions(
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Switch and Set Default").size(13))
+                .on_press(Message::ConfirmTraySetDefault {
+                    env_id: env_id.clone(),
+                    version: version.to_string(),
+                })
+                .style(styles::primary_button)
+                .padding([10, 20]),
+            focus,
+        ),
     ]
     .spacing(4)
     .width(Length::Fill)
@@ -277,13 +926,30 @@ fn confirm_uninstall_default_view(version: &str) -> Element<'_, Message> {
 
 fn version_detail_view<'a>(
     version: &'a str,
+    note: &'a str,
     metadata: Option<&'a HashMap<String, VersionMeta>>,
     state: &'a MainState,
+    settings: &AppSettings,
+    language: Language,
+    focus: ModalFocus,
 ) -> Element<'a, Message> {
     let muted = crate::theme::tokens::TEXT_MUTED;
     let meta = metadata.and_then(|m| m.get(version));
 
-    let mut content = column![text(format!("Node {version}")).size(20),].spacing(4);
+    let mut content =
+        column![text(format!("Node {}", settings.display_version(version))).size(20),].spacing(4);
+
+    if let Some(installed) = installed_version(version, state) {
+        let installed_text = match installed.install_date {
+            Some(date) => format!(
+                "{} ({})",
+                crate::widgets::helpers::format_installed_ago(date, state.now, language),
+                date.format("%Y-%m-%d")
+            ),
+            None => t(language, "installed.unknown").to_string(),
+        };
+        content = content.push(text(installed_text).size(13).color(muted));
+    }
 
     content = content.push(Space::new().height(12));
 
@@ -293,6 +959,14 @@ fn version_detail_view<'a>(
                 .size(13)
                 .color(muted),
         );
+        if let Some(countdown) = eol_countdown(version, state) {
+            let color = if countdown.is_eol() || countdown.is_imminent() {
+                crate::theme::tokens::EOL_ORANGE
+            } else {
+                muted
+            };
+            content = content.push(text(countdown.label).size(13).color(color));
+        }
         content = content.push(Space::new().height(8));
 
         let mut badge_row = row![].spacing(8).align_y(iced::Alignment::Center);
@@ -313,18 +987,36 @@ fn version_detail_view<'a>(
             );
         }
 
+        if let Some(phase) = release_phase(version, state)
+            && phase != ReleasePhase::EndOfLife
+        {
+            let style = if phase == ReleasePhase::Maintenance {
+                styles::badge_eol
+            } else {
+                styles::badge_default
+            };
+            badge_row = badge_row.push(
+                container(text(phase.label()).size(11))
+                    .padding([2, 6])
+                    .style(style),
+            );
+        }
+
         content = content.push(badge_row);
         content = content.push(Space::new().height(12));
 
         let mut details = column![].spacing(6);
-        if let Some(npm) = &meta.npm {
-            details = details.push(meta_row("npm", npm, muted));
-        }
-        if let Some(v8) = &meta.v8 {
-            details = details.push(meta_row("V8", v8, muted));
-        }
-        if let Some(openssl) = &meta.openssl {
-            details = details.push(meta_row("OpenSSL", openssl, muted));
+        let fields = [
+            (MetadataField::Npm, "npm", &meta.npm),
+            (MetadataField::V8, "V8", &meta.v8),
+            (MetadataField::OpenSsl, "OpenSSL", &meta.openssl),
+        ];
+        for (field, label, value) in fields {
+            if let Some(value) = value
+                && settings.shows_metadata_field(field)
+            {
+                details = details.push(meta_row(label, value, muted));
+            }
         }
         content = content.push(details);
 
@@ -336,29 +1028,122 @@ fn version_detail_view<'a>(
                     .color(crate::theme::tokens::EOL_ORANGE),
             );
         }
-    } else {
+    } else if settings.fetch_version_metadata {
         content = content.push(text("No metadata available").size(13).color(muted));
     }
 
-    content = content.push(Space::new().height(24));
-    content = content.push(
-        row![
-            button(text("Close").size(13))
-                .on_press(Message::CloseModal)
-                .style(styles::secondary_button)
-                .padding([10, 20]),
-            Space::new().width(Length::Fill),
-            button(text("View Full Changelog").size(13))
-                .on_press(Message::OpenChangelog(version.to_string()))
-                .style(styles::primary_button)
-                .padding([10, 20]),
+    let aliases: Vec<&str> = version
+        .parse::<versi_backend::NodeVersion>()
+        .map(|parsed| state.active_environment().aliases_for(&parsed))
+        .unwrap_or_default();
+    if !aliases.is_empty() {
+        content = content.push(Space::new().height(12));
+        let mut alias_row = row![
+            text("Aliases")
+                .size(12)
+                .width(Length::Fixed(crate::theme::tokens::COL_META_LABEL))
+                .color(muted),
         ]
-        .spacing(16),
-    );
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+        for alias in aliases {
+            alias_row = alias_row.push(
+                row![
+                    text(alias).size(12),
+                    button(text("\u{00d7}").size(11))
+                        .on_press(Message::RemoveAlias(alias.to_string()))
+                        .style(styles::ghost_button)
+                        .padding([0, 4]),
+                ]
+                .spacing(2)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        content = content.push(alias_row);
+    }
+
+    if installed_version(version, state).is_some()
+        && state.backend.capabilities().shell_options.corepack
+    {
+        content = content.push(Space::new().height(12));
+        content = content.push(
+            row![
+                text("Corepack")
+                    .size(12)
+                    .width(Length::Fixed(crate::theme::tokens::COL_META_LABEL))
+                    .color(muted),
+                button(text("Enable pnpm/yarn").size(11))
+                    .on_press(Message::SetCorepack {
+                        version: version.to_string(),
+                        enabled: true,
+                    })
+                    .style(styles::secondary_button)
+                    .padding([4, 10]),
+                button(text("Disable").size(11))
+                    .on_press(Message::SetCorepack {
+                        version: version.to_string(),
+                        enabled: false,
+                    })
+                    .style(styles::ghost_button)
+                    .padding([4, 10]),
+            ]
+            .spacing(8)
+            .align_y(iced::Alignment::Center),
+        );
+    }
+
+    content = content.push(Space::new().height(12));
+    content = content.push(note_row(version, note, settings, muted));
+
+    content = content.push(Space::new().height(24));
+    content = content.push(modal_actions(
+        button(text("Close").size(13))
+            .on_press(Message::CloseModal)
+            .style(styles::secondary_button)
+            .padding([10, 20]),
+        button(text("View Full Changelog").size(13))
+            .on_press(Message::OpenChangelog(version.to_string()))
+            .style(styles::primary_button)
+            .padding([10, 20]),
+        focus,
+    ));
 
     content.width(Length::Fill).into()
 }
 
+fn note_row<'a>(
+    version: &str,
+    note: &'a str,
+    settings: &AppSettings,
+    muted: iced::Color,
+) -> Element<'a, Message> {
+    let saved = settings.version_note(version).unwrap_or_default();
+    let save = button(text("Save").size(11))
+        .style(styles::secondary_button)
+        .padding([4, 10]);
+    let save = if note.trim() == saved {
+        save
+    } else {
+        save.on_press(Message::SaveVersionNote)
+    };
+
+    row![
+        text("Note")
+            .size(12)
+            .width(Length::Fixed(crate::theme::tokens::COL_META_LABEL))
+            .color(muted),
+        text_input("Add a note, e.g. used for project X", note)
+            .on_input(Message::VersionNoteChanged)
+            .on_submit(Message::SaveVersionNote)
+            .padding(6)
+            .size(12),
+        save,
+    ]
+    .spacing(8)
+    .align_y(iced::Alignment::Center)
+    .into()
+}
+
 fn meta_row<'a>(label: &'a str, value: &'a str, muted: iced::Color) -> Element<'a, Message> {
     row![
         text(label)
@@ -372,6 +1157,36 @@ fn meta_row<'a>(label: &'a str, value: &'a str, muted: iced::Color) -> Element<'
     .into()
 }
 
+fn release_phase(version: &str, state: &MainState) -> Option<ReleasePhase> {
+    let major = version.parse::<versi_backend::NodeVersion>().ok()?.major;
+    state
+        .available_versions
+        .schedule
+        .as_ref()?
+        .release_phase_at(major, state.now)
+}
+
+fn eol_countdown(version: &str, state: &MainState) -> Option<EolCountdown> {
+    let major = version.parse::<versi_backend::NodeVersion>().ok()?.major;
+    EolCountdown::for_major(
+        state.available_versions.schedule.as_ref()?,
+        major,
+        state.now,
+    )
+}
+
+fn installed_version<'a>(
+    version: &str,
+    state: &'a MainState,
+) -> Option<&'a versi_backend::InstalledVersion> {
+    let parsed = version.parse::<versi_backend::NodeVersion>().ok()?;
+    state
+        .active_environment()
+        .installed_versions
+        .iter()
+        .find(|v| v.version == parsed)
+}
+
 fn lookup_lts<'a>(version: &str, state: &'a MainState) -> Option<&'a str> {
     let parsed = version.parse::<versi_backend::NodeVersion>().ok()?;
     state
@@ -402,6 +1217,8 @@ fn keyboard_shortcuts_view() -> Element<'static, Message> {
         (format!("{mod_key}Shift+Tab"), "Previous environment"),
         ("\u{2191}/\u{2193}".to_string(), "Navigate versions"),
         ("Enter".to_string(), "Install / set default"),
+        ("1\u{2013}5".to_string(), "Toggle search filters"),
+        ("Tab".to_string(), "Move focus in dialogs"),
         ("Esc".to_string(), "Close modal"),
         ("?".to_string(), "This help"),
     ];
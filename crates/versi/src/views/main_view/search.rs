@@ -6,6 +6,7 @@ use iced::{Element, Length};
 use crate::icon;
 use crate::message::Message;
 use crate::state::{MainState, SearchFilter};
+use crate::strings::{Language, filter_chip_key, t};
 use crate::theme::styles;
 use crate::widgets::helpers::styled_tooltip;
 
@@ -22,35 +23,24 @@ fn should_show_clear_button(query: &str) -> bool {
     !query.is_empty()
 }
 
-const FILTER_CHIPS: &[(&str, SearchFilter)] = &[
-    ("LTS", SearchFilter::Lts),
-    ("Installed", SearchFilter::Installed),
-    ("Not installed", SearchFilter::NotInstalled),
-    ("EOL", SearchFilter::Eol),
-    ("Active", SearchFilter::Active),
-];
-
 fn filter_chip_states(active_filters: &HashSet<SearchFilter>) -> Vec<FilterChipState> {
-    FILTER_CHIPS
-        .iter()
-        .map(|&(label, filter)| FilterChipState {
-            label,
+    SearchFilter::ALL
+        .into_iter()
+        .map(|filter| FilterChipState {
+            label: filter.chip_label(),
             filter,
             active: active_filters.contains(&filter),
         })
         .collect()
 }
 
-pub(super) fn search_bar_view(state: &MainState) -> Element<'_, Message> {
-    let input = text_input(
-        "Search versions (e.g., '22', 'lts', 'lts/iron', 'latest')...",
-        &state.search_query,
-    )
-    .id(SEARCH_INPUT_ID)
-    .on_input(Message::SearchChanged)
-    .padding(14)
-    .size(14)
-    .style(styles::search_input);
+pub(super) fn search_bar_view(state: &MainState, language: Language) -> Element<'_, Message> {
+    let input = text_input(t(language, "search.placeholder"), &state.search_query)
+        .id(SEARCH_INPUT_ID)
+        .on_input(Message::SearchChanged)
+        .padding(14)
+        .size(14)
+        .style(styles::search_input);
 
     let clear_btn: Element<Message> = if should_show_clear_button(&state.search_query) {
         styled_tooltip(
@@ -58,7 +48,7 @@ pub(super) fn search_bar_view(state: &MainState) -> Element<'_, Message> {
                 .on_press(Message::SearchChanged(String::new()))
                 .style(styles::ghost_button)
                 .padding([6, 10]),
-            "Clear search",
+            t(language, "search.clear"),
             tooltip::Position::Left,
         )
     } else {
@@ -77,29 +67,56 @@ pub(super) fn search_bar_view(state: &MainState) -> Element<'_, Message> {
     .into()
 }
 
-fn chip_button(label: &str, filter: SearchFilter, active: bool) -> Element<'_, Message> {
+fn chip_button(
+    label: &str,
+    filter: SearchFilter,
+    active: bool,
+    language: Language,
+) -> Element<'_, Message> {
     let style = if active {
         styles::filter_chip_active as fn(&iced::Theme, button::Status) -> button::Style
     } else {
         styles::filter_chip
     };
 
-    button(text(label).size(12))
-        .on_press(Message::SearchFilterToggled(filter))
-        .style(style)
-        .padding([4, 12])
-        .into()
+    styled_tooltip(
+        button(text(label).size(12))
+            .on_press(Message::SearchFilterToggled(filter))
+            .style(style)
+            .padding([4, 12]),
+        t(language, filter_chip_key(filter)),
+        tooltip::Position::Bottom,
+    )
 }
 
-pub(super) fn filter_chips_view(active_filters: &HashSet<SearchFilter>) -> Element<'_, Message> {
+pub(super) fn filter_chips_view(
+    active_filters: &HashSet<SearchFilter>,
+    language: Language,
+) -> Element<'_, Message> {
     let chips = filter_chip_states(active_filters);
     let mut r = row![].spacing(8);
     for chip in &chips {
-        r = r.push(chip_button(chip.label, chip.filter, chip.active));
+        r = r.push(chip_button(chip.label, chip.filter, chip.active, language));
+    }
+    if !active_filters.is_empty() {
+        r = r.push(
+            button(text("Clear filters").size(12))
+                .on_press(Message::ClearSearchFilters)
+                .style(styles::ghost_button)
+                .padding([4, 12]),
+        );
     }
     r.into()
 }
 
+pub(super) fn copy_results_button<'a>() -> Element<'a, Message> {
+    button(text("Copy results").size(12))
+        .on_press(Message::CopySearchResults)
+        .style(styles::ghost_button)
+        .padding([4, 8])
+        .into()
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -1,9 +1,12 @@
-use iced::Element;
-use iced::widget::{button, row, text};
+use iced::widget::{Space, button, container, row, text, tooltip};
+use iced::{Alignment, Element};
 
+use crate::icon;
 use crate::message::Message;
 use crate::state::MainState;
+use crate::strings::{Language, t};
 use crate::theme::styles;
+use crate::widgets::helpers::styled_tooltip;
 
 pub fn environment_tabs_view(state: &MainState) -> Option<Element<'_, Message>> {
     if state.environments.len() <= 1 {
@@ -35,7 +38,14 @@ pub fn environment_tabs_view(state: &MainState) -> Option<Element<'_, Message>>
                 styles::inactive_tab_button
             };
 
-            button(text(&env.name).size(13))
+            let mut label = row![accent_dot(env.accent()), text(&env.name).size(13)]
+                .spacing(6)
+                .align_y(Alignment::Center);
+            if env.backend_update.is_some() {
+                label = label.push(icon::arrow_up_right(11.0));
+            }
+
+            button(label)
                 .on_press(Message::EnvironmentSelected(idx))
                 .style(style)
                 .padding([8, 16])
@@ -43,5 +53,35 @@ pub fn environment_tabs_view(state: &MainState) -> Option<Element<'_, Message>>
         })
         .collect();
 
-    Some(row(tabs).spacing(4).into())
+    Some(row(tabs).spacing(4).align_y(Alignment::Center).into())
+}
+
+/// Rescan button for WSL distros. It sits beside the tabs rather than in
+/// them so it stays reachable while only one environment is known.
+pub fn rescan_wsl_button<'a>(language: Language) -> Option<Element<'a, Message>> {
+    if !cfg!(windows) {
+        return None;
+    }
+
+    Some(styled_tooltip(
+        button(icon::refresh(14.0))
+            .on_press(Message::RefreshWslEnvironments)
+            .style(styles::ghost_button)
+            .padding([4, 6]),
+        t(language, "tabs.rescan_wsl"),
+        tooltip::Position::Bottom,
+    ))
+}
+
+fn accent_dot(accent: iced::Color) -> Element<'static, Message> {
+    container(Space::new().width(8).height(8))
+        .style(move |_theme| container::Style {
+            background: Some(iced::Background::Color(accent)),
+            border: iced::Border {
+                radius: 4.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .into()
 }
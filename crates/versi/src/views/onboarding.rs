@@ -1,9 +1,9 @@
-use iced::widget::{Space, button, column, container, row, text};
+use iced::widget::{Space, button, column, container, row, text, toggler};
 use iced::{Alignment, Element, Length};
 
 use crate::backend_kind::BackendKind;
 use crate::message::Message;
-use crate::state::{OnboardingState, OnboardingStep};
+use crate::state::{OnboardingState, OnboardingStep, ShellReloadHint};
 use crate::theme::styles;
 
 pub fn view(state: &OnboardingState, backend_name: BackendKind) -> Element<'_, Message> {
@@ -257,9 +257,75 @@ fn configure_shell_step(
         content = content.push(Space::new().height(8));
     }
 
+    if let Some(hint) = &state.shell_reload_hint {
+        content = content.push(Space::new().height(8));
+        content = content.push(shell_reload_hint_view(hint));
+    }
+
+    if let Some(suggestion) = &state.suggested_version {
+        content = content.push(Space::new().height(16));
+        content = content.push(
+            row![
+                toggler(state.install_suggested_version)
+                    .on_toggle(Message::OnboardingInstallSuggestedToggled)
+                    .size(18),
+                text(format!(
+                    "Install Node.js {} after setup",
+                    suggestion.label()
+                ))
+                .size(14),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+        content = content.push(
+            text(format!("Requested by {}", suggestion.path.display()))
+                .size(12)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    } else {
+        content = content.push(Space::new().height(16));
+        content = content.push(
+            row![
+                toggler(state.install_latest_lts)
+                    .on_toggle(Message::OnboardingInstallLatestLtsToggled)
+                    .size(18),
+                text("Install the latest LTS release after setup").size(14),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+        content = content.push(
+            text("It also becomes your default version")
+                .size(12)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    }
+
     content.into()
 }
 
+fn shell_reload_hint_view(hint: &ShellReloadHint) -> Element<'_, Message> {
+    let mut hint_row = row![text(hint.message()).size(14).width(Length::Fill)]
+        .spacing(12)
+        .align_y(Alignment::Center);
+
+    if let Some(command) = &hint.command {
+        hint_row = hint_row.push(
+            button(text("Copy").size(12))
+                .on_press(Message::CopyToClipboard(command.clone()))
+                .style(styles::secondary_button)
+                .padding([6, 12]),
+        );
+    }
+
+    container(hint_row)
+        .padding(12)
+        .style(styles::card_container)
+        .width(Length::Fill)
+        .into()
+}
+
 fn navigation_buttons(state: &OnboardingState) -> Element<'_, Message> {
     let back_button = if state.step == OnboardingStep::Welcome {
         button(text("Back"))
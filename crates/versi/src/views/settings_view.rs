@@ -1,39 +1,66 @@
-use iced::widget::{Space, button, column, container, row, scrollable, text, toggler, tooltip};
+use iced::widget::{
+    Space, button, column, container, row, scrollable, text, text_input, toggler, tooltip,
+};
 use iced::{Alignment, Element, Length};
 
+use versi_core::UpdateChannel;
+
+use crate::audit_log::AuditRecord;
 use crate::backend_kind::BackendKind;
 use crate::icon;
 use crate::message::Message;
-use crate::settings::{AppSettings, AppUpdateBehavior, ThemeSetting, TrayBehavior};
-use crate::state::{MainState, SettingsModalState, ShellVerificationStatus};
+use crate::settings::{
+    AppSettings, AppUpdateBehavior, ConfirmUninstall, DefaultOnInstall, MetadataField,
+    NumericSetting, SettingsSection, ThemeSetting, TrayBehavior,
+};
+use crate::state::{
+    MainState, SettingsModalState, ShellRepairOutcome, ShellTestOutcome, ShellVerificationStatus,
+};
+use crate::strings::{Language, t};
 use crate::theme::styles;
-use crate::widgets::helpers::nav_icons;
+use crate::tray::TrayAvailability;
+use crate::widgets::helpers::{nav_icons, styled_tooltip};
 
 pub fn view<'a>(
     settings_state: &'a SettingsModalState,
     settings: &'a AppSettings,
     state: &'a MainState,
+    language: Language,
     has_tabs: bool,
     is_system_dark: bool,
+    reduced_motion: bool,
 ) -> Element<'a, Message> {
-    let header = settings_header(state);
+    let header = settings_header(state, language, settings_state.has_changes, reduced_motion);
     let capabilities = state.backend.capabilities();
     let shell_opts = settings.shell_options_for(state.backend_name);
 
     let content = column![
-        appearance_section(settings, is_system_dark),
+        appearance_section(settings_state, settings, is_system_dark),
         preferred_engine_section(settings, state),
-        tray_section(settings),
-        update_behavior_section(settings),
-        shell_options_section(capabilities, shell_opts),
-        shell_setup_section(settings_state),
+        environments_section(state),
+        tray_section(settings, state.tray_availability),
+        update_behavior_section(settings_state, settings),
+        default_on_install_section(settings),
+        confirm_uninstall_section(settings_state, settings),
+        install_verification_section(settings),
+        version_metadata_section(settings),
+        retention_section(settings, state.banner_stats.retention_candidates.len()),
+        timeouts_section(settings_state, settings),
+        shell_options_section(settings, capabilities, shell_opts),
+        shell_setup_section(
+            settings_state,
+            matches!(
+                state.active_environment().id,
+                versi_platform::EnvironmentId::Wsl { .. }
+            ),
+        ),
         settings_data_section(),
-        advanced_section(settings_state, settings),
+        advanced_section(settings_state, settings, language),
     ]
     .spacing(4)
     .width(Length::Fill);
 
-    column![
+    let page: Element<Message> = column![
         container(header).padding(iced::Padding::new(0.0).right(crate::theme::tokens::INSET_RIGHT)),
         Space::new().height(12),
         scrollable(
@@ -45,27 +72,72 @@ pub fn view<'a>(
     .padding(super::content_padding(has_tabs))
     .width(Length::Fill)
     .height(Length::Fill)
-    .into()
+    .into();
+
+    match &state.modal {
+        Some(modal) => super::main_view::modal_overlay(page, modal, state, settings, language),
+        None => page,
+    }
 }
 
-fn settings_header(state: &MainState) -> iced::widget::Row<'_, Message> {
+fn section_title(
+    title: &'static str,
+    section: SettingsSection,
+    settings: &AppSettings,
+) -> Element<'static, Message> {
+    let at_defaults = settings.section_is_default(section);
     row![
-        text("Settings").size(14),
+        text(title).size(14),
         Space::new().width(Length::Fill),
-        nav_icons(&state.view, state.refresh_rotation),
+        styled_tooltip(
+            button(text("Reset").size(11))
+                .on_press_maybe((!at_defaults).then_some(Message::ResetSettingsSection(section)))
+                .style(styles::secondary_button)
+                .padding([2, 8]),
+            if at_defaults {
+                "Already at the defaults"
+            } else {
+                "Restore the defaults for this section"
+            },
+            tooltip::Position::Left,
+        ),
     ]
-    .spacing(8)
     .align_y(Alignment::Center)
+    .into()
 }
 
-fn appearance_section(settings: &AppSettings, is_system_dark: bool) -> Element<'_, Message> {
+fn settings_header(
+    state: &MainState,
+    language: Language,
+    has_changes: bool,
+    reduced_motion: bool,
+) -> iced::widget::Row<'_, Message> {
+    let mut header = row![text("Settings").size(14), Space::new().width(Length::Fill)]
+        .spacing(8)
+        .align_y(Alignment::Center);
+    if has_changes {
+        header = header.push(
+            button(text("Discard Changes").size(11))
+                .on_press(Message::DiscardSettingsChanges)
+                .style(styles::secondary_button)
+                .padding([4, 10]),
+        );
+    }
+    header.push(nav_icons(state, language, reduced_motion))
+}
+
+fn appearance_section<'a>(
+    settings_state: &'a SettingsModalState,
+    settings: &'a AppSettings,
+    is_system_dark: bool,
+) -> Element<'a, Message> {
     let system_label = if is_system_dark {
         "System (Dark)"
     } else {
         "System (Light)"
     };
     column![
-        text("Appearance").size(14),
+        section_title("Appearance", SettingsSection::Appearance, settings),
         Space::new().height(8),
         row![
             theme_button(system_label, ThemeSetting::System, settings),
@@ -73,12 +145,60 @@ fn appearance_section(settings: &AppSettings, is_system_dark: bool) -> Element<'
             theme_button("Dark", ThemeSetting::Dark, settings),
         ]
         .spacing(8),
+        Space::new().height(8),
+        language_selector(settings),
+        Space::new().height(8),
+        row![
+            toggler(settings.reduced_motion)
+                .on_toggle(Message::ReducedMotionToggled)
+                .size(18),
+            text("Reduce motion").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        row![
+            toggler(settings.version_prefix)
+                .on_toggle(Message::VersionPrefixToggled)
+                .size(18),
+            text("Show the \"v\" prefix on versions").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        Space::new().height(8),
+        numeric_rows(SettingsSection::Appearance, settings_state, settings),
         Space::new().height(28),
     ]
     .spacing(4)
     .into()
 }
 
+fn language_selector(settings: &AppSettings) -> Element<'_, Message> {
+    let mut languages = row![language_button("System", None, settings)].spacing(8);
+    for language in Language::ALL {
+        languages = languages.push(language_button(
+            language.native_name(),
+            Some(language),
+            settings,
+        ));
+    }
+    languages.into()
+}
+
+fn language_button<'a>(
+    label: &'a str,
+    language: Option<Language>,
+    settings: &'a AppSettings,
+) -> iced::widget::Button<'a, Message> {
+    button(text(label).size(13))
+        .on_press(Message::LanguageChanged(language))
+        .style(if settings.language == language {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+}
+
 fn theme_button<'a>(
     label: &'a str,
     theme: ThemeSetting,
@@ -108,15 +228,99 @@ fn preferred_engine_section<'a>(
         text("Each environment uses whichever engine is available")
             .size(11)
             .color(crate::theme::tokens::TEXT_MUTED),
+        migration_button(state),
         Space::new().height(28),
     ]
     .spacing(4)
     .into()
 }
 
-fn tray_section(settings: &AppSettings) -> Element<'_, Message> {
+/// Offer to bring versions over from the other engine when it's installed.
+fn migration_button(state: &MainState) -> Element<'_, Message> {
+    let source = state
+        .detected_backends
+        .iter()
+        .copied()
+        .find(|kind| *kind != state.backend_name);
+    match source {
+        Some(source) if state.active_environment().id == versi_platform::EnvironmentId::Native => {
+            let up_to_date = state.nothing_to_migrate_from(source);
+            let hint = if up_to_date {
+                format!(
+                    "Every {source} version is already installed with {}",
+                    state.backend_name
+                )
+            } else {
+                format!(
+                    "Install the versions {source} has with {}",
+                    state.backend_name
+                )
+            };
+            column![
+                Space::new().height(4),
+                styled_tooltip(
+                    button(text(format!("Import versions from {source}")).size(13))
+                        .on_press_maybe((!up_to_date).then_some(Message::RequestMigration(source)))
+                        .style(styles::secondary_button)
+                        .padding([10, 16]),
+                    hint,
+                    tooltip::Position::Top,
+                ),
+            ]
+            .into()
+        }
+        _ => Space::new().into(),
+    }
+}
+
+fn environments_section(state: &MainState) -> Element<'_, Message> {
+    if state.environments.len() <= 1 {
+        return Space::new().into();
+    }
+
+    let target = state.active_environment();
+    let mut buttons = row![].spacing(8);
+    for (idx, env) in state.environments.iter().enumerate() {
+        if idx == state.active_environment_idx || !env.available {
+            continue;
+        }
+        let blocker = env.clone_blocker(target);
+        let hint = blocker
+            .clone()
+            .unwrap_or_else(|| format!("Install the versions {} has", env.name));
+        buttons = buttons.push(styled_tooltip(
+            button(text(format!("Copy from {}", env.name)).size(13))
+                .on_press_maybe(
+                    blocker
+                        .is_none()
+                        .then(|| Message::RequestCloneEnvironment(env.id.clone())),
+                )
+                .style(styles::secondary_button)
+                .padding([10, 16]),
+            hint,
+            tooltip::Position::Top,
+        ));
+    }
+
     column![
-        text("System Tray").size(14),
+        text("Environments").size(14),
+        Space::new().height(8),
+        buttons,
+        text(format!(
+            "Installs versions the other environment has into {}",
+            target.name
+        ))
+        .size(11)
+        .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn tray_section(settings: &AppSettings, availability: TrayAvailability) -> Element<'_, Message> {
+    let mut section = column![
+        section_title("System Tray", SettingsSection::Tray, settings),
         Space::new().height(8),
         row![
             tray_behavior_button("When Open", TrayBehavior::WhenWindowOpen, settings),
@@ -134,18 +338,51 @@ fn tray_section(settings: &AppSettings) -> Element<'_, Message> {
         .spacing(8)
         .align_y(Alignment::Center),
         launch_at_login_row(settings),
+        row![
+            toggler(settings.confirm_tray_environment_switch)
+                .on_toggle(Message::ConfirmTrayEnvironmentSwitchToggled)
+                .size(18),
+            text("Confirm before tray actions switch environments").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
         text("\"Always\" keeps the app running in the tray when closed")
             .size(11)
             .color(crate::theme::tokens::TEXT_MUTED),
-        Space::new().height(28),
     ]
-    .spacing(4)
-    .into()
+    .spacing(4);
+
+    if let Some(reason) = availability.description() {
+        section = section.push(
+            text(reason)
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    }
+
+    section.push(Space::new().height(28)).into()
 }
 
-fn update_behavior_section(settings: &AppSettings) -> Element<'_, Message> {
+fn update_behavior_section<'a>(
+    settings_state: &'a SettingsModalState,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let repo = settings_state
+        .update_repo_input
+        .as_deref()
+        .unwrap_or(&settings.update_repo);
+    let repo_hint = if versi_core::is_valid_update_repo(repo.trim()) {
+        if settings_state.update_repo_input.is_some() {
+            "Press Enter to apply."
+        } else {
+            "GitHub repository (owner/name) to check for releases."
+        }
+    } else {
+        "Use the owner/name format, e.g. almeidx/versi."
+    };
+
     column![
-        text("App Updates").size(14),
+        section_title("App Updates", SettingsSection::Updates, settings),
         Space::new().height(8),
         row![
             update_behavior_button("Off", AppUpdateBehavior::DoNotCheck, settings),
@@ -164,12 +401,321 @@ fn update_behavior_section(settings: &AppSettings) -> Element<'_, Message> {
         text("Off: never check. Check Periodically: notify about updates. Auto Update: download and apply in background.")
             .size(11)
             .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(8),
+        row![
+            update_channel_button("Stable", UpdateChannel::Stable, settings),
+            update_channel_button("Beta", UpdateChannel::Beta, settings),
+        ]
+        .spacing(8),
+        text("Beta also offers pre-release builds.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(8),
+        text_input(versi_core::DEFAULT_UPDATE_REPO, repo)
+            .on_input(Message::UpdateRepoInputChanged)
+            .on_submit(Message::UpdateRepoSubmitted)
+            .padding(6)
+            .size(12)
+            .width(Length::Fixed(260.0)),
+        text(repo_hint)
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(8),
+        numeric_rows(SettingsSection::Updates, settings_state, settings),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn default_on_install_section(settings: &AppSettings) -> Element<'_, Message> {
+    column![
+        text("Default After Install").size(14),
+        Space::new().height(8),
+        row![
+            default_on_install_button("Auto", DefaultOnInstall::Auto, settings),
+            default_on_install_button("Always", DefaultOnInstall::Always, settings),
+            default_on_install_button("Never", DefaultOnInstall::Never, settings),
+        ]
+        .spacing(8),
+        text("Auto: only when no default is set. Always: every newly installed version becomes the default.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn confirm_uninstall_section<'a>(
+    settings_state: &'a SettingsModalState,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    column![
+        text("Confirm Uninstall").size(14),
+        Space::new().height(8),
+        row![
+            confirm_uninstall_button("Always", ConfirmUninstall::Always, settings),
+            confirm_uninstall_button("Default Only", ConfirmUninstall::DefaultOnly, settings),
+            confirm_uninstall_button("Never", ConfirmUninstall::Never, settings),
+        ]
+        .spacing(8),
+        text("Uninstalling the default version always asks first.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(8),
+        row![
+            toggler(settings.confirm_eol_default)
+                .on_toggle(Message::ConfirmEolDefaultToggled)
+                .size(18),
+            text("Ask before making an end-of-life version the default").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        Space::new().height(8),
+        numeric_rows(SettingsSection::Versions, settings_state, settings),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn install_verification_section(settings: &AppSettings) -> Element<'_, Message> {
+    column![
+        text("Install Verification").size(14),
+        Space::new().height(8),
+        row![
+            toggler(settings.verify_installs)
+                .on_toggle(Message::VerifyInstallsToggled)
+                .size(18),
+            text("Verify checksums after install").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        text("Compares installed files with the SHASUMS256.txt published for each release. Downloads the release again on macOS and Linux.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn version_metadata_section(settings: &AppSettings) -> Element<'_, Message> {
+    let mut section = column![
+        text("Version Details").size(14),
+        Space::new().height(8),
+        row![
+            toggler(settings.fetch_version_metadata)
+                .on_toggle(Message::FetchVersionMetadataToggled)
+                .size(18),
+            text("Fetch release metadata").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(4);
+
+    if settings.fetch_version_metadata {
+        for (field, label) in [
+            (MetadataField::Npm, "Show npm version"),
+            (MetadataField::V8, "Show V8 version"),
+            (MetadataField::OpenSsl, "Show OpenSSL version"),
+        ] {
+            section = section.push(shell_option_toggle(
+                settings.shows_metadata_field(field),
+                label,
+                move |visible| Message::MetadataFieldToggled(field, visible),
+            ));
+        }
+    }
+
+    section
+        .push(
+            text("Release dates and bundled tool versions come from nodejs.org/dist/index.json.")
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        )
+        .push(Space::new().height(28))
+        .into()
+}
+
+fn retention_section(settings: &AppSettings, prune_count: usize) -> Element<'_, Message> {
+    let keep = settings.patches_to_keep_per_major;
+    let apply_hint = match keep {
+        None => "Pick how many patches to keep first".to_string(),
+        Some(_) if prune_count == 0 => {
+            "Nothing to prune: every major is within the policy".to_string()
+        }
+        Some(_) => format!(
+            "Remove {prune_count} {}",
+            if prune_count == 1 {
+                "version"
+            } else {
+                "versions"
+            }
+        ),
+    };
+    let apply = tooltip(
+        button(text("Apply Now").size(13))
+            .on_press_maybe(
+                (keep.is_some() && prune_count > 0).then_some(Message::RequestApplyRetentionPolicy),
+            )
+            .style(styles::secondary_button)
+            .padding([10, 16]),
+        container(text(apply_hint).size(12))
+            .padding([4, 8])
+            .style(styles::tooltip_container),
+        tooltip::Position::Top,
+    );
+
+    column![
+        text("Patch Retention").size(14),
+        Space::new().height(8),
+        row![
+            retention_button("Off", None, keep),
+            retention_button("1", Some(1), keep),
+            retention_button("2", Some(2), keep),
+            retention_button("3", Some(3), keep),
+            Space::new().width(Length::Fill),
+            apply,
+        ]
+        .spacing(8),
+        text("Keep only the newest patches of each major. Older ones are offered for removal; the default version is always kept.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
         Space::new().height(28),
     ]
     .spacing(4)
     .into()
 }
 
+fn timeouts_section<'a>(
+    settings_state: &'a SettingsModalState,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let retry_delays = settings
+        .retry_delays_secs
+        .iter()
+        .map(|delay| format!("{delay}s"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    column![
+        section_title("Timeouts", SettingsSection::Timeouts, settings),
+        Space::new().height(8),
+        numeric_rows(SettingsSection::Timeouts, settings_state, settings),
+        row![
+            text("Retry delays").size(12).width(Length::Fixed(120.0)),
+            text(retry_delays).size(12),
+        ]
+        .spacing(8),
+        Space::new().height(28),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn numeric_rows<'a>(
+    section: SettingsSection,
+    settings_state: &'a SettingsModalState,
+    settings: &AppSettings,
+) -> iced::widget::Column<'a, Message> {
+    NumericSetting::in_section(section).fold(column![].spacing(4), |rows, setting| {
+        rows.push(numeric_row(
+            setting,
+            settings_state.numeric_inputs.get(&setting),
+            settings,
+        ))
+    })
+}
+
+/// A numeric input with its allowed range, flagged while the typed value
+/// can't be applied.
+fn numeric_row<'a>(
+    setting: NumericSetting,
+    input: Option<&'a String>,
+    settings: &AppSettings,
+) -> Element<'a, Message> {
+    let range = setting.range();
+    let value = input.map_or_else(|| setting.get(settings).to_string(), Clone::clone);
+    let hint = match input.map(|input| setting.validate(input)) {
+        Some(Err(error)) => text(error.to_string())
+            .size(11)
+            .color(crate::theme::tokens::EOL_ORANGE),
+        Some(Ok(_)) => text("Press Enter to apply.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        None => text(format!(
+            "{}–{} {}",
+            range.start(),
+            range.end(),
+            setting.unit()
+        ))
+        .size(11)
+        .color(crate::theme::tokens::TEXT_MUTED),
+    };
+
+    row![
+        text(setting.label()).size(12).width(Length::Fixed(120.0)),
+        text_input("", &value)
+            .on_input(move |value| Message::NumericInputChanged(setting, value))
+            .on_submit(Message::NumericSubmitted(setting))
+            .padding(4)
+            .size(12)
+            .width(Length::Fixed(80.0)),
+        hint,
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn retention_button(
+    label: &str,
+    value: Option<usize>,
+    current: Option<usize>,
+) -> iced::widget::Button<'_, Message> {
+    button(text(label).size(13))
+        .on_press(Message::PatchRetentionChanged(value))
+        .style(if current == value {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+}
+
+fn default_on_install_button<'a>(
+    label: &'a str,
+    policy: DefaultOnInstall,
+    settings: &'a AppSettings,
+) -> iced::widget::Button<'a, Message> {
+    button(text(label).size(13))
+        .on_press(Message::DefaultOnInstallChanged(policy))
+        .style(if settings.set_default_on_install == policy {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+}
+
+fn confirm_uninstall_button<'a>(
+    label: &'a str,
+    policy: ConfirmUninstall,
+    settings: &'a AppSettings,
+) -> iced::widget::Button<'a, Message> {
+    button(text(label).size(13))
+        .on_press(Message::ConfirmUninstallChanged(policy))
+        .style(if settings.confirm_uninstall == policy {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+}
+
 fn tray_behavior_button<'a>(
     label: &'a str,
     behavior: TrayBehavior,
@@ -200,27 +746,48 @@ fn update_behavior_button<'a>(
         .padding([10, 16])
 }
 
+fn update_channel_button<'a>(
+    label: &'a str,
+    channel: UpdateChannel,
+    settings: &'a AppSettings,
+) -> iced::widget::Button<'a, Message> {
+    button(text(label).size(13))
+        .on_press(Message::UpdateChannelChanged(channel))
+        .style(if settings.update_channel == channel {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([6, 14])
+}
+
 fn shell_options_section(
+    settings: &AppSettings,
     capabilities: versi_backend::ManagerCapabilities,
     shell_opts: crate::settings::ShellOptions,
 ) -> Element<'static, Message> {
-    let mut section = column![text("Shell Options").size(14), Space::new().height(8),].spacing(4);
+    let mut section = column![
+        section_title("Shell Options", SettingsSection::ShellOptions, settings),
+        Space::new().height(8),
+    ]
+    .spacing(4);
+    let supported = capabilities.shell_options;
 
-    if capabilities.supports_auto_switch {
+    if supported.auto_switch {
         section = section.push(shell_option_toggle(
             shell_opts.use_on_cd,
             "Auto-switch on cd",
             Message::ShellOptionUseOnCdToggled,
         ));
     }
-    if capabilities.supports_resolve_engines {
+    if supported.resolve_engines {
         section = section.push(shell_option_toggle(
             shell_opts.resolve_engines,
             "Resolve engines from package.json",
             Message::ShellOptionResolveEnginesToggled,
         ));
     }
-    if capabilities.supports_corepack {
+    if supported.corepack {
         section = section.push(shell_option_toggle(
             shell_opts.corepack_enabled,
             "Enable corepack",
@@ -228,10 +795,7 @@ fn shell_options_section(
         ));
     }
 
-    if !capabilities.supports_auto_switch
-        && !capabilities.supports_resolve_engines
-        && !capabilities.supports_corepack
-    {
+    if !supported.any() {
         section = section.push(
             text("No shell options available for this engine")
                 .size(12)
@@ -260,7 +824,7 @@ where
     .align_y(Alignment::Center)
 }
 
-fn shell_setup_section(settings_state: &SettingsModalState) -> Element<'_, Message> {
+fn shell_setup_section(settings_state: &SettingsModalState, is_wsl: bool) -> Element<'_, Message> {
     let mut section = column![text("Shell Setup").size(14), Space::new().height(8),].spacing(4);
 
     if settings_state.checking_shells {
@@ -269,14 +833,46 @@ fn shell_setup_section(settings_state: &SettingsModalState) -> Element<'_, Messa
         section = section.push(text("No shells detected").size(12));
     } else {
         for shell in &settings_state.shell_statuses {
-            section = section.push(shell_status_row(shell));
+            section = section.push(shell_status_row(shell, is_wsl));
+            if let Some(outcome) = &shell.test_outcome {
+                section = section.push(test_outcome_text(outcome));
+            }
+            if let Some(outcome) = &shell.repair_outcome {
+                section = section.push(repair_outcome_text(outcome));
+            }
         }
     }
 
+    if let Some(conflict) = &settings_state.node_path_conflict {
+        section = section
+            .push(Space::new().height(8))
+            .push(node_path_conflict_notice(conflict));
+    }
+
     section.push(Space::new().height(28)).into()
 }
 
-fn shell_status_row(shell: &crate::state::ShellSetupStatus) -> iced::widget::Row<'_, Message> {
+fn node_path_conflict_notice(conflict: &versi_shell::NodePathConflict) -> Element<'_, Message> {
+    column![
+        text(format!(
+            "{} comes before {} on PATH, so the default version won't take effect.",
+            conflict.shadowing.display(),
+            conflict.managed.display()
+        ))
+        .size(12)
+        .color(crate::theme::tokens::EOL_ORANGE),
+        text("Uninstall the system Node.js (e.g. from apt or Homebrew), or move the version manager's init line to the end of your shell config.")
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+    ]
+    .spacing(2)
+    .into()
+}
+
+fn shell_status_row(
+    shell: &crate::state::ShellSetupStatus,
+    is_wsl: bool,
+) -> iced::widget::Row<'_, Message> {
     let status_text = match &shell.status {
         ShellVerificationStatus::Configured => "Configured",
         ShellVerificationStatus::NotConfigured => "Not configured",
@@ -316,7 +912,15 @@ fn shell_status_row(shell: &crate::state::ShellSetupStatus) -> iced::widget::Row
                     color: Some(iced::Color::from_rgb8(52, 199, 89)),
                 })
                 .into();
-            row = row.push(check_icon);
+            row = row
+                .push(check_icon)
+                .push(Space::new().width(Length::Fill))
+                .push(test_shell_button(shell, is_wsl))
+                .push(repair_shell_button(shell, is_wsl));
+        } else {
+            row = row
+                .push(Space::new().width(Length::Fill))
+                .push(test_shell_button(shell, is_wsl));
         }
         return row;
     }
@@ -342,6 +946,7 @@ fn shell_status_row(shell: &crate::state::ShellSetupStatus) -> iced::widget::Row
             .size(12)
             .color(crate::theme::tokens::EOL_ORANGE),
         Space::new().width(Length::Fill),
+        test_shell_button(shell, is_wsl),
         button(text("Configure").size(11))
             .on_press(Message::ConfigureShell(shell.shell_type.clone()))
             .style(styles::secondary_button)
@@ -351,6 +956,93 @@ fn shell_status_row(shell: &crate::state::ShellSetupStatus) -> iced::widget::Row
     .align_y(Alignment::Center)
 }
 
+fn test_shell_button(shell: &crate::state::ShellSetupStatus, is_wsl: bool) -> Element<'_, Message> {
+    let label = if shell.testing { "Testing..." } else { "Test" };
+    let button = button(text(label).size(11))
+        .style(styles::secondary_button)
+        .padding([4, 10]);
+    let (button, hint) = if is_wsl {
+        (button, "Testing shell integration is not supported in WSL")
+    } else if shell.testing {
+        (button, "Checking that node resolves after the init command")
+    } else {
+        (
+            button.on_press(Message::TestShellIntegration(shell.shell_type.clone())),
+            "Start this shell, run the init command and check that node resolves",
+        )
+    };
+    tooltip(
+        button,
+        container(text(hint).size(12))
+            .padding([4, 8])
+            .style(styles::tooltip_container),
+        tooltip::Position::Top,
+    )
+    .into()
+}
+
+fn repair_shell_button(
+    shell: &crate::state::ShellSetupStatus,
+    is_wsl: bool,
+) -> Element<'_, Message> {
+    let label = if shell.repairing {
+        "Repairing..."
+    } else {
+        "Repair"
+    };
+    let button = button(text(label).size(11))
+        .style(styles::secondary_button)
+        .padding([4, 10]);
+    let (button, hint) = if is_wsl {
+        (button, "Repairing shell config is not supported in WSL")
+    } else if shell.repairing {
+        (button, "Removing duplicate and stale init lines")
+    } else {
+        (
+            button.on_press(Message::RepairShellConfig(shell.shell_type.clone())),
+            "Remove duplicate and stale init lines",
+        )
+    };
+    tooltip(
+        button,
+        container(text(hint).size(12))
+            .padding([4, 8])
+            .style(styles::tooltip_container),
+        tooltip::Position::Top,
+    )
+    .into()
+}
+
+fn repair_outcome_text(outcome: &ShellRepairOutcome) -> Element<'_, Message> {
+    let (message, color) = match outcome {
+        ShellRepairOutcome::NothingToRepair => (
+            "Nothing to repair".to_string(),
+            crate::theme::tokens::TEXT_MUTED,
+        ),
+        ShellRepairOutcome::Repaired(changes) => (
+            format!("Repaired: {}", changes.join(", ")),
+            iced::Color::from_rgb8(52, 199, 89),
+        ),
+        ShellRepairOutcome::Failed(error) => (error.to_string(), crate::theme::tokens::EOL_ORANGE),
+    };
+    container(text(message).size(11).color(color))
+        .padding(iced::Padding::new(0.0).left(crate::theme::tokens::COL_SHELL_NAME + 8.0))
+        .into()
+}
+
+fn test_outcome_text(outcome: &ShellTestOutcome) -> Element<'_, Message> {
+    let (message, color) = match outcome {
+        ShellTestOutcome::Resolved(node) => (
+            format!("node resolves to {}", node.display()),
+            iced::Color::from_rgb8(52, 199, 89),
+        ),
+        ShellTestOutcome::Failed(error) => (error.to_string(), crate::theme::tokens::EOL_ORANGE),
+    };
+    container(text(message).size(11).color(color))
+        .padding(iced::Padding::new(0.0).left(crate::theme::tokens::COL_SHELL_NAME + 8.0))
+        .into()
+}
+
 fn settings_data_section() -> Element<'static, Message> {
     column![
         text("Settings Data").size(14),
@@ -370,7 +1062,18 @@ fn settings_data_section() -> Element<'static, Message> {
                 .padding([4, 10]),
         ]
         .spacing(8),
-        text("Export or import preferences, or edit the config file directly")
+        row![
+            button(text("Copy as fnm Commands").size(11))
+                .on_press(Message::CopyEnvironmentSnippet(BackendKind::Fnm))
+                .style(styles::secondary_button)
+                .padding([4, 10]),
+            button(text("Copy as nvm Commands").size(11))
+                .on_press(Message::CopyEnvironmentSnippet(BackendKind::Nvm))
+                .style(styles::secondary_button)
+                .padding([4, 10]),
+        ]
+        .spacing(8),
+        text("Export or import preferences, edit the config file directly, or copy commands that reinstall this environment's versions")
             .size(11)
             .color(crate::theme::tokens::TEXT_MUTED),
         Space::new().height(28),
@@ -379,9 +1082,20 @@ fn settings_data_section() -> Element<'static, Message> {
     .into()
 }
 
+fn cache_cleared_note(cleared: bool, language: Language) -> Element<'static, Message> {
+    if !cleared {
+        return Space::new().into();
+    }
+    text(t(language, "settings.cache_cleared"))
+        .size(11)
+        .color(crate::theme::tokens::TEXT_MUTED)
+        .into()
+}
+
 fn advanced_section<'a>(
     settings_state: &'a SettingsModalState,
     settings: &'a AppSettings,
+    language: Language,
 ) -> Element<'a, Message> {
     let log_path = versi_platform::AppPaths::new()
         .map(|paths| paths.log_file().to_string_lossy().to_string())
@@ -428,13 +1142,127 @@ fn advanced_section<'a>(
                 .on_press(Message::ClearLogFile)
                 .style(styles::secondary_button)
                 .padding([4, 10]),
+            button(text("Clear Version Cache").size(11))
+                .on_press(Message::ClearVersionCache)
+                .style(styles::secondary_button)
+                .padding([4, 10]),
+            cache_cleared_note(settings_state.version_cache_cleared, language),
         ]
-        .spacing(8),
+        .spacing(8)
+        .align_y(Alignment::Center),
+        Space::new().height(8),
+        numeric_rows(SettingsSection::Advanced, settings_state, settings),
+        Space::new().height(8),
+        npm_registry_row(settings_state),
+        Space::new().height(8),
+        audit_log_view(settings_state.audit_log.as_deref()),
+    ]
+    .spacing(4)
+    .into()
+}
+
+fn audit_log_view(records: Option<&[AuditRecord]>) -> Element<'_, Message> {
+    let Some(records) = records else {
+        return button(text("View Audit Log").size(11))
+            .on_press(Message::ShowAuditLog)
+            .style(styles::secondary_button)
+            .padding([4, 10])
+            .into();
+    };
+
+    let mut entries = column![].spacing(2);
+    if records.is_empty() {
+        entries = entries.push(
+            text("No operations recorded yet")
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    }
+    for record in records.iter().rev() {
+        entries = entries.push(audit_record_row(record));
+    }
+
+    column![
+        row![
+            text("Audit log").size(12),
+            Space::new().width(Length::Fill),
+            button(text("Hide").size(11))
+                .on_press(Message::HideAuditLog)
+                .style(styles::ghost_button)
+                .padding([4, 10]),
+        ]
+        .align_y(Alignment::Center),
+        scrollable(entries).height(Length::Fixed(180.0)),
     ]
     .spacing(4)
     .into()
 }
 
+fn audit_record_row(record: &AuditRecord) -> Element<'_, Message> {
+    let outcome = match &record.error {
+        None => text("ok")
+            .size(11)
+            .color(iced::Color::from_rgb8(52, 199, 89)),
+        Some(error) => text(format!("failed: {error}"))
+            .size(11)
+            .color(crate::theme::tokens::EOL_ORANGE),
+    };
+    row![
+        text(record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string())
+            .size(11)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        text(format!(
+            "{} {} ({})",
+            record.action.label(),
+            record.version,
+            record.environment
+        ))
+        .size(11),
+        outcome,
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn npm_registry_row(settings_state: &SettingsModalState) -> iced::widget::Row<'_, Message> {
+    let (status_text, color) = if settings_state.checking_npm_registry {
+        ("Checking...".to_string(), crate::theme::tokens::TEXT_MUTED)
+    } else {
+        match &settings_state.npm_registry {
+            None => ("Not checked".to_string(), crate::theme::tokens::TEXT_MUTED),
+            Some(versi_core::RegistryStatus::Reachable { latency }) => (
+                format!("Reachable ({} ms)", latency.as_millis()),
+                iced::Color::from_rgb8(52, 199, 89),
+            ),
+            Some(versi_core::RegistryStatus::Unreachable { reason }) => (
+                format!("Unreachable: {reason}"),
+                crate::theme::tokens::EOL_ORANGE,
+            ),
+        }
+    };
+
+    let mut check = button(text("Check").size(11))
+        .style(styles::secondary_button)
+        .padding([4, 10]);
+    if !settings_state.checking_npm_registry {
+        check = check.on_press(Message::CheckNpmRegistry);
+    }
+
+    let label = match &settings_state.npm_registry_url {
+        Some(url) => format!("npm registry ({url}): "),
+        None => "npm registry: ".to_string(),
+    };
+
+    row![
+        text(label).size(11).color(crate::theme::tokens::TEXT_MUTED),
+        text(status_text).size(11).color(color),
+        Space::new().width(Length::Fill),
+        check,
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center)
+}
+
 fn engine_button<'a>(
     kind: BackendKind,
     is_selected: bool,
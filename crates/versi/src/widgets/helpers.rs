@@ -3,7 +3,8 @@ use iced::{Alignment, Element};
 
 use crate::icon;
 use crate::message::Message;
-use crate::state::MainViewKind;
+use crate::state::{MainState, MainViewKind};
+use crate::strings::{Language, t};
 use crate::theme::styles;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,9 +34,85 @@ fn nav_button_style(is_active: bool) -> fn(&iced::Theme, button::Status) -> butt
     }
 }
 
+/// Relative install age such as `installed 3 days ago`, measured from `now`.
+pub fn format_installed_ago(
+    install_date: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+    language: Language,
+) -> String {
+    let days = now.signed_duration_since(install_date).num_days().max(0);
+    let (key, count) = match days {
+        0 => ("installed.today", None),
+        1 => ("installed.yesterday", None),
+        2..30 => ("installed.days_ago", Some(days)),
+        30..60 => ("installed.a_month_ago", None),
+        60..365 => ("installed.months_ago", Some(days / 30)),
+        365..730 => ("installed.a_year_ago", None),
+        _ => ("installed.years_ago", Some(days / 365)),
+    };
+    let template = t(language, key);
+    match count {
+        Some(count) => template.replace("{n}", &count.to_string()),
+        None => template.to_string(),
+    }
+}
+
+/// Running time of an operation as `m:ss`, or `h:mm:ss` past an hour.
+pub fn format_elapsed(elapsed: std::time::Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+/// Release lines this close to end-of-life get the warning colour.
+const EOL_WARNING_DAYS: i64 = 90;
+
+/// Remaining support for a release line, such as `EOL in 45 days` or
+/// `EOL since 2023-04-30`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EolCountdown {
+    pub label: String,
+    pub days: i64,
+}
+
+impl EolCountdown {
+    pub fn new(days: i64, end_date: &str) -> Self {
+        let label = match days {
+            i64::MIN..0 => format!("EOL since {end_date}"),
+            0 => "EOL today".to_string(),
+            1 => "EOL tomorrow".to_string(),
+            _ => format!("EOL in {days} days"),
+        };
+        Self { label, days }
+    }
+
+    /// Countdown for `major` as of `as_of`, normally the state's clock.
+    pub fn for_major(
+        schedule: &versi_core::ReleaseSchedule,
+        major: u32,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Option<Self> {
+        let days = schedule.days_until_eol_at(major, as_of)?;
+        let end_date = &schedule.versions.get(&major)?.end;
+        Some(Self::new(days, end_date))
+    }
+
+    pub fn is_eol(&self) -> bool {
+        self.days < 0
+    }
+
+    pub fn is_imminent(&self) -> bool {
+        (0..EOL_WARNING_DAYS).contains(&self.days)
+    }
+}
+
 pub fn styled_tooltip<'a>(
     content: impl Into<Element<'a, Message>>,
-    label: &'a str,
+    label: impl text::IntoFragment<'a>,
     position: tooltip::Position,
 ) -> Element<'a, Message> {
     tooltip(
@@ -49,14 +126,20 @@ pub fn styled_tooltip<'a>(
     .into()
 }
 
-pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Element<'a, Message> {
-    let refresh_icon = if should_spin_refresh_icon(refresh_rotation) {
+pub fn nav_icons<'a>(
+    state: &MainState,
+    language: Language,
+    reduced_motion: bool,
+) -> Element<'a, Message> {
+    let refresh_rotation = state.refresh_rotation;
+    let static_busy = reduced_motion && state.is_refreshing();
+    let refresh_icon = if !reduced_motion && should_spin_refresh_icon(refresh_rotation) {
         icon::refresh_spinning(16.0, refresh_rotation)
     } else {
         icon::refresh(16.0)
     };
 
-    let active_states = nav_active_states(active_view);
+    let active_states = nav_active_states(&state.view);
     let home_style = nav_button_style(active_states.home);
     let settings_style = nav_button_style(active_states.settings);
     let about_style = nav_button_style(active_states.about);
@@ -65,9 +148,16 @@ pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Eleme
         styled_tooltip(
             button(refresh_icon)
                 .on_press(Message::RefreshEnvironment)
-                .style(styles::ghost_button)
+                .style(nav_button_style(static_busy))
                 .padding([4, 6]),
-            "Refresh",
+            t(
+                language,
+                if static_busy {
+                    "nav.refreshing"
+                } else {
+                    "nav.refresh"
+                },
+            ),
             tooltip::Position::Bottom,
         ),
         styled_tooltip(
@@ -75,7 +165,7 @@ pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Eleme
                 .on_press(Message::NavigateToVersions)
                 .style(home_style)
                 .padding([4, 6]),
-            "Home",
+            t(language, "nav.home"),
             tooltip::Position::Bottom,
         ),
         styled_tooltip(
@@ -83,7 +173,7 @@ pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Eleme
                 .on_press(Message::NavigateToSettings)
                 .style(settings_style)
                 .padding([4, 6]),
-            "Settings",
+            t(language, "nav.settings"),
             tooltip::Position::Bottom,
         ),
         styled_tooltip(
@@ -91,7 +181,7 @@ pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Eleme
                 .on_press(Message::NavigateToAbout)
                 .style(about_style)
                 .padding([4, 6]),
-            "About",
+            t(language, "nav.about"),
             tooltip::Position::Bottom,
         ),
     ]
@@ -102,8 +192,14 @@ pub fn nav_icons<'a>(active_view: &MainViewKind, refresh_rotation: f32) -> Eleme
 
 #[cfg(test)]
 mod tests {
-    use super::{nav_active_states, should_spin_refresh_icon};
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::{
+        EolCountdown, format_elapsed, format_installed_ago, nav_active_states,
+        should_spin_refresh_icon,
+    };
     use crate::state::MainViewKind;
+    use crate::strings::Language;
 
     #[test]
     fn nav_active_states_marks_single_active_view() {
@@ -129,4 +225,80 @@ mod tests {
         assert!(should_spin_refresh_icon(0.1));
         assert!(should_spin_refresh_icon(-0.2));
     }
+
+    #[test]
+    fn installed_ago_covers_days_months_and_years() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let ago = |days| format_installed_ago(now - Duration::days(days), now, Language::English);
+
+        assert_eq!(ago(0), "installed today");
+        assert_eq!(ago(1), "installed yesterday");
+        assert_eq!(ago(3), "installed 3 days ago");
+        assert_eq!(ago(45), "installed a month ago");
+        assert_eq!(ago(100), "installed 3 months ago");
+        assert_eq!(ago(400), "installed a year ago");
+        assert_eq!(ago(1000), "installed 2 years ago");
+    }
+
+    #[test]
+    fn installed_ago_is_translated() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        assert_eq!(
+            format_installed_ago(now - Duration::days(3), now, Language::Portuguese),
+            "instalada há 3 dias"
+        );
+    }
+
+    #[test]
+    fn elapsed_is_shown_as_minutes_and_seconds() {
+        let secs = std::time::Duration::from_secs;
+
+        assert_eq!(format_elapsed(secs(0)), "0:00");
+        assert_eq!(format_elapsed(secs(42)), "0:42");
+        assert_eq!(format_elapsed(secs(605)), "10:05");
+        assert_eq!(format_elapsed(secs(3723)), "1:02:03");
+    }
+
+    #[test]
+    fn installed_ago_treats_future_dates_as_today() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        assert_eq!(
+            format_installed_ago(now + Duration::hours(5), now, Language::English),
+            "installed today"
+        );
+    }
+
+    #[test]
+    fn eol_countdown_labels_and_warning_window() {
+        let soon = EolCountdown::new(45, "2026-04-30");
+        assert_eq!(soon.label, "EOL in 45 days");
+        assert!(soon.is_imminent());
+        assert!(!soon.is_eol());
+
+        let later = EolCountdown::new(400, "2027-04-30");
+        assert_eq!(later.label, "EOL in 400 days");
+        assert!(!later.is_imminent());
+
+        let past = EolCountdown::new(-900, "2023-04-30");
+        assert_eq!(past.label, "EOL since 2023-04-30");
+        assert!(past.is_eol());
+        assert!(!past.is_imminent());
+
+        assert_eq!(EolCountdown::new(0, "2026-10-16").label, "EOL today");
+    }
+
+    #[test]
+    fn eol_countdown_for_major_counts_from_the_given_instant() {
+        let schedule: versi_core::ReleaseSchedule = serde_json::from_value(serde_json::json!({
+            "versions": {
+                "20": { "start": "2023-04-18", "end": "2026-04-30" }
+            }
+        }))
+        .expect("sample release schedule should deserialize");
+        let as_of = Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap();
+
+        let countdown = EolCountdown::for_major(&schedule, 20, as_of).unwrap();
+        assert_eq!(countdown.label, "EOL in 45 days");
+        assert!(EolCountdown::for_major(&schedule, 99, as_of).is_none());
+    }
 }
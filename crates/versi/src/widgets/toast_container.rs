@@ -3,7 +3,7 @@ use iced::{Alignment, Element, Length};
 
 use crate::icon;
 use crate::message::Message;
-use crate::state::Toast;
+use crate::state::{Toast, ToastAction, ToastKind};
 
 pub fn view<'a>(
     content: Element<'a, Message>,
@@ -46,8 +46,36 @@ fn toast_view(toast: &Toast) -> Element<'_, Message> {
         })
         .into();
 
-    let content = row![
-        text(&toast.message).size(14),
+    let mut content = row![text(&toast.message).size(14)]
+        .spacing(8)
+        .align_y(Alignment::Center);
+
+    if let Some(action) = &toast.action {
+        content = content.push(
+            button(text(action.label()).size(13))
+                .on_press(action_message(action))
+                .style(|_theme, status| iced::widget::button::Style {
+                    background: Some(iced::Background::Color(iced::Color {
+                        a: if matches!(status, iced::widget::button::Status::Hovered) {
+                            0.3
+                        } else {
+                            0.2
+                        },
+                        ..iced::Color::WHITE
+                    })),
+                    text_color: iced::Color::WHITE,
+                    border: iced::Border {
+                        radius: crate::theme::tokens::RADIUS_SM.into(),
+                        ..Default::default()
+                    },
+                    shadow: iced::Shadow::default(),
+                    snap: false,
+                })
+                .padding([2, 8]),
+        );
+    }
+
+    let content = content.push(
         button(close_icon)
             .on_press(Message::ToastDismiss(toast.id))
             .style(|_theme, _status| iced::widget::button::Style {
@@ -58,13 +86,16 @@ fn toast_view(toast: &Toast) -> Element<'_, Message> {
                 snap: false,
             })
             .padding([0, 4]),
-    ]
-    .spacing(8)
-    .align_y(Alignment::Center);
+    );
+
+    let background = match toast.kind {
+        ToastKind::Error => iced::Color::from_rgb8(255, 59, 48),
+        ToastKind::Info => iced::Color::from_rgb8(58, 58, 60),
+    };
 
     container(content)
-        .style(|_theme| container::Style {
-            background: Some(iced::Background::Color(iced::Color::from_rgb8(255, 59, 48))),
+        .style(move |_theme| container::Style {
+            background: Some(iced::Background::Color(background)),
             text_color: Some(iced::Color::WHITE),
             border: iced::Border {
                 radius: crate::theme::tokens::RADIUS_SM.into(),
@@ -84,3 +115,29 @@ fn toast_view(toast: &Toast) -> Element<'_, Message> {
         .max_width(crate::theme::tokens::TOAST_MAX_WIDTH)
         .into()
 }
+
+fn action_message(action: &ToastAction) -> Message {
+    match action {
+        ToastAction::UndoUninstall {
+            environment,
+            version,
+        } => Message::UndoUninstall {
+            environment: environment.clone(),
+            version: version.clone(),
+        },
+        ToastAction::RevertDefault {
+            environment,
+            version,
+        } => Message::RevertDefault {
+            environment: environment.clone(),
+            version: version.clone(),
+        },
+        ToastAction::RetryInstall {
+            version,
+            timeout_secs,
+        } => Message::RetryInstall {
+            version: version.clone(),
+            timeout_secs: *timeout_secs,
+        },
+    }
+}
@@ -1,3 +1,6 @@
+use std::ops::Range;
+use std::time::Duration;
+
 use iced::widget::{Space, button, container, mouse_area, row, text};
 use iced::{Alignment, Element, Length};
 
@@ -5,6 +8,9 @@ use versi_backend::RemoteVersion;
 
 use crate::message::Message;
 use crate::theme::styles;
+use crate::version_display::display_version;
+use crate::version_query::{QueryMatch, matches_version_query};
+use crate::widgets::helpers::format_elapsed;
 
 use super::VersionListContext;
 
@@ -78,9 +84,13 @@ fn version_badge_kinds(has_lts: bool, is_eol: bool, has_security: bool) -> Vec<V
     badges
 }
 
-fn action_button<'a>(action: VersionRowAction, version: &str) -> Element<'a, Message> {
+fn action_button<'a>(
+    action: VersionRowAction,
+    version: &str,
+    elapsed: Option<Duration>,
+) -> Element<'a, Message> {
     match action {
-        VersionRowAction::Installing => button(text("Installing...").size(12))
+        VersionRowAction::Installing => button(text(installing_label(elapsed)).size(12))
             .style(styles::primary_button)
             .padding([6, 12])
             .into(),
@@ -115,20 +125,76 @@ fn action_button<'a>(action: VersionRowAction, version: &str) -> Element<'a, Mes
     }
 }
 
+fn installing_label(elapsed: Option<Duration>) -> String {
+    match elapsed {
+        Some(elapsed) => format!("Installing\u{2026} {}", format_elapsed(elapsed)),
+        None => "Installing...".to_string(),
+    }
+}
+
+/// Split `content` around `range`, or `None` if the range is empty or
+/// doesn't fall on character boundaries.
+fn split_match(content: &str, range: Range<usize>) -> Option<(&str, &str, &str)> {
+    if range.is_empty() {
+        return None;
+    }
+    Some((
+        content.get(..range.start)?,
+        content.get(range.clone())?,
+        content.get(range.end..)?,
+    ))
+}
+
+/// Move a match in the `v`-prefixed version text onto the text as displayed.
+fn displayed_version_match(range: Range<usize>, version_prefix: bool) -> Range<usize> {
+    if version_prefix {
+        range
+    } else {
+        range.start.saturating_sub(1)..range.end.saturating_sub(1)
+    }
+}
+
+fn highlighted_text<'a>(
+    content: &str,
+    range: Option<Range<usize>>,
+    size: f32,
+) -> Element<'a, Message> {
+    let Some((before, matched, after)) = range.and_then(|range| split_match(content, range)) else {
+        return text(content.to_string()).size(size).into();
+    };
+    row![
+        text(before.to_string()).size(size),
+        text(matched.to_string()).size(size).style(text::primary),
+        text(after.to_string()).size(size),
+    ]
+    .into()
+}
+
 fn version_badges(
     version: &RemoteVersion,
     is_eol: bool,
     has_security: bool,
+    is_recommended: bool,
+    codename_match: Option<Range<usize>>,
 ) -> Element<'_, Message> {
     let mut badges = row![].spacing(6).align_y(Alignment::Center);
+    if is_recommended {
+        badges = badges.push(recommended_badge());
+    }
     for badge_kind in version_badge_kinds(version.lts_codename.is_some(), is_eol, has_security) {
         badges = match badge_kind {
             VersionBadgeKind::Lts => {
                 if let Some(lts) = &version.lts_codename {
+                    let codename_match = codename_match
+                        .clone()
+                        .filter(|_| lts.len() == lts.to_lowercase().len());
                     badges.push(
-                        container(text(format!("LTS: {lts}")).size(11))
-                            .padding([2, 6])
-                            .style(styles::badge_lts),
+                        container(row![
+                            text("LTS: ").size(11),
+                            highlighted_text(lts, codename_match, 11.0),
+                        ])
+                        .padding([2, 6])
+                        .style(styles::badge_lts),
                     )
                 } else {
                     badges
@@ -149,15 +215,40 @@ fn version_badges(
     badges.into()
 }
 
+pub(super) fn recommended_badge<'a>() -> Element<'a, Message> {
+    container(text("recommended").size(11))
+        .padding([2, 6])
+        .style(styles::badge_recommended)
+        .into()
+}
+
+/// A remote version row. `highlight` is the search query whose match is
+/// emphasized in the version and codename.
 pub(super) fn available_version_row<'a>(
     version: &'a RemoteVersion,
+    highlight: Option<&str>,
     ctx: &VersionListContext<'a>,
 ) -> Element<'a, Message> {
     let version_label = version.version.to_string();
+    let query_match = highlight
+        .and_then(|query| {
+            let codename_lower = version.lts_codename.as_deref().map(str::to_lowercase);
+            matches_version_query(
+                &version_label,
+                codename_lower.as_deref(),
+                query,
+                &query.to_lowercase(),
+            )
+        })
+        .unwrap_or_default();
+    let QueryMatch {
+        version: version_match,
+        codename: codename_match,
+    } = query_match;
     let meta = ctx.metadata.and_then(|m| m.get(&version_label));
     let is_eol = ctx
         .schedule
-        .is_some_and(|s| !s.is_active(version.version.major));
+        .is_some_and(|s| !s.is_active_at(version.version.major, ctx.as_of));
     let is_installed = ctx.installed_set.contains(&version.version);
 
     let is_active = ctx.operation_queue.is_current_version(&version_label);
@@ -186,8 +277,19 @@ pub(super) fn available_version_row<'a>(
     };
     let action = resolve_version_row_action(activity, install_state, hover_state);
     let has_security = meta.is_some_and(|m| m.security);
-    let action_button = action_button(action, &version_label);
-    let badges = version_badges(version, is_eol, has_security);
+    let action_button = action_button(
+        action,
+        &version_label,
+        ctx.operation_queue.install_elapsed(&version_label),
+    );
+    let is_recommended = ctx.recommended == Some(&version.version);
+    let badges = version_badges(
+        version,
+        is_eol,
+        has_security,
+        is_recommended,
+        codename_match,
+    );
 
     let date_text: Element<Message> = if let Some(date) = meta.map(|m| m.date.as_str()) {
         text(date)
@@ -198,12 +300,22 @@ pub(super) fn available_version_row<'a>(
         Space::new().into()
     };
 
+    let note: Element<Message> = match ctx.version_notes.get(&version_label) {
+        Some(note) => super::note_label(note),
+        None => Space::new().into(),
+    };
+
     let row_content = row![
-        container(text(version_label.clone()).size(14))
-            .padding([2, 4])
-            .width(Length::Fixed(crate::theme::tokens::COL_VERSION)),
+        container(highlighted_text(
+            &display_version(&version_label, ctx.version_prefix),
+            version_match.map(|range| displayed_version_match(range, ctx.version_prefix)),
+            14.0,
+        ))
+        .padding([2, 4])
+        .width(Length::Fixed(crate::theme::tokens::COL_VERSION)),
         container(date_text).width(Length::Fixed(crate::theme::tokens::COL_DATE)),
         badges,
+        note,
         Space::new().width(Length::Fill),
         action_button,
     ]
@@ -225,7 +337,7 @@ pub(super) fn available_version_row<'a>(
 mod tests {
     use super::{
         HoverState, InstallState, RowActivity, VersionBadgeKind, VersionRowAction,
-        resolve_version_row_action, version_badge_kinds,
+        displayed_version_match, resolve_version_row_action, split_match, version_badge_kinds,
     };
 
     #[test]
@@ -296,4 +408,18 @@ mod tests {
         );
         assert!(version_badge_kinds(false, false, false).is_empty());
     }
+
+    #[test]
+    fn split_match_surrounds_the_matched_text() {
+        assert_eq!(split_match("v20.11.1", 4..6), Some(("v20.", "11", ".1")));
+        assert_eq!(split_match("v20.11.1", 2..2), None);
+        assert_eq!(split_match("v20", 2..8), None);
+    }
+
+    #[test]
+    fn displayed_match_shifts_when_prefix_is_hidden() {
+        assert_eq!(displayed_version_match(4..6, true), 4..6);
+        assert_eq!(displayed_version_match(4..6, false), 3..5);
+        assert_eq!(displayed_version_match(0..2, false), 0..1);
+    }
 }
@@ -1,9 +1,5 @@
-use std::collections::HashSet;
+use versi_backend::RemoteVersion;
 
-use versi_backend::{NodeVersion, RemoteVersion};
-use versi_core::ReleaseSchedule;
-
-use crate::state::SearchFilter;
 use crate::version_query;
 
 pub(super) fn search_available_versions<'a>(
@@ -11,18 +7,14 @@ pub(super) fn search_available_versions<'a>(
     search_index: Option<&version_query::RemoteVersionSearchIndex>,
     query: &str,
     limit: usize,
-    active_filters: &HashSet<SearchFilter>,
-    installed_set: &HashSet<NodeVersion>,
-    schedule: Option<&ReleaseSchedule>,
+    filters: version_query::ResultFilters<'_>,
 ) -> version_query::AvailableVersionSearch<'a> {
     version_query::search_available_versions_with_index(
         versions,
         search_index,
         query,
         limit,
-        active_filters,
-        installed_set,
-        schedule,
+        filters,
     )
 }
 
@@ -32,6 +24,22 @@ mod tests {
 
     use super::search_available_versions;
     use crate::state::SearchFilter;
+    use crate::version_query::ResultFilters;
+
+    fn filters<'a>(
+        active: &'a HashSet<SearchFilter>,
+        installed_set: &'a HashSet<versi_backend::NodeVersion>,
+        schedule: Option<&'a versi_core::ReleaseSchedule>,
+    ) -> ResultFilters<'a> {
+        ResultFilters {
+            active,
+            installed_set,
+            schedule,
+            as_of: chrono::DateTime::parse_from_rfc3339("2026-01-15T12:00:00Z")
+                .expect("fixture date should parse")
+                .to_utc(),
+        }
+    }
 
     fn remote(version: &str, lts_codename: Option<&str>) -> versi_backend::RemoteVersion {
         versi_backend::RemoteVersion {
@@ -97,9 +105,7 @@ mod tests {
             None,
             "v22",
             2,
-            &HashSet::new(),
-            &HashSet::new(),
-            None,
+            filters(&HashSet::new(), &HashSet::new(), None),
         )
         .versions;
 
@@ -123,9 +129,7 @@ mod tests {
             None,
             "v",
             10,
-            &filters,
-            &installed,
-            Some(&schedule),
+            filters(&filters, &installed, Some(&schedule)),
         )
         .versions;
 
@@ -141,9 +145,7 @@ mod tests {
             None,
             "stable",
             10,
-            &HashSet::new(),
-            &HashSet::new(),
-            None,
+            filters(&HashSet::new(), &HashSet::new(), None),
         );
 
         assert!(search.alias_resolved);
@@ -156,8 +158,13 @@ mod tests {
         let installed = HashSet::from([versi_backend::NodeVersion::new(22, 1, 0)]);
         let filters = HashSet::from([SearchFilter::NotInstalled]);
 
-        let search =
-            search_available_versions(&versions, None, "stable", 10, &filters, &installed, None);
+        let search = search_available_versions(
+            &versions,
+            None,
+            "stable",
+            10,
+            filters(&filters, &installed, None),
+        );
 
         assert!(search.alias_resolved);
         assert!(search.versions.is_empty());
@@ -173,8 +180,13 @@ mod tests {
         let installed = HashSet::from([versi_backend::NodeVersion::new(22, 2, 0)]);
         let filters = HashSet::from([SearchFilter::Installed]);
 
-        let search =
-            search_available_versions(&versions, None, "v22", 1, &filters, &installed, None);
+        let search = search_available_versions(
+            &versions,
+            None,
+            "v22",
+            1,
+            filters(&filters, &installed, None),
+        );
 
         assert_eq!(search.versions.len(), 1);
         assert_eq!(search.versions[0].version.to_string(), "v22.2.0");
@@ -3,14 +3,16 @@ use std::collections::HashSet;
 use iced::widget::{Space, button, column, container, row, text};
 use iced::{Alignment, Element, Length};
 
-use versi_backend::{InstalledVersion, VersionGroup};
+use versi_backend::{InstalledVersion, RemoteVersion, VersionGroup};
 
 use crate::icon;
 use crate::message::Message;
 use crate::state::SearchFilter;
 use crate::theme::styles;
+use crate::widgets::helpers::EolCountdown;
 
 use super::VersionListContext;
+use super::available::available_version_row;
 use super::filter_version;
 use super::item::version_item_view;
 
@@ -64,16 +66,24 @@ pub(super) fn version_group_view<'a>(
         .versions
         .iter()
         .any(|v| default.is_some_and(|d| d == &v.version));
-    let is_eol = ctx.schedule.is_some_and(|s| !s.is_active(group.major));
-
-    let header_button = button(group_header_row(group, has_lts, has_default, is_eol))
-        .on_press(Message::VersionGroupToggled { major: group.major })
-        .style(|theme, status| {
-            let mut style = iced::widget::button::text(theme, status);
-            style.text_color = theme.palette().text;
-            style
-        })
-        .padding([8, 12]);
+    let is_eol = ctx
+        .schedule
+        .is_some_and(|s| !s.is_active_at(group.major, ctx.as_of));
+    let eol_countdown = ctx
+        .schedule
+        .and_then(|s| EolCountdown::for_major(s, group.major, ctx.as_of));
+
+    let header_button = button(group_header_row(
+        group,
+        has_lts,
+        has_default,
+        is_eol,
+        eol_countdown,
+        ctx.schedule,
+    ))
+    .on_press(Message::VersionGroupToggled { major: group.major })
+    .style(group_header_button_style)
+    .padding([8, 12]);
 
     let header: Element<Message> = row![
         header_button,
@@ -102,11 +112,113 @@ pub(super) fn version_group_view<'a>(
     }
 }
 
+fn group_header_button_style(
+    theme: &iced::Theme,
+    status: iced::widget::button::Status,
+) -> iced::widget::button::Style {
+    let mut style = iced::widget::button::text(theme, status);
+    style.text_color = theme.palette().text;
+    style
+}
+
+fn search_result_count_label(count: usize) -> String {
+    if count == 1 {
+        "(1 result)".to_string()
+    } else {
+        format!("({count} results)")
+    }
+}
+
+/// A collapsible card of search results for one major, laid out like the
+/// installed groups.
+pub(super) fn search_result_group_view<'a>(
+    major: u32,
+    versions: &[&'a RemoteVersion],
+    search_query: &str,
+    ctx: &VersionListContext<'a>,
+) -> Element<'a, Message> {
+    let is_expanded = !ctx.collapsed_search_majors.contains(&major);
+    let has_lts = versions.iter().any(|v| v.lts_codename.is_some());
+    let is_eol = ctx
+        .schedule
+        .is_some_and(|s| !s.is_active_at(major, ctx.as_of));
+    let expansion = if is_expanded {
+        GroupExpansion::Expanded
+    } else {
+        GroupExpansion::Collapsed
+    };
+
+    let chevron = if is_expanded {
+        icon::chevron_down(12.0)
+    } else {
+        icon::chevron_right(12.0)
+    };
+    let mut header_row = row![
+        chevron,
+        text(format!("Node {major}.x")).size(16),
+        text(search_result_count_label(versions.len())).size(12),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    for badge in group_header_badges(has_lts, false, is_eol, expansion) {
+        header_row = match badge {
+            HeaderBadgeKind::Lts => header_row.push(
+                container(text("LTS").size(10))
+                    .padding([2, 6])
+                    .style(styles::badge_lts),
+            ),
+            HeaderBadgeKind::Eol => header_row.push(
+                container(text("End-of-Life").size(10))
+                    .padding([2, 6])
+                    .style(styles::badge_eol),
+            ),
+            HeaderBadgeKind::Default => header_row,
+        };
+    }
+
+    let header = button(header_row)
+        .on_press(Message::SearchGroupToggled { major })
+        .style(group_header_button_style)
+        .padding([8, 12]);
+
+    if !is_expanded {
+        return container(header)
+            .style(styles::card_container)
+            .padding(12)
+            .width(Length::Fill)
+            .into();
+    }
+
+    let rows: Vec<Element<Message>> = versions
+        .iter()
+        .map(|version| available_version_row(version, Some(search_query), ctx))
+        .collect();
+
+    container(
+        column![
+            header,
+            container(column(rows).spacing(4)).padding(iced::Padding {
+                top: 0.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: crate::theme::tokens::GROUP_INDENT,
+            }),
+        ]
+        .spacing(4),
+    )
+    .style(styles::card_container)
+    .padding(12)
+    .width(Length::Fill)
+    .into()
+}
+
 fn group_header_row(
     group: &VersionGroup,
     has_lts: bool,
     has_default: bool,
     is_eol: bool,
+    eol_countdown: Option<EolCountdown>,
+    schedule: Option<&versi_core::ReleaseSchedule>,
 ) -> iced::widget::Row<'_, Message> {
     let chevron = if group.is_expanded {
         icon::chevron_down(12.0)
@@ -135,9 +247,17 @@ fn group_header_row(
                     .style(styles::badge_lts),
             ),
             HeaderBadgeKind::Eol => header_row.push(
-                container(text("End-of-Life").size(10))
-                    .padding([2, 6])
-                    .style(styles::badge_eol),
+                container(
+                    text(
+                        eol_countdown
+                            .as_ref()
+                            .filter(|countdown| countdown.is_eol())
+                            .map_or_else(|| "End-of-Life".to_string(), |c| c.label.clone()),
+                    )
+                    .size(10),
+                )
+                .padding([2, 6])
+                .style(styles::badge_eol),
             ),
             HeaderBadgeKind::Default => header_row.push(
                 container(text("default").size(10))
@@ -146,6 +266,20 @@ fn group_header_row(
             ),
         };
     }
+    if schedule.is_some_and(|s| s.is_pending(group.major)) {
+        header_row = header_row.push(
+            text("schedule data pending")
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+        );
+    }
+    if let Some(countdown) = eol_countdown.filter(EolCountdown::is_imminent) {
+        header_row = header_row.push(
+            text(countdown.label)
+                .size(11)
+                .color(crate::theme::tokens::EOL_ORANGE),
+        );
+    }
     header_row
 }
 
@@ -203,6 +337,7 @@ fn expanded_group_view<'a>(
                 query_lower,
                 active_filters,
                 ctx.schedule,
+                ctx.as_of,
                 &mut version_text,
             )
         })
@@ -232,7 +367,10 @@ fn expanded_group_view<'a>(
 
 #[cfg(test)]
 mod tests {
-    use super::{GroupExpansion, HeaderBadgeKind, group_header_badges, show_bulk_actions};
+    use super::{
+        GroupExpansion, HeaderBadgeKind, group_header_badges, search_result_count_label,
+        show_bulk_actions,
+    };
 
     #[test]
     fn group_header_badges_include_default_only_when_collapsed() {
@@ -258,6 +396,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn search_result_count_label_pluralizes() {
+        assert_eq!(search_result_count_label(1), "(1 result)");
+        assert_eq!(search_result_count_label(3), "(3 results)");
+    }
+
     #[test]
     fn bulk_actions_require_expanded_group_with_multiple_versions() {
         assert!(!show_bulk_actions(false, 5));
@@ -1,4 +1,4 @@
-use iced::widget::{Space, button, container, mouse_area, row, text};
+use iced::widget::{Space, button, checkbox, container, mouse_area, row, text};
 use iced::{Alignment, Element, Length};
 
 use versi_backend::InstalledVersion;
@@ -6,6 +6,8 @@ use versi_backend::InstalledVersion;
 use crate::message::Message;
 use crate::state::Operation;
 use crate::theme::styles;
+use crate::version_display::display_version;
+use crate::widgets::helpers::{format_installed_ago, styled_tooltip};
 
 use super::VersionListContext;
 
@@ -15,6 +17,7 @@ pub(super) fn version_item_view<'a>(
     ctx: &VersionListContext<'a>,
 ) -> Element<'a, Message> {
     let is_default = default.is_some_and(|d| d == &version.version);
+    let is_broken = ctx.broken_versions.contains(&version.version);
 
     let version_str = version.version.to_string();
     let meta = ctx.metadata.and_then(|m| m.get(&version_str));
@@ -32,15 +35,31 @@ pub(super) fn version_item_view<'a>(
         .is_some_and(|h| h == &version_str);
     let show_actions = is_hovered || is_default;
 
-    let row_content = row![
-        container(text(version_str.clone()).size(14))
+    let mut row_content = row![].spacing(8).align_y(Alignment::Center);
+    if let Some(selection) = ctx.selection {
+        let message_version = version_str.clone();
+        row_content = row_content.push(
+            checkbox(selection.contains(&version_str))
+                .on_toggle(move |_| Message::VersionSelectionToggled(message_version.clone()))
+                .size(14),
+        );
+    }
+    let row_content = row_content.push(
+        container(text(display_version(&version_str, ctx.version_prefix)).size(14))
             .padding([2, 4])
             .width(Length::Fixed(crate::theme::tokens::COL_VERSION)),
-    ]
-    .spacing(8)
-    .align_y(Alignment::Center);
+    );
 
-    let row_content = push_badges_and_size(row_content, version, meta, is_default);
+    let is_recommended = ctx.recommended == Some(&version.version);
+    let row_content = push_badges_and_size(
+        row_content,
+        version,
+        meta,
+        is_default,
+        is_broken,
+        is_recommended,
+        ctx,
+    );
 
     let action_style = if show_actions {
         styles::row_action_button
@@ -61,6 +80,11 @@ pub(super) fn version_item_view<'a>(
         is_busy || !show_actions,
         &version_str,
     );
+    let row_content = if is_broken {
+        push_reinstall_button(row_content, action_style, is_busy, &version_str)
+    } else {
+        row_content
+    };
     let row_content = push_uninstall_button(
         row_content,
         danger_style,
@@ -96,7 +120,14 @@ fn push_badges_and_size<'a>(
     version: &'a InstalledVersion,
     meta: Option<&'a versi_core::VersionMeta>,
     is_default: bool,
+    is_broken: bool,
+    is_recommended: bool,
+    ctx: &VersionListContext<'a>,
 ) -> iced::widget::Row<'a, Message> {
+    if is_recommended {
+        row_content = row_content.push(super::available::recommended_badge());
+    }
+
     if let Some(lts) = &version.lts_codename {
         row_content = row_content.push(
             container(text(format!("LTS: {lts}")).size(11))
@@ -113,6 +144,14 @@ fn push_badges_and_size<'a>(
         );
     }
 
+    if is_broken {
+        row_content = row_content.push(
+            container(text("broken").size(11))
+                .padding([2, 6])
+                .style(styles::badge_broken),
+        );
+    }
+
     if meta.is_some_and(|version_meta| version_meta.security) {
         row_content = row_content.push(
             container(text("Security").size(11))
@@ -121,7 +160,20 @@ fn push_badges_and_size<'a>(
         );
     }
 
+    if let Some(note) = ctx.version_notes.get(&version.version.to_string()) {
+        row_content = row_content.push(super::note_label(note));
+    }
+
     row_content = row_content.push(Space::new().width(Length::Fill));
+    if let Some(install_date) = version.install_date {
+        row_content = row_content.push(styled_tooltip(
+            text(format_installed_ago(install_date, ctx.as_of, ctx.language))
+                .size(11)
+                .color(crate::theme::tokens::TEXT_MUTED),
+            install_date.format("%Y-%m-%d %H:%M UTC").to_string(),
+            iced::widget::tooltip::Position::Top,
+        ));
+    }
     if let Some(size) = version.disk_size {
         row_content = row_content.push(text(format_bytes(size)).size(12));
     }
@@ -156,6 +208,23 @@ fn push_set_default_button<'a>(
     }
 }
 
+fn push_reinstall_button<'a>(
+    row_content: iced::widget::Row<'a, Message>,
+    action_style: fn(&iced::Theme, iced::widget::button::Status) -> iced::widget::button::Style,
+    is_disabled: bool,
+    version: &str,
+) -> iced::widget::Row<'a, Message> {
+    let button = button(text("Reinstall").size(12))
+        .style(action_style)
+        .padding([6, 12]);
+
+    if is_disabled {
+        row_content.push(button)
+    } else {
+        row_content.push(button.on_press(Message::ReinstallVersion(version.to_string())))
+    }
+}
+
 fn push_uninstall_button<'a>(
     row_content: iced::widget::Row<'a, Message>,
     danger_style: fn(&iced::Theme, iced::widget::button::Status) -> iced::widget::button::Style,
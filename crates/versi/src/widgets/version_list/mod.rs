@@ -5,7 +5,8 @@ mod item;
 
 use std::collections::{HashMap, HashSet};
 
-use iced::widget::{Space, button, column, container, scrollable, text};
+use chrono::{DateTime, Utc};
+use iced::widget::{Space, button, column, container, row, scrollable, text};
 use iced::{Alignment, Element, Length};
 
 use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionGroup};
@@ -13,8 +14,12 @@ use versi_core::{ReleaseSchedule, VersionMeta};
 
 use crate::message::Message;
 use crate::state::{EnvironmentState, OperationQueue, SearchFilter};
+use crate::strings::{Language, t};
 use crate::theme::styles;
-use crate::version_query::{matches_version_query_case_insensitive, passes_release_filters};
+use crate::version_query::{
+    QuerySuggestion, ResultFilters, group_results_by_major, matches_version_query_case_insensitive,
+    passes_release_filters, resolve_alias_with_index, suggest_for_query,
+};
 
 use filters::search_available_versions;
 
@@ -25,6 +30,37 @@ pub struct VersionListContext<'a> {
     pub hovered_version: &'a Option<String>,
     pub metadata: Option<&'a HashMap<String, VersionMeta>>,
     pub installed_set: &'a HashSet<NodeVersion>,
+    pub broken_versions: &'a HashSet<NodeVersion>,
+    pub pinned_versions: &'a [String],
+    /// Checked versions, present while selection mode is on.
+    pub selection: Option<&'a HashSet<String>>,
+    pub recommended: Option<&'a NodeVersion>,
+    /// Whether versions are shown with their `v` prefix.
+    pub version_prefix: bool,
+    /// Only list installed majors that have a newer release.
+    pub updatable_only: bool,
+    /// Majors whose search result group is collapsed.
+    pub collapsed_search_majors: &'a HashSet<u32>,
+    pub version_notes: &'a HashMap<String, String>,
+    pub language: Language,
+    /// Instant release phases are judged at, taken once per frame.
+    pub as_of: DateTime<Utc>,
+    /// Whether the list of available versions is still being fetched.
+    pub fetching_remote: bool,
+}
+
+const NOTE_PREVIEW_CHARS: usize = 40;
+
+/// A version's note, shortened to fit on its row.
+fn note_label<'a>(note: &str) -> Element<'a, Message> {
+    let mut preview: String = note.chars().take(NOTE_PREVIEW_CHARS).collect();
+    if note.chars().count() > NOTE_PREVIEW_CHARS {
+        preview.push('\u{2026}');
+    }
+    text(preview)
+        .size(11)
+        .color(crate::theme::tokens::TEXT_MUTED)
+        .into()
 }
 
 fn filter_group(
@@ -33,6 +69,7 @@ fn filter_group(
     query_lower: &str,
     active_filters: &HashSet<SearchFilter>,
     schedule: Option<&ReleaseSchedule>,
+    as_of: DateTime<Utc>,
 ) -> bool {
     if query.is_empty() {
         return true;
@@ -42,7 +79,7 @@ fn filter_group(
         return false;
     }
 
-    if !passes_release_filters(group.major, active_filters, schedule) {
+    if !passes_release_filters(group.major, active_filters, schedule, as_of) {
         return false;
     }
 
@@ -83,6 +120,7 @@ fn filter_version(
     query_lower: &str,
     active_filters: &HashSet<SearchFilter>,
     schedule: Option<&ReleaseSchedule>,
+    as_of: DateTime<Utc>,
     version_text: &mut String,
 ) -> bool {
     if query.is_empty() {
@@ -101,7 +139,7 @@ fn filter_version(
     if active_filters.contains(&SearchFilter::NotInstalled) {
         return false;
     }
-    if !passes_release_filters(version.version.major, active_filters, schedule) {
+    if !passes_release_filters(version.version.major, active_filters, schedule, as_of) {
         return false;
     }
 
@@ -132,13 +170,19 @@ pub fn view<'a>(
     active_filters: &'a HashSet<SearchFilter>,
     ctx: &VersionListContext<'a>,
 ) -> Element<'a, Message> {
-    if let Some(status_view) = loading_or_error_view(env) {
+    if let Some(status_view) = loading_or_error_view(env, ctx.language) {
         return status_view;
     }
 
     let query_lower = search_query.to_lowercase();
 
     let mut content_items: Vec<Element<Message>> = Vec::new();
+    if search_query.is_empty()
+        && !ctx.updatable_only
+        && let Some(pinned) = pinned_section(env, ctx)
+    {
+        content_items.push(pinned);
+    }
     content_items.extend(installed_groups_content(
         env,
         search_query,
@@ -147,18 +191,35 @@ pub fn view<'a>(
         active_filters,
         ctx,
     ));
-    if let Some(search_results) = search_results_content(
+    content_items.extend(search_results_content(
         remote_versions,
         search_query,
         search_results_limit,
         active_filters,
         ctx,
-    ) {
-        content_items.push(search_results);
+    ));
+
+    if content_items.is_empty() && ctx.updatable_only && search_query.is_empty() {
+        return container(text("Every installed major is up to date").size(14))
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
     if content_items.is_empty() {
-        return empty_versions_view(search_query);
+        let latest_lts = latest_lts_action(remote_versions, ctx);
+        let suggestion = (!search_query.is_empty())
+            .then(|| suggest_for_query(remote_versions, search_query))
+            .flatten();
+        return empty_versions_view(
+            search_query,
+            !active_filters.is_empty(),
+            latest_lts,
+            ctx.fetching_remote,
+            suggestion,
+            ctx.language,
+        );
     }
 
     scrollable(
@@ -170,11 +231,14 @@ pub fn view<'a>(
     .into()
 }
 
-fn loading_or_error_view(env: &EnvironmentState) -> Option<Element<'_, Message>> {
+fn loading_or_error_view(
+    env: &EnvironmentState,
+    language: Language,
+) -> Option<Element<'_, Message>> {
     if env.loading && env.installed_versions.is_empty() {
         return Some(
             container(
-                column![text("Loading versions...").size(16),]
+                column![text(t(language, "versions.loading")).size(16),]
                     .spacing(8)
                     .align_x(Alignment::Center),
             )
@@ -188,10 +252,10 @@ fn loading_or_error_view(env: &EnvironmentState) -> Option<Element<'_, Message>>
     env.error.as_ref().map(|error| {
         container(
             column![
-                text("Error loading versions").size(16),
+                text(t(language, "versions.load_error")).size(16),
                 text(error.to_string()).size(14),
                 Space::new().height(16),
-                button(text("Retry"))
+                button(text(t(language, "versions.retry")))
                     .on_press(Message::RefreshEnvironment)
                     .style(styles::primary_button)
                     .padding([8, 16]),
@@ -206,6 +270,52 @@ fn loading_or_error_view(env: &EnvironmentState) -> Option<Element<'_, Message>>
     })
 }
 
+/// Installed versions in the order they were pinned. Pins for versions
+/// missing from this environment are skipped.
+fn pinned_installed<'a>(
+    env: &'a EnvironmentState,
+    pinned_versions: &[String],
+) -> Vec<&'a InstalledVersion> {
+    pinned_versions
+        .iter()
+        .filter_map(|pinned| {
+            env.installed_versions
+                .iter()
+                .find(|installed| installed.version.to_string() == *pinned)
+        })
+        .collect()
+}
+
+fn pinned_section<'a>(
+    env: &'a EnvironmentState,
+    ctx: &VersionListContext<'a>,
+) -> Option<Element<'a, Message>> {
+    let pinned = pinned_installed(env, ctx.pinned_versions);
+    if pinned.is_empty() {
+        return None;
+    }
+
+    let mut items: Vec<Element<Message>> = vec![
+        text("Pinned")
+            .size(12)
+            .color(crate::theme::tokens::TEXT_MUTED)
+            .into(),
+    ];
+    items.extend(
+        pinned
+            .into_iter()
+            .map(|version| item::version_item_view(version, env.default_version.as_ref(), ctx)),
+    );
+
+    Some(
+        container(column(items).spacing(2))
+            .style(styles::card_container)
+            .padding(12)
+            .width(Length::Fill)
+            .into(),
+    )
+}
+
 fn installed_groups_content<'a>(
     env: &'a EnvironmentState,
     search_query: &'a str,
@@ -224,6 +334,7 @@ fn installed_groups_content<'a>(
                 query_lower,
                 active_filters,
                 ctx.schedule,
+                ctx.as_of,
             )
         })
         .collect();
@@ -233,9 +344,10 @@ fn installed_groups_content<'a>(
     }
 
     filtered_groups
-        .iter()
-        .map(|group| {
-            let update_available = update_available_for_group(group, latest_by_major);
+        .into_iter()
+        .map(|group| (group, update_available_for_group(group, latest_by_major)))
+        .filter(|(_, update_available)| !ctx.updatable_only || update_available.is_some())
+        .map(|(group, update_available)| {
             group::version_group_view(
                 group,
                 env.default_version.as_ref(),
@@ -249,6 +361,19 @@ fn installed_groups_content<'a>(
         .collect()
 }
 
+/// Installed majors with a newer release, paired with that release.
+pub(crate) fn updatable_groups<'a>(
+    groups: &'a [VersionGroup],
+    latest_by_major: &HashMap<u32, NodeVersion>,
+) -> Vec<(&'a VersionGroup, String)> {
+    groups
+        .iter()
+        .filter_map(|group| {
+            update_available_for_group(group, latest_by_major).map(|latest| (group, latest))
+        })
+        .collect()
+}
+
 fn update_available_for_group(
     group: &VersionGroup,
     latest_by_major: &HashMap<u32, NodeVersion>,
@@ -271,9 +396,9 @@ fn search_results_content<'a>(
     search_results_limit: usize,
     active_filters: &'a HashSet<SearchFilter>,
     ctx: &VersionListContext<'a>,
-) -> Option<Element<'a, Message>> {
+) -> Vec<Element<'a, Message>> {
     if search_query.is_empty() {
-        return None;
+        return Vec::new();
     }
 
     let search = search_available_versions(
@@ -281,64 +406,188 @@ fn search_results_content<'a>(
         ctx.search_index,
         search_query,
         search_results_limit,
-        active_filters,
-        ctx.installed_set,
-        ctx.schedule,
+        ResultFilters {
+            active: active_filters,
+            installed_set: ctx.installed_set,
+            schedule: ctx.schedule,
+            as_of: ctx.as_of,
+        },
     );
 
     if search.versions.is_empty() {
-        return None;
+        return Vec::new();
     }
 
-    let mut card_items: Vec<Element<Message>> = Vec::new();
-    if search.alias_resolved {
-        card_items.push(
-            text(format!("\"{search_query}\" resolves to:"))
-                .size(12)
-                .color(crate::theme::tokens::TEXT_MUTED)
-                .into(),
-        );
-        card_items.push(Space::new().height(4).into());
+    if !search.alias_resolved {
+        let mut items: Vec<Element<Message>> = group_results_by_major(&search.versions)
+            .into_iter()
+            .map(|(major, versions)| {
+                group::search_result_group_view(major, &versions, search_query, ctx)
+            })
+            .collect();
+        if search.total > search.versions.len() {
+            items.push(results_count_row(search.versions.len(), search.total));
+        }
+        return items;
     }
+
+    let mut card_items: Vec<Element<Message>> = vec![
+        text(format!("\"{search_query}\" resolves to:"))
+            .size(12)
+            .color(crate::theme::tokens::TEXT_MUTED)
+            .into(),
+        Space::new().height(4).into(),
+    ];
     for version in &search.versions {
-        card_items.push(available::available_version_row(version, ctx));
+        card_items.push(available::available_version_row(version, None, ctx));
     }
 
-    Some(
+    vec![
         container(column(card_items).spacing(4))
             .style(styles::card_container)
             .padding(12)
             .into(),
-    )
+    ]
 }
 
-fn empty_versions_view(search_query: &str) -> Element<'_, Message> {
-    container(
-        column![
-            text("No versions found").size(16),
-            if search_query.is_empty() {
-                text("Install your first Node.js version by searching above.").size(14)
-            } else {
-                text(format!("No versions match '{search_query}'")).size(14)
-            },
-        ]
-        .spacing(8)
-        .align_x(Alignment::Center),
-    )
-    .center_x(Length::Fill)
-    .center_y(Length::Fill)
-    .height(Length::Fill)
+fn results_count_row<'a>(shown: usize, total: usize) -> Element<'a, Message> {
+    row![
+        text(format!("Showing {shown} of {total}"))
+            .size(12)
+            .color(crate::theme::tokens::TEXT_MUTED),
+        Space::new().width(Length::Fill),
+        button(text("Show more").size(12))
+            .on_press(Message::ShowMoreSearchResults)
+            .style(styles::ghost_button)
+            .padding([4, 8]),
+    ]
+    .align_y(Alignment::Center)
+    .padding([0, 12])
     .into()
 }
 
+#[derive(Debug, Clone)]
+struct LatestLtsAction {
+    label: String,
+    on_press: Option<Message>,
+}
+
+fn latest_lts_action(
+    remote_versions: &[RemoteVersion],
+    ctx: &VersionListContext,
+) -> LatestLtsAction {
+    let Some(latest) = resolve_alias_with_index(remote_versions, ctx.search_index, "lts/*") else {
+        return LatestLtsAction {
+            label: "Install latest LTS".to_string(),
+            on_press: None,
+        };
+    };
+
+    let version = latest.version.to_string();
+    if ctx.operation_queue.has_active_install(&version) {
+        return LatestLtsAction {
+            label: format!("Installing {version}..."),
+            on_press: None,
+        };
+    }
+
+    LatestLtsAction {
+        label: format!("Install latest LTS ({version})"),
+        on_press: Some(Message::InstallLatestLts),
+    }
+}
+
+fn suggestion_action(suggestion: QuerySuggestion) -> (String, String, Message) {
+    match suggestion {
+        QuerySuggestion::NearestMajor { requested, nearest } => (
+            format!("There is no Node {requested} release line."),
+            format!("Search Node {nearest}"),
+            Message::SearchChanged(format!("v{nearest}")),
+        ),
+        QuerySuggestion::Codename(codename) => (
+            format!("Did you mean {codename}?"),
+            format!("Search {codename}"),
+            Message::SearchChanged(codename.to_lowercase()),
+        ),
+    }
+}
+
+fn empty_versions_view(
+    search_query: &str,
+    has_filters: bool,
+    latest_lts: LatestLtsAction,
+    fetching_remote: bool,
+    suggestion: Option<QuerySuggestion>,
+    language: Language,
+) -> Element<'_, Message> {
+    let mut content = column![text(t(language, "versions.empty")).size(16)]
+        .spacing(8)
+        .align_x(Alignment::Center);
+
+    if search_query.is_empty() {
+        content = content
+            .push(text(t(language, "versions.empty_hint")).size(14))
+            .push(Space::new().height(8))
+            .push(
+                button(text(latest_lts.label).size(14))
+                    .on_press_maybe(latest_lts.on_press)
+                    .style(styles::primary_button)
+                    .padding([10, 16]),
+            );
+        if fetching_remote {
+            content = content.push(
+                text(t(language, "versions.waiting_for_remote"))
+                    .size(11)
+                    .color(crate::theme::tokens::TEXT_MUTED),
+            );
+        }
+    } else {
+        content = content.push(text(format!("No versions match '{search_query}'")).size(14));
+        if let Some(suggestion) = suggestion {
+            let (hint, label, message) = suggestion_action(suggestion);
+            content = content.push(text(hint).size(12).color(crate::theme::tokens::TEXT_MUTED));
+            content = content.push(
+                button(text(label).size(13))
+                    .on_press(message)
+                    .style(styles::primary_button)
+                    .padding([8, 14]),
+            );
+        }
+        if has_filters {
+            content = content.push(
+                button(text("Clear filters").size(13))
+                    .on_press(Message::ClearSearchFilters)
+                    .style(styles::secondary_button)
+                    .padding([8, 14]),
+            );
+        }
+    }
+
+    container(content)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
-
-    use super::update_available_for_group;
-    use crate::state::SearchFilter;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::LazyLock;
+
+    use chrono::TimeZone;
+    use versi_platform::EnvironmentId;
+
+    use super::{
+        VersionListContext, latest_lts_action, pinned_installed, updatable_groups,
+        update_available_for_group,
+    };
+    use crate::backend_kind::BackendKind;
+    use crate::message::Message;
+    use crate::state::{EnvironmentState, OperationQueue, SearchFilter};
+    use crate::strings::Language;
     use crate::version_query::{matches_version_query, passes_release_filters};
-    use versi_backend::{InstalledVersion, NodeVersion, VersionGroup};
+    use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionGroup};
 
     fn installed(version: &str) -> InstalledVersion {
         InstalledVersion {
@@ -372,45 +621,62 @@ mod tests {
     #[test]
     fn matches_query_handles_versions_and_lts_codenames() {
         let version_text = NodeVersion::new(22, 11, 0).to_string();
-        assert!(matches_version_query(
-            &version_text,
-            Some("jod"),
-            "22",
-            "22"
-        ));
-        assert!(matches_version_query(
-            &version_text,
-            Some("jod"),
-            "jod",
-            "jod"
-        ));
-        assert!(matches_version_query(
-            &version_text,
-            Some("jod"),
-            "lts",
-            "lts"
-        ));
-        assert!(!matches_version_query(&version_text, None, "lts", "lts"));
+        assert!(matches_version_query(&version_text, Some("jod"), "22", "22").is_some());
+        assert!(matches_version_query(&version_text, Some("jod"), "jod", "jod").is_some());
+        assert!(matches_version_query(&version_text, Some("jod"), "lts", "lts").is_some());
+        assert!(matches_version_query(&version_text, None, "lts", "lts").is_none());
     }
 
     #[test]
     fn release_filters_respect_eol_and_active_flags() {
         let schedule = schedule_with_eol_major(20);
-        assert!(passes_release_filters(22, &HashSet::new(), Some(&schedule)));
+        let as_of = chrono::Utc
+            .with_ymd_and_hms(2026, 10, 16, 12, 0, 0)
+            .unwrap();
+        assert!(passes_release_filters(
+            22,
+            &HashSet::new(),
+            Some(&schedule),
+            as_of
+        ));
         assert!(!passes_release_filters(
             22,
             &HashSet::from([SearchFilter::Eol]),
-            Some(&schedule)
+            Some(&schedule),
+            as_of
         ));
         assert!(!passes_release_filters(
             20,
             &HashSet::from([SearchFilter::Active]),
-            Some(&schedule)
+            Some(&schedule),
+            as_of
         ));
         assert!(passes_release_filters(
             20,
             &HashSet::from([SearchFilter::Eol]),
-            Some(&schedule)
+            Some(&schedule),
+            as_of
+        ));
+    }
+
+    #[test]
+    fn release_filters_treat_unscheduled_new_major_as_active() {
+        let schedule = schedule_with_eol_major(20);
+        let as_of = chrono::Utc
+            .with_ymd_and_hms(2026, 10, 16, 12, 0, 0)
+            .unwrap();
+
+        assert!(passes_release_filters(
+            30,
+            &HashSet::from([SearchFilter::Active]),
+            Some(&schedule),
+            as_of
+        ));
+        assert!(!passes_release_filters(
+            30,
+            &HashSet::from([SearchFilter::Eol]),
+            Some(&schedule),
+            as_of
         ));
     }
 
@@ -433,4 +699,108 @@ mod tests {
         let latest_equal = std::collections::HashMap::from([(22, NodeVersion::new(22, 1, 0))]);
         assert_eq!(update_available_for_group(&group, &latest_equal), None);
     }
+
+    #[test]
+    fn updatable_groups_lists_only_majors_with_newer_releases() {
+        let groups = VersionGroup::from_versions(&[
+            installed("v22.1.0"),
+            installed("v20.11.0"),
+            installed("v20.10.0"),
+            installed("v18.19.1"),
+        ]);
+        let latest = std::collections::HashMap::from([
+            (22, NodeVersion::new(22, 3, 0)),
+            (20, NodeVersion::new(20, 11, 0)),
+            (18, NodeVersion::new(18, 20, 4)),
+        ]);
+
+        let mut updatable: Vec<(u32, String)> = updatable_groups(&groups, &latest)
+            .into_iter()
+            .map(|(group, latest)| (group.major, latest))
+            .collect();
+        updatable.sort_unstable();
+
+        assert_eq!(
+            updatable,
+            vec![(18, "v18.20.4".to_string()), (22, "v22.3.0".to_string())]
+        );
+    }
+
+    fn remote(version: &str, lts_codename: Option<&str>) -> RemoteVersion {
+        RemoteVersion {
+            version: version.parse().expect("test version should parse"),
+            lts_codename: lts_codename.map(str::to_string),
+            is_latest: false,
+        }
+    }
+
+    static NO_VERSIONS: LazyLock<HashSet<NodeVersion>> = LazyLock::new(HashSet::new);
+    static NO_MAJORS: LazyLock<HashSet<u32>> = LazyLock::new(HashSet::new);
+    static NO_NOTES: LazyLock<HashMap<String, String>> = LazyLock::new(HashMap::new);
+
+    fn test_ctx(queue: &OperationQueue) -> VersionListContext<'_> {
+        VersionListContext {
+            schedule: None,
+            search_index: None,
+            operation_queue: queue,
+            hovered_version: &None,
+            metadata: None,
+            installed_set: &NO_VERSIONS,
+            broken_versions: &NO_VERSIONS,
+            pinned_versions: &[],
+            selection: None,
+            recommended: None,
+            version_prefix: true,
+            updatable_only: false,
+            collapsed_search_majors: &NO_MAJORS,
+            version_notes: &NO_NOTES,
+            language: Language::English,
+            as_of: chrono::Utc::now(),
+            fetching_remote: false,
+        }
+    }
+
+    #[test]
+    fn latest_lts_action_is_disabled_until_versions_load() {
+        let queue = OperationQueue::new();
+        let ctx = test_ctx(&queue);
+
+        let action = latest_lts_action(&[], &ctx);
+        assert_eq!(action.label, "Install latest LTS");
+        assert!(action.on_press.is_none());
+
+        let versions = [
+            remote("v24.1.0", None),
+            remote("v22.11.0", Some("Jod")),
+            remote("v20.18.0", Some("Iron")),
+        ];
+        let action = latest_lts_action(&versions, &ctx);
+        assert_eq!(action.label, "Install latest LTS (v22.11.0)");
+        assert!(matches!(action.on_press, Some(Message::InstallLatestLts)));
+    }
+
+    #[test]
+    fn latest_lts_action_is_disabled_while_installing() {
+        let mut queue = OperationQueue::new();
+        queue.start_install("v22.11.0".to_string());
+        let ctx = test_ctx(&queue);
+
+        let action = latest_lts_action(&[remote("v22.11.0", Some("Jod"))], &ctx);
+        assert_eq!(action.label, "Installing v22.11.0...");
+        assert!(action.on_press.is_none());
+    }
+
+    #[test]
+    fn pinned_installed_keeps_pin_order_and_skips_missing() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, BackendKind::Fnm, None);
+        env.update_versions(vec![installed("v22.1.0"), installed("v20.11.0")]);
+        let pins = ["v20.11.0", "v18.0.0", "v22.1.0"].map(str::to_string);
+
+        let pinned: Vec<String> = pinned_installed(&env, &pins)
+            .iter()
+            .map(|installed| installed.version.to_string())
+            .collect();
+
+        assert_eq!(pinned, vec!["v20.11.0", "v22.1.0"]);
+    }
 }